@@ -0,0 +1,1509 @@
+use crate::conf_int::t_bounds;
+use crate::regression::design_matrix;
+use crate::utils::*;
+use js_sys::Object;
+use js_sys::Reflect;
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use statrs::distribution::{Beta, Binomial, ContinuousCDF, Gamma, Normal};
+use statrs::function::gamma::ln_gamma;
+use statrs::statistics::Statistics;
+use wasm_bindgen::prelude::*;
+
+/// Performs a Bayesian t-test with the JZS (Cauchy) prior on the standardized effect
+/// size, reporting the Bayes factor and the posterior estimate of the effect.
+///
+/// The Bayes factor integrates over the Zellner-Siow g-prior representation of the
+/// Cauchy prior, and the posterior mean and standard deviation of the effect are
+/// averaged over the posterior of g.
+///
+/// # Arguments
+///
+/// * `column1` - A reference to a JsValue representing the first JavaScript array.
+/// * `column2` - A reference to a JsValue representing the second JavaScript array, or
+///   undefined for a one-sample test.
+/// * `options` - An optional object with the properties `paired` (treat the columns as
+///   matched pairs; defaults to false), `mu0` (the null mean for one-sample and paired
+///   designs; defaults to 0), and `r` (the Cauchy prior scale; defaults to √2/2).
+///
+/// # Returns
+///
+/// * An object with the properties `bf10`, `bf01`, `t`, `df`, `design`, `posterior_mean`
+///   and `posterior_sd` (of the standardized effect size), or null if the inputs are
+///   invalid.
+#[wasm_bindgen]
+pub fn bayes_t_test(column1: &JsValue, column2: &JsValue, options: &JsValue) -> JsValue {
+    let paired = get_bool_option(options, "paired");
+    let mu0 = get_f64_option(options, "mu0").unwrap_or(0.0);
+    let r = get_f64_option(options, "r").unwrap_or(std::f64::consts::FRAC_1_SQRT_2);
+
+    let c1 = js_array_to_vector(column1);
+    let c2 = if column2.is_undefined() || column2.is_null() {
+        None
+    } else {
+        Some(js_array_to_vector(column2))
+    };
+
+    if r <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    // Reduce each design to an effective sample size, a mean difference from the null,
+    // a sum of squares, and degrees of freedom
+    let (design, n_eff, diff, ss, df) = match c2 {
+        Some(c2) if !paired => {
+            if c1.len() < 2 || c2.len() < 2 {
+                return JsValue::NULL;
+            }
+            let n1 = c1.len() as f64;
+            let n2 = c2.len() as f64;
+            let mean1 = c1.iter().mean();
+            let mean2 = c2.iter().mean();
+            let ss = c1.iter().map(|x| (x - mean1).powi(2)).sum::<f64>()
+                + c2.iter().map(|x| (x - mean2).powi(2)).sum::<f64>();
+            (
+                "two-sample",
+                n1 * n2 / (n1 + n2),
+                mean1 - mean2,
+                ss,
+                n1 + n2 - 2.0,
+            )
+        }
+        other => {
+            let data: Vec<f64> = match other {
+                Some(c2) if c2.len() == c1.len() => {
+                    c1.iter().zip(&c2).map(|(a, b)| a - b).collect()
+                }
+                Some(_) => return JsValue::NULL,
+                None => c1,
+            };
+            if data.len() < 2 {
+                return JsValue::NULL;
+            }
+            let n = data.len() as f64;
+            let mean = data.iter().mean();
+            let ss = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+            let design = if paired { "paired" } else { "one-sample" };
+            (design, n, mean - mu0, ss, n - 1.0)
+        }
+    };
+
+    let t = diff / (ss / df / n_eff).sqrt();
+
+    // Integrate over u = ln(g) with the trapezoid rule, in log space for stability
+    let steps = 4000;
+    let (u_min, u_max) = (-30.0, 40.0);
+    let h = (u_max - u_min) / steps as f64;
+    let shape = (df + 1.0) / 2.0;
+
+    let mut log_terms = Vec::with_capacity(steps + 1);
+    let mut mean_terms = Vec::with_capacity(steps + 1);
+    let mut second_moment_terms = Vec::with_capacity(steps + 1);
+    for i in 0..=steps {
+        let u = u_min + i as f64 * h;
+        let g = u.exp();
+        let scale = 1.0 + n_eff * g * r.powi(2);
+        let weight: f64 = if i == 0 || i == steps { 0.5 } else { 1.0 };
+
+        // Marginal likelihood ratio given g times the inverse-gamma(1/2, 1/2) prior on g
+        // and the Jacobian of the substitution
+        let log_term = -0.5 * scale.ln()
+            - shape * (1.0 + t.powi(2) / (scale * df)).ln()
+            - 0.5 * (2.0 * std::f64::consts::PI).ln()
+            - 1.5 * u
+            - 1.0 / (2.0 * g)
+            + u
+            + weight.ln();
+        log_terms.push(log_term);
+
+        // Conditional posterior moments of the effect given g
+        let shrink = n_eff * g * r.powi(2) / scale;
+        let b = (ss + n_eff * diff.powi(2) / scale) / 2.0;
+        let inv_sigma = (ln_gamma(shape + 0.5) - ln_gamma(shape)).exp() / b.sqrt();
+        let inv_sigma2 = shape / b;
+        let cond_mean = shrink * diff * inv_sigma;
+        let cond_second = shrink / n_eff + (shrink * diff).powi(2) * inv_sigma2;
+        mean_terms.push(cond_mean);
+        second_moment_terms.push(cond_second);
+    }
+
+    let max_log = log_terms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = log_terms.iter().map(|l| (l - max_log).exp()).collect();
+    let total = weights.iter().sum::<f64>();
+    let log_marginal = max_log + (total * h).ln();
+    let log_null = -shape * (1.0 + t.powi(2) / df).ln();
+    let bf10 = (log_marginal - log_null).exp();
+
+    let posterior_mean = weights
+        .iter()
+        .zip(&mean_terms)
+        .map(|(w, m)| w * m)
+        .sum::<f64>()
+        / total;
+    let posterior_second = weights
+        .iter()
+        .zip(&second_moment_terms)
+        .map(|(w, m)| w * m)
+        .sum::<f64>()
+        / total;
+    let posterior_sd = (posterior_second - posterior_mean.powi(2)).max(0.0).sqrt();
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("bf10"), &JsValue::from_f64(bf10));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("bf01"),
+        &JsValue::from_f64(1.0 / bf10),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("t"), &JsValue::from_f64(t));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("design"),
+        &JsValue::from_str(design),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_mean"),
+        &JsValue::from_f64(posterior_mean),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_sd"),
+        &JsValue::from_f64(posterior_sd),
+    );
+    obj.into()
+}
+
+/// Finds a quantile of a beta distribution by bisection on its CDF.
+pub(crate) fn beta_quantile(dist: &Beta, p: f64) -> f64 {
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..60 {
+        let mid = (low + high) / 2.0;
+        if dist.cdf(mid) < p {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// Performs a Bayesian A/B test on two binomial proportions with conjugate beta priors.
+///
+/// The posterior probabilities and expected losses are computed exactly by numerical
+/// integration over the quantiles of the posterior for A, rather than by Monte Carlo.
+///
+/// # Arguments
+///
+/// * `successes_a` - A reference to a JsValue representing the number of successes in A.
+/// * `n_a` - A reference to a JsValue representing the number of trials in A.
+/// * `successes_b` - A reference to a JsValue representing the number of successes in B.
+/// * `n_b` - A reference to a JsValue representing the number of trials in B.
+/// * `prior` - An optional object with the beta prior parameters `alpha` and `beta`,
+///   shared by both groups; defaults to the uniform prior Beta(1, 1).
+///
+/// # Returns
+///
+/// * An object with the properties `prob_b_better` (P(B > A)), `expected_loss_a` and
+///   `expected_loss_b` (the expected shortfall in rate from choosing each variant), and
+///   `a`, `b`, and `difference` (B − A), each holding an `estimate` (the posterior mean)
+///   and a 95% credible interval `ci`. Returns null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_ab_test(
+    successes_a: &JsValue,
+    n_a: &JsValue,
+    successes_b: &JsValue,
+    n_b: &JsValue,
+    prior: &JsValue,
+) -> JsValue {
+    let (successes_a, n_a, successes_b, n_b) = match (
+        successes_a.as_f64(),
+        n_a.as_f64(),
+        successes_b.as_f64(),
+        n_b.as_f64(),
+    ) {
+        (Some(sa), Some(na), Some(sb), Some(nb)) => (sa, na, sb, nb),
+        _ => return JsValue::NULL,
+    };
+    let prior_alpha = get_f64_option(prior, "alpha").unwrap_or(1.0);
+    let prior_beta = get_f64_option(prior, "beta").unwrap_or(1.0);
+
+    if [successes_a, n_a, successes_b, n_b, prior_alpha, prior_beta]
+        .iter()
+        .any(|v| !v.is_finite())
+        || successes_a < 0.0
+        || successes_b < 0.0
+        || successes_a > n_a
+        || successes_b > n_b
+        || prior_alpha <= 0.0
+        || prior_beta <= 0.0
+    {
+        return JsValue::NULL;
+    }
+
+    let (alpha_a, beta_a) = (prior_alpha + successes_a, prior_beta + n_a - successes_a);
+    let (alpha_b, beta_b) = (prior_alpha + successes_b, prior_beta + n_b - successes_b);
+    let dist_a = Beta::new(alpha_a, beta_a).unwrap();
+    let dist_b = Beta::new(alpha_b, beta_b).unwrap();
+    // The size-biased posterior of B, used for the partial expectations of its rate
+    let dist_b_shifted = Beta::new(alpha_b + 1.0, beta_b).unwrap();
+    let mean_a = alpha_a / (alpha_a + beta_a);
+    let mean_b = alpha_b / (alpha_b + beta_b);
+
+    // Integrate over A by averaging over its quantiles at the midpoints of a uniform grid
+    let grid = 2000;
+    let quantiles_a: Vec<f64> = (0..grid)
+        .map(|i| beta_quantile(&dist_a, (i as f64 + 0.5) / grid as f64))
+        .collect();
+
+    let mut prob_b_better = 0.0;
+    let mut expected_loss_a = 0.0;
+    let mut expected_loss_b = 0.0;
+    for &x in &quantiles_a {
+        let cdf_b = dist_b.cdf(x);
+        let cdf_b_shifted = dist_b_shifted.cdf(x);
+        prob_b_better += 1.0 - cdf_b;
+        expected_loss_a += mean_b * (1.0 - cdf_b_shifted) - x * (1.0 - cdf_b);
+        expected_loss_b += x * cdf_b - mean_b * cdf_b_shifted;
+    }
+    prob_b_better /= grid as f64;
+    expected_loss_a /= grid as f64;
+    expected_loss_b /= grid as f64;
+
+    // Invert the CDF of the difference P(B - A <= d) by bisection
+    let difference_quantile = |p: f64| {
+        let (mut low, mut high) = (-1.0, 1.0);
+        for _ in 0..50 {
+            let mid = (low + high) / 2.0;
+            let cdf = quantiles_a
+                .iter()
+                .map(|x| dist_b.cdf((x + mid).clamp(0.0, 1.0)))
+                .sum::<f64>()
+                / grid as f64;
+            if cdf < p {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        (low + high) / 2.0
+    };
+
+    let level = 0.05;
+    let summary_a = Object::new();
+    set_estimate(
+        &summary_a,
+        mean_a,
+        (
+            beta_quantile(&dist_a, level / 2.0),
+            beta_quantile(&dist_a, 1.0 - level / 2.0),
+        ),
+    );
+    let summary_b = Object::new();
+    set_estimate(
+        &summary_b,
+        mean_b,
+        (
+            beta_quantile(&dist_b, level / 2.0),
+            beta_quantile(&dist_b, 1.0 - level / 2.0),
+        ),
+    );
+    let difference = Object::new();
+    set_estimate(
+        &difference,
+        mean_b - mean_a,
+        (
+            difference_quantile(level / 2.0),
+            difference_quantile(1.0 - level / 2.0),
+        ),
+    );
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("prob_b_better"),
+        &JsValue::from_f64(prob_b_better),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected_loss_a"),
+        &JsValue::from_f64(expected_loss_a),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected_loss_b"),
+        &JsValue::from_f64(expected_loss_b),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("a"), &summary_a);
+    let _ = Reflect::set(&obj, &JsValue::from_str("b"), &summary_b);
+    let _ = Reflect::set(&obj, &JsValue::from_str("difference"), &difference);
+    obj.into()
+}
+
+/// Reads the normal-inverse-gamma prior for a mean, defaulting to the reference prior.
+fn mean_prior(prior: &JsValue) -> (f64, f64, f64, f64) {
+    (
+        get_f64_option(prior, "mu0").unwrap_or(0.0),
+        get_f64_option(prior, "kappa0").unwrap_or(0.0),
+        get_f64_option(prior, "a0").unwrap_or(-0.5),
+        get_f64_option(prior, "b0").unwrap_or(0.0),
+    )
+}
+
+/// Updates a normal-inverse-gamma prior `(mu, kappa, a, b)` with the data, returning the
+/// posterior parameters, or `None` if the data are empty or the posterior is improper.
+fn mean_posterior(data: &[f64], prior: (f64, f64, f64, f64)) -> Option<(f64, f64, f64, f64)> {
+    let (mu0, kappa0, a0, b0) = prior;
+    if data.is_empty() || kappa0 < 0.0 || b0 < 0.0 {
+        return None;
+    }
+
+    let n = data.len() as f64;
+    let mean = data.iter().mean();
+    let ss = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+
+    let kappa_n = kappa0 + n;
+    let mu_n = (kappa0 * mu0 + n * mean) / kappa_n;
+    let a_n = a0 + n / 2.0;
+    let b_n = b0 + ss / 2.0 + kappa0 * n * (mean - mu0).powi(2) / (2.0 * kappa_n);
+
+    if a_n <= 0.0 || b_n <= 0.0 {
+        return None;
+    }
+    Some((mu_n, kappa_n, a_n, b_n))
+}
+
+/// Calculates a credible interval for a population mean under the conjugate
+/// normal-inverse-gamma model, as a Bayesian alternative to `one_samp_t_interval`.
+///
+/// The marginal posterior of the mean is a scaled Student's t-distribution. With the
+/// default reference prior, the credible interval coincides with the t-interval.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of data.
+/// * `alpha` - A reference to a JsValue representing the credible level complement
+///   (e.g., 0.05 for a 95% credible interval).
+/// * `prior` - An optional object with the normal-inverse-gamma parameters `mu0` (the
+///   prior mean), `kappa0` (the prior pseudo-observations for the mean), and `a0` and
+///   `b0` (the shape and rate for the variance); defaults to the reference prior with
+///   `kappa0 = 0`, `a0 = -1/2`, and `b0 = 0`.
+///
+/// # Returns
+///
+/// * An object with the properties `posterior_mean`, `ci`, `df`, and `scale` (of the
+///   marginal t-distribution), or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_mean_interval(column: &JsValue, alpha: &JsValue, prior: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let data = js_array_to_vector(column);
+
+    let (mu_n, kappa_n, a_n, b_n) = match mean_posterior(&data, mean_prior(prior)) {
+        Some(posterior) => posterior,
+        None => return JsValue::NULL,
+    };
+
+    let df = 2.0 * a_n;
+    let scale = (b_n / (a_n * kappa_n)).sqrt();
+    let (lower, upper) = t_bounds(mu_n, scale, df, alpha);
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_mean"),
+        &JsValue::from_f64(mu_n),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("ci"),
+        &vec_to_jsvalue(vec![lower, upper]),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("scale"), &JsValue::from_f64(scale));
+    obj.into()
+}
+
+/// Estimates a binomial proportion with a conjugate beta prior.
+///
+/// The 95% highest-density interval is found by minimizing the width of the interval
+/// over the posterior probability left in its lower tail.
+///
+/// # Arguments
+///
+/// * `successes` - A reference to a JsValue representing the number of successes.
+/// * `n` - A reference to a JsValue representing the number of trials.
+/// * `prior_alpha` - A reference to a JsValue representing the first shape parameter of
+///   the beta prior; defaults to 1.
+/// * `prior_beta` - A reference to a JsValue representing the second shape parameter of
+///   the beta prior; defaults to 1.
+///
+/// # Returns
+///
+/// * An object with the properties `posterior_alpha`, `posterior_beta`, `mean`, and
+///   `hdi`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_proportion(
+    successes: &JsValue,
+    n: &JsValue,
+    prior_alpha: &JsValue,
+    prior_beta: &JsValue,
+) -> JsValue {
+    let (successes, n) = match (successes.as_f64(), n.as_f64()) {
+        (Some(successes), Some(n)) => (successes, n),
+        _ => return JsValue::NULL,
+    };
+    let prior_alpha = prior_alpha.as_f64().unwrap_or(1.0);
+    let prior_beta = prior_beta.as_f64().unwrap_or(1.0);
+
+    if [successes, n, prior_alpha, prior_beta]
+        .iter()
+        .any(|v| !v.is_finite())
+        || successes < 0.0
+        || successes > n
+        || prior_alpha <= 0.0
+        || prior_beta <= 0.0
+    {
+        return JsValue::NULL;
+    }
+
+    let posterior_alpha = prior_alpha + successes;
+    let posterior_beta = prior_beta + n - successes;
+    let dist = Beta::new(posterior_alpha, posterior_beta).unwrap();
+    let mean = posterior_alpha / (posterior_alpha + posterior_beta);
+
+    // Golden-section search for the lower tail probability giving the narrowest interval
+    let level = 0.95;
+    let width = |p: f64| beta_quantile(&dist, p + level) - beta_quantile(&dist, p);
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (0.0, 1.0 - level);
+    for _ in 0..60 {
+        let left = high - ratio * (high - low);
+        let right = low + ratio * (high - low);
+        if width(left) < width(right) {
+            high = right;
+        } else {
+            low = left;
+        }
+    }
+    let tail = (low + high) / 2.0;
+    let hdi = vec![
+        beta_quantile(&dist, tail),
+        beta_quantile(&dist, tail + level),
+    ];
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_alpha"),
+        &JsValue::from_f64(posterior_alpha),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_beta"),
+        &JsValue::from_f64(posterior_beta),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("mean"), &JsValue::from_f64(mean));
+    let _ = Reflect::set(&obj, &JsValue::from_str("hdi"), &vec_to_jsvalue(hdi));
+    obj.into()
+}
+
+/// Evaluates the Gauss hypergeometric function 2F1(a, b; c; z) by its power series.
+fn hypergeometric_2f1(a: f64, b: f64, c: f64, z: f64) -> f64 {
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 0..10000 {
+        let k = k as f64;
+        term *= (a + k) * (b + k) / ((c + k) * (k + 1.0)) * z;
+        sum += term;
+        if term.abs() < 1e-15 * sum.abs() {
+            break;
+        }
+    }
+    sum
+}
+
+/// Performs a Bayesian test of the Pearson correlation using the exact likelihood of
+/// the sample correlation and a stretched beta prior on the population correlation.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the first JavaScript array.
+/// * `y` - A reference to a JsValue representing the second JavaScript array.
+/// * `prior` - A reference to a JsValue representing the width `kappa` of the stretched
+///   beta prior on rho; defaults to 1 (the uniform prior on [-1, 1]).
+///
+/// # Returns
+///
+/// * An object with the properties `bf10`, `bf01`, `r`, `n`, and the `posterior_mean`,
+///   `posterior_sd`, and 95% credible interval `ci` of rho, or null if the inputs are
+///   invalid.
+#[wasm_bindgen]
+pub fn bayes_correlation_test(x: &JsValue, y: &JsValue, prior: &JsValue) -> JsValue {
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let kappa = prior.as_f64().unwrap_or(1.0);
+
+    if x.len() != y.len() || x.len() < 3 || kappa <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    let n = x.len() as f64;
+    let mean_x = x.iter().mean();
+    let mean_y = y.iter().mean();
+    let sxy = x
+        .iter()
+        .zip(&y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum::<f64>();
+    let sxx = x.iter().map(|a| (a - mean_x).powi(2)).sum::<f64>();
+    let syy = y.iter().map(|b| (b - mean_y).powi(2)).sum::<f64>();
+    let r = sxy / (sxx * syy).sqrt();
+
+    if r.is_nan() {
+        return JsValue::NULL;
+    }
+
+    // Log of the normalized stretched beta prior density, up to the (1 - rho²) factor
+    let shape = 1.0 / kappa;
+    let log_prior_norm =
+        -((2.0 * shape - 1.0) * 2.0_f64.ln() + 2.0 * ln_gamma(shape) - ln_gamma(2.0 * shape));
+    let log_null = hypergeometric_2f1(0.5, 0.5, n - 0.5, 0.5).ln();
+
+    // Integrate the likelihood ratio against the prior with the midpoint rule
+    let steps = 4000;
+    let h = 2.0 / steps as f64;
+    let rhos: Vec<f64> = (0..steps).map(|i| -1.0 + (i as f64 + 0.5) * h).collect();
+    let log_terms: Vec<f64> = rhos
+        .iter()
+        .map(|rho| {
+            ((n - 1.0) / 2.0 + shape - 1.0) * (1.0 - rho.powi(2)).ln()
+                - (n - 1.5) * (1.0 - rho * r).ln()
+                + hypergeometric_2f1(0.5, 0.5, n - 0.5, (1.0 + rho * r) / 2.0).ln()
+                - log_null
+                + log_prior_norm
+        })
+        .collect();
+
+    let max_log = log_terms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = log_terms.iter().map(|l| (l - max_log).exp()).collect();
+    let total = weights.iter().sum::<f64>();
+    let bf10 = (max_log + (total * h).ln()).exp();
+
+    let posterior_mean = rhos.iter().zip(&weights).map(|(p, w)| p * w).sum::<f64>() / total;
+    let posterior_second = rhos
+        .iter()
+        .zip(&weights)
+        .map(|(p, w)| p.powi(2) * w)
+        .sum::<f64>()
+        / total;
+    let posterior_sd = (posterior_second - posterior_mean.powi(2)).max(0.0).sqrt();
+
+    // Interpolate the quantiles within the posterior mass of each grid cell
+    let quantile = |p: f64| {
+        let mut cumulative = 0.0;
+        for (rho, w) in rhos.iter().zip(&weights) {
+            let mass = w / total;
+            if cumulative + mass >= p {
+                return rho - h / 2.0 + h * (p - cumulative) / mass;
+            }
+            cumulative += mass;
+        }
+        1.0
+    };
+    let ci = vec![quantile(0.025), quantile(0.975)];
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("bf10"), &JsValue::from_f64(bf10));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("bf01"),
+        &JsValue::from_f64(1.0 / bf10),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("r"), &JsValue::from_f64(r));
+    let _ = Reflect::set(&obj, &JsValue::from_str("n"), &JsValue::from_f64(n));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_mean"),
+        &JsValue::from_f64(posterior_mean),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("posterior_sd"),
+        &JsValue::from_f64(posterior_sd),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("ci"), &vec_to_jsvalue(ci));
+    obj.into()
+}
+
+/// Estimates the probability that each variant of a multi-variant experiment has the
+/// highest success rate by simulating draws from the beta posteriors.
+///
+/// # Arguments
+///
+/// * `successes` - A reference to a JsValue representing the JavaScript array of success
+///   counts for each variant.
+/// * `n` - A reference to a JsValue representing the JavaScript array of trial counts for
+///   each variant.
+/// * `prior` - An optional object with the beta prior parameters `alpha` and `beta`,
+///   shared by all variants; defaults to the uniform prior Beta(1, 1).
+/// * `draws` - A reference to a JsValue representing the number of posterior draws;
+///   defaults to 10000.
+/// * `seed` - A reference to a JsValue representing the seed for the random number
+///   generator, or undefined to seed from entropy.
+///
+/// # Returns
+///
+/// * An object with the properties `prob_best` and `expected_loss` (the expected shortfall
+///   in rate from the best variant), each with one entry per variant, or null if the
+///   inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_multi_variant(
+    successes: &JsValue,
+    n: &JsValue,
+    prior: &JsValue,
+    draws: &JsValue,
+    seed: &JsValue,
+) -> JsValue {
+    let successes = js_array_to_vector(successes);
+    let n = js_array_to_vector(n);
+    let prior_alpha = get_f64_option(prior, "alpha").unwrap_or(1.0);
+    let prior_beta = get_f64_option(prior, "beta").unwrap_or(1.0);
+    let draws = draws.as_f64().unwrap_or(10000.0) as usize;
+
+    if successes.is_empty()
+        || successes.len() != n.len()
+        || successes.iter().chain(&n).any(|v| !v.is_finite())
+        || successes.iter().zip(&n).any(|(s, n)| *s < 0.0 || s > n)
+        || !prior_alpha.is_finite()
+        || !prior_beta.is_finite()
+        || prior_alpha <= 0.0
+        || prior_beta <= 0.0
+        || draws == 0
+    {
+        return JsValue::NULL;
+    }
+
+    let posteriors: Vec<Beta> = successes
+        .iter()
+        .zip(&n)
+        .map(|(s, n)| Beta::new(prior_alpha + s, prior_beta + n - s).unwrap())
+        .collect();
+    let mut rng = seeded_rng(seed.as_f64());
+
+    let k = posteriors.len();
+    let mut wins = vec![0.0; k];
+    let mut losses = vec![0.0; k];
+    for _ in 0..draws {
+        let sample: Vec<f64> = posteriors.iter().map(|d| d.sample(&mut rng)).collect();
+        let (best, max) =
+            sample
+                .iter()
+                .enumerate()
+                .fold((0, f64::NEG_INFINITY), |(bi, bv), (i, &v)| {
+                    if v > bv {
+                        (i, v)
+                    } else {
+                        (bi, bv)
+                    }
+                });
+        wins[best] += 1.0;
+        for (loss, value) in losses.iter_mut().zip(&sample) {
+            *loss += max - value;
+        }
+    }
+
+    let prob_best: Vec<f64> = wins.iter().map(|w| w / draws as f64).collect();
+    let expected_loss: Vec<f64> = losses.iter().map(|l| l / draws as f64).collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("prob_best"),
+        &vec_to_jsvalue(prob_best),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected_loss"),
+        &vec_to_jsvalue(expected_loss),
+    );
+    obj.into()
+}
+
+/// The conjugate posterior of a linear regression with an intercept.
+struct RegressionPosterior {
+    design: Vec<Vec<f64>>,
+    coefficients: Vec<f64>,
+    covariance: Vec<Vec<f64>>,
+    a_n: f64,
+    b_n: f64,
+}
+
+/// Computes the quadratic form `v' M v`.
+fn quadratic_form(v: &[f64], m: &[Vec<f64>]) -> f64 {
+    v.iter()
+        .zip(m)
+        .map(|(va, row)| va * row.iter().zip(v).map(|(m, vb)| m * vb).sum::<f64>())
+        .sum()
+}
+
+/// Updates the normal-inverse-gamma regression prior with the data, returning `None` if
+/// the prior is invalid, the predictors are collinear, or the posterior is improper.
+fn regression_posterior(
+    predictors: &[Vec<f64>],
+    y: &[f64],
+    prior: &JsValue,
+) -> Option<RegressionPosterior> {
+    let n = y.len();
+    let p = predictors.len() + 1;
+    let mu0 = match Reflect::get(prior, &JsValue::from_str("mu0")) {
+        Ok(value) if !value.is_undefined() => js_array_to_vector(&value),
+        _ => vec![0.0; p - 1],
+    };
+    let lambda = get_f64_option(prior, "lambda").unwrap_or(0.0);
+    let a0 = get_f64_option(prior, "a0").unwrap_or(-(p as f64) / 2.0);
+    let b0 = get_f64_option(prior, "b0").unwrap_or(0.0);
+
+    if mu0.len() != p - 1 || lambda < 0.0 || b0 < 0.0 {
+        return None;
+    }
+
+    let design = design_matrix(predictors, n);
+    let prior_mean: Vec<f64> = std::iter::once(0.0).chain(mu0).collect();
+    let prior_precision: Vec<f64> = (0..p).map(|j| if j == 0 { 0.0 } else { lambda }).collect();
+
+    // Posterior precision and mean of the coefficients
+    let precision: Vec<Vec<f64>> = (0..p)
+        .map(|a| {
+            (0..p)
+                .map(|b| {
+                    design.iter().map(|row| row[a] * row[b]).sum::<f64>()
+                        + if a == b { prior_precision[a] } else { 0.0 }
+                })
+                .collect()
+        })
+        .collect();
+    let covariance = invert_matrix(&precision)?;
+    let rhs: Vec<f64> = (0..p)
+        .map(|a| {
+            design
+                .iter()
+                .zip(y)
+                .map(|(row, yi)| row[a] * yi)
+                .sum::<f64>()
+                + prior_precision[a] * prior_mean[a]
+        })
+        .collect();
+    let coefficients: Vec<f64> = covariance
+        .iter()
+        .map(|row| row.iter().zip(&rhs).map(|(a, b)| a * b).sum())
+        .collect();
+
+    // Posterior shape and rate of the error variance
+    let a_n = a0 + n as f64 / 2.0;
+    let b_n = b0
+        + (y.iter().map(|yi| yi.powi(2)).sum::<f64>()
+            + (0..p)
+                .map(|j| prior_precision[j] * prior_mean[j].powi(2))
+                .sum::<f64>()
+            - quadratic_form(&coefficients, &precision))
+            / 2.0;
+
+    if a_n <= 0.0 || b_n <= 0.0 {
+        return None;
+    }
+
+    Some(RegressionPosterior {
+        design,
+        coefficients,
+        covariance,
+        a_n,
+        b_n,
+    })
+}
+
+/// Fits a Bayesian linear regression with an intercept under the conjugate
+/// normal-inverse-gamma prior.
+///
+/// The slopes share the prior `β ~ N(mu0, σ²/lambda)` while the intercept has a flat
+/// prior. With the default reference prior, the posterior means and 95% credible
+/// intervals coincide with the least squares estimates and their t-intervals.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `prior` - An optional object with the properties `mu0` (an array of prior means for
+///   the slopes; defaults to zeros), `lambda` (the prior precision of the slopes relative
+///   to the error variance; defaults to 0), and `a0` and `b0` (the shape and rate for the
+///   error variance; default to `-p/2` and 0, where `p` counts the coefficients).
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients` (posterior means, intercept first),
+///   `ci` (a credible interval for each coefficient), `df`, `sigma2` (the posterior mean of
+///   the error variance), and `predictive`, holding the posterior predictive `mean` and
+///   `ci` for each observation. Returns null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_linear_regression(x_matrix: &JsValue, y: &JsValue, prior: &JsValue) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let RegressionPosterior {
+        design,
+        coefficients,
+        covariance,
+        a_n,
+        b_n,
+    } = match regression_posterior(&predictors, &y, prior) {
+        Some(posterior) => posterior,
+        None => return JsValue::NULL,
+    };
+    let p = coefficients.len();
+
+    let df = 2.0 * a_n;
+    let ci: Vec<Vec<f64>> = (0..p)
+        .map(|j| {
+            let (lower, upper) = t_bounds(
+                coefficients[j],
+                (b_n / a_n * covariance[j][j]).sqrt(),
+                df,
+                0.05,
+            );
+            vec![lower, upper]
+        })
+        .collect();
+
+    let mut predictive_mean = Vec::with_capacity(n);
+    let mut predictive_ci = Vec::with_capacity(n);
+    for row in &design {
+        let mean = row
+            .iter()
+            .zip(&coefficients)
+            .map(|(x, b)| x * b)
+            .sum::<f64>();
+        let scale = (b_n / a_n * (1.0 + quadratic_form(row, &covariance))).sqrt();
+        let (lower, upper) = t_bounds(mean, scale, df, 0.05);
+        predictive_mean.push(mean);
+        predictive_ci.push(vec![lower, upper]);
+    }
+    let predictive = Object::new();
+    let _ = Reflect::set(
+        &predictive,
+        &JsValue::from_str("mean"),
+        &vec_to_jsvalue(predictive_mean),
+    );
+    let _ = Reflect::set(
+        &predictive,
+        &JsValue::from_str("ci"),
+        &nested_vec_to_jsvalue(predictive_ci),
+    );
+
+    let sigma2 = if a_n > 1.0 {
+        b_n / (a_n - 1.0)
+    } else {
+        f64::NAN
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(coefficients),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("ci"), &nested_vec_to_jsvalue(ci));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("sigma2"),
+        &JsValue::from_f64(sigma2),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("predictive"), &predictive);
+    obj.into()
+}
+
+/// Reads the options shared by the predictive simulations: whether to draw from the prior
+/// rather than the posterior, the number of datasets, and the random number generator.
+fn predictive_options(options: &JsValue) -> (bool, usize, StdRng) {
+    let from_prior = get_string_option(options, "type").as_deref() == Some("prior");
+    let datasets = get_f64_option(options, "datasets").unwrap_or(100.0) as usize;
+    (
+        from_prior,
+        datasets,
+        seeded_rng(get_f64_option(options, "seed")),
+    )
+}
+
+/// Draws an error variance from an inverse-gamma distribution with the given shape and
+/// rate.
+fn sample_variance(rng: &mut StdRng, shape: f64, rate: f64) -> f64 {
+    1.0 / Gamma::new(shape, rate).unwrap().sample(rng)
+}
+
+/// Simulates datasets from the prior or posterior predictive distribution of the
+/// normal-inverse-gamma model for a mean used by `bayes_mean_interval`.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of data. Its
+///   length sets the size of each simulated dataset.
+/// * `prior` - An optional object with the prior parameters `mu0`, `kappa0`, `a0`, and
+///   `b0`, as in `bayes_mean_interval`. Drawing from the prior requires all of `kappa0`,
+///   `a0`, and `b0` to be positive.
+/// * `options` - An optional object with the properties `type` ("prior" or "posterior";
+///   defaults to "posterior"), `datasets` (defaults to 100), and `seed`.
+///
+/// # Returns
+///
+/// * A JavaScript array of simulated datasets, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_mean_predictive(column: &JsValue, prior: &JsValue, options: &JsValue) -> JsValue {
+    let data = js_array_to_vector(column);
+    let (from_prior, datasets, mut rng) = predictive_options(options);
+
+    let params = if from_prior {
+        Some(mean_prior(prior)).filter(|(_, kappa, a, b)| *kappa > 0.0 && *a > 0.0 && *b > 0.0)
+    } else {
+        mean_posterior(&data, mean_prior(prior))
+    };
+    let (mu, kappa, a, b) = match params {
+        Some(params) if !data.is_empty() => params,
+        _ => return JsValue::NULL,
+    };
+
+    let simulated: Vec<Vec<f64>> = (0..datasets)
+        .map(|_| {
+            let sigma = sample_variance(&mut rng, a, b).sqrt();
+            let mean = Normal::new(mu, sigma / kappa.sqrt())
+                .unwrap()
+                .sample(&mut rng);
+            let dist = Normal::new(mean, sigma).unwrap();
+            (0..data.len()).map(|_| dist.sample(&mut rng)).collect()
+        })
+        .collect();
+
+    nested_vec_to_jsvalue(simulated)
+}
+
+/// Simulates success counts from the prior or posterior predictive distribution of the
+/// beta-binomial model used by `bayes_proportion`.
+///
+/// # Arguments
+///
+/// * `successes` - A reference to a JsValue representing the number of successes.
+/// * `n` - A reference to a JsValue representing the number of trials, which is also
+///   the number of trials in each simulated dataset.
+/// * `prior_alpha` - A reference to a JsValue representing the first shape parameter of
+///   the beta prior; defaults to 1.
+/// * `prior_beta` - A reference to a JsValue representing the second shape parameter of
+///   the beta prior; defaults to 1.
+/// * `options` - An optional object with the properties `type` ("prior" or "posterior";
+///   defaults to "posterior"), `datasets` (defaults to 100), and `seed`.
+///
+/// # Returns
+///
+/// * A JavaScript array of simulated success counts, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn bayes_proportion_predictive(
+    successes: &JsValue,
+    n: &JsValue,
+    prior_alpha: &JsValue,
+    prior_beta: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let (successes, n) = match (successes.as_f64(), n.as_f64()) {
+        (Some(successes), Some(n)) => (successes, n),
+        _ => return JsValue::NULL,
+    };
+    let prior_alpha = prior_alpha.as_f64().unwrap_or(1.0);
+    let prior_beta = prior_beta.as_f64().unwrap_or(1.0);
+    let (from_prior, datasets, mut rng) = predictive_options(options);
+
+    if [successes, n, prior_alpha, prior_beta]
+        .iter()
+        .any(|v| !v.is_finite())
+        || successes < 0.0
+        || successes > n
+        || prior_alpha <= 0.0
+        || prior_beta <= 0.0
+    {
+        return JsValue::NULL;
+    }
+
+    let dist = if from_prior {
+        Beta::new(prior_alpha, prior_beta).unwrap()
+    } else {
+        Beta::new(prior_alpha + successes, prior_beta + n - successes).unwrap()
+    };
+
+    let simulated: Vec<f64> = (0..datasets)
+        .map(|_| {
+            let rate = dist.sample(&mut rng);
+            Binomial::new(rate, n as u64).unwrap().sample(&mut rng)
+        })
+        .collect();
+
+    vec_to_jsvalue(simulated)
+}
+
+/// Computes the lower-triangular Cholesky factor of a symmetric positive definite matrix,
+/// or `None` if the matrix is not positive definite.
+fn cholesky(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let size = matrix.len();
+    let mut lower = vec![vec![0.0; size]; size];
+    for i in 0..size {
+        for j in 0..=i {
+            let sum = matrix[i][j] - (0..j).map(|k| lower[i][k] * lower[j][k]).sum::<f64>();
+            if i == j {
+                if sum <= 0.0 {
+                    return None;
+                }
+                lower[i][j] = sum.sqrt();
+            } else {
+                lower[i][j] = sum / lower[j][j];
+            }
+        }
+    }
+    Some(lower)
+}
+
+/// Simulates responses from the posterior predictive distribution of the regression
+/// model used by `bayes_linear_regression`, at the observed predictor values.
+///
+/// Prior predictive draws are not available because the intercept has a flat prior.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `prior` - An optional object with the prior parameters `mu0`, `lambda`, `a0`, and
+///   `b0`, as in `bayes_linear_regression`.
+/// * `options` - An optional object with the properties `datasets` (defaults to 100) and
+///   `seed`.
+///
+/// # Returns
+///
+/// * A JavaScript array of simulated response vectors, or null if the inputs are invalid
+///   or prior draws are requested.
+#[wasm_bindgen]
+pub fn bayes_regression_predictive(
+    x_matrix: &JsValue,
+    y: &JsValue,
+    prior: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let (from_prior, datasets, mut rng) = predictive_options(options);
+
+    if from_prior || predictors.is_empty() || predictors.iter().any(|col| col.len() != y.len()) {
+        return JsValue::NULL;
+    }
+
+    let posterior = match regression_posterior(&predictors, &y, prior) {
+        Some(posterior) => posterior,
+        None => return JsValue::NULL,
+    };
+    let chol = match cholesky(&posterior.covariance) {
+        Some(chol) => chol,
+        None => return JsValue::NULL,
+    };
+    let standard = Normal::new(0.0, 1.0).unwrap();
+
+    let simulated: Vec<Vec<f64>> = (0..datasets)
+        .map(|_| {
+            // Draw the coefficients from N(m, σ² V) through the Cholesky factor of V
+            let sigma = sample_variance(&mut rng, posterior.a_n, posterior.b_n).sqrt();
+            let z: Vec<f64> = chol.iter().map(|_| standard.sample(&mut rng)).collect();
+            let coefficients: Vec<f64> = posterior
+                .coefficients
+                .iter()
+                .zip(&chol)
+                .map(|(m, row)| m + sigma * row.iter().zip(&z).map(|(l, z)| l * z).sum::<f64>())
+                .collect();
+            posterior
+                .design
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(&coefficients)
+                        .map(|(x, b)| x * b)
+                        .sum::<f64>()
+                        + sigma * standard.sample(&mut rng)
+                })
+                .collect()
+        })
+        .collect();
+
+    nested_vec_to_jsvalue(simulated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_t_test() {
+        let column1 = vec_to_jsvalue(vec![0.5, 1.2, -0.3, 2.1, 0.8, 1.5, 0.1, 1.9]);
+        let column2 = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+        let result1 = bayes_t_test(&column1, &JsValue::UNDEFINED, &JsValue::UNDEFINED);
+        let result2 = bayes_t_test(&column1, &column2, &JsValue::UNDEFINED);
+
+        let bf1 = Reflect::get(&result1, &JsValue::from_str("bf10")).unwrap();
+        let mean1 = Reflect::get(&result1, &JsValue::from_str("posterior_mean")).unwrap();
+        let sd1 = Reflect::get(&result1, &JsValue::from_str("posterior_sd")).unwrap();
+        let bf2 = Reflect::get(&result2, &JsValue::from_str("bf10")).unwrap();
+        let mean2 = Reflect::get(&result2, &JsValue::from_str("posterior_mean")).unwrap();
+
+        assert!((bf1.as_f64().unwrap() - 4.9108).abs() < 0.01);
+        assert!((mean1.as_f64().unwrap() - 0.9294).abs() < 0.01);
+        assert!((sd1.as_f64().unwrap() - 0.4520).abs() < 0.01);
+        assert!((bf2.as_f64().unwrap() - 18.6608).abs() < 0.01);
+        assert!((mean2.as_f64().unwrap() - (-1.5643)).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_ab_test() {
+        let result = bayes_ab_test(
+            &JsValue::from_f64(12.0),
+            &JsValue::from_f64(100.0),
+            &JsValue::from_f64(20.0),
+            &JsValue::from_f64(100.0),
+            &JsValue::UNDEFINED,
+        );
+
+        let prob = Reflect::get(&result, &JsValue::from_str("prob_b_better")).unwrap();
+        let loss_a = Reflect::get(&result, &JsValue::from_str("expected_loss_a")).unwrap();
+        let loss_b = Reflect::get(&result, &JsValue::from_str("expected_loss_b")).unwrap();
+        let a = Reflect::get(&result, &JsValue::from_str("a")).unwrap();
+        let a_ci = js_array_to_vector(&Reflect::get(&a, &JsValue::from_str("ci")).unwrap());
+        let difference = Reflect::get(&result, &JsValue::from_str("difference")).unwrap();
+        let difference_ci =
+            js_array_to_vector(&Reflect::get(&difference, &JsValue::from_str("ci")).unwrap());
+
+        assert!((prob.as_f64().unwrap() - 0.9365).abs() < 0.001);
+        assert!((loss_a.as_f64().unwrap() - 0.07987).abs() < 0.0001);
+        assert!((loss_b.as_f64().unwrap() - 0.00144).abs() < 0.0001);
+        assert!((a_ci[0] - 0.07035).abs() < 0.0001);
+        assert!((a_ci[1] - 0.19835).abs() < 0.0001);
+        assert!((difference_ci[0] - (-0.02255)).abs() < 0.001);
+        assert!((difference_ci[1] - 0.18037).abs() < 0.001);
+        assert!(bayes_ab_test(
+            &JsValue::from_f64(12.0),
+            &JsValue::from_f64(f64::NAN),
+            &JsValue::from_f64(20.0),
+            &JsValue::from_f64(100.0),
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_mean_interval() {
+        let column = vec_to_jsvalue(vec![0.5, 1.2, -0.3, 2.1, 0.8, 1.5, 0.1, 1.9]);
+        let prior = Object::new();
+        let _ = Reflect::set(
+            &prior,
+            &JsValue::from_str("kappa0"),
+            &JsValue::from_f64(1.0),
+        );
+        let _ = Reflect::set(&prior, &JsValue::from_str("a0"), &JsValue::from_f64(1.0));
+        let _ = Reflect::set(&prior, &JsValue::from_str("b0"), &JsValue::from_f64(1.0));
+
+        let result1 = bayes_mean_interval(&column, &JsValue::from_f64(0.05), &JsValue::UNDEFINED);
+        let result2 = bayes_mean_interval(&column, &JsValue::from_f64(0.05), &prior.into());
+
+        let ci1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let mean2 = Reflect::get(&result2, &JsValue::from_str("posterior_mean")).unwrap();
+        let ci2 = js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("ci")).unwrap());
+
+        assert!((ci1[0] - 0.26175).abs() < 0.001);
+        assert!((ci1[1] - 1.68825).abs() < 0.001);
+        assert!((mean2.as_f64().unwrap() - 0.86667).abs() < 0.001);
+        assert!((ci2[0] - 0.20486).abs() < 0.001);
+        assert!((ci2[1] - 1.52847).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_proportion() {
+        let result1 = bayes_proportion(
+            &JsValue::from_f64(12.0),
+            &JsValue::from_f64(100.0),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        );
+        let result2 = bayes_proportion(
+            &JsValue::from_f64(0.0),
+            &JsValue::from_f64(10.0),
+            &JsValue::from_f64(0.5),
+            &JsValue::from_f64(0.5),
+        );
+
+        let mean1 = Reflect::get(&result1, &JsValue::from_str("mean")).unwrap();
+        let hdi1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("hdi")).unwrap());
+        let hdi2 = js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("hdi")).unwrap());
+
+        assert!((mean1.as_f64().unwrap() - 0.12745).abs() < 0.0001);
+        assert!((hdi1[0] - 0.06627).abs() < 0.0001);
+        assert!((hdi1[1] - 0.19282).abs() < 0.0001);
+        assert!(hdi2[0].abs() < 0.0001);
+        assert!((hdi2[1] - 0.17077).abs() < 0.0001);
+        assert!(bayes_proportion(
+            &JsValue::from_f64(12.0),
+            &JsValue::from_f64(100.0),
+            &JsValue::from_f64(f64::NAN),
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_correlation_test() {
+        let x = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+        let y = vec_to_jsvalue(vec![2.0, 1.0, 4.0, 3.0, 7.0, 4.0, 6.0, 9.0, 5.0, 8.0]);
+
+        let result1 = bayes_correlation_test(&x, &y, &JsValue::UNDEFINED);
+        let result2 = bayes_correlation_test(&x, &y, &JsValue::from_f64(2.0));
+
+        let bf1 = Reflect::get(&result1, &JsValue::from_str("bf10")).unwrap();
+        let mean1 = Reflect::get(&result1, &JsValue::from_str("posterior_mean")).unwrap();
+        let sd1 = Reflect::get(&result1, &JsValue::from_str("posterior_sd")).unwrap();
+        let ci1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let bf2 = Reflect::get(&result2, &JsValue::from_str("bf10")).unwrap();
+
+        assert!((bf1.as_f64().unwrap() - 10.6909).abs() < 0.01);
+        assert!((mean1.as_f64().unwrap() - 0.66462).abs() < 0.001);
+        assert!((sd1.as_f64().unwrap() - 0.18635).abs() < 0.001);
+        assert!((ci1[0] - 0.19627).abs() < 0.001);
+        assert!((ci1[1] - 0.91102).abs() < 0.001);
+        assert!((bf2.as_f64().unwrap() - 10.1311).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_multi_variant() {
+        let successes = vec_to_jsvalue(vec![12.0, 20.0]);
+        let n = vec_to_jsvalue(vec![100.0, 100.0]);
+
+        let result1 = bayes_multi_variant(
+            &successes,
+            &n,
+            &JsValue::UNDEFINED,
+            &JsValue::from_f64(20000.0),
+            &JsValue::from_f64(42.0),
+        );
+        let result2 = bayes_multi_variant(
+            &successes,
+            &n,
+            &JsValue::UNDEFINED,
+            &JsValue::from_f64(20000.0),
+            &JsValue::from_f64(42.0),
+        );
+
+        let prob1 =
+            js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("prob_best")).unwrap());
+        let loss1 = js_array_to_vector(
+            &Reflect::get(&result1, &JsValue::from_str("expected_loss")).unwrap(),
+        );
+        let prob2 =
+            js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("prob_best")).unwrap());
+
+        // Two variants should agree with the exact A/B computation
+        assert!((prob1[1] - 0.9365).abs() < 0.01);
+        assert!((loss1[0] - 0.07987).abs() < 0.002);
+        assert!((loss1[1] - 0.00144).abs() < 0.001);
+        assert_eq!(prob1, prob2);
+        assert!(bayes_multi_variant(
+            &vec_to_jsvalue(vec![f64::NAN, 20.0]),
+            &n,
+            &JsValue::UNDEFINED,
+            &JsValue::from_f64(100.0),
+            &JsValue::from_f64(42.0),
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_linear_regression() {
+        let x = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 9.0],
+        ]);
+        let y = vec_to_jsvalue(vec![3.0, 4.0, 8.0, 9.0, 12.0, 14.0, 15.0, 19.0]);
+        let prior = Object::new();
+        let _ = Reflect::set(
+            &prior,
+            &JsValue::from_str("mu0"),
+            &vec_to_jsvalue(vec![1.0, 0.0]),
+        );
+        let _ = Reflect::set(
+            &prior,
+            &JsValue::from_str("lambda"),
+            &JsValue::from_f64(1.0),
+        );
+        let _ = Reflect::set(&prior, &JsValue::from_str("a0"), &JsValue::from_f64(1.0));
+        let _ = Reflect::set(&prior, &JsValue::from_str("b0"), &JsValue::from_f64(1.0));
+
+        let result1 = bayes_linear_regression(&x, &y, &JsValue::UNDEFINED);
+        let result2 = bayes_linear_regression(&x, &y, &prior.into());
+
+        let coefficients1 = js_array_to_vector(
+            &Reflect::get(&result1, &JsValue::from_str("coefficients")).unwrap(),
+        );
+        let ci1 =
+            js_nested_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let slope_ci1 = js_array_to_vector(&ci1[1]);
+        let predictive1 = Reflect::get(&result1, &JsValue::from_str("predictive")).unwrap();
+        let predictive_ci1 = js_nested_array_to_vector(
+            &Reflect::get(&predictive1, &JsValue::from_str("ci")).unwrap(),
+        );
+        let first_ci1 = js_array_to_vector(&predictive_ci1[0]);
+        let coefficients2 = js_array_to_vector(
+            &Reflect::get(&result2, &JsValue::from_str("coefficients")).unwrap(),
+        );
+        let ci2 =
+            js_nested_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("ci")).unwrap());
+        let slope_ci2 = js_array_to_vector(&ci2[1]);
+
+        assert!((coefficients1[1] - 1.90196).abs() < 0.001);
+        assert!((slope_ci1[0] - 1.06794).abs() < 0.001);
+        assert!((slope_ci1[1] - 2.73598).abs() < 0.001);
+        assert!((first_ci1[0] - 0.53917).abs() < 0.001);
+        assert!((first_ci1[1] - 5.42161).abs() < 0.001);
+        assert!((coefficients2[0] - 0.54265).abs() < 0.001);
+        assert!((coefficients2[1] - 1.81088).abs() < 0.001);
+        assert!((slope_ci2[0] - 1.17927).abs() < 0.001);
+        assert!((slope_ci2[1] - 2.44248).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_mean_predictive() {
+        let column = vec_to_jsvalue(vec![0.5, 1.2, -0.3, 2.1, 0.8, 1.5, 0.1, 1.9]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("datasets"),
+            &JsValue::from_f64(2000.0),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("seed"),
+            &JsValue::from_f64(7.0),
+        );
+        let prior_options = Object::new();
+        let _ = Reflect::set(
+            &prior_options,
+            &JsValue::from_str("type"),
+            &JsValue::from_str("prior"),
+        );
+
+        let result1 = bayes_mean_predictive(&column, &JsValue::UNDEFINED, &options.into());
+        let result2 = bayes_mean_predictive(&column, &JsValue::UNDEFINED, &prior_options.into());
+
+        let datasets = js_nested_array_to_vector(&result1);
+        let overall_mean = datasets
+            .iter()
+            .map(|d| js_array_to_vector(d).iter().sum::<f64>() / 8.0)
+            .sum::<f64>()
+            / datasets.len() as f64;
+
+        assert_eq!(datasets.len(), 2000);
+        assert_eq!(js_array_to_vector(&datasets[0]).len(), 8);
+        assert!((overall_mean - 0.975).abs() < 0.05);
+        assert!(result2.is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_proportion_predictive() {
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("datasets"),
+            &JsValue::from_f64(2000.0),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("seed"),
+            &JsValue::from_f64(7.0),
+        );
+
+        let result = bayes_proportion_predictive(
+            &JsValue::from_f64(12.0),
+            &JsValue::from_f64(100.0),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+            &options.into(),
+        );
+
+        let counts = js_array_to_vector(&result);
+        let mean = counts.iter().sum::<f64>() / counts.len() as f64;
+
+        assert_eq!(counts.len(), 2000);
+        assert!(counts.iter().all(|c| (0.0..=100.0).contains(c)));
+        assert!((mean - 12.745).abs() < 0.5);
+        assert!(bayes_proportion_predictive(
+            &JsValue::from_f64(f64::NAN),
+            &JsValue::from_f64(100.0),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bayes_regression_predictive() {
+        let x = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 9.0],
+        ]);
+        let y = vec_to_jsvalue(vec![3.0, 4.0, 8.0, 9.0, 12.0, 14.0, 15.0, 19.0]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("datasets"),
+            &JsValue::from_f64(2000.0),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("seed"),
+            &JsValue::from_f64(7.0),
+        );
+
+        let result = bayes_regression_predictive(&x, &y, &JsValue::UNDEFINED, &options.into());
+
+        let datasets = js_nested_array_to_vector(&result);
+        let first_mean = datasets
+            .iter()
+            .map(|d| js_array_to_vector(d)[0])
+            .sum::<f64>()
+            / datasets.len() as f64;
+
+        assert_eq!(datasets.len(), 2000);
+        assert!((first_mean - 2.98039).abs() < 0.15);
+    }
+}
@@ -0,0 +1,770 @@
+use crate::conf_int::z_bounds;
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use statrs::function::factorial::ln_binomial;
+use wasm_bindgen::prelude::*;
+
+/// A level of a categorical variable, which is either numeric or a string.
+#[derive(Clone, PartialEq)]
+enum Level {
+    Number(f64),
+    Text(String),
+}
+
+impl Level {
+    /// Reads a level from a JavaScript value, treating non-finite numbers as missing.
+    fn from_js(value: &JsValue) -> Option<Level> {
+        if let Some(number) = value.as_f64() {
+            number.is_finite().then_some(Level::Number(number))
+        } else {
+            value.as_string().map(Level::Text)
+        }
+    }
+
+    fn to_js(&self) -> JsValue {
+        match self {
+            Level::Number(number) => JsValue::from_f64(*number),
+            Level::Text(text) => JsValue::from_str(text),
+        }
+    }
+
+    /// Orders numeric levels numerically before string levels in lexicographic order.
+    fn compare(&self, other: &Level) -> std::cmp::Ordering {
+        match (self, other) {
+            (Level::Number(a), Level::Number(b)) => a.total_cmp(b),
+            (Level::Text(a), Level::Text(b)) => a.cmp(b),
+            (Level::Number(_), Level::Text(_)) => std::cmp::Ordering::Less,
+            (Level::Text(_), Level::Number(_)) => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
+/// Groups the positions of an array of categorical labels by level.
+///
+/// # Arguments
+///
+/// * `labels` - A reference to a JsValue representing the JavaScript array of labels
+///   (strings or numbers).
+///
+/// # Returns
+///
+/// * The indices belonging to each level, with the levels sorted as in
+///   `build_contingency_table`, or `None` if any label is missing or a non-finite number.
+pub(crate) fn group_indices(labels: &JsValue) -> Option<Vec<Vec<usize>>> {
+    let labels: Option<Vec<Level>> = Array::from(labels)
+        .iter()
+        .map(|v| Level::from_js(&v))
+        .collect();
+    let labels = labels?;
+
+    let mut levels: Vec<Level> = Vec::new();
+    for label in &labels {
+        if !levels.contains(label) {
+            levels.push(label.clone());
+        }
+    }
+    levels.sort_by(|a, b| a.compare(b));
+
+    let mut groups = vec![Vec::new(); levels.len()];
+    for (i, label) in labels.iter().enumerate() {
+        let level = levels.iter().position(|level| level == label).unwrap();
+        groups[level].push(i);
+    }
+    Some(groups)
+}
+
+/// Groups the positions of an array of categorical labels by an explicit list of levels.
+///
+/// # Arguments
+///
+/// * `labels` - A reference to a JsValue representing the JavaScript array of labels
+///   (strings or numbers).
+/// * `levels` - A reference to a JsValue representing the JavaScript array of levels in
+///   the order wanted.
+///
+/// # Returns
+///
+/// * The indices belonging to each level, in the order given and including levels no
+///   label takes, or `None` if any label or level is missing, a level repeats, or a label
+///   is not among the levels.
+pub(crate) fn group_indices_by(labels: &JsValue, levels: &JsValue) -> Option<Vec<Vec<usize>>> {
+    let levels: Vec<Level> = Array::from(levels)
+        .iter()
+        .map(|v| Level::from_js(&v))
+        .collect::<Option<_>>()?;
+    if (1..levels.len()).any(|i| levels[..i].contains(&levels[i])) {
+        return None;
+    }
+
+    let mut groups = vec![Vec::new(); levels.len()];
+    for (i, label) in Array::from(labels).iter().enumerate() {
+        let label = Level::from_js(&label)?;
+        groups[levels.iter().position(|level| *level == label)?].push(i);
+    }
+    Some(groups)
+}
+
+/// Cross-classifies raw paired observations into a contingency table of counts.
+///
+/// # Arguments
+///
+/// * `column_a` - A reference to a JsValue representing the JavaScript array of the row
+///   variable's values (strings or numbers).
+/// * `column_b` - A reference to a JsValue representing the JavaScript array of the column
+///   variable's values (strings or numbers).
+///
+/// # Returns
+///
+/// * An object with the properties `table` (the counts, one array per row level),
+///   `row_levels`, and `column_levels`, or null if the columns differ in length or have
+///   no complete pairs. Pairs with a missing, non-finite, or non-categorical value are
+///   skipped, and the levels are sorted with numbers before strings.
+#[wasm_bindgen]
+pub fn build_contingency_table(column_a: &JsValue, column_b: &JsValue) -> JsValue {
+    let values_a = Array::from(column_a);
+    let values_b = Array::from(column_b);
+    if values_a.length() != values_b.length() {
+        return JsValue::NULL;
+    }
+
+    let pairs: Vec<(Level, Level)> = values_a
+        .iter()
+        .zip(values_b.iter())
+        .filter_map(|(a, b)| Some((Level::from_js(&a)?, Level::from_js(&b)?)))
+        .collect();
+    if pairs.is_empty() {
+        return JsValue::NULL;
+    }
+
+    let levels = |select: fn(&(Level, Level)) -> &Level| {
+        let mut levels: Vec<Level> = Vec::new();
+        for pair in &pairs {
+            if !levels.contains(select(pair)) {
+                levels.push(select(pair).clone());
+            }
+        }
+        levels.sort_by(|a, b| a.compare(b));
+        levels
+    };
+    let row_levels = levels(|pair| &pair.0);
+    let column_levels = levels(|pair| &pair.1);
+
+    let mut table = vec![vec![0.0; column_levels.len()]; row_levels.len()];
+    for (a, b) in &pairs {
+        let i = row_levels.iter().position(|level| level == a).unwrap();
+        let j = column_levels.iter().position(|level| level == b).unwrap();
+        table[i][j] += 1.0;
+    }
+
+    let to_array = |levels: &[Level]| {
+        let arr = Array::new();
+        for level in levels {
+            arr.push(&level.to_js());
+        }
+        arr
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("table"),
+        &nested_vec_to_jsvalue(table),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("row_levels"),
+        &to_array(&row_levels),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("column_levels"),
+        &to_array(&column_levels),
+    );
+    obj.into()
+}
+
+/// Cross-tabulates two raw categorical arrays into a contingency table with its
+/// marginal totals. The `table` can be passed directly to `chi2_ind_test`.
+///
+/// # Arguments
+///
+/// * `column_a` - A reference to a JsValue representing the JavaScript array of the row
+///   variable's values (strings or numbers).
+/// * `column_b` - A reference to a JsValue representing the JavaScript array of the column
+///   variable's values (strings or numbers).
+///
+/// # Returns
+///
+/// * An object with the properties of `build_contingency_table` (`table`, `row_levels`,
+///   and `column_levels`) plus `row_totals`, `column_totals`, and `total`, or null under
+///   the same conditions as `build_contingency_table`.
+#[wasm_bindgen]
+pub fn crosstab(column_a: &JsValue, column_b: &JsValue) -> JsValue {
+    let result = build_contingency_table(column_a, column_b);
+    if result.is_null() {
+        return result;
+    }
+
+    let table: Vec<Vec<f64>> =
+        js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("table")).unwrap())
+            .iter()
+            .map(js_array_to_vector)
+            .collect();
+    let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+    let column_totals: Vec<f64> = (0..table[0].len())
+        .map(|j| table.iter().map(|row| row[j]).sum())
+        .collect();
+    let total = row_totals.iter().sum::<f64>();
+
+    let _ = Reflect::set(
+        &result,
+        &JsValue::from_str("row_totals"),
+        &vec_to_jsvalue(row_totals),
+    );
+    let _ = Reflect::set(
+        &result,
+        &JsValue::from_str("column_totals"),
+        &vec_to_jsvalue(column_totals),
+    );
+    let _ = Reflect::set(
+        &result,
+        &JsValue::from_str("total"),
+        &JsValue::from_f64(total),
+    );
+    result
+}
+
+/// Parses a 2x2 table of counts into its cells `[a, b, c, d]`, where the rows are the
+/// exposed and unexposed groups and the columns are events and non-events.
+fn two_by_two(table: &JsValue) -> Option<[f64; 4]> {
+    let rows: Vec<Vec<f64>> = js_nested_array_to_vector(table)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    if rows.len() != 2 || rows.iter().any(|row| row.len() != 2) {
+        return None;
+    }
+    let cells = [rows[0][0], rows[0][1], rows[1][0], rows[1][1]];
+    if cells.iter().any(|cell| !cell.is_finite() || *cell < 0.0) {
+        return None;
+    }
+    Some(cells)
+}
+
+/// Builds the result object for a ratio measure from its estimate on the log scale.
+fn ratio_result(log_estimate: f64, log_std_error: f64, alpha: f64) -> JsValue {
+    let (lower, upper) = z_bounds(log_estimate, log_std_error, alpha);
+    let z = log_estimate / log_std_error;
+    let p = 2.0 * (1.0 - Normal::new(0.0, 1.0).unwrap().cdf(z.abs()));
+
+    let obj = Object::new();
+    set_estimate(&obj, log_estimate.exp(), (lower.exp(), upper.exp()));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("log_std_error"),
+        &JsValue::from_f64(log_std_error),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Adds 0.5 to every cell of a 2x2 table if any cell is zero (the Haldane correction).
+fn haldane_correction(cells: [f64; 4]) -> [f64; 4] {
+    if cells.contains(&0.0) {
+        cells.map(|cell| cell + 0.5)
+    } else {
+        cells
+    }
+}
+
+/// Computes the odds ratio of a 2x2 table with a Woolf (log-scale) confidence interval and
+/// a z-test against an odds ratio of 1.
+///
+/// # Arguments
+///
+/// * `table` - A reference to a JsValue representing the 2x2 table of counts, with the
+///   exposed group in the first row and events in the first column.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci`, `log_std_error`, `z`, and `p`, or
+///   null if the table is not a valid 2x2 table. If any cell is zero, 0.5 is added to
+///   every cell.
+#[wasm_bindgen]
+pub fn odds_ratio(table: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let [a, b, c, d] = match two_by_two(table) {
+        Some(cells) => haldane_correction(cells),
+        None => return JsValue::NULL,
+    };
+
+    let log_or = (a * d / (b * c)).ln();
+    let std_error = (1.0 / a + 1.0 / b + 1.0 / c + 1.0 / d).sqrt();
+    ratio_result(log_or, std_error, alpha)
+}
+
+/// Computes the relative risk of a 2x2 table with a log-scale confidence interval and a
+/// z-test against a relative risk of 1.
+///
+/// # Arguments
+///
+/// * `table` - A reference to a JsValue representing the 2x2 table of counts, with the
+///   exposed group in the first row and events in the first column.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci`, `log_std_error`, `z`, and `p`, or
+///   null if the table is not a valid 2x2 table. If any cell is zero, 0.5 is added to
+///   every cell.
+#[wasm_bindgen]
+pub fn relative_risk(table: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let [a, b, c, d] = match two_by_two(table) {
+        Some(cells) => haldane_correction(cells),
+        None => return JsValue::NULL,
+    };
+
+    let log_rr = ((a / (a + b)) / (c / (c + d))).ln();
+    let std_error = (1.0 / a - 1.0 / (a + b) + 1.0 / c - 1.0 / (c + d)).sqrt();
+    ratio_result(log_rr, std_error, alpha)
+}
+
+/// Parses a JavaScript array of 2x2 tables, one per stratum.
+fn stratified_tables(tables: &JsValue) -> Option<Vec<[f64; 4]>> {
+    let strata: Vec<[f64; 4]> = js_nested_array_to_vector(tables)
+        .iter()
+        .map(two_by_two)
+        .collect::<Option<_>>()?;
+    if strata.is_empty() {
+        None
+    } else {
+        Some(strata)
+    }
+}
+
+/// Computes the Mantel-Haenszel numerator and denominator sums of the pooled odds ratio
+/// and the Robins-Breslow-Greenland variance of its logarithm.
+fn pooled_odds_ratio(strata: &[[f64; 4]]) -> (f64, f64, f64) {
+    let mut r = 0.0;
+    let mut s = 0.0;
+    let mut pr = 0.0;
+    let mut ps_qr = 0.0;
+    let mut qs = 0.0;
+    for [a, b, c, d] in strata {
+        let n = a + b + c + d;
+        let p_i = (a + d) / n;
+        let q_i = (b + c) / n;
+        let r_i = a * d / n;
+        let s_i = b * c / n;
+        r += r_i;
+        s += s_i;
+        pr += p_i * r_i;
+        ps_qr += p_i * s_i + q_i * r_i;
+        qs += q_i * s_i;
+    }
+    let variance = pr / (2.0 * r * r) + ps_qr / (2.0 * r * s) + qs / (2.0 * s * s);
+    (r, s, variance)
+}
+
+/// Pools the odds ratio or relative risk across the strata of a stratified 2x2 analysis
+/// using the Mantel-Haenszel estimator.
+///
+/// # Arguments
+///
+/// * `tables` - A reference to a JsValue representing a JavaScript array of 2x2 tables,
+///   one per stratum, each laid out as in `odds_ratio`.
+/// * `measure` - A reference to a JsValue representing the measure to pool, either "or"
+///   (the default) or "rr".
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci`, `log_std_error`, `z`, and `p`, or
+///   null if any table is invalid or the pooled estimate is undefined. The standard error
+///   is the Robins-Breslow-Greenland estimator for the odds ratio and the
+///   Greenland-Robins estimator for the relative risk.
+#[wasm_bindgen]
+pub fn mantel_haenszel(tables: &JsValue, measure: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let measure = measure.as_string().unwrap_or_else(|| "or".to_string());
+    let strata = match stratified_tables(tables) {
+        Some(strata) => strata,
+        None => return JsValue::NULL,
+    };
+
+    let (numerator, denominator, variance) = match measure.as_str() {
+        "or" => pooled_odds_ratio(&strata),
+        "rr" => {
+            let mut r = 0.0;
+            let mut s = 0.0;
+            let mut v = 0.0;
+            for [a, b, c, d] in &strata {
+                let n = a + b + c + d;
+                r += a * (c + d) / n;
+                s += c * (a + b) / n;
+                v += ((a + b) * (c + d) * (a + c) - a * c * n) / (n * n);
+            }
+            (r, s, v / (r * s))
+        }
+        _ => return JsValue::NULL,
+    };
+
+    if numerator <= 0.0 || denominator <= 0.0 || !variance.is_finite() {
+        return JsValue::NULL;
+    }
+    ratio_result((numerator / denominator).ln(), variance.sqrt(), alpha)
+}
+
+/// Performs the Cochran-Mantel-Haenszel test of conditional independence across the
+/// strata of a stratified 2x2 analysis, alongside the pooled Mantel-Haenszel odds ratio.
+///
+/// # Arguments
+///
+/// * `tables` - A reference to a JsValue representing a JavaScript array of 2x2 tables,
+///   one per stratum, each laid out as in `odds_ratio`.
+/// * `options` - An optional object; `{ alpha: 0.05 }` sets the level of the odds ratio
+///   interval, and `{ continuity_correction: true }` subtracts 0.5 from the absolute
+///   deviation of the summed top-left cells from their expectation.
+///
+/// # Returns
+///
+/// * An object with the properties `estimate` and `ci` (the pooled odds ratio and its
+///   Robins-Breslow-Greenland interval), `x2`, `df`, `p`, and `continuity_correction`, or
+///   null if any table is invalid or every stratum has a single observation.
+#[wasm_bindgen]
+pub fn mantel_haenszel_test(tables: &JsValue, options: &JsValue) -> JsValue {
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let yates = get_bool_option(options, "continuity_correction");
+    let strata = match stratified_tables(tables) {
+        Some(strata) => strata,
+        None => return JsValue::NULL,
+    };
+
+    // Sum the top-left cells against their hypergeometric means and variances
+    let mut deviation = 0.0;
+    let mut variance = 0.0;
+    for [a, b, c, d] in &strata {
+        let n = a + b + c + d;
+        if n < 2.0 {
+            continue;
+        }
+        deviation += a - (a + b) * (a + c) / n;
+        variance += (a + b) * (c + d) * (a + c) * (b + d) / (n * n * (n - 1.0));
+    }
+    if variance <= 0.0 {
+        return JsValue::NULL;
+    }
+    let deviation = if yates {
+        (deviation.abs() - 0.5).max(0.0)
+    } else {
+        deviation
+    };
+    let x2 = deviation.powi(2) / variance;
+    let p = 1.0 - ChiSquared::new(1.0).unwrap().cdf(x2);
+
+    let obj = Object::new();
+    let (r, s, log_variance) = pooled_odds_ratio(&strata);
+    if r > 0.0 && s > 0.0 {
+        let log_or = (r / s).ln();
+        let (lower, upper) = z_bounds(log_or, log_variance.sqrt(), alpha);
+        set_estimate(&obj, log_or.exp(), (lower.exp(), upper.exp()));
+    }
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(1.0));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("continuity_correction"),
+        &JsValue::from_bool(yates),
+    );
+    obj.into()
+}
+
+/// Performs Fisher's exact test on a 2x2 table using the hypergeometric distribution of
+/// the top-left cell given the margins.
+///
+/// # Arguments
+///
+/// * `table` - A reference to a JsValue representing the 2x2 table of counts.
+/// * `tails` - A reference to a JsValue indicating the alternative for the odds ratio
+///   ("two-sided", "less", or "greater"; defaults to "two-sided").
+///
+/// # Returns
+///
+/// * An object with the properties `p` and `odds_ratio` (the sample odds ratio), or null
+///   if the table is not a valid 2x2 table of whole counts, or an object with the property
+///   `error` if `tails` is not recognized. The two-sided p-value sums the
+///   probabilities of all tables no more likely than the observed one.
+#[wasm_bindgen]
+pub fn fisher_exact_test(table: &JsValue, tails: &JsValue) -> JsValue {
+    let [a, b, c, d] = match two_by_two(table) {
+        Some(cells) if cells.iter().all(|cell| cell.fract() == 0.0) => cells,
+        _ => return JsValue::NULL,
+    };
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    let row1 = (a + b) as u64;
+    let row2 = (c + d) as u64;
+    let col1 = (a + c) as u64;
+    let total = row1 + row2;
+    let lower = col1.saturating_sub(row2);
+    let upper = col1.min(row1);
+
+    let probability = |x: u64| {
+        (ln_binomial(row1, x) + ln_binomial(row2, col1 - x) - ln_binomial(total, col1)).exp()
+    };
+    let observed = a as u64;
+    let p_observed = probability(observed);
+
+    let p = match tails.as_str() {
+        "less" => (lower..=observed).map(probability).sum::<f64>(),
+        "greater" => (observed..=upper).map(probability).sum::<f64>(),
+        "two-sided" => (lower..=upper)
+            .map(probability)
+            .filter(|p| *p <= p_observed * (1.0 + 1e-7))
+            .sum::<f64>(),
+        _ => return invalid_test_type(),
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p"),
+        &JsValue::from_f64(p.min(1.0)),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("odds_ratio"),
+        &JsValue::from_f64(a * d / (b * c)),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_build_contingency_table() {
+        let column_a = Array::new();
+        let column_b = Array::new();
+        for (a, b) in [
+            ("yes", 2.0),
+            ("no", 1.0),
+            ("yes", 1.0),
+            ("yes", 2.0),
+            ("no", 2.0),
+            ("maybe", 1.0),
+        ] {
+            column_a.push(&JsValue::from_str(a));
+            column_b.push(&JsValue::from_f64(b));
+        }
+        column_a.push(&JsValue::NULL);
+        column_b.push(&JsValue::from_f64(1.0));
+        column_a.push(&JsValue::from_str("yes"));
+        column_b.push(&JsValue::from_f64(f64::NAN));
+
+        let result = build_contingency_table(&column_a.into(), &column_b.into());
+
+        let table: Vec<Vec<f64>> =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("table")).unwrap())
+                .iter()
+                .map(js_array_to_vector)
+                .collect();
+        let row_levels =
+            Array::from(&Reflect::get(&result, &JsValue::from_str("row_levels")).unwrap());
+        let column_levels = js_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("column_levels")).unwrap(),
+        );
+
+        assert_eq!(row_levels.get(0).as_string().unwrap(), "maybe");
+        assert_eq!(row_levels.get(2).as_string().unwrap(), "yes");
+        assert_eq!(column_levels, vec![1.0, 2.0]);
+        assert_eq!(table, vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_crosstab() {
+        let column_a = Array::new();
+        let column_b = Array::new();
+        for (a, b) in [
+            ("yes", "b"),
+            ("no", "a"),
+            ("yes", "a"),
+            ("yes", "b"),
+            ("no", "b"),
+            ("maybe", "a"),
+        ] {
+            column_a.push(&JsValue::from_str(a));
+            column_b.push(&JsValue::from_str(b));
+        }
+
+        let result = crosstab(&column_a.into(), &column_b.into());
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let table: Vec<Vec<f64>> = js_nested_array_to_vector(&get("table"))
+            .iter()
+            .map(js_array_to_vector)
+            .collect();
+
+        assert_eq!(table, vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]);
+        assert_eq!(js_array_to_vector(&get("row_totals")), vec![1.0, 2.0, 3.0]);
+        assert_eq!(js_array_to_vector(&get("column_totals")), vec![3.0, 3.0]);
+        assert_eq!(get("total").as_f64(), Some(6.0));
+        assert!(crosstab(&Array::new().into(), &Array::new().into()).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_odds_ratio() {
+        let table = nested_vec_to_jsvalue(vec![vec![20.0, 80.0], vec![10.0, 90.0]]);
+
+        let result = odds_ratio(&table, &JsValue::from_f64(0.05));
+
+        let estimate = Reflect::get(&result, &JsValue::from_str("estimate")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        assert!((estimate.as_f64().unwrap() - 2.25).abs() < 1e-9);
+        assert!((ci[0] - 0.99429).abs() < 0.0001);
+        assert!((ci[1] - 5.09155).abs() < 0.0001);
+        assert!((p.as_f64().unwrap() - 0.05163).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_relative_risk() {
+        let table = nested_vec_to_jsvalue(vec![vec![20.0, 80.0], vec![10.0, 90.0]]);
+
+        let result = relative_risk(&table, &JsValue::from_f64(0.05));
+
+        let estimate = Reflect::get(&result, &JsValue::from_str("estimate")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+        let z = Reflect::get(&result, &JsValue::from_str("z")).unwrap();
+
+        assert!((estimate.as_f64().unwrap() - 2.0).abs() < 1e-9);
+        assert!((ci[0] - 0.98656).abs() < 0.0001);
+        assert!((ci[1] - 4.05448).abs() < 0.0001);
+        assert!((z.as_f64().unwrap() - 1.92244).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_mantel_haenszel() {
+        let tables = Array::new();
+        for table in [
+            vec![vec![20.0, 80.0], vec![10.0, 90.0]],
+            vec![vec![15.0, 35.0], vec![12.0, 48.0]],
+            vec![vec![8.0, 12.0], vec![5.0, 25.0]],
+        ] {
+            tables.push(&nested_vec_to_jsvalue(table));
+        }
+        let tables: JsValue = tables.into();
+
+        let pooled_or = mantel_haenszel(&tables, &JsValue::from_str("or"), &JsValue::UNDEFINED);
+        let pooled_rr = mantel_haenszel(&tables, &JsValue::from_str("rr"), &JsValue::UNDEFINED);
+
+        let or = Reflect::get(&pooled_or, &JsValue::from_str("estimate")).unwrap();
+        let or_ci =
+            js_array_to_vector(&Reflect::get(&pooled_or, &JsValue::from_str("ci")).unwrap());
+        let or_p = Reflect::get(&pooled_or, &JsValue::from_str("p")).unwrap();
+        let rr = Reflect::get(&pooled_rr, &JsValue::from_str("estimate")).unwrap();
+        let rr_ci =
+            js_array_to_vector(&Reflect::get(&pooled_rr, &JsValue::from_str("ci")).unwrap());
+
+        assert!((or.as_f64().unwrap() - 2.16734).abs() < 0.0001);
+        assert!((or_ci[0] - 1.26075).abs() < 0.0001);
+        assert!((or_ci[1] - 3.72586).abs() < 0.0001);
+        assert!((or_p.as_f64().unwrap() - 0.00514).abs() < 0.0001);
+        assert!((rr.as_f64().unwrap() - 1.84526).abs() < 0.0001);
+        assert!((rr_ci[0] - 1.19847).abs() < 0.0001);
+        assert!((rr_ci[1] - 2.84110).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_mantel_haenszel_test() {
+        let tables = Array::new();
+        for table in [
+            vec![vec![20.0, 80.0], vec![10.0, 90.0]],
+            vec![vec![15.0, 35.0], vec![12.0, 48.0]],
+            vec![vec![8.0, 12.0], vec![5.0, 25.0]],
+        ] {
+            tables.push(&nested_vec_to_jsvalue(table));
+        }
+        let tables: JsValue = tables.into();
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("continuity_correction"),
+            &JsValue::from_bool(true),
+        );
+
+        let result1 = mantel_haenszel_test(&tables, &JsValue::UNDEFINED);
+        let result2 = mantel_haenszel_test(&tables, &options);
+
+        let estimate = Reflect::get(&result1, &JsValue::from_str("estimate")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let x2_1 = Reflect::get(&result1, &JsValue::from_str("x2")).unwrap();
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let x2_2 = Reflect::get(&result2, &JsValue::from_str("x2")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+
+        assert!((estimate.as_f64().unwrap() - 2.16734).abs() < 0.0001);
+        assert!((ci[0] - 1.26075).abs() < 0.0001);
+        assert!((ci[1] - 3.72586).abs() < 0.0001);
+        assert!((x2_1.as_f64().unwrap() - 7.99570).abs() < 0.0001);
+        assert!((p1.as_f64().unwrap() - 0.00469).abs() < 0.0001);
+        assert!((x2_2.as_f64().unwrap() - 7.25421).abs() < 0.0001);
+        assert!((p2.as_f64().unwrap() - 0.00707).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_fisher_exact_test() {
+        let tea = nested_vec_to_jsvalue(vec![vec![3.0, 1.0], vec![1.0, 3.0]]);
+        let table = nested_vec_to_jsvalue(vec![vec![1.0, 9.0], vec![11.0, 3.0]]);
+
+        let get_p = |table: &JsValue, tails: &str| {
+            Reflect::get(
+                &fisher_exact_test(table, &JsValue::from_str(tails)),
+                &JsValue::from_str("p"),
+            )
+            .unwrap()
+            .as_f64()
+            .unwrap()
+        };
+        let result = fisher_exact_test(&tea, &JsValue::UNDEFINED);
+        let odds_ratio = Reflect::get(&result, &JsValue::from_str("odds_ratio")).unwrap();
+
+        assert!((get_p(&tea, "two-sided") - 0.485714).abs() < 1e-6);
+        assert!((get_p(&tea, "greater") - 0.242857).abs() < 1e-6);
+        assert!((get_p(&tea, "less") - 0.985714).abs() < 1e-6);
+        assert!((get_p(&table, "two-sided") - 0.002759).abs() < 1e-6);
+        assert!((get_p(&table, "less") - 0.001380).abs() < 1e-6);
+        assert!((odds_ratio.as_f64().unwrap() - 9.0).abs() < 1e-9);
+        assert!(fisher_exact_test(
+            &nested_vec_to_jsvalue(vec![vec![1.5, 2.0], vec![3.0, 4.0]]),
+            &JsValue::UNDEFINED
+        )
+        .is_null());
+        let invalid = fisher_exact_test(&tea, &JsValue::from_str("sideways"));
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use crate::conf_int::z_bounds;
+use crate::utils::*;
+use js_sys::{Object, Reflect};
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use wasm_bindgen::prelude::*;
+
+/// Splits classifier scores into those of the positive and negative cases.
+///
+/// # Arguments
+///
+/// * `scores` - The classifier scores.
+/// * `labels` - The true labels, 1 for positive cases and 0 for negative cases.
+///
+/// # Returns
+///
+/// * The positive and negative scores, or `None` if the lengths differ, a label is not 0
+///   or 1, or either class is empty.
+fn split_by_label(scores: &[f64], labels: &[f64]) -> Option<(Vec<f64>, Vec<f64>)> {
+    if scores.len() != labels.len() {
+        return None;
+    }
+    let mut positives = Vec::new();
+    let mut negatives = Vec::new();
+    for (score, label) in scores.iter().zip(labels) {
+        match *label {
+            1.0 => positives.push(*score),
+            0.0 => negatives.push(*score),
+            _ => return None,
+        }
+    }
+    if positives.is_empty() || negatives.is_empty() {
+        return None;
+    }
+    Some((positives, negatives))
+}
+
+/// Computes the AUC and the DeLong structural components of a set of scores.
+///
+/// # Returns
+///
+/// * A tuple of the AUC, the placement values of the positive cases, and the placement
+///   values of the negative cases.
+fn delong_components(positives: &[f64], negatives: &[f64]) -> (f64, Vec<f64>, Vec<f64>) {
+    let psi = |x: f64, y: f64| {
+        if x > y {
+            1.0
+        } else if x == y {
+            0.5
+        } else {
+            0.0
+        }
+    };
+    let v10: Vec<f64> = positives
+        .iter()
+        .map(|x| negatives.iter().map(|y| psi(*x, *y)).sum::<f64>() / negatives.len() as f64)
+        .collect();
+    let v01: Vec<f64> = negatives
+        .iter()
+        .map(|y| positives.iter().map(|x| psi(*x, *y)).sum::<f64>() / positives.len() as f64)
+        .collect();
+    let auc = v10.iter().sum::<f64>() / v10.len() as f64;
+    (auc, v10, v01)
+}
+
+/// Computes the sample covariance of two equal-length vectors.
+fn covariance(a: &[f64], b: &[f64]) -> f64 {
+    let mean_a = a.iter().sum::<f64>() / a.len() as f64;
+    let mean_b = b.iter().sum::<f64>() / b.len() as f64;
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+        .sum::<f64>()
+        / (a.len() as f64 - 1.0)
+}
+
+/// Computes the receiver operating characteristic curve of a binary classifier.
+///
+/// # Arguments
+///
+/// * `scores` - A reference to a JsValue representing the classifier scores, where higher
+///   scores indicate the positive class.
+/// * `labels` - A reference to a JsValue representing the true labels (1 for positive, 0
+///   for negative).
+///
+/// # Returns
+///
+/// * An object with the properties `fpr`, `tpr`, and `thresholds`, where each point
+///   classifies scores at or above its threshold as positive. The first point uses an
+///   infinite threshold so the curve starts at (0, 0). Returns null if the inputs are
+///   invalid.
+#[wasm_bindgen]
+pub fn roc_curve(scores: &JsValue, labels: &JsValue) -> JsValue {
+    let scores = js_array_to_vector(scores);
+    let labels = js_array_to_vector(labels);
+    let (positives, negatives) = match split_by_label(&scores, &labels) {
+        Some(split) => split,
+        None => return JsValue::NULL,
+    };
+
+    let mut cutoffs = scores.clone();
+    cutoffs.sort_by(|a, b| b.total_cmp(a));
+    cutoffs.dedup();
+
+    let mut thresholds = vec![f64::INFINITY];
+    let mut fpr = vec![0.0];
+    let mut tpr = vec![0.0];
+    for cutoff in cutoffs {
+        let true_positives = positives.iter().filter(|s| **s >= cutoff).count();
+        let false_positives = negatives.iter().filter(|s| **s >= cutoff).count();
+        thresholds.push(cutoff);
+        tpr.push(true_positives as f64 / positives.len() as f64);
+        fpr.push(false_positives as f64 / negatives.len() as f64);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("fpr"), &vec_to_jsvalue(fpr));
+    let _ = Reflect::set(&obj, &JsValue::from_str("tpr"), &vec_to_jsvalue(tpr));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("thresholds"),
+        &vec_to_jsvalue(thresholds),
+    );
+    obj.into()
+}
+
+/// Computes the area under the ROC curve with a DeLong confidence interval.
+///
+/// # Arguments
+///
+/// * `scores` - A reference to a JsValue representing the classifier scores.
+/// * `labels` - A reference to a JsValue representing the true labels (1 for positive, 0
+///   for negative).
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci` (clamped to [0, 1]), and `std_error`,
+///   or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn auc(scores: &JsValue, labels: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let scores = js_array_to_vector(scores);
+    let labels = js_array_to_vector(labels);
+    let (positives, negatives) = match split_by_label(&scores, &labels) {
+        Some(split) if split.0.len() > 1 && split.1.len() > 1 => split,
+        _ => return JsValue::NULL,
+    };
+
+    let (auc, v10, v01) = delong_components(&positives, &negatives);
+    let std_error = (covariance(&v10, &v10) / v10.len() as f64
+        + covariance(&v01, &v01) / v01.len() as f64)
+        .sqrt();
+    let (lower, upper) = z_bounds(auc, std_error, alpha);
+
+    let obj = Object::new();
+    set_estimate(&obj, auc, (lower.max(0.0), upper.min(1.0)));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &JsValue::from_f64(std_error),
+    );
+    obj.into()
+}
+
+/// Compares the AUCs of two classifiers scored on the same cases using DeLong's test for
+/// correlated ROC curves.
+///
+/// # Arguments
+///
+/// * `scores1` - A reference to a JsValue representing the first classifier's scores.
+/// * `scores2` - A reference to a JsValue representing the second classifier's scores.
+/// * `labels` - A reference to a JsValue representing the true labels (1 for positive, 0
+///   for negative).
+///
+/// # Returns
+///
+/// * An object with the properties `auc1`, `auc2`, `difference` (`auc1 - auc2`),
+///   `std_error`, `z`, and `p`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn compare_auc(scores1: &JsValue, scores2: &JsValue, labels: &JsValue) -> JsValue {
+    let scores1 = js_array_to_vector(scores1);
+    let scores2 = js_array_to_vector(scores2);
+    let labels = js_array_to_vector(labels);
+    let (split1, split2) = match (
+        split_by_label(&scores1, &labels),
+        split_by_label(&scores2, &labels),
+    ) {
+        (Some(split1), Some(split2)) if split1.0.len() > 1 && split1.1.len() > 1 => {
+            (split1, split2)
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let (auc1, v10_1, v01_1) = delong_components(&split1.0, &split1.1);
+    let (auc2, v10_2, v01_2) = delong_components(&split2.0, &split2.1);
+    let variance = (covariance(&v10_1, &v10_1) + covariance(&v10_2, &v10_2)
+        - 2.0 * covariance(&v10_1, &v10_2))
+        / v10_1.len() as f64
+        + (covariance(&v01_1, &v01_1) + covariance(&v01_2, &v01_2)
+            - 2.0 * covariance(&v01_1, &v01_2))
+            / v01_1.len() as f64;
+    let std_error = variance.sqrt();
+    let difference = auc1 - auc2;
+    let z = difference / std_error;
+    let p = 2.0 * (1.0 - Normal::new(0.0, 1.0).unwrap().cdf(z.abs()));
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("auc1"), &JsValue::from_f64(auc1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("auc2"), &JsValue::from_f64(auc2));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("difference"),
+        &JsValue::from_f64(difference),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &JsValue::from_f64(std_error),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Performs the Hosmer-Lemeshow goodness-of-fit test for the calibration of predicted
+/// probabilities.
+///
+/// # Arguments
+///
+/// * `predicted_probs` - A reference to a JsValue representing the predicted probabilities
+///   of the positive outcome.
+/// * `outcomes` - A reference to a JsValue representing the observed outcomes (1 or 0).
+/// * `groups` - A reference to a JsValue representing the number of risk groups (defaults
+///   to 10).
+///
+/// # Returns
+///
+/// * An object with the properties `x2`, `df` (`groups - 2`), `p`, and the per-group
+///   `size`, `observed`, and `expected` event counts, or null if the inputs are invalid.
+///   The cases are sorted by predicted probability and split into groups of nearly equal
+///   size.
+#[wasm_bindgen]
+pub fn hosmer_lemeshow_test(
+    predicted_probs: &JsValue,
+    outcomes: &JsValue,
+    groups: &JsValue,
+) -> JsValue {
+    let probs = js_array_to_vector(predicted_probs);
+    let outcomes = js_array_to_vector(outcomes);
+    let g = groups.as_f64().unwrap_or(10.0) as usize;
+    let n = probs.len();
+    if n != outcomes.len()
+        || g < 3
+        || n < g
+        || probs.iter().any(|p| !(0.0..=1.0).contains(p))
+        || outcomes.iter().any(|y| *y != 0.0 && *y != 1.0)
+    {
+        return JsValue::NULL;
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| probs[*a].total_cmp(&probs[*b]));
+
+    let mut size = Vec::with_capacity(g);
+    let mut observed = Vec::with_capacity(g);
+    let mut expected = Vec::with_capacity(g);
+    let mut x2 = 0.0;
+    for j in 0..g {
+        let members = &order[j * n / g..(j + 1) * n / g];
+        let m = members.len() as f64;
+        let o: f64 = members.iter().map(|i| outcomes[*i]).sum();
+        let e: f64 = members.iter().map(|i| probs[*i]).sum();
+        if e > 0.0 {
+            x2 += (o - e).powi(2) / e;
+        }
+        if m - e > 0.0 {
+            x2 += (o - e).powi(2) / (m - e);
+        }
+        size.push(m);
+        observed.push(o);
+        expected.push(e);
+    }
+
+    let df = (g - 2) as f64;
+    let p = 1.0 - ChiSquared::new(df).unwrap().cdf(x2);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(&obj, &JsValue::from_str("size"), &vec_to_jsvalue(size));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("observed"),
+        &vec_to_jsvalue(observed),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected"),
+        &vec_to_jsvalue(expected),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn scores1() -> JsValue {
+        vec_to_jsvalue(vec![
+            0.9, 0.8, 0.7, 0.6, 0.55, 0.54, 0.53, 0.52, 0.51, 0.505, 0.4, 0.39, 0.38, 0.37, 0.36,
+            0.35, 0.34, 0.33, 0.30, 0.1,
+        ])
+    }
+
+    fn scores2() -> JsValue {
+        vec_to_jsvalue(vec![
+            0.7, 0.9, 0.6, 0.5, 0.65, 0.3, 0.53, 0.58, 0.2, 0.45, 0.4, 0.35, 0.52, 0.1, 0.38, 0.25,
+            0.6, 0.15, 0.05, 0.2,
+        ])
+    }
+
+    fn labels() -> JsValue {
+        vec_to_jsvalue(vec![
+            1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+            0.0, 0.0, 0.0,
+        ])
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_roc_curve() {
+        let result = roc_curve(&scores1(), &labels());
+
+        let fpr = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("fpr")).unwrap());
+        let tpr = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("tpr")).unwrap());
+        let thresholds =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("thresholds")).unwrap());
+
+        assert_eq!(thresholds.len(), 21);
+        assert!(thresholds[0].is_infinite());
+        assert_eq!((fpr[0], tpr[0]), (0.0, 0.0));
+        assert_eq!(thresholds[5], 0.55);
+        assert!((tpr[5] - 4.0 / 9.0).abs() < 1e-12);
+        assert!((fpr[5] - 1.0 / 11.0).abs() < 1e-12);
+        assert_eq!((fpr[20], tpr[20]), (1.0, 1.0));
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_auc() {
+        let result = auc(&scores1(), &labels(), &JsValue::from_f64(0.05));
+
+        let estimate = Reflect::get(&result, &JsValue::from_str("estimate")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+        let std_error = Reflect::get(&result, &JsValue::from_str("std_error")).unwrap();
+
+        assert!((estimate.as_f64().unwrap() - 0.767677).abs() < 0.0001);
+        assert!((std_error.as_f64().unwrap() - 0.110328).abs() < 0.0001);
+        assert!((ci[0] - 0.551438).abs() < 0.0001);
+        assert!((ci[1] - 0.983915).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_compare_auc() {
+        let result = compare_auc(&scores1(), &scores2(), &labels());
+
+        let auc2 = Reflect::get(&result, &JsValue::from_str("auc2")).unwrap();
+        let std_error = Reflect::get(&result, &JsValue::from_str("std_error")).unwrap();
+        let z = Reflect::get(&result, &JsValue::from_str("z")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        assert!((auc2.as_f64().unwrap() - 0.757576).abs() < 0.0001);
+        assert!((std_error.as_f64().unwrap() - 0.115716).abs() < 0.0001);
+        assert!((z.as_f64().unwrap() - 0.087291).abs() < 0.0001);
+        assert!((p.as_f64().unwrap() - 0.930440).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_hosmer_lemeshow_test() {
+        let probs = vec_to_jsvalue(vec![
+            0.238, 0.544, 0.37, 0.604, 0.626, 0.066, 0.013, 0.837, 0.259, 0.234, 0.996, 0.47,
+            0.836, 0.476, 0.639, 0.151, 0.635, 0.868, 0.523, 0.741, 0.671, 0.064, 0.758, 0.591,
+            0.301, 0.031, 0.866, 0.473, 0.719, 0.879,
+        ]);
+        let outcomes = vec_to_jsvalue(vec![
+            0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 1.0,
+            1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+        ]);
+
+        let result = hosmer_lemeshow_test(&probs, &outcomes, &JsValue::from_f64(5.0));
+
+        let x2 = Reflect::get(&result, &JsValue::from_str("x2")).unwrap();
+        let df = Reflect::get(&result, &JsValue::from_str("df")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+        let observed =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("observed")).unwrap());
+        let expected =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("expected")).unwrap());
+
+        assert!((x2.as_f64().unwrap() - 6.137819).abs() < 0.0001);
+        assert_eq!(df.as_f64().unwrap(), 3.0);
+        assert!((p.as_f64().unwrap() - 0.105094).abs() < 0.0001);
+        assert_eq!(observed, vec![1.0, 2.0, 2.0, 2.0, 6.0]);
+        assert!((expected[4] - 5.282).abs() < 1e-9);
+    }
+}
@@ -1,6 +1,10 @@
 use crate::utils::*;
-use js_sys::Array;
-use statrs::distribution::{ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+use js_sys::{Array, Object, Reflect};
+use rand::Rng;
+use statrs::distribution::{
+    Beta, Binomial, ChiSquared, ContinuousCDF, DiscreteCDF, FisherSnedecor, Normal, StudentsT,
+};
+use statrs::statistics::{Data, OrderStatistics};
 use wasm_bindgen::prelude::*;
 
 /// Calculates a one-sample Z-interval (confidence interval for population mean)
@@ -9,23 +13,34 @@ use wasm_bindgen::prelude::*;
 /// # Arguments
 /// * `column` - A JavaScript array of numerical values representing the sample
 /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+/// * `options` - An optional object; `{ sigma: 2 }` supplies the known population standard
+///   deviation, which must be positive (the sample standard deviation is used when omitted)
 ///
 /// # Returns
 /// A JavaScript array containing:
 /// - Lower bound of the confidence interval
 /// - Upper bound of the confidence interval
+///
+/// or null if the sample is empty or `sigma` is not positive.
 #[wasm_bindgen]
-pub fn one_samp_z_interval(column: &JsValue, alpha: &JsValue) -> JsValue {
+pub fn one_samp_z_interval(column: &JsValue, alpha: &JsValue, options: &JsValue) -> JsValue {
     let alpha = alpha.as_f64().unwrap_or(0.05);
     let data = js_array_to_vector(column);
+    let sigma = get_f64_option(options, "sigma");
 
-    if data.is_empty() {
+    if data.is_empty() || sigma.is_some_and(|sigma| sigma.is_nan() || sigma <= 0.0) {
         return JsValue::NULL;
     }
 
     let mean = data.iter().sum::<f64>() / data.len() as f64;
-    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (data.len() - 1) as f64;
-    let std_dev = variance.sqrt();
+    let std_dev = match sigma {
+        Some(sigma) => sigma,
+        None => {
+            let variance =
+                data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (data.len() - 1) as f64;
+            variance.sqrt()
+        }
+    };
 
     let z_score = Normal::new(0.0, 1.0)
         .unwrap()
@@ -50,18 +65,36 @@ pub fn one_samp_z_interval(column: &JsValue, alpha: &JsValue) -> JsValue {
 /// * `column1` - A JavaScript array of numerical values for the first sample
 /// * `column2` - A JavaScript array of numerical values for the second sample
 /// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+/// * `options` - An optional object; `{ sigma1: 2, sigma2: 3 }` supplies the known,
+///   positive standard deviations of the populations (the sample standard deviations are
+///   used when omitted)
 ///
 /// # Returns
 /// A JavaScript array containing:
 /// - Lower bound of the confidence interval
 /// - Upper bound of the confidence interval
+///
+/// or null if either sample is empty or a given sigma is not positive.
 #[wasm_bindgen]
-pub fn two_samp_z_interval(column1: &JsValue, column2: &JsValue, alpha: &JsValue) -> JsValue {
+pub fn two_samp_z_interval(
+    column1: &JsValue,
+    column2: &JsValue,
+    alpha: &JsValue,
+    options: &JsValue,
+) -> JsValue {
     let alpha = alpha.as_f64().unwrap_or(0.05);
     let data1 = js_array_to_vector(column1);
     let data2 = js_array_to_vector(column2);
-
-    if data1.is_empty() || data2.is_empty() {
+    let sigma1 = get_f64_option(options, "sigma1");
+    let sigma2 = get_f64_option(options, "sigma2");
+
+    if data1.is_empty()
+        || data2.is_empty()
+        || [sigma1, sigma2]
+            .iter()
+            .flatten()
+            .any(|sigma| sigma.is_nan() || *sigma <= 0.0)
+    {
         return JsValue::NULL;
     }
 
@@ -71,8 +104,8 @@ pub fn two_samp_z_interval(column1: &JsValue, column2: &JsValue, alpha: &JsValue
     let var1 = data1.iter().map(|x| (x - mean1).powi(2)).sum::<f64>() / (data1.len() - 1) as f64;
     let var2 = data2.iter().map(|x| (x - mean2).powi(2)).sum::<f64>() / (data2.len() - 1) as f64;
 
-    let std_dev1 = var1.sqrt();
-    let std_dev2 = var2.sqrt();
+    let std_dev1 = sigma1.unwrap_or(var1.sqrt());
+    let std_dev2 = sigma2.unwrap_or(var2.sqrt());
 
     let z_score = Normal::new(0.0, 1.0)
         .unwrap()
@@ -244,19 +277,539 @@ pub fn two_samp_var_interval(column1: &JsValue, column2: &JsValue, alpha: &JsVal
     arr.into()
 }
 
+/// Calculates a confidence interval for the variance and standard deviation of a single
+/// population using the chi-square distribution.
+///
+/// # Arguments
+/// * `column` - A JavaScript array of numerical values representing the sample
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// An object with two properties, each an array with the lower and upper bounds:
+/// - `variance`: the interval for the population variance
+/// - `std_dev`: the interval for the population standard deviation
+///
+/// Returns null if the sample has fewer than two values.
+#[wasm_bindgen]
+pub fn one_samp_var_interval(column: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let data = js_array_to_vector(column);
+
+    if data.len() < 2 {
+        return JsValue::NULL;
+    }
+
+    let mean = data.iter().sum::<f64>() / data.len() as f64;
+    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (data.len() - 1) as f64;
+    let (lower, upper) = variance_bounds(variance, (data.len() - 1) as f64, alpha);
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("variance"),
+        &vec_to_jsvalue(vec![lower, upper]),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_dev"),
+        &vec_to_jsvalue(vec![lower.sqrt(), upper.sqrt()]),
+    );
+
+    obj.into()
+}
+
+/// Calculates a confidence interval for Pearson's correlation coefficient using the
+/// Fisher z transformation.
+///
+/// # Arguments
+/// * `x` - A JavaScript array of numerical values for the first variable
+/// * `y` - A JavaScript array of numerical values for the second variable
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A JavaScript array containing:
+/// - Lower bound of the confidence interval
+/// - Upper bound of the confidence interval
+///
+/// Returns null if the variables differ in length, have fewer than four values, or either
+/// is constant.
+#[wasm_bindgen]
+pub fn correlation_interval(x: &JsValue, y: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+
+    if x.len() != y.len() || x.len() < 4 {
+        return JsValue::NULL;
+    }
+
+    let r = pearson(&x, &y);
+    if r.is_nan() {
+        return JsValue::NULL;
+    }
+
+    // atanh(r) is approximately normal with standard error 1 / sqrt(n - 3)
+    let std_error = 1.0 / ((x.len() - 3) as f64).sqrt();
+    let (lower, upper) = z_bounds(r.atanh(), std_error, alpha);
+
+    let arr = Array::new();
+    arr.set(0, JsValue::from_f64(lower.tanh()));
+    arr.set(1, JsValue::from_f64(upper.tanh()));
+
+    arr.into()
+}
+
+/// Calculates a confidence interval for the population median.
+///
+/// # Arguments
+/// * `column` - A JavaScript array of numerical values representing the sample
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+/// * `method` - The interval method: "exact" (order statistics chosen from the
+///   binomial distribution, with coverage of at least `1 - alpha`; the default) or
+///   "bootstrap" (the percentile bootstrap)
+/// * `options` - An optional object with the properties `resamples` (the number of
+///   bootstrap resamples; defaults to 9999) and `seed`
+///
+/// # Returns
+/// A JavaScript array containing:
+/// - Lower bound of the confidence interval
+/// - Upper bound of the confidence interval
+///
+/// Returns null if the sample is empty, the method is unknown, or the sample is too
+/// small for an exact interval at the requested level.
+#[wasm_bindgen]
+pub fn median_interval(
+    column: &JsValue,
+    alpha: &JsValue,
+    method: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let method = method.as_string().unwrap_or("exact".to_string());
+    let mut data = js_array_to_vector(column);
+
+    if data.is_empty() {
+        return JsValue::NULL;
+    }
+    data.sort_by(|a, b| a.total_cmp(b));
+
+    let bounds = match method.as_str() {
+        "exact" => order_statistic_bounds(&data, 0.5, alpha),
+        "bootstrap" => {
+            let resamples = get_f64_option(options, "resamples").unwrap_or(9999.0) as usize;
+            let mut rng = seeded_rng(get_f64_option(options, "seed"));
+            let n = data.len();
+            let medians: Vec<f64> = (0..resamples)
+                .map(|_| {
+                    let resample: Vec<f64> = (0..n).map(|_| data[rng.gen_range(0..n)]).collect();
+                    Data::new(resample).median()
+                })
+                .collect();
+            let mut medians = Data::new(medians);
+            Some((
+                medians.quantile(alpha / 2.0),
+                medians.quantile(1.0 - alpha / 2.0),
+            ))
+        }
+        _ => None,
+    };
+    let (lower, upper) = match bounds {
+        Some(bounds) => bounds,
+        None => return JsValue::NULL,
+    };
+
+    let arr = Array::new();
+    arr.set(0, JsValue::from_f64(lower));
+    arr.set(1, JsValue::from_f64(upper));
+
+    arr.into()
+}
+
+/// Calculates a distribution-free confidence interval for a population quantile from the
+/// order statistics of the sample.
+///
+/// # Arguments
+/// * `column` - A JavaScript array of numerical values representing the sample
+/// * `q` - The quantile, strictly between 0 and 1 (e.g., 0.9 for the 90th percentile)
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A JavaScript array containing:
+/// - Lower bound of the confidence interval
+/// - Upper bound of the confidence interval
+///
+/// The interval has coverage of at least `1 - alpha`. Returns null if the quantile is out
+/// of range or the sample is too small to reach that coverage.
+#[wasm_bindgen]
+pub fn quantile_interval(column: &JsValue, q: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let q = q.as_f64().unwrap_or(f64::NAN);
+    let mut data = js_array_to_vector(column);
+
+    if data.is_empty() || q.is_nan() || q <= 0.0 || q >= 1.0 {
+        return JsValue::NULL;
+    }
+    data.sort_by(|a, b| a.total_cmp(b));
+
+    let (lower, upper) = match order_statistic_bounds(&data, q, alpha) {
+        Some(bounds) => bounds,
+        None => return JsValue::NULL,
+    };
+
+    let arr = Array::new();
+    arr.set(0, JsValue::from_f64(lower));
+    arr.set(1, JsValue::from_f64(upper));
+
+    arr.into()
+}
+
+/// Calculates an exact confidence interval for a Poisson event rate using the chi-square
+/// quantiles of the Poisson-gamma relationship.
+///
+/// # Arguments
+/// * `count` - The observed number of events
+/// * `exposure` - The exposure over which the events were counted (e.g., person-years)
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A JavaScript array containing:
+/// - Lower bound of the confidence interval for the rate
+/// - Upper bound of the confidence interval for the rate
+///
+/// Returns null if the count is negative or not whole, or the exposure is not positive.
+#[wasm_bindgen]
+pub fn poisson_rate_interval(count: &JsValue, exposure: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let count = count.as_f64().unwrap_or(f64::NAN);
+    let exposure = exposure.as_f64().unwrap_or(f64::NAN);
+
+    if count.is_nan() || count < 0.0 || count.fract() != 0.0 || exposure.is_nan() || exposure <= 0.0
+    {
+        return JsValue::NULL;
+    }
+
+    let lower = if count == 0.0 {
+        0.0
+    } else {
+        ChiSquared::new(2.0 * count)
+            .unwrap()
+            .inverse_cdf(alpha / 2.0)
+            / (2.0 * exposure)
+    };
+    let upper = ChiSquared::new(2.0 * count + 2.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0)
+        / (2.0 * exposure);
+
+    let arr = Array::new();
+    arr.set(0, JsValue::from_f64(lower));
+    arr.set(1, JsValue::from_f64(upper));
+
+    arr.into()
+}
+
+/// Calculates a confidence interval for a single population proportion.
+///
+/// # Arguments
+/// * `successes` - The number of successes
+/// * `n` - The number of trials
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+/// * `method` - The interval method: "wald", "wilson" (the default), "clopper_pearson"
+///   (exact), or "agresti_coull"
+///
+/// # Returns
+/// A JavaScript array containing:
+/// - Lower bound of the confidence interval
+/// - Upper bound of the confidence interval
+///
+/// Returns null if the counts are invalid or the method is unknown. The Wald and
+/// Agresti-Coull bounds are clipped to [0, 1].
+#[wasm_bindgen]
+pub fn one_prop_interval(
+    successes: &JsValue,
+    n: &JsValue,
+    alpha: &JsValue,
+    method: &JsValue,
+) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let method = method.as_string().unwrap_or("wilson".to_string());
+    let x = successes.as_f64().unwrap_or(f64::NAN);
+    let n = n.as_f64().unwrap_or(f64::NAN);
+
+    if x.is_nan() || n.is_nan() || n <= 0.0 || x < 0.0 || x > n {
+        return JsValue::NULL;
+    }
+
+    let z_score = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let (lower, upper) = match method.as_str() {
+        "wald" => {
+            let p_hat = x / n;
+            let (lower, upper) = z_bounds(p_hat, (p_hat * (1.0 - p_hat) / n).sqrt(), alpha);
+            (lower.max(0.0), upper.min(1.0))
+        }
+        "wilson" => wilson_bounds(x, n, alpha),
+        "clopper_pearson" => {
+            let lower = if x == 0.0 {
+                0.0
+            } else {
+                Beta::new(x, n - x + 1.0).unwrap().inverse_cdf(alpha / 2.0)
+            };
+            let upper = if x == n {
+                1.0
+            } else {
+                Beta::new(x + 1.0, n - x)
+                    .unwrap()
+                    .inverse_cdf(1.0 - alpha / 2.0)
+            };
+            (lower, upper)
+        }
+        "agresti_coull" => {
+            // The Wald interval after adding z²/2 successes and z²/2 failures
+            let n_tilde = n + z_score.powi(2);
+            let p_tilde = (x + z_score.powi(2) / 2.0) / n_tilde;
+            let (lower, upper) =
+                z_bounds(p_tilde, (p_tilde * (1.0 - p_tilde) / n_tilde).sqrt(), alpha);
+            (lower.max(0.0), upper.min(1.0))
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let arr = Array::new();
+    arr.set(0, JsValue::from_f64(lower));
+    arr.set(1, JsValue::from_f64(upper));
+
+    arr.into()
+}
+
+/// Calculates a confidence interval for the difference between two population
+/// proportions, p1 - p2.
+///
+/// # Arguments
+/// * `x1` - The number of successes in the first sample
+/// * `n1` - The size of the first sample
+/// * `x2` - The number of successes in the second sample
+/// * `n2` - The size of the second sample
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+/// * `method` - The interval method: "newcombe" (the hybrid score interval built from
+///   Wilson intervals for each proportion; the default), "wald", or "wald_cc" (the Wald
+///   interval widened by the continuity correction `(1/n1 + 1/n2) / 2`)
+///
+/// # Returns
+/// A JavaScript array containing:
+/// - Lower bound of the confidence interval
+/// - Upper bound of the confidence interval
+///
+/// Returns null if the counts are invalid or the method is unknown. The Wald bounds are
+/// clipped to [-1, 1].
+#[wasm_bindgen]
+pub fn two_prop_interval(
+    x1: &JsValue,
+    n1: &JsValue,
+    x2: &JsValue,
+    n2: &JsValue,
+    alpha: &JsValue,
+    method: &JsValue,
+) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let method = method.as_string().unwrap_or("newcombe".to_string());
+    let counts = [x1, n1, x2, n2].map(|value| value.as_f64().unwrap_or(f64::NAN));
+    let [x1, n1, x2, n2] = counts;
+
+    if counts.iter().any(|count| count.is_nan())
+        || n1 <= 0.0
+        || n2 <= 0.0
+        || !(0.0..=n1).contains(&x1)
+        || !(0.0..=n2).contains(&x2)
+    {
+        return JsValue::NULL;
+    }
+
+    let p1 = x1 / n1;
+    let p2 = x2 / n2;
+    let diff = p1 - p2;
+    let std_error = (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt();
+
+    let (lower, upper) = match method.as_str() {
+        "newcombe" => {
+            let (l1, u1) = wilson_bounds(x1, n1, alpha);
+            let (l2, u2) = wilson_bounds(x2, n2, alpha);
+            (
+                diff - ((p1 - l1).powi(2) + (u2 - p2).powi(2)).sqrt(),
+                diff + ((u1 - p1).powi(2) + (p2 - l2).powi(2)).sqrt(),
+            )
+        }
+        "wald" => {
+            let (lower, upper) = z_bounds(diff, std_error, alpha);
+            (lower.max(-1.0), upper.min(1.0))
+        }
+        "wald_cc" => {
+            let correction = 0.5 * (1.0 / n1 + 1.0 / n2);
+            let (lower, upper) = z_bounds(diff, std_error, alpha);
+            (
+                (lower - correction).max(-1.0),
+                (upper + correction).min(1.0),
+            )
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let arr = Array::new();
+    arr.set(0, JsValue::from_f64(lower));
+    arr.set(1, JsValue::from_f64(upper));
+
+    arr.into()
+}
+
+/// Computes the Wilson score confidence interval for a proportion.
+///
+/// # Arguments
+/// * `successes` - The number of successes
+/// * `n` - The number of trials
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A tuple containing the lower and upper bounds of the confidence interval
+pub(crate) fn wilson_bounds(successes: f64, n: f64, alpha: f64) -> (f64, f64) {
+    let z_score = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let p_hat = successes / n;
+    let z2 = z_score.powi(2);
+
+    let center = (p_hat + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let half_width =
+        z_score / (1.0 + z2 / n) * (p_hat * (1.0 - p_hat) / n + z2 / (4.0 * n * n)).sqrt();
+
+    (center - half_width, center + half_width)
+}
+
+/// Computes a two-sided normal-theory confidence interval around an estimate.
+///
+/// # Arguments
+/// * `estimate` - The point estimate
+/// * `std_error` - The standard error of the estimate
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A tuple containing the lower and upper bounds of the confidence interval
+pub(crate) fn z_bounds(estimate: f64, std_error: f64, alpha: f64) -> (f64, f64) {
+    let z_score = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let margin_of_error = z_score * std_error;
+
+    (estimate - margin_of_error, estimate + margin_of_error)
+}
+
+/// Computes a two-sided Student's t confidence interval around an estimate.
+///
+/// # Arguments
+/// * `estimate` - The point estimate
+/// * `std_error` - The standard error of the estimate
+/// * `df` - The degrees of freedom of the t-distribution
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A tuple containing the lower and upper bounds of the confidence interval
+pub(crate) fn t_bounds(estimate: f64, std_error: f64, df: f64, alpha: f64) -> (f64, f64) {
+    let t_score = StudentsT::new(0.0, 1.0, df)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let margin_of_error = t_score * std_error;
+
+    (estimate - margin_of_error, estimate + margin_of_error)
+}
+
+/// Computes a two-sided confidence interval for a ratio of variances using the
+/// F-distribution.
+///
+/// # Arguments
+/// * `ratio` - The ratio of the first sample variance to the second
+/// * `df1` - The degrees of freedom of the first sample
+/// * `df2` - The degrees of freedom of the second sample
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A tuple containing the lower and upper bounds of the confidence interval
+pub(crate) fn f_ratio_bounds(ratio: f64, df1: f64, df2: f64, alpha: f64) -> (f64, f64) {
+    let f_dist = FisherSnedecor::new(df1, df2).unwrap();
+    let f_lower = f_dist.inverse_cdf(alpha / 2.0);
+    let f_upper = f_dist.inverse_cdf(1.0 - alpha / 2.0);
+
+    (ratio / f_upper, ratio / f_lower)
+}
+
+/// Computes a two-sided confidence interval for a variance using the chi-square
+/// distribution.
+///
+/// # Arguments
+/// * `variance` - The sample variance
+/// * `df` - The degrees of freedom of the sample variance
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A tuple containing the lower and upper bounds of the confidence interval
+pub(crate) fn variance_bounds(variance: f64, df: f64, alpha: f64) -> (f64, f64) {
+    let chi2_dist = ChiSquared::new(df).unwrap();
+    let chi2_lower = chi2_dist.inverse_cdf(alpha / 2.0);
+    let chi2_upper = chi2_dist.inverse_cdf(1.0 - alpha / 2.0);
+
+    (df * variance / chi2_upper, df * variance / chi2_lower)
+}
+
+/// Chooses the order statistics that bracket a population quantile with coverage of at
+/// least `1 - alpha`, using the binomial distribution of the number of observations below
+/// the quantile.
+///
+/// # Arguments
+/// * `sorted` - The sample, sorted in ascending order
+/// * `q` - The quantile, between 0 and 1
+/// * `alpha` - The significance level (e.g., 0.05 for a 95% confidence interval)
+///
+/// # Returns
+/// A tuple containing the lower and upper bounds of the confidence interval, or `None` if
+/// the sample is too small to reach the requested coverage on either side
+fn order_statistic_bounds(sorted: &[f64], q: f64, alpha: f64) -> Option<(f64, f64)> {
+    let n = sorted.len() as u64;
+    let binomial = Binomial::new(q, n).ok()?;
+
+    // The lower bound is the largest order statistic k with P(B <= k - 1) <= alpha / 2, and
+    // the upper bound the smallest k with P(B <= k - 1) >= 1 - alpha / 2
+    let lower = (1..=n)
+        .take_while(|k| binomial.cdf(k - 1) <= alpha / 2.0)
+        .last()?;
+    let upper = (1..=n).find(|k| binomial.cdf(k - 1) >= 1.0 - alpha / 2.0)?;
+
+    Some((sorted[lower as usize - 1], sorted[upper as usize - 1]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::vec_to_jsvalue;
+    use crate::utils::{js_array_to_vector, vec_to_jsvalue};
     use wasm_bindgen_test::*;
 
+    fn sigma_options(sigmas: &[(&str, f64)]) -> JsValue {
+        let options = Object::new();
+        for (key, value) in sigmas {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str(key),
+                &JsValue::from_f64(*value),
+            );
+        }
+        options.into()
+    }
+
     #[allow(unused)]
     #[wasm_bindgen_test]
     fn test_one_samp_z_interval() {
         let data = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
         let alpha = JsValue::from_f64(0.05);
 
-        let result = one_samp_z_interval(&data, &alpha);
+        let result = one_samp_z_interval(&data, &alpha, &JsValue::UNDEFINED);
         let result_arr: Array = result.into();
 
         assert_eq!(
@@ -284,6 +837,12 @@ mod tests {
             "Upper bound should be 4.3859, not {}",
             upper_bound
         );
+
+        let known = one_samp_z_interval(&data, &alpha, &sigma_options(&[("sigma", 2.0)]));
+        let known_arr: Array = known.into();
+        assert!((known_arr.get(0).as_f64().unwrap() - 1.246955).abs() < 0.001);
+        assert!((known_arr.get(1).as_f64().unwrap() - 4.753045).abs() < 0.001);
+        assert!(one_samp_z_interval(&data, &alpha, &sigma_options(&[("sigma", 0.0)])).is_null());
     }
 
     #[allow(unused)]
@@ -293,7 +852,7 @@ mod tests {
         let data2 = vec_to_jsvalue(vec![2.0, 3.0, 4.0, 5.0, 6.0]);
         let alpha = JsValue::from_f64(0.05);
 
-        let result = two_samp_z_interval(&data1, &data2, &alpha);
+        let result = two_samp_z_interval(&data1, &data2, &alpha, &JsValue::UNDEFINED);
         let result_arr: Array = result.into();
 
         assert_eq!(
@@ -320,7 +879,15 @@ mod tests {
             (upper_bound - 0.959964).abs() < 0.01,
             "Upper bound should be 0.959964, not {}",
             upper_bound
-        )
+        );
+
+        let sigma = sigma_options(&[("sigma1", 2.0), ("sigma2", 2.0)]);
+        let known = two_samp_z_interval(&data1, &data2, &alpha, &sigma);
+        let known_arr: Array = known.into();
+        assert!((known_arr.get(0).as_f64().unwrap() + 3.479180).abs() < 0.001);
+        assert!((known_arr.get(1).as_f64().unwrap() - 1.479180).abs() < 0.001);
+        let negative = sigma_options(&[("sigma1", 2.0), ("sigma2", -1.0)]);
+        assert!(two_samp_z_interval(&data1, &data2, &alpha, &negative).is_null());
     }
 
     #[allow(unused)]
@@ -432,4 +999,227 @@ mod tests {
             upper_bound
         )
     }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_one_prop_interval() {
+        let successes = JsValue::from_f64(7.0);
+        let n = JsValue::from_f64(40.0);
+        let alpha = JsValue::from_f64(0.05);
+
+        let bounds = |method: &str| -> Vec<f64> {
+            js_array_to_vector(&one_prop_interval(
+                &successes,
+                &n,
+                &alpha,
+                &JsValue::from_str(method),
+            ))
+        };
+        let wald = bounds("wald");
+        let wilson = bounds("wilson");
+        let exact = bounds("clopper_pearson");
+        let agresti_coull = bounds("agresti_coull");
+        let default = js_array_to_vector(&one_prop_interval(
+            &successes,
+            &n,
+            &alpha,
+            &JsValue::UNDEFINED,
+        ));
+        let zero = js_array_to_vector(&one_prop_interval(
+            &JsValue::from_f64(0.0),
+            &n,
+            &alpha,
+            &JsValue::from_str("clopper_pearson"),
+        ));
+
+        assert!((wald[0] - 0.057249).abs() < 0.0001);
+        assert!((wald[1] - 0.292751).abs() < 0.0001);
+        assert!((wilson[0] - 0.087454).abs() < 0.0001);
+        assert!((wilson[1] - 0.319500).abs() < 0.0001);
+        assert!((exact[0] - 0.073383).abs() < 0.0001);
+        assert!((exact[1] - 0.327790).abs() < 0.0001);
+        assert!((agresti_coull[0] - 0.084308).abs() < 0.0001);
+        assert!((agresti_coull[1] - 0.322646).abs() < 0.0001);
+        assert_eq!(default, wilson);
+        assert_eq!(zero[0], 0.0);
+        assert!((zero[1] - 0.088097).abs() < 0.0001);
+        assert!(one_prop_interval(&successes, &n, &alpha, &JsValue::from_str("logit")).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_two_prop_interval() {
+        let counts = [56.0, 70.0, 48.0, 80.0].map(JsValue::from_f64);
+        let alpha = JsValue::from_f64(0.05);
+
+        let bounds = |method: &JsValue| -> Vec<f64> {
+            js_array_to_vector(&two_prop_interval(
+                &counts[0], &counts[1], &counts[2], &counts[3], &alpha, method,
+            ))
+        };
+        let newcombe = bounds(&JsValue::UNDEFINED);
+        let wald = bounds(&JsValue::from_str("wald"));
+        let corrected = bounds(&JsValue::from_str("wald_cc"));
+
+        assert!((newcombe[0] - 0.052431).abs() < 0.0001);
+        assert!((newcombe[1] - 0.333873).abs() < 0.0001);
+        assert!((wald[0] - 0.057505).abs() < 0.0001);
+        assert!((wald[1] - 0.342495).abs() < 0.0001);
+        assert!((corrected[0] - 0.044112).abs() < 0.0001);
+        assert!((corrected[1] - 0.355888).abs() < 0.0001);
+        assert!(two_prop_interval(
+            &counts[1],
+            &counts[0],
+            &counts[2],
+            &counts[3],
+            &alpha,
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_one_samp_var_interval() {
+        let data = vec_to_jsvalue(vec![4.2, 5.1, 3.8, 6.0, 5.5, 4.9, 5.3, 4.4]);
+        let alpha = JsValue::from_f64(0.05);
+
+        let result = one_samp_var_interval(&data, &alpha);
+        let variance =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("variance")).unwrap());
+        let std_dev =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("std_dev")).unwrap());
+
+        assert!((variance[0] - 0.232315).abs() < 0.0001);
+        assert!((variance[1] - 2.201354).abs() < 0.0001);
+        assert!((std_dev[0] - 0.481990).abs() < 0.0001);
+        assert!((std_dev[1] - 1.483696).abs() < 0.0001);
+        assert!(one_samp_var_interval(&vec_to_jsvalue(vec![1.0]), &alpha).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_correlation_interval() {
+        let x = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+        let y = vec_to_jsvalue(vec![2.1, 3.9, 3.2, 5.8, 4.9, 7.2, 6.1, 8.8, 8.1, 9.9]);
+
+        let ci95 = js_array_to_vector(&correlation_interval(&x, &y, &JsValue::from_f64(0.05)));
+        let ci99 = js_array_to_vector(&correlation_interval(&x, &y, &JsValue::from_f64(0.01)));
+
+        assert!((ci95[0] - 0.791862).abs() < 0.0001);
+        assert!((ci95[1] - 0.988072).abs() < 0.0001);
+        assert!((ci99[0] - 0.687726).abs() < 0.0001);
+        assert!((ci99[1] - 0.992495).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_median_interval() {
+        let data = vec_to_jsvalue(vec![
+            3.1, 7.4, 2.2, 9.8, 4.5, 5.0, 12.3, 3.8, 6.7, 4.1, 8.2, 2.9, 5.6, 15.4, 4.8,
+        ]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("resamples"),
+            &JsValue::from_f64(2000.0),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("seed"),
+            &JsValue::from_f64(7.0),
+        );
+        let options: JsValue = options.into();
+
+        let exact95 = js_array_to_vector(&median_interval(
+            &data,
+            &JsValue::from_f64(0.05),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        ));
+        let exact99 = js_array_to_vector(&median_interval(
+            &data,
+            &JsValue::from_f64(0.01),
+            &JsValue::from_str("exact"),
+            &JsValue::UNDEFINED,
+        ));
+        let bootstrap = |options: &JsValue| {
+            js_array_to_vector(&median_interval(
+                &data,
+                &JsValue::from_f64(0.05),
+                &JsValue::from_str("bootstrap"),
+                options,
+            ))
+        };
+        let boot1 = bootstrap(&options);
+        let boot2 = bootstrap(&options);
+        let tiny = median_interval(
+            &vec_to_jsvalue(vec![1.0, 2.0, 3.0]),
+            &JsValue::from_f64(0.05),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        );
+
+        assert_eq!(exact95, vec![3.8, 8.2]);
+        assert_eq!(exact99, vec![3.1, 9.8]);
+        assert_eq!(boot1, boot2);
+        assert!(boot1[0] >= 2.2 && boot1[0] <= 5.0);
+        assert!(boot1[1] >= 5.0 && boot1[1] <= 15.4);
+        assert!(tiny.is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_poisson_rate_interval() {
+        let alpha = JsValue::from_f64(0.05);
+        let exposure = JsValue::from_f64(4.5);
+
+        let ci = js_array_to_vector(&poisson_rate_interval(
+            &JsValue::from_f64(12.0),
+            &exposure,
+            &alpha,
+        ));
+        let zero = js_array_to_vector(&poisson_rate_interval(
+            &JsValue::from_f64(0.0),
+            &exposure,
+            &alpha,
+        ));
+
+        assert!((ci[0] - 1.377906).abs() < 0.0001);
+        assert!((ci[1] - 4.658130).abs() < 0.0001);
+        assert_eq!(zero[0], 0.0);
+        assert!((zero[1] - 0.819751).abs() < 0.0001);
+        assert!(poisson_rate_interval(&JsValue::from_f64(2.5), &exposure, &alpha).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_quantile_interval() {
+        let data = vec_to_jsvalue(vec![
+            3.1, 7.4, 2.2, 9.8, 4.5, 5.0, 12.3, 3.8, 6.7, 4.1, 8.2, 2.9, 5.6, 15.4, 4.8,
+        ]);
+        let latencies = vec_to_jsvalue((1..=60).map(|i| i as f64).collect());
+
+        let quartile = js_array_to_vector(&quantile_interval(
+            &data,
+            &JsValue::from_f64(0.25),
+            &JsValue::from_f64(0.1),
+        ));
+        let median = js_array_to_vector(&quantile_interval(
+            &data,
+            &JsValue::from_f64(0.5),
+            &JsValue::from_f64(0.05),
+        ));
+        let p90 = js_array_to_vector(&quantile_interval(
+            &latencies,
+            &JsValue::from_f64(0.9),
+            &JsValue::from_f64(0.05),
+        ));
+        let too_small = quantile_interval(&data, &JsValue::from_f64(0.9), &JsValue::from_f64(0.05));
+
+        assert_eq!(quartile, vec![2.2, 5.0]);
+        assert_eq!(median, vec![3.8, 8.2]);
+        assert_eq!(p90, vec![49.0, 59.0]);
+        assert!(too_small.is_null());
+    }
 }
@@ -0,0 +1,656 @@
+use crate::categorical::group_indices;
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+/// Computes a quantile of sorted values by linear interpolation between order statistics
+/// (the default method of R and NumPy).
+fn sorted_quantile(sorted: &[f64], q: f64) -> f64 {
+    let h = (sorted.len() - 1) as f64 * q;
+    let lower = h.floor() as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    sorted[lower] + (h - lower as f64) * (sorted[upper] - sorted[lower])
+}
+
+/// Computes the mean and the second, third, and fourth central moments of values.
+fn central_moments(values: &[f64]) -> (f64, f64, f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let moment = |k: i32| values.iter().map(|v| (v - mean).powi(k)).sum::<f64>() / n;
+    (mean, moment(2), moment(3), moment(4))
+}
+
+/// Computes the adjusted Fisher-Pearson skewness from the central moments.
+fn adjusted_skewness(n: f64, m2: f64, m3: f64) -> f64 {
+    m3 / m2.powf(1.5) * (n * (n - 1.0)).sqrt() / (n - 2.0)
+}
+
+/// Computes the adjusted excess kurtosis from the central moments.
+fn adjusted_kurtosis(n: f64, m2: f64, m4: f64) -> f64 {
+    let excess = m4 / m2.powi(2) - 3.0;
+    (n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * excess + 6.0)
+}
+
+/// Computes the standard error of the adjusted skewness under normality.
+fn skewness_se(n: f64) -> f64 {
+    (6.0 * n * (n - 1.0) / ((n - 2.0) * (n + 1.0) * (n + 3.0))).sqrt()
+}
+
+/// Summarizes a column with its size, central tendency, spread, and shape.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+///
+/// # Returns
+///
+/// * An object with the properties `n`, `mean`, `median`, `mode` (an array of every most
+///   frequent value), `sd`, `variance`, `se`, `min`, `max`, `range`, `quartiles` (the
+///   first quartile, median, and third quartile by linear interpolation), `iqr`,
+///   `skewness` (the adjusted Fisher-Pearson coefficient), and `kurtosis` (the adjusted
+///   excess kurtosis), or null if the column is empty. Statistics that need more values
+///   than are available (two for the variance, three for skewness, and four for
+///   kurtosis) are NaN.
+#[wasm_bindgen]
+pub fn summary_stats(column: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    if values.is_empty() {
+        return JsValue::NULL;
+    }
+
+    let n = values.len() as f64;
+    let mut sorted = values.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let (mean, m2, m3, m4) = central_moments(&values);
+    let variance = if n > 1.0 {
+        m2 * n / (n - 1.0)
+    } else {
+        f64::NAN
+    };
+    let sd = variance.sqrt();
+    let skewness = if n > 2.0 {
+        adjusted_skewness(n, m2, m3)
+    } else {
+        f64::NAN
+    };
+    let kurtosis = if n > 3.0 {
+        adjusted_kurtosis(n, m2, m4)
+    } else {
+        f64::NAN
+    };
+
+    let counts: Vec<(f64, usize)> = sorted
+        .chunk_by(|a, b| a == b)
+        .map(|group| (group[0], group.len()))
+        .collect();
+    let most = counts.iter().map(|(_, c)| *c).max().unwrap_or(0);
+    let mode: Vec<f64> = counts
+        .iter()
+        .filter(|(_, c)| *c == most)
+        .map(|(v, _)| *v)
+        .collect();
+
+    let min = sorted[0];
+    let max = sorted[sorted.len() - 1];
+    let quartiles: Vec<f64> = [0.25, 0.5, 0.75]
+        .iter()
+        .map(|q| sorted_quantile(&sorted, *q))
+        .collect();
+
+    let obj = Object::new();
+    let set = |key: &str, value: f64| {
+        let _ = Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_f64(value));
+    };
+    set("n", n);
+    set("mean", mean);
+    set("median", quartiles[1]);
+    set("sd", sd);
+    set("variance", variance);
+    set("se", sd / n.sqrt());
+    set("min", min);
+    set("max", max);
+    set("range", max - min);
+    set("iqr", quartiles[2] - quartiles[0]);
+    set("skewness", skewness);
+    set("kurtosis", kurtosis);
+    let _ = Reflect::set(&obj, &JsValue::from_str("mode"), &vec_to_jsvalue(mode));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("quartiles"),
+        &vec_to_jsvalue(quartiles),
+    );
+    obj.into()
+}
+
+/// Summarizes a column separately for each level of a parallel array of group labels.
+///
+/// # Arguments
+///
+/// * `values` - A reference to a JsValue representing the JavaScript array of values.
+/// * `groups` - A reference to a JsValue representing the JavaScript array of group
+///   labels (strings or numbers), one per value.
+///
+/// # Returns
+///
+/// * An object with the properties `groups` (the levels, sorted with numbers before
+///   strings), `summaries` (the `summary_stats` result for each level, with an added
+///   `group` property), and `data` (the values of each level, which can be passed to
+///   `anova_1way_test`), or null if the arrays differ in length, are empty, or any label
+///   is missing.
+#[wasm_bindgen]
+pub fn grouped_summary(values: &JsValue, groups: &JsValue) -> JsValue {
+    let data = js_array_to_vector(values);
+    let labels = Array::from(groups);
+    let indices = match group_indices(groups) {
+        Some(indices) if !data.is_empty() && labels.length() as usize == data.len() => indices,
+        _ => return JsValue::NULL,
+    };
+
+    let levels = Array::new();
+    let summaries = Array::new();
+    let mut grouped = Vec::with_capacity(indices.len());
+    for group in &indices {
+        let level = labels.get(group[0] as u32);
+        let group_values: Vec<f64> = group.iter().map(|&i| data[i]).collect();
+        let summary = summary_stats(&vec_to_jsvalue(group_values.clone()));
+        let _ = Reflect::set(&summary, &JsValue::from_str("group"), &level);
+        levels.push(&level);
+        summaries.push(&summary);
+        grouped.push(group_values);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("groups"), &levels);
+    let _ = Reflect::set(&obj, &JsValue::from_str("summaries"), &summaries);
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("data"),
+        &nested_vec_to_jsvalue(grouped),
+    );
+    obj.into()
+}
+
+/// Ranks a column from 1 to n with a choice of how tied values are ranked.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+/// * `ties_method` - A reference to a JsValue representing the tie handling, one of
+///   "average" (the default), "min", "max", "dense" (consecutive ranks for distinct
+///   values), or "ordinal" (ties broken by order of appearance).
+///
+/// # Returns
+///
+/// * A JavaScript array of the rank of each value in the original order, or null if the
+///   method is unknown or any value is NaN.
+#[wasm_bindgen]
+pub fn rank(column: &JsValue, ties_method: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    let method = ties_method.as_string().unwrap_or("average".to_string());
+    if !["average", "min", "max", "dense", "ordinal"].contains(&method.as_str())
+        || values.iter().any(|v| v.is_nan())
+    {
+        return JsValue::NULL;
+    }
+    if method == "average" {
+        return vec_to_jsvalue(average_ranks(&values));
+    }
+
+    let (order, runs) = tie_runs(&values);
+    let mut ranks = vec![0.0; values.len()];
+    for (distinct, (start, end)) in runs.into_iter().enumerate() {
+        for (offset, i) in order[start..=end].iter().enumerate() {
+            ranks[*i] = match method.as_str() {
+                "min" => (start + 1) as f64,
+                "max" => (end + 1) as f64,
+                "dense" => (distinct + 1) as f64,
+                // "ordinal"
+                _ => (start + offset + 1) as f64,
+            };
+        }
+    }
+    vec_to_jsvalue(ranks)
+}
+
+/// Computes the sample skewness of a column, corrected for small-sample bias, with its
+/// standard error under normality.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+///
+/// # Returns
+///
+/// * An object with the properties `skewness` (the adjusted Fisher-Pearson coefficient),
+///   `se`, and `z` (the skewness divided by its standard error), or null if there are
+///   fewer than three values or they are all equal.
+#[wasm_bindgen]
+pub fn skewness(column: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    let n = values.len() as f64;
+    if n < 3.0 {
+        return JsValue::NULL;
+    }
+    let (_, m2, m3, _) = central_moments(&values);
+    if m2 == 0.0 {
+        return JsValue::NULL;
+    }
+
+    let skewness = adjusted_skewness(n, m2, m3);
+    let se = skewness_se(n);
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("skewness"),
+        &JsValue::from_f64(skewness),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("se"), &JsValue::from_f64(se));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("z"),
+        &JsValue::from_f64(skewness / se),
+    );
+    obj.into()
+}
+
+/// Computes the sample excess kurtosis of a column, corrected for small-sample bias, with
+/// its standard error under normality.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+///
+/// # Returns
+///
+/// * An object with the properties `kurtosis` (the adjusted excess kurtosis), `se`, and
+///   `z` (the kurtosis divided by its standard error), or null if there are fewer than
+///   four values or they are all equal.
+#[wasm_bindgen]
+pub fn kurtosis(column: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    let n = values.len() as f64;
+    if n < 4.0 {
+        return JsValue::NULL;
+    }
+    let (_, m2, _, m4) = central_moments(&values);
+    if m2 == 0.0 {
+        return JsValue::NULL;
+    }
+
+    let kurtosis = adjusted_kurtosis(n, m2, m4);
+    let se = 2.0 * skewness_se(n) * ((n * n - 1.0) / ((n - 3.0) * (n + 5.0))).sqrt();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("kurtosis"),
+        &JsValue::from_f64(kurtosis),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("se"), &JsValue::from_f64(se));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("z"),
+        &JsValue::from_f64(kurtosis / se),
+    );
+    obj.into()
+}
+
+/// Computes the pooled standard deviation of several groups, weighting each group's
+/// variance by its degrees of freedom.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups, each an
+///   array of values.
+///
+/// # Returns
+///
+/// * An object with the properties `sd`, `variance`, and `df` (the total observations
+///   minus the number of non-empty groups), or null if there are no degrees of freedom.
+#[wasm_bindgen]
+pub fn pooled_sd(data: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .filter(|group| !group.is_empty())
+        .collect();
+
+    let df = groups.iter().map(|group| group.len() - 1).sum::<usize>() as f64;
+    if df <= 0.0 {
+        return JsValue::NULL;
+    }
+    let sum_of_squares = groups
+        .iter()
+        .map(|group| central_moments(group).1 * group.len() as f64)
+        .sum::<f64>();
+    let variance = sum_of_squares / df;
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("sd"),
+        &JsValue::from_f64(variance.sqrt()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("variance"),
+        &JsValue::from_f64(variance),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    obj.into()
+}
+
+/// Builds the result of a scaling transform from the transformed values and the
+/// parameters that define it.
+fn scaled_result(values: Vec<f64>, parameters: &[(&str, f64)]) -> JsValue {
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("values"), &vec_to_jsvalue(values));
+    for (key, value) in parameters {
+        let _ = Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_f64(*value));
+    }
+    obj.into()
+}
+
+/// Standardizes a column to z-scores using its mean and sample standard deviation.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+///
+/// # Returns
+///
+/// * An object with the properties `values` (the z-scores), `mean`, and `sd`, so the
+///   transform can be inverted or applied to new data, or null if there are fewer than
+///   two values or they are all equal.
+#[wasm_bindgen]
+pub fn standardize(column: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    let n = values.len() as f64;
+    if n < 2.0 {
+        return JsValue::NULL;
+    }
+    let (mean, m2, _, _) = central_moments(&values);
+    let sd = (m2 * n / (n - 1.0)).sqrt();
+    if sd == 0.0 {
+        return JsValue::NULL;
+    }
+
+    let scaled = values.iter().map(|v| (v - mean) / sd).collect();
+    scaled_result(scaled, &[("mean", mean), ("sd", sd)])
+}
+
+/// Rescales a column linearly so that its minimum and maximum map to `lo` and `hi`.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+/// * `lo` - A reference to a JsValue representing the lower end of the target range
+///   (defaults to 0).
+/// * `hi` - A reference to a JsValue representing the upper end of the target range
+///   (defaults to 1).
+///
+/// # Returns
+///
+/// * An object with the properties `values`, `min`, and `max` (of the original column),
+///   and `lo` and `hi`, or null if the column is empty or constant.
+#[wasm_bindgen]
+pub fn min_max_scale(column: &JsValue, lo: &JsValue, hi: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    let lo = lo.as_f64().unwrap_or(0.0);
+    let hi = hi.as_f64().unwrap_or(1.0);
+    if values.is_empty() {
+        return JsValue::NULL;
+    }
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if max == min {
+        return JsValue::NULL;
+    }
+
+    let scaled = values
+        .iter()
+        .map(|v| lo + (v - min) / (max - min) * (hi - lo))
+        .collect();
+    scaled_result(
+        scaled,
+        &[("min", min), ("max", max), ("lo", lo), ("hi", hi)],
+    )
+}
+
+/// Scales a column robustly by centering on its median and dividing by its interquartile
+/// range, with quartiles by linear interpolation as in `summary_stats`.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of values.
+///
+/// # Returns
+///
+/// * An object with the properties `values`, `median`, and `iqr`, or null if the column
+///   is empty or its interquartile range is zero.
+#[wasm_bindgen]
+pub fn robust_scale(column: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    if values.is_empty() {
+        return JsValue::NULL;
+    }
+    let mut sorted = values.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let median = sorted_quantile(&sorted, 0.5);
+    let iqr = sorted_quantile(&sorted, 0.75) - sorted_quantile(&sorted, 0.25);
+    if iqr == 0.0 {
+        return JsValue::NULL;
+    }
+
+    let scaled = values.iter().map(|v| (v - median) / iqr).collect();
+    scaled_result(scaled, &[("median", median), ("iqr", iqr)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_summary_stats() {
+        let column = vec_to_jsvalue(vec![
+            4.0, 8.0, 15.0, 16.0, 23.0, 42.0, 8.0, 15.0, 4.0, 8.0, 30.0, 12.0,
+        ]);
+
+        let result = summary_stats(&column);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let get_f64 = |key: &str| get(key).as_f64().unwrap();
+        let quartiles = js_array_to_vector(&get("quartiles"));
+
+        assert_eq!(get_f64("n"), 12.0);
+        assert!((get_f64("mean") - 15.416667).abs() < 0.0001);
+        assert_eq!(get_f64("median"), 13.5);
+        assert_eq!(js_array_to_vector(&get("mode")), vec![8.0]);
+        assert!((get_f64("variance") - 128.628788).abs() < 0.0001);
+        assert!((get_f64("sd") - 11.341463).abs() < 0.0001);
+        assert!((get_f64("se") - 3.273998).abs() < 0.0001);
+        assert_eq!(get_f64("range"), 38.0);
+        assert_eq!(quartiles, vec![8.0, 13.5, 17.75]);
+        assert_eq!(get_f64("iqr"), 9.75);
+        assert!((get_f64("skewness") - 1.352270).abs() < 0.0001);
+        assert!((get_f64("kurtosis") - 1.596370).abs() < 0.0001);
+        assert!(summary_stats(&vec_to_jsvalue(vec![])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_grouped_summary() {
+        let values = vec_to_jsvalue(vec![5.0, 7.0, 3.0, 9.0, 4.0, 6.0, 8.0]);
+        let groups = Array::new();
+        for label in ["b", "a", "b", "a", "b", "c", "a"] {
+            groups.push(&JsValue::from_str(label));
+        }
+
+        let result = grouped_summary(&values, &groups.clone().into());
+
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+        let levels = Array::from(&get(&result, "groups"));
+        let summaries = Array::from(&get(&result, "summaries"));
+        let data: Vec<Vec<f64>> = js_nested_array_to_vector(&get(&result, "data"))
+            .iter()
+            .map(js_array_to_vector)
+            .collect();
+        let first = summaries.get(0);
+
+        assert_eq!(levels.get(0).as_string().unwrap(), "a");
+        assert_eq!(levels.length(), 3);
+        assert_eq!(get(&first, "group").as_string().unwrap(), "a");
+        assert_eq!(get(&first, "n").as_f64(), Some(3.0));
+        assert_eq!(get(&first, "mean").as_f64(), Some(8.0));
+        assert_eq!(get(&first, "sd").as_f64(), Some(1.0));
+        assert_eq!(get(&summaries.get(1), "median").as_f64(), Some(4.0));
+        assert_eq!(
+            data,
+            vec![vec![7.0, 9.0, 8.0], vec![5.0, 3.0, 4.0], vec![6.0]]
+        );
+        assert!(grouped_summary(&vec_to_jsvalue(vec![1.0]), &groups.into()).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_rank() {
+        let column = vec_to_jsvalue(vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0]);
+        let ranks = |method: &str| js_array_to_vector(&rank(&column, &JsValue::from_str(method)));
+
+        assert_eq!(
+            js_array_to_vector(&rank(&column, &JsValue::UNDEFINED)),
+            vec![4.0, 1.5, 5.0, 1.5, 6.5, 9.0, 3.0, 8.0, 6.5]
+        );
+        assert_eq!(
+            ranks("min"),
+            vec![4.0, 1.0, 5.0, 1.0, 6.0, 9.0, 3.0, 8.0, 6.0]
+        );
+        assert_eq!(
+            ranks("max"),
+            vec![4.0, 2.0, 5.0, 2.0, 7.0, 9.0, 3.0, 8.0, 7.0]
+        );
+        assert_eq!(
+            ranks("dense"),
+            vec![3.0, 1.0, 4.0, 1.0, 5.0, 7.0, 2.0, 6.0, 5.0]
+        );
+        assert_eq!(
+            ranks("ordinal"),
+            vec![4.0, 1.0, 5.0, 2.0, 6.0, 9.0, 3.0, 8.0, 7.0]
+        );
+        assert!(rank(&column, &JsValue::from_str("random")).is_null());
+        assert!(rank(&vec_to_jsvalue(vec![]), &JsValue::from_str("random")).is_null());
+        assert!(js_array_to_vector(&rank(&vec_to_jsvalue(vec![]), &JsValue::UNDEFINED)).is_empty());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_skewness() {
+        let column = vec_to_jsvalue(vec![
+            4.0, 8.0, 15.0, 16.0, 23.0, 42.0, 8.0, 15.0, 4.0, 8.0, 30.0, 12.0,
+        ]);
+
+        let result = skewness(&column);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+
+        assert!((get("skewness").as_f64().unwrap() - 1.352270).abs() < 0.0001);
+        assert!((get("se").as_f64().unwrap() - 0.637302).abs() < 0.0001);
+        assert!((get("z").as_f64().unwrap() - 2.121867).abs() < 0.0001);
+        assert!(skewness(&vec_to_jsvalue(vec![1.0, 2.0])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_kurtosis() {
+        let column = vec_to_jsvalue(vec![
+            4.0, 8.0, 15.0, 16.0, 23.0, 42.0, 8.0, 15.0, 4.0, 8.0, 30.0, 12.0,
+        ]);
+
+        let result = kurtosis(&column);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+
+        assert!((get("kurtosis").as_f64().unwrap() - 1.596370).abs() < 0.0001);
+        assert!((get("se").as_f64().unwrap() - 1.232246).abs() < 0.0001);
+        assert!((get("z").as_f64().unwrap() - 1.295495).abs() < 0.0001);
+        assert!(kurtosis(&vec_to_jsvalue(vec![3.0, 3.0, 3.0, 3.0])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_pooled_sd() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![4.0, 6.0, 8.0],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![10.0, 14.0],
+        ]);
+
+        let result = pooled_sd(&data);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+
+        // (8 + 10 + 8) / (2 + 4 + 1)
+        assert!((get("variance").as_f64().unwrap() - 26.0 / 7.0).abs() < 1e-10);
+        assert!((get("sd").as_f64().unwrap() - 1.927248).abs() < 0.0001);
+        assert_eq!(get("df").as_f64(), Some(7.0));
+        assert!(pooled_sd(&nested_vec_to_jsvalue(vec![vec![1.0], vec![2.0]])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_standardize() {
+        let result = standardize(&vec_to_jsvalue(vec![
+            2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0,
+        ]));
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let values = js_array_to_vector(&get("values"));
+
+        assert_eq!(get("mean").as_f64(), Some(5.0));
+        assert!((get("sd").as_f64().unwrap() - 2.138090).abs() < 0.0001);
+        assert!((values[0] + 1.403122).abs() < 0.0001);
+        assert!((values[7] - 1.870829).abs() < 0.0001);
+        assert!(standardize(&vec_to_jsvalue(vec![1.0, 1.0])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_min_max_scale() {
+        let column = vec_to_jsvalue(vec![2.0, 4.0, 7.0, 12.0]);
+
+        let unit = min_max_scale(&column, &JsValue::UNDEFINED, &JsValue::UNDEFINED);
+        let ranged = min_max_scale(&column, &JsValue::from_f64(-1.0), &JsValue::from_f64(1.0));
+
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+
+        assert_eq!(
+            js_array_to_vector(&get(&unit, "values")),
+            vec![0.0, 0.2, 0.5, 1.0]
+        );
+        assert_eq!(
+            js_array_to_vector(&get(&ranged, "values")),
+            vec![-1.0, -0.6, 0.0, 1.0]
+        );
+        assert_eq!(get(&ranged, "min").as_f64(), Some(2.0));
+        assert_eq!(get(&ranged, "max").as_f64(), Some(12.0));
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_robust_scale() {
+        let result = robust_scale(&vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0, 100.0]));
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let values = js_array_to_vector(&get("values"));
+
+        assert_eq!(get("median").as_f64(), Some(3.5));
+        assert_eq!(get("iqr").as_f64(), Some(2.5));
+        assert_eq!(values[0], -1.0);
+        assert_eq!(values[5], 38.6);
+        assert!(robust_scale(&vec_to_jsvalue(vec![])).is_null());
+    }
+}
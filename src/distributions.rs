@@ -0,0 +1,740 @@
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{
+    Beta, Binomial, ChiSquared, Continuous, ContinuousCDF, Discrete, DiscreteCDF, Exp,
+    FisherSnedecor, Gamma, LogNormal, Normal, Poisson, StudentsT, Uniform, Weibull,
+};
+use statrs::function::gamma::digamma;
+use wasm_bindgen::prelude::*;
+
+/// The function of a distribution to evaluate.
+#[derive(Clone, Copy)]
+enum Function {
+    Pdf,
+    Cdf,
+    Quantile,
+}
+
+/// Evaluates a function of a continuous distribution.
+fn continuous<D: Continuous<f64, f64> + ContinuousCDF<f64, f64>>(
+    dist: D,
+    x: f64,
+    function: Function,
+) -> f64 {
+    match function {
+        Function::Pdf => dist.pdf(x),
+        Function::Cdf => dist.cdf(x),
+        Function::Quantile => dist.inverse_cdf(x),
+    }
+}
+
+/// Evaluates a function of a continuous distribution whose inverse CDF in statrs is coarse
+/// or fails in the tails, finding the quantile by bisection on the CDF instead.
+fn bisected<D: Continuous<f64, f64> + ContinuousCDF<f64, f64>>(
+    dist: D,
+    x: f64,
+    function: Function,
+) -> f64 {
+    match function {
+        Function::Quantile if x == 0.0 => dist.min(),
+        Function::Quantile if x == 1.0 => dist.max(),
+        Function::Quantile => {
+            // Bisecting over the ordering of the doubles takes at most 64 steps on any
+            // support, and the survival function keeps upper quantiles precise
+            let key = |x: f64| {
+                let bits = x.to_bits() as i64;
+                (bits ^ (((bits >> 63) as u64) >> 1) as i64) as i128
+            };
+            let value = |key: i128| {
+                let bits = key as i64;
+                f64::from_bits((bits ^ (((bits >> 63) as u64) >> 1) as i64) as u64)
+            };
+            let reached = |key: i128| {
+                let q = value(key);
+                if x <= 0.5 {
+                    dist.cdf(q) >= x
+                } else {
+                    dist.sf(q) <= 1.0 - x
+                }
+            };
+            value(bisect(key(dist.min()), key(dist.max()), reached))
+        }
+        _ => continuous(dist, x, function),
+    }
+}
+
+/// Evaluates a function of a distribution on the non-negative integers, where the mass
+/// is zero off the integers and the CDF is a step function.
+fn discrete<D: Discrete<u64, f64> + DiscreteCDF<u64, f64>>(
+    dist: D,
+    x: f64,
+    function: Function,
+) -> f64 {
+    match function {
+        Function::Pdf if x >= 0.0 && x.fract() == 0.0 => dist.pmf(x as u64),
+        Function::Pdf => 0.0,
+        Function::Cdf if x >= 0.0 => dist.cdf(x.floor() as u64),
+        Function::Cdf => 0.0,
+        Function::Quantile if x == 1.0 && dist.max() == u64::MAX => f64::INFINITY,
+        Function::Quantile if x == 1.0 => dist.max() as f64,
+        Function::Quantile => {
+            let reached = |k: i128| {
+                if x <= 0.5 {
+                    dist.cdf(k as u64) >= x
+                } else {
+                    dist.sf(k as u64) <= 1.0 - x
+                }
+            };
+            bisect(dist.min() as i128 - 1, dist.max() as i128, reached) as f64
+        }
+    }
+}
+
+/// Finds the smallest point in `(low, high]` where a monotone condition holds, given that
+/// it holds at `high`.
+fn bisect(mut low: i128, mut high: i128, reached: impl Fn(i128) -> bool) -> i128 {
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if reached(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
+
+/// Evaluates a function of a named distribution.
+///
+/// # Arguments
+///
+/// * `name` - The name of the distribution.
+/// * `params` - The distribution parameters, with defaults where the distribution has a
+///   standard form.
+/// * `x` - The point to evaluate, or the probability for the quantile function.
+/// * `function` - The function to evaluate.
+///
+/// # Returns
+///
+/// * The value of the function, or `None` if the distribution is unknown, the parameters
+///   are invalid, or the probability is outside [0, 1].
+fn evaluate(name: &str, params: &[f64], x: f64, function: Function) -> Option<f64> {
+    if matches!(function, Function::Quantile) && !(0.0..=1.0).contains(&x) {
+        return None;
+    }
+    let param = |i: usize, default: f64| params.get(i).copied().unwrap_or(default);
+
+    let value = match name {
+        "normal" => continuous(Normal::new(param(0, 0.0), param(1, 1.0)).ok()?, x, function),
+        "t" => {
+            let df = param(0, f64::NAN);
+            let dist = StudentsT::new(0.0, 1.0, df).ok()?;
+            // Beyond this the statrs t loses precision, while the normal is within about
+            // (x^2 + 1) / (4 df) of the t in relative terms
+            if df > 1e10 {
+                continuous(Normal::new(0.0, 1.0).unwrap(), x, function)
+            } else {
+                // The t is symmetric, and its CDF rounds to 1/2 near the median, so only the
+                // lower half is bisected
+                match function {
+                    Function::Quantile if x == 0.5 => 0.0,
+                    Function::Quantile if x > 0.5 => -bisected(dist, 1.0 - x, function),
+                    _ => bisected(dist, x, function),
+                }
+            }
+        }
+        "f" => bisected(
+            FisherSnedecor::new(param(0, f64::NAN), param(1, f64::NAN)).ok()?,
+            x,
+            function,
+        ),
+        "chi_squared" => bisected(ChiSquared::new(param(0, f64::NAN)).ok()?, x, function),
+        "binomial" => {
+            let n = param(0, f64::NAN);
+            if n.is_nan() || n < 0.0 || n.fract() != 0.0 {
+                return None;
+            }
+            discrete(
+                Binomial::new(param(1, f64::NAN), n as u64).ok()?,
+                x,
+                function,
+            )
+        }
+        "poisson" => discrete(Poisson::new(param(0, f64::NAN)).ok()?, x, function),
+        "exponential" => continuous(Exp::new(param(0, 1.0)).ok()?, x, function),
+        "gamma" => bisected(
+            Gamma::new(param(0, f64::NAN), param(1, 1.0)).ok()?,
+            x,
+            function,
+        ),
+        "beta" => bisected(
+            Beta::new(param(0, f64::NAN), param(1, f64::NAN)).ok()?,
+            x,
+            function,
+        ),
+        "uniform" => continuous(
+            Uniform::new(param(0, 0.0), param(1, 1.0)).ok()?,
+            x,
+            function,
+        ),
+        "lognormal" => continuous(
+            LogNormal::new(param(0, 0.0), param(1, 1.0)).ok()?,
+            x,
+            function,
+        ),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Evaluates a function of a named distribution for the JavaScript-facing entry points.
+fn evaluate_js(name: &JsValue, x: &JsValue, params: &JsValue, function: Function) -> JsValue {
+    let name = name.as_string().unwrap_or_default();
+    let params = if params.is_undefined() || params.is_null() {
+        Vec::new()
+    } else {
+        js_array_to_vector(params)
+    };
+    match x
+        .as_f64()
+        .and_then(|x| evaluate(&name, &params, x, function))
+    {
+        Some(value) => JsValue::from_f64(value),
+        None => JsValue::NULL,
+    }
+}
+
+/// Computes the probability density (or, for discrete distributions, the probability
+/// mass) of a named distribution.
+///
+/// # Arguments
+///
+/// * `name` - A reference to a JsValue naming the distribution: "normal", "t", "f",
+///   "chi_squared", "binomial", "poisson", "exponential", "gamma", "beta", "uniform", or
+///   "lognormal".
+/// * `x` - A reference to a JsValue representing the point at which to evaluate.
+/// * `params` - A reference to a JsValue representing the distribution parameters:
+///   `[mean, sd]` for the normal (default `[0, 1]`), `[df]` for the t (evaluated as the
+///   standard normal beyond 1e10 degrees of freedom) and chi-square,
+///   `[df1, df2]` for the F, `[n, p]` for the binomial, `[lambda]` for the Poisson,
+///   `[rate]` for the exponential (default `[1]`), `[shape, rate]` for the gamma (rate
+///   defaults to 1), `[a, b]` for the beta, `[min, max]` for the uniform (default
+///   `[0, 1]`), or `[meanlog, sdlog]` for the log-normal (default `[0, 1]`).
+///
+/// # Returns
+///
+/// * The density or mass, or null if the distribution is unknown or the parameters are
+///   invalid.
+#[wasm_bindgen]
+pub fn dist_pdf(name: &JsValue, x: &JsValue, params: &JsValue) -> JsValue {
+    evaluate_js(name, x, params, Function::Pdf)
+}
+
+/// Computes the cumulative distribution function of a named distribution.
+///
+/// # Arguments
+///
+/// * `name` - A reference to a JsValue naming the distribution, as in `dist_pdf`.
+/// * `x` - A reference to a JsValue representing the point at which to evaluate.
+/// * `params` - A reference to a JsValue representing the distribution parameters, as in
+///   `dist_pdf`.
+///
+/// # Returns
+///
+/// * The probability of a value at most `x`, or null if the distribution is unknown or
+///   the parameters are invalid.
+#[wasm_bindgen]
+pub fn dist_cdf(name: &JsValue, x: &JsValue, params: &JsValue) -> JsValue {
+    evaluate_js(name, x, params, Function::Cdf)
+}
+
+/// Computes the quantile function (inverse CDF) of a named distribution, which for
+/// discrete distributions is the smallest value whose CDF reaches `p`. Except for the
+/// normal, exponential, uniform, and log-normal, quantiles are found by bisection on the
+/// CDF and are as precise as `dist_cdf`.
+///
+/// # Arguments
+///
+/// * `name` - A reference to a JsValue naming the distribution, as in `dist_pdf`.
+/// * `p` - A reference to a JsValue representing the probability.
+/// * `params` - A reference to a JsValue representing the distribution parameters, as in
+///   `dist_pdf`.
+///
+/// # Returns
+///
+/// * The quantile, or null if the distribution is unknown, the parameters are invalid, or
+///   `p` is outside [0, 1].
+#[wasm_bindgen]
+pub fn dist_quantile(name: &JsValue, p: &JsValue, params: &JsValue) -> JsValue {
+    evaluate_js(name, p, params, Function::Quantile)
+}
+
+/// Finds the root of a monotone function of a positive parameter by bisection on the log
+/// scale, where `increasing` gives the direction of the function.
+fn log_bisect(f: impl Fn(f64) -> f64, increasing: bool) -> f64 {
+    let (mut low, mut high) = (-20.0_f64, 20.0_f64);
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if (f(mid.exp()) < 0.0) == increasing {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    ((low + high) / 2.0).exp()
+}
+
+/// Estimates the standard errors of two maximum likelihood estimates by inverting the
+/// observed information, found from central differences of the log-likelihood.
+fn observed_std_errors(ll: impl Fn(f64, f64) -> f64, a: f64, b: f64) -> Option<Vec<f64>> {
+    let (ha, hb) = (a.abs() * 1e-4, b.abs() * 1e-4);
+    let center = ll(a, b);
+    let haa = (ll(a + ha, b) - 2.0 * center + ll(a - ha, b)) / ha.powi(2);
+    let hbb = (ll(a, b + hb) - 2.0 * center + ll(a, b - hb)) / hb.powi(2);
+    let hab = (ll(a + ha, b + hb) - ll(a + ha, b - hb) - ll(a - ha, b + hb) + ll(a - ha, b - hb))
+        / (4.0 * ha * hb);
+    let covariance = invert_matrix(&[vec![-haa, -hab], vec![-hab, -hbb]])?;
+    Some(vec![covariance[0][0].sqrt(), covariance[1][1].sqrt()])
+}
+
+/// Computes the Kolmogorov-Smirnov distance between a sorted sample and a continuous CDF.
+fn ks_distance(sorted: &[f64], cdf: impl Fn(f64) -> f64) -> f64 {
+    let n = sorted.len() as f64;
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let f = cdf(*x);
+            ((i as f64 + 1.0) / n - f).max(f - i as f64 / n)
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Fits a named distribution to a sample by maximum likelihood.
+///
+/// The normal, log-normal, exponential, and Poisson estimates are closed-form, with
+/// standard errors from the expected information. The gamma shape and Weibull shape
+/// solve their profile score equations by bisection, with standard errors from the
+/// observed information. The Kolmogorov-Smirnov statistic measures goodness of fit; no
+/// p-value is reported because the standard one does not hold for estimated parameters.
+/// For the Poisson it is the largest gap between the empirical and fitted CDFs over the
+/// integers up to the sample maximum.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of data.
+/// * `dist_name` - A reference to a JsValue naming the distribution: "normal",
+///   "exponential", "gamma", "lognormal", "weibull", or "poisson".
+///
+/// # Returns
+///
+/// * An object with the properties `parameters` (the parameter names: `[mean, sd]`,
+///   `[rate]`, `[shape, rate]`, `[meanlog, sdlog]`, `[shape, scale]`, or `[lambda]`),
+///   `estimates`, `std_errors`, `log_likelihood`, `aic`, `bic`, and `ks_statistic`, or null
+///   if the distribution is unknown, there are fewer than two values, the data are
+///   constant, or any value lies outside the distribution's support.
+#[wasm_bindgen]
+pub fn fit_distribution(column: &JsValue, dist_name: &JsValue) -> JsValue {
+    let mut data = js_array_to_vector(column);
+    let dist_name = dist_name.as_string().unwrap_or_default();
+    if data.len() < 2 || data.iter().any(|x| !x.is_finite()) {
+        return JsValue::NULL;
+    }
+    data.sort_by(|a, b| a.total_cmp(b));
+    if data[0] == data[data.len() - 1] {
+        return JsValue::NULL;
+    }
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let positive = data[0] > 0.0;
+
+    let (parameters, estimates, std_errors, log_likelihood, ks): (
+        Vec<&str>,
+        Vec<f64>,
+        Vec<f64>,
+        f64,
+        f64,
+    ) = match dist_name.as_str() {
+        "normal" | "lognormal" => {
+            let is_log = dist_name == "lognormal";
+            if is_log && !positive {
+                return JsValue::NULL;
+            }
+            let values: Vec<f64> = if is_log {
+                data.iter().map(|x| x.ln()).collect()
+            } else {
+                data.clone()
+            };
+            let mu = values.iter().sum::<f64>() / n;
+            let sigma = (values.iter().map(|v| (v - mu).powi(2)).sum::<f64>() / n).sqrt();
+            let std_errors = vec![sigma / n.sqrt(), sigma / (2.0 * n).sqrt()];
+            if is_log {
+                let dist = LogNormal::new(mu, sigma).unwrap();
+                (
+                    vec!["meanlog", "sdlog"],
+                    vec![mu, sigma],
+                    std_errors,
+                    data.iter().map(|x| dist.ln_pdf(*x)).sum(),
+                    ks_distance(&data, |x| dist.cdf(x)),
+                )
+            } else {
+                let dist = Normal::new(mu, sigma).unwrap();
+                (
+                    vec!["mean", "sd"],
+                    vec![mu, sigma],
+                    std_errors,
+                    data.iter().map(|x| dist.ln_pdf(*x)).sum(),
+                    ks_distance(&data, |x| dist.cdf(x)),
+                )
+            }
+        }
+        "exponential" => {
+            if data[0] < 0.0 {
+                return JsValue::NULL;
+            }
+            let rate = 1.0 / mean;
+            let dist = Exp::new(rate).unwrap();
+            (
+                vec!["rate"],
+                vec![rate],
+                vec![rate / n.sqrt()],
+                data.iter().map(|x| dist.ln_pdf(*x)).sum(),
+                ks_distance(&data, |x| dist.cdf(x)),
+            )
+        }
+        "poisson" => {
+            if data.iter().any(|x| *x < 0.0 || x.fract() != 0.0) {
+                return JsValue::NULL;
+            }
+            let dist = Poisson::new(mean).unwrap();
+            // The empirical CDF is flat between distinct values, so the largest gap is at a
+            // value or at the integer just below it
+            let ks = data
+                .iter()
+                .enumerate()
+                .map(|(i, x)| {
+                    let k = *x as u64;
+                    let first = i == 0 || data[i - 1] != *x;
+                    let last = i + 1 == data.len() || data[i + 1] != *x;
+                    let below = if first && k > 0 {
+                        (i as f64 / n - dist.cdf(k - 1)).abs()
+                    } else {
+                        0.0
+                    };
+                    let at = if last {
+                        ((i + 1) as f64 / n - dist.cdf(k)).abs()
+                    } else {
+                        0.0
+                    };
+                    below.max(at)
+                })
+                .fold(0.0, f64::max);
+            (
+                vec!["lambda"],
+                vec![mean],
+                vec![(mean / n).sqrt()],
+                data.iter().map(|x| dist.ln_pmf(*x as u64)).sum(),
+                ks,
+            )
+        }
+        "gamma" => {
+            if !positive {
+                return JsValue::NULL;
+            }
+            // ln k - ψ(k) decreases from infinity to zero
+            let target = mean.ln() - data.iter().map(|x| x.ln()).sum::<f64>() / n;
+            let shape = log_bisect(|k| k.ln() - digamma(k) - target, false);
+            let rate = shape / mean;
+            let ll = |k: f64, rate: f64| match Gamma::new(k, rate) {
+                Ok(dist) => data.iter().map(|x| dist.ln_pdf(*x)).sum(),
+                Err(_) => f64::NAN,
+            };
+            let std_errors = match observed_std_errors(ll, shape, rate) {
+                Some(std_errors) => std_errors,
+                None => return JsValue::NULL,
+            };
+            let dist = Gamma::new(shape, rate).unwrap();
+            (
+                vec!["shape", "rate"],
+                vec![shape, rate],
+                std_errors,
+                ll(shape, rate),
+                ks_distance(&data, |x| dist.cdf(x)),
+            )
+        }
+        "weibull" => {
+            if !positive {
+                return JsValue::NULL;
+            }
+            let mean_log = data.iter().map(|x| x.ln()).sum::<f64>() / n;
+            let score = |k: f64| {
+                let weighted = data.iter().map(|x| x.powf(k)).sum::<f64>();
+                let weighted_log = data.iter().map(|x| x.powf(k) * x.ln()).sum::<f64>();
+                weighted_log / weighted - 1.0 / k - mean_log
+            };
+            let shape = log_bisect(score, true);
+            let scale = (data.iter().map(|x| x.powf(shape)).sum::<f64>() / n).powf(1.0 / shape);
+            let ll = |k: f64, scale: f64| match Weibull::new(k, scale) {
+                Ok(dist) => data.iter().map(|x| dist.ln_pdf(*x)).sum(),
+                Err(_) => f64::NAN,
+            };
+            let std_errors = match observed_std_errors(ll, shape, scale) {
+                Some(std_errors) => std_errors,
+                None => return JsValue::NULL,
+            };
+            let dist = Weibull::new(shape, scale).unwrap();
+            (
+                vec!["shape", "scale"],
+                vec![shape, scale],
+                std_errors,
+                ll(shape, scale),
+                ks_distance(&data, |x| dist.cdf(x)),
+            )
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let k = estimates.len() as f64;
+    let names = Array::new();
+    for name in parameters {
+        names.push(&JsValue::from_str(name));
+    }
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("parameters"), &names);
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("estimates"),
+        &vec_to_jsvalue(estimates),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_errors"),
+        &vec_to_jsvalue(std_errors),
+    );
+    for (key, value) in [
+        ("log_likelihood", log_likelihood),
+        ("aic", 2.0 * k - 2.0 * log_likelihood),
+        ("bic", k * n.ln() - 2.0 * log_likelihood),
+        ("ks_statistic", ks),
+    ] {
+        let _ = Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_f64(value));
+    }
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_dist_pdf() {
+        let pdf = |name: &str, x: f64, params: Vec<f64>| {
+            dist_pdf(
+                &JsValue::from_str(name),
+                &JsValue::from_f64(x),
+                &vec_to_jsvalue(params),
+            )
+            .as_f64()
+            .unwrap()
+        };
+
+        assert!((pdf("normal", 0.0, vec![]) - 0.398942).abs() < 0.0001);
+        assert!((pdf("gamma", 2.0, vec![2.0]) - 0.270671).abs() < 0.0001);
+        assert!((pdf("binomial", 3.0, vec![10.0, 0.3]) - 0.266828).abs() < 0.0001);
+        assert!((pdf("poisson", 2.0, vec![3.0]) - 0.224042).abs() < 0.0001);
+        assert_eq!(pdf("poisson", 2.5, vec![3.0]), 0.0);
+        assert_eq!(pdf("uniform", 0.5, vec![0.0, 4.0]), 0.25);
+        assert!(dist_pdf(
+            &JsValue::from_str("cauchy"),
+            &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_dist_cdf() {
+        let cdf = |name: &str, x: f64, params: Vec<f64>| {
+            dist_cdf(
+                &JsValue::from_str(name),
+                &JsValue::from_f64(x),
+                &vec_to_jsvalue(params),
+            )
+            .as_f64()
+            .unwrap()
+        };
+
+        assert!((cdf("normal", 1.96, vec![]) - 0.975002).abs() < 0.0001);
+        assert!((cdf("chi_squared", 3.84, vec![1.0]) - 0.949956).abs() < 0.0001);
+        assert!((cdf("f", 2.5, vec![3.0, 12.0]) - 0.890845).abs() < 0.0001);
+        assert!((cdf("binomial", 3.7, vec![10.0, 0.3]) - 0.649611).abs() < 0.0001);
+        assert!((cdf("lognormal", 1.0, vec![]) - 0.5).abs() < 1e-10);
+        assert_eq!(cdf("poisson", -1.0, vec![3.0]), 0.0);
+        assert!(dist_cdf(
+            &JsValue::from_str("t"),
+            &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_dist_quantile() {
+        let quantile = |name: &str, p: f64, params: Vec<f64>| {
+            dist_quantile(
+                &JsValue::from_str(name),
+                &JsValue::from_f64(p),
+                &vec_to_jsvalue(params),
+            )
+            .as_f64()
+            .unwrap()
+        };
+
+        assert!((quantile("t", 0.975, vec![10.0]) - 2.228139).abs() < 0.0001);
+        assert!((quantile("f", 0.95, vec![3.0, 12.0]) - 3.490295).abs() < 0.0001);
+        assert!((quantile("beta", 0.5, vec![2.0, 5.0]) - 0.264450).abs() < 0.0001);
+        assert!((quantile("exponential", 0.5, vec![2.0]) - 0.346574).abs() < 0.0001);
+        assert_eq!(quantile("binomial", 0.5, vec![10.0, 0.3]), 3.0);
+        assert_eq!(quantile("poisson", 0.9, vec![3.0]), 5.0);
+        assert_eq!(quantile("poisson", 1.0, vec![3.0]), f64::INFINITY);
+        assert_eq!(quantile("t", 0.5, vec![3.0]), 0.0);
+        assert!((quantile("t", 1e-20, vec![3.0]) + 4795275.720469).abs() < 0.001);
+        assert!((quantile("t", 0.999999999, vec![1e10]) - 5.997807).abs() < 0.0001);
+        assert!((quantile("t", 1e-300, vec![1e10]) + 37.047096).abs() < 0.0001);
+        assert!((quantile("t", 1e-20, vec![1e300]) + 9.262340).abs() < 0.0001);
+        assert!((quantile("chi_squared", 0.999999999, vec![3.0]) - 44.841275).abs() < 0.0001);
+        assert!(quantile("f", 1e-300, vec![3.0, 12.0]).is_finite());
+        assert!(quantile("beta", 1e-300, vec![2.0, 5.0]) < 1e-10);
+        assert_eq!(quantile("beta", 1.0, vec![2.0, 5.0]), 1.0);
+        assert_eq!(quantile("binomial", 1e-300, vec![10.0, 0.3]), 0.0);
+        assert_eq!(quantile("binomial", 1e-20, vec![1000.0, 0.3]), 173.0);
+        assert_eq!(quantile("binomial", 0.999999, vec![10.0, 0.3]), 10.0);
+        assert_eq!(quantile("poisson", 1e-20, vec![3.0]), 0.0);
+        assert_eq!(quantile("poisson", 0.999999999, vec![1e6]), 1006004.0);
+        assert!(dist_quantile(
+            &JsValue::from_str("normal"),
+            &JsValue::from_f64(1.5),
+            &JsValue::UNDEFINED
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_fit_distribution() {
+        let data = vec![2.3, 1.1, 4.5, 3.2, 0.8, 2.9, 5.6, 1.7, 3.8, 2.2, 1.4, 3.0];
+        let field =
+            |result: &JsValue, key: &str| Reflect::get(result, &JsValue::from_str(key)).unwrap();
+        let check = |name: &str,
+                     data: Vec<f64>,
+                     estimates: &[f64],
+                     std_errors: &[f64],
+                     ll: f64,
+                     aic: f64,
+                     ks: f64| {
+            let result = fit_distribution(&vec_to_jsvalue(data), &JsValue::from_str(name));
+            let est = js_array_to_vector(&field(&result, "estimates"));
+            let se = js_array_to_vector(&field(&result, "std_errors"));
+            for i in 0..estimates.len() {
+                assert!((est[i] - estimates[i]).abs() < 0.0001);
+                assert!((se[i] - std_errors[i]).abs() < 0.001);
+            }
+            assert!((field(&result, "log_likelihood").as_f64().unwrap() - ll).abs() < 0.0001);
+            assert!((field(&result, "aic").as_f64().unwrap() - aic).abs() < 0.0001);
+            assert!((field(&result, "ks_statistic").as_f64().unwrap() - ks).abs() < 0.0001);
+        };
+
+        check(
+            "normal",
+            data.clone(),
+            &[2.708333, 1.369585],
+            &[0.395365, 0.279565],
+            -20.801358,
+            45.602717,
+            0.117203,
+        );
+        check(
+            "exponential",
+            data.clone(),
+            &[0.369231],
+            &[0.106588],
+            -23.956001,
+            49.912003,
+            0.255755,
+        );
+        check(
+            "lognormal",
+            data.clone(),
+            &[0.854079, 0.556890],
+            &[0.160760, 0.113675],
+            -20.251568,
+            44.503136,
+            0.147369,
+        );
+        check(
+            "gamma",
+            data.clone(),
+            &[3.673177, 1.356250],
+            &[1.436677, 0.568440],
+            -20.011750,
+            44.023501,
+            0.120387,
+        );
+        check(
+            "weibull",
+            data.clone(),
+            &[2.112249, 3.068849],
+            &[0.475772, 0.442970],
+            -20.049220,
+            44.098440,
+            0.088247,
+        );
+        let counts = vec![2.0, 0.0, 3.0, 1.0, 4.0, 2.0, 1.0, 2.0, 5.0, 3.0, 1.0, 2.0];
+        check(
+            "poisson",
+            counts,
+            &[2.166667],
+            &[0.424918],
+            -20.218716,
+            42.437432,
+            0.035002,
+        );
+
+        let result = fit_distribution(
+            &vec_to_jsvalue(vec![0.0, 2e9]),
+            &JsValue::from_str("poisson"),
+        );
+        assert!((field(&result, "ks_statistic").as_f64().unwrap() - 0.5).abs() < 1e-9);
+
+        let result = fit_distribution(&vec_to_jsvalue(data.clone()), &JsValue::from_str("gamma"));
+        let bic = field(&result, "bic").as_f64().unwrap();
+        assert!((bic - 44.993314).abs() < 0.0001);
+        assert_eq!(
+            js_sys::Array::from(&field(&result, "parameters"))
+                .get(1)
+                .as_string()
+                .unwrap(),
+            "rate"
+        );
+        assert!(fit_distribution(
+            &vec_to_jsvalue(vec![-1.0, 2.0]),
+            &JsValue::from_str("weibull")
+        )
+        .is_null());
+        assert!(fit_distribution(
+            &vec_to_jsvalue(vec![1.5, 2.0]),
+            &JsValue::from_str("poisson")
+        )
+        .is_null());
+        assert!(fit_distribution(
+            &vec_to_jsvalue(vec![2.0, 2.0]),
+            &JsValue::from_str("normal")
+        )
+        .is_null());
+        assert!(fit_distribution(&vec_to_jsvalue(data), &JsValue::from_str("cauchy")).is_null());
+    }
+}
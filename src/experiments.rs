@@ -0,0 +1,1026 @@
+use crate::bayes::beta_quantile;
+use crate::conf_int::z_bounds;
+use crate::utils::*;
+use js_sys::{Object, Reflect};
+use rand::distributions::Distribution;
+use statrs::distribution::{Beta, ChiSquared, ContinuousCDF, Gamma, Normal, StudentsT};
+use statrs::function::erf::erfc;
+use wasm_bindgen::prelude::*;
+
+/// Reads a numeric field of a state object, falling back to a default.
+fn state_field(state: &JsValue, key: &str, default: f64) -> f64 {
+    get_f64_option(state, key).unwrap_or(default)
+}
+
+/// Updates a two-arm sequential test with a new batch of observations using the mixture
+/// sequential probability ratio test (mSPRT), whose p-values and confidence sequences
+/// remain valid no matter how often the results are checked.
+///
+/// # Arguments
+///
+/// * `state` - A reference to a JsValue representing the state returned by the previous
+///   update, or an object with the initial settings `type` ("mean" or "proportion";
+///   defaults to "mean"), `alpha` (defaults to 0.05), and `tau2` (the variance of the
+///   normal mixture over the difference; defaults to 0.01 times the pooled variance of
+///   the first batch). Undefined starts a mean test with the default settings.
+/// * `new_data` - A reference to a JsValue representing an object with the arrays `a` and
+///   `b` of new observations for each arm (0 or 1 for proportions).
+///
+/// # Returns
+///
+/// * The updated state, which holds the running sums along with the properties
+///   `estimate` (the difference `b - a`), `p` (the always-valid p-value), `ci` (the
+///   running intersection of the confidence sequence), and `reject` (whether `p` has
+///   fallen below `alpha`). The p-value is 1 and the interval unbounded until each arm
+///   has an observation and the pooled variance is positive. Returns null if the state or
+///   data are invalid.
+#[wasm_bindgen]
+pub fn sequential_test_update(state: &JsValue, new_data: &JsValue) -> JsValue {
+    let test_type = get_string_option(state, "type").unwrap_or_else(|| "mean".to_string());
+    if test_type != "mean" && test_type != "proportion" {
+        return JsValue::NULL;
+    }
+    let alpha = state_field(state, "alpha", 0.05);
+    let a = js_array_to_vector(
+        &Reflect::get(new_data, &JsValue::from_str("a")).unwrap_or(JsValue::UNDEFINED),
+    );
+    let b = js_array_to_vector(
+        &Reflect::get(new_data, &JsValue::from_str("b")).unwrap_or(JsValue::UNDEFINED),
+    );
+    if test_type == "proportion" && a.iter().chain(&b).any(|x| *x != 0.0 && *x != 1.0) {
+        return JsValue::NULL;
+    }
+
+    let n_a = state_field(state, "n_a", 0.0) + a.len() as f64;
+    let sum_a = state_field(state, "sum_a", 0.0) + a.iter().sum::<f64>();
+    let sum_sq_a = state_field(state, "sum_sq_a", 0.0) + a.iter().map(|x| x * x).sum::<f64>();
+    let n_b = state_field(state, "n_b", 0.0) + b.len() as f64;
+    let sum_b = state_field(state, "sum_b", 0.0) + b.iter().sum::<f64>();
+    let sum_sq_b = state_field(state, "sum_sq_b", 0.0) + b.iter().map(|x| x * x).sum::<f64>();
+    let mut tau2 = get_f64_option(state, "tau2");
+    let mut p = state_field(state, "p", 1.0);
+    let mut lower = state_field(state, "lower", f64::NEG_INFINITY);
+    let mut upper = state_field(state, "upper", f64::INFINITY);
+
+    let mean_a = sum_a / n_a;
+    let mean_b = sum_b / n_b;
+    let estimate = mean_b - mean_a;
+    let pooled_variance = if test_type == "mean" {
+        ((sum_sq_a - n_a * mean_a * mean_a) + (sum_sq_b - n_b * mean_b * mean_b))
+            / (n_a + n_b - 2.0)
+    } else {
+        let pooled = (sum_a + sum_b) / (n_a + n_b);
+        pooled * (1.0 - pooled)
+    };
+
+    if n_a >= 1.0 && n_b >= 1.0 && pooled_variance > 0.0 && pooled_variance.is_finite() {
+        let tau2 = *tau2.get_or_insert(0.01 * pooled_variance);
+        let v = pooled_variance * (1.0 / n_a + 1.0 / n_b);
+        let log_lambda =
+            0.5 * (v / (v + tau2)).ln() + estimate.powi(2) * tau2 / (2.0 * v * (v + tau2));
+        p = p.min((-log_lambda).exp().min(1.0));
+
+        let half_width =
+            (v * (v + tau2) / tau2 * (2.0 * (1.0 / alpha).ln() + ((v + tau2) / v).ln())).sqrt();
+        lower = lower.max(estimate - half_width);
+        upper = upper.min(estimate + half_width);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("type"),
+        &JsValue::from_str(&test_type),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("alpha"), &JsValue::from_f64(alpha));
+    if let Some(tau2) = tau2 {
+        let _ = Reflect::set(&obj, &JsValue::from_str("tau2"), &JsValue::from_f64(tau2));
+    }
+    for (key, value) in [
+        ("n_a", n_a),
+        ("sum_a", sum_a),
+        ("sum_sq_a", sum_sq_a),
+        ("n_b", n_b),
+        ("sum_b", sum_b),
+        ("sum_sq_b", sum_sq_b),
+        ("lower", lower),
+        ("upper", upper),
+    ] {
+        let _ = Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_f64(value));
+    }
+    set_estimate(&obj, estimate, (lower, upper));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("reject"),
+        &JsValue::from_bool(p < alpha),
+    );
+    obj.into()
+}
+
+/// Checks an experiment for sample ratio mismatch with a chi-square goodness-of-fit test
+/// of the observed arm sizes against the intended allocation.
+///
+/// # Arguments
+///
+/// * `observed_counts` - A reference to a JsValue representing the number of units
+///   assigned to each arm.
+/// * `expected_ratios` - A reference to a JsValue representing the intended allocation
+///   weights of the arms (e.g., `[1, 1]` or `[0.5, 0.5]`).
+/// * `options` - An optional object with the property `alpha`, the threshold below which
+///   the p-value flags a mismatch (defaults to 0.001, the conventional strict guardrail).
+///
+/// # Returns
+///
+/// * An object with the properties `x2`, `df`, `p`, `expected` (the expected counts), and
+///   `mismatch` (true when `p < alpha`), or null if the arrays differ in length, there are
+///   fewer than two arms, a count or ratio is invalid, or no units were observed.
+#[wasm_bindgen]
+pub fn srm_test(
+    observed_counts: &JsValue,
+    expected_ratios: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let observed = js_array_to_vector(observed_counts);
+    let ratios = js_array_to_vector(expected_ratios);
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.001);
+    if observed.len() != ratios.len()
+        || observed.len() < 2
+        || observed.iter().any(|o| !o.is_finite() || *o < 0.0)
+        || ratios.iter().any(|r| !r.is_finite() || *r <= 0.0)
+    {
+        return JsValue::NULL;
+    }
+
+    let total: f64 = observed.iter().sum();
+    if total == 0.0 {
+        return JsValue::NULL;
+    }
+    let ratio_total: f64 = ratios.iter().sum();
+    let expected: Vec<f64> = ratios.iter().map(|r| total * r / ratio_total).collect();
+    let x2: f64 = observed
+        .iter()
+        .zip(&expected)
+        .map(|(o, e)| (o - e).powi(2) / e)
+        .sum();
+    let df = (observed.len() - 1) as f64;
+    let p = 1.0 - ChiSquared::new(df).unwrap().cdf(x2);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected"),
+        &vec_to_jsvalue(expected),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("mismatch"),
+        &JsValue::from_bool(p < alpha),
+    );
+    obj.into()
+}
+
+/// A multi-armed bandit that allocates traffic by Thompson sampling, with either
+/// Beta-Bernoulli arms (uniform Beta(1, 1) priors) or Gaussian arms (normal-inverse-gamma
+/// reference priors).
+#[wasm_bindgen]
+pub struct Bandit {
+    gaussian: bool,
+    counts: Vec<f64>,
+    sums: Vec<f64>,
+    sums_sq: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl Bandit {
+    /// The kind of arms, either "bernoulli" or "gaussian".
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> String {
+        if self.gaussian {
+            "gaussian"
+        } else {
+            "bernoulli"
+        }
+        .to_string()
+    }
+
+    /// The number of rewards observed on each arm.
+    #[wasm_bindgen(getter)]
+    pub fn counts(&self) -> JsValue {
+        vec_to_jsvalue(self.counts.clone())
+    }
+
+    /// Records a reward for an arm.
+    ///
+    /// # Arguments
+    ///
+    /// * `arm` - The index of the arm that was played.
+    /// * `reward` - The observed reward, which must be 0 or 1 for Bernoulli arms.
+    ///
+    /// # Returns
+    ///
+    /// * Whether the reward was recorded; invalid arms and rewards are ignored.
+    pub fn update(&mut self, arm: usize, reward: f64) -> bool {
+        if arm >= self.counts.len()
+            || !reward.is_finite()
+            || (!self.gaussian && reward != 0.0 && reward != 1.0)
+        {
+            return false;
+        }
+        self.counts[arm] += 1.0;
+        self.sums[arm] += reward;
+        self.sums_sq[arm] += reward * reward;
+        true
+    }
+
+    /// Chooses the next arm to play by drawing once from each arm's posterior and taking
+    /// the largest draw. Gaussian arms with fewer than two rewards are played first, since
+    /// their reference posterior is improper.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A reference to a JsValue representing the random seed, or undefined.
+    ///
+    /// # Returns
+    ///
+    /// * The index of the selected arm.
+    pub fn select_arm(&self, seed: &JsValue) -> usize {
+        let mut rng = seeded_rng(seed.as_f64());
+        if self.gaussian {
+            if let Some(arm) = self.counts.iter().position(|n| *n < 2.0) {
+                return arm;
+            }
+        }
+
+        let draws: Vec<f64> = (0..self.counts.len())
+            .map(|arm| {
+                let n = self.counts[arm];
+                if self.gaussian {
+                    let mean = self.sums[arm] / n;
+                    let ss = self.sums_sq[arm] - n * mean * mean;
+                    if ss <= 0.0 {
+                        return mean;
+                    }
+                    let variance = 1.0
+                        / Gamma::new((n - 1.0) / 2.0, ss / 2.0)
+                            .unwrap()
+                            .sample(&mut rng);
+                    Normal::new(mean, (variance / n).sqrt())
+                        .unwrap()
+                        .sample(&mut rng)
+                } else {
+                    Beta::new(1.0 + self.sums[arm], 1.0 + n - self.sums[arm])
+                        .unwrap()
+                        .sample(&mut rng)
+                }
+            })
+            .collect();
+        (0..draws.len())
+            .max_by(|a, b| draws[*a].total_cmp(&draws[*b]))
+            .unwrap()
+    }
+
+    /// Summarizes the posterior of each arm's mean reward.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - A reference to a JsValue representing the credible level complement
+    ///   (defaults to 0.05).
+    ///
+    /// # Returns
+    ///
+    /// * An object with the arrays `counts`, `means` (the posterior means), `lower`, and
+    ///   `upper` (the equal-tailed credible bounds). Gaussian arms with fewer than two
+    ///   rewards have NaN summaries.
+    pub fn summary(&self, alpha: &JsValue) -> JsValue {
+        let alpha = alpha.as_f64().unwrap_or(0.05);
+        let mut means = Vec::with_capacity(self.counts.len());
+        let mut lower = Vec::with_capacity(self.counts.len());
+        let mut upper = Vec::with_capacity(self.counts.len());
+        for arm in 0..self.counts.len() {
+            let n = self.counts[arm];
+            if self.gaussian {
+                if n < 2.0 {
+                    means.push(f64::NAN);
+                    lower.push(f64::NAN);
+                    upper.push(f64::NAN);
+                    continue;
+                }
+                let mean = self.sums[arm] / n;
+                let ss = (self.sums_sq[arm] - n * mean * mean).max(0.0);
+                let scale = (ss / (n * (n - 1.0))).sqrt();
+                let t = StudentsT::new(0.0, 1.0, n - 1.0)
+                    .unwrap()
+                    .inverse_cdf(1.0 - alpha / 2.0);
+                means.push(mean);
+                lower.push(mean - t * scale);
+                upper.push(mean + t * scale);
+            } else {
+                let a = 1.0 + self.sums[arm];
+                let b = 1.0 + n - self.sums[arm];
+                let posterior = Beta::new(a, b).unwrap();
+                means.push(a / (a + b));
+                lower.push(beta_quantile(&posterior, alpha / 2.0));
+                upper.push(beta_quantile(&posterior, 1.0 - alpha / 2.0));
+            }
+        }
+
+        let obj = Object::new();
+        let _ = Reflect::set(&obj, &JsValue::from_str("counts"), &self.counts());
+        let _ = Reflect::set(&obj, &JsValue::from_str("means"), &vec_to_jsvalue(means));
+        let _ = Reflect::set(&obj, &JsValue::from_str("lower"), &vec_to_jsvalue(lower));
+        let _ = Reflect::set(&obj, &JsValue::from_str("upper"), &vec_to_jsvalue(upper));
+        obj.into()
+    }
+}
+
+/// Creates a Thompson sampling bandit with no observed rewards.
+///
+/// # Arguments
+///
+/// * `arms` - A reference to a JsValue representing the number of arms (at least two).
+/// * `kind` - A reference to a JsValue representing the kind of arms, either "bernoulli"
+///   (the default) or "gaussian".
+///
+/// # Returns
+///
+/// * The bandit, or undefined if the arguments are invalid.
+#[wasm_bindgen]
+pub fn create_bandit(arms: &JsValue, kind: &JsValue) -> Option<Bandit> {
+    let arms = arms.as_f64().filter(|arms| *arms >= 2.0)? as usize;
+    let gaussian = match kind.as_string().as_deref() {
+        None | Some("bernoulli") => false,
+        Some("gaussian") => true,
+        _ => return None,
+    };
+    Some(Bandit {
+        gaussian,
+        counts: vec![0.0; arms],
+        sums: vec![0.0; arms],
+        sums_sq: vec![0.0; arms],
+    })
+}
+
+/// Computes the cumulative type I error spent by information fraction `t` under a
+/// Lan-DeMets spending function approximating O'Brien-Fleming or Pocock boundaries.
+fn alpha_spent(t: f64, alpha: f64, pocock: bool) -> f64 {
+    if pocock {
+        alpha * (1.0 + (std::f64::consts::E - 1.0) * t).ln()
+    } else {
+        // Two normal tails through erfc, which keeps its precision at early looks
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        erfc(-normal.inverse_cdf(alpha / 2.0) / (2.0 * t).sqrt())
+    }
+}
+
+/// Integrates evenly spaced function values by Simpson's rule.
+fn simpson(values: &[f64], step: f64) -> f64 {
+    let last = values.len() - 1;
+    let interior: f64 = values[1..last]
+        .iter()
+        .enumerate()
+        .map(|(i, v)| if i % 2 == 0 { 4.0 * v } else { 2.0 * v })
+        .sum();
+    step / 3.0 * (values[0] + values[last] + interior)
+}
+
+/// Computes two-sided group-sequential boundaries on the z scale by recursive numerical
+/// integration of the Brownian motion score process, so that the probability of first
+/// crossing at each look under the null matches the alpha spent there.
+fn sequential_boundaries(fractions: &[f64], alpha: f64, pocock: bool) -> Vec<f64> {
+    const POINTS: usize = 401;
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let grid_for = |bound: f64| -> Vec<f64> {
+        (0..POINTS)
+            .map(|i| -bound + 2.0 * bound * i as f64 / (POINTS - 1) as f64)
+            .collect()
+    };
+
+    let mut boundaries = Vec::with_capacity(fractions.len());
+    let mut grid: Vec<f64> = Vec::new();
+    let mut density: Vec<f64> = Vec::new();
+    let mut previous_t = 0.0;
+    let mut spent = 0.0;
+    for (k, t) in fractions.iter().enumerate() {
+        let cumulative = alpha_spent(*t, alpha, pocock);
+        let increment = (cumulative - spent).max(0.0);
+        spent = spent.max(cumulative);
+        let delta = t - previous_t;
+        let step = if k == 0 { 0.0 } else { grid[1] - grid[0] };
+
+        let c = if increment == 0.0 {
+            // No alpha is spent at this look, so it can never stop the trial
+            f64::INFINITY
+        } else if k == 0 {
+            -normal.inverse_cdf(increment / 2.0)
+        } else {
+            let exit_probability = |c: f64| {
+                let bound = c * t.sqrt();
+                let values: Vec<f64> = grid
+                    .iter()
+                    .zip(&density)
+                    .map(|(b, f)| {
+                        f * (normal.sf((bound - b) / delta.sqrt())
+                            + normal.cdf((-bound - b) / delta.sqrt()))
+                    })
+                    .collect();
+                simpson(&values, step)
+            };
+            let (mut low, mut high) = (0.0, 40.0);
+            for _ in 0..60 {
+                let mid = (low + high) / 2.0;
+                if exit_probability(mid) > increment {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            (low + high) / 2.0
+        };
+        boundaries.push(c);
+
+        // An infinite boundary truncates nothing, so carry the density out to where the
+        // remaining mass is negligible
+        let bound = if c.is_finite() { c } else { 8.0 };
+        let next_grid = grid_for(bound * t.sqrt());
+        density = if k == 0 {
+            next_grid
+                .iter()
+                .map(|x| (-x * x / (2.0 * t)).exp() / (2.0 * std::f64::consts::PI * t).sqrt())
+                .collect()
+        } else {
+            next_grid
+                .iter()
+                .map(|x| {
+                    let values: Vec<f64> = grid
+                        .iter()
+                        .zip(&density)
+                        .map(|(b, f)| {
+                            f * (-(x - b).powi(2) / (2.0 * delta)).exp()
+                                / (2.0 * std::f64::consts::PI * delta).sqrt()
+                        })
+                        .collect();
+                    simpson(&values, step)
+                })
+                .collect()
+        };
+        grid = next_grid;
+        previous_t = *t;
+    }
+    boundaries
+}
+
+/// Reads the design of a group-sequential trial from its information fractions, alpha,
+/// and spending function name.
+fn read_design(fractions: &[f64], alpha: f64, spending: Option<String>) -> Option<bool> {
+    let increasing = fractions.windows(2).all(|w| w[0] < w[1]);
+    if fractions.is_empty()
+        || !increasing
+        || fractions[0] <= 0.0
+        || fractions[fractions.len() - 1] > 1.0
+        || alpha <= 0.0
+        || alpha >= 1.0
+    {
+        return None;
+    }
+    match spending.as_deref() {
+        None | Some("obrien_fleming") => Some(false),
+        Some("pocock") => Some(true),
+        _ => None,
+    }
+}
+
+/// Computes the two-sided boundaries of a group-sequential design from a Lan-DeMets
+/// alpha spending function.
+///
+/// # Arguments
+///
+/// * `information_fractions` - A reference to a JsValue representing the increasing
+///   fractions of the total information at each look, ending at 1 for the final analysis.
+/// * `alpha` - A reference to a JsValue representing the overall two-sided significance
+///   level (defaults to 0.05).
+/// * `spending` - A reference to a JsValue representing the spending function, either
+///   "obrien_fleming" (the default) or "pocock".
+///
+/// # Returns
+///
+/// * An object with the properties `z` (the critical |z| at each look, which is infinite
+///   at a look too early to spend any alpha), `nominal_p` (the two-sided nominal
+///   significance level at each look), and `cumulative_alpha` (the alpha spent by each
+///   look), or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn group_sequential_boundaries(
+    information_fractions: &JsValue,
+    alpha: &JsValue,
+    spending: &JsValue,
+) -> JsValue {
+    let fractions = js_array_to_vector(information_fractions);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let pocock = match read_design(&fractions, alpha, spending.as_string()) {
+        Some(pocock) => pocock,
+        None => return JsValue::NULL,
+    };
+
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let boundaries = sequential_boundaries(&fractions, alpha, pocock);
+    let nominal_p: Vec<f64> = boundaries.iter().map(|c| 2.0 * normal.sf(*c)).collect();
+    let cumulative_alpha: Vec<f64> = fractions
+        .iter()
+        .map(|t| alpha_spent(*t, alpha, pocock))
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &vec_to_jsvalue(boundaries));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("nominal_p"),
+        &vec_to_jsvalue(nominal_p),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("cumulative_alpha"),
+        &vec_to_jsvalue(cumulative_alpha),
+    );
+    obj.into()
+}
+
+/// Analyzes the looks of a group-sequential trial completed so far against its
+/// alpha-spending boundaries.
+///
+/// # Arguments
+///
+/// * `looks` - A reference to a JsValue representing the design, an object with the
+///   properties `information_fractions` (for all planned looks), `alpha` (defaults to
+///   0.05), and `spending` ("obrien_fleming" or "pocock"; defaults to "obrien_fleming").
+/// * `data` - A reference to a JsValue representing an object with the arrays `estimates`
+///   and `std_errors` of the effect estimate at each completed look.
+///
+/// # Returns
+///
+/// * An object with the properties `boundaries` (the critical |z| for every planned look),
+///   `z` (the observed statistics), `crossed` (whether any look crossed its boundary),
+///   `stop_look` (the index of the first crossing, or null), and the `estimate` and `ci`
+///   at the final analyzed look (the first crossing, or else the latest look), where the
+///   interval is the repeated confidence interval using that look's boundary. Returns null
+///   if the inputs are invalid or there are more completed looks than planned.
+#[wasm_bindgen]
+pub fn interim_analysis(looks: &JsValue, data: &JsValue) -> JsValue {
+    let fractions = js_array_to_vector(
+        &Reflect::get(looks, &JsValue::from_str("information_fractions"))
+            .unwrap_or(JsValue::UNDEFINED),
+    );
+    let alpha = get_f64_option(looks, "alpha").unwrap_or(0.05);
+    let pocock = match read_design(&fractions, alpha, get_string_option(looks, "spending")) {
+        Some(pocock) => pocock,
+        None => return JsValue::NULL,
+    };
+    let estimates = js_array_to_vector(
+        &Reflect::get(data, &JsValue::from_str("estimates")).unwrap_or(JsValue::UNDEFINED),
+    );
+    let std_errors = js_array_to_vector(
+        &Reflect::get(data, &JsValue::from_str("std_errors")).unwrap_or(JsValue::UNDEFINED),
+    );
+    if estimates.is_empty()
+        || estimates.len() != std_errors.len()
+        || estimates.len() > fractions.len()
+        || std_errors.iter().any(|se| *se <= 0.0)
+    {
+        return JsValue::NULL;
+    }
+
+    let boundaries = sequential_boundaries(&fractions, alpha, pocock);
+    let z: Vec<f64> = estimates
+        .iter()
+        .zip(&std_errors)
+        .map(|(estimate, se)| estimate / se)
+        .collect();
+    let stop_look = z.iter().zip(&boundaries).position(|(z, c)| z.abs() >= *c);
+    let last = stop_look.unwrap_or(estimates.len() - 1);
+    let margin = boundaries[last] * std_errors[last];
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("boundaries"),
+        &vec_to_jsvalue(boundaries),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &vec_to_jsvalue(z));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("crossed"),
+        &JsValue::from_bool(stop_look.is_some()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("stop_look"),
+        &stop_look.map_or(JsValue::NULL, |look| JsValue::from_f64(look as f64)),
+    );
+    set_estimate(
+        &obj,
+        estimates[last],
+        (estimates[last] - margin, estimates[last] + margin),
+    );
+    obj.into()
+}
+
+/// Computes a ratio of sums and its delta-method variance from cluster-level totals.
+///
+/// # Returns
+///
+/// * The ratio and its variance, or `None` if there are fewer than two clusters, the
+///   arrays differ in length, or the denominators sum to zero.
+fn ratio_with_variance(numerators: &[f64], denominators: &[f64]) -> Option<(f64, f64)> {
+    let k = numerators.len();
+    if k < 2 || denominators.len() != k {
+        return None;
+    }
+    let k_f = k as f64;
+    let mean_x = numerators.iter().sum::<f64>() / k_f;
+    let mean_n = denominators.iter().sum::<f64>() / k_f;
+    if mean_n == 0.0 {
+        return None;
+    }
+    let ratio = mean_x / mean_n;
+
+    let var_x = numerators.iter().map(|x| (x - mean_x).powi(2)).sum::<f64>() / (k_f - 1.0);
+    let var_n = denominators
+        .iter()
+        .map(|n| (n - mean_n).powi(2))
+        .sum::<f64>()
+        / (k_f - 1.0);
+    let cov = numerators
+        .iter()
+        .zip(denominators)
+        .map(|(x, n)| (x - mean_x) * (n - mean_n))
+        .sum::<f64>()
+        / (k_f - 1.0);
+    let variance = (var_x - 2.0 * ratio * cov + ratio * ratio * var_n) / (k_f * mean_n * mean_n);
+    Some((ratio, variance))
+}
+
+/// Builds the summary object for one arm's ratio metric.
+fn ratio_summary(ratio: f64, variance: f64, alpha: f64) -> JsValue {
+    let obj = Object::new();
+    set_estimate(&obj, ratio, z_bounds(ratio, variance.sqrt(), alpha));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &JsValue::from_f64(variance.sqrt()),
+    );
+    obj.into()
+}
+
+/// Compares a ratio metric (such as revenue per session, the sum of revenue over the sum
+/// of sessions) between two arms using the delta method on cluster-level totals, which
+/// accounts for the correlation of observations within each randomization unit.
+///
+/// # Arguments
+///
+/// * `numerator_a` - A reference to a JsValue representing the numerator total of each
+///   cluster (e.g., user) in arm A.
+/// * `denominator_a` - A reference to a JsValue representing the denominator total of each
+///   cluster in arm A.
+/// * `numerator_b` - A reference to a JsValue representing the numerator totals in arm B.
+/// * `denominator_b` - A reference to a JsValue representing the denominator totals in arm
+///   B.
+/// * `options` - An optional object with the property `alpha` (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `a` and `b` (each with the ratio's `estimate`, `ci`,
+///   and `std_error`), the `estimate` and `ci` of the difference `b - a`, its `std_error`,
+///   `z`, and `p`, or null if either arm has fewer than two clusters, mismatched arrays, or
+///   a zero denominator total.
+#[wasm_bindgen]
+pub fn ratio_metric_test(
+    numerator_a: &JsValue,
+    denominator_a: &JsValue,
+    numerator_b: &JsValue,
+    denominator_b: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let arm_a = ratio_with_variance(
+        &js_array_to_vector(numerator_a),
+        &js_array_to_vector(denominator_a),
+    );
+    let arm_b = ratio_with_variance(
+        &js_array_to_vector(numerator_b),
+        &js_array_to_vector(denominator_b),
+    );
+    let ((ratio_a, var_a), (ratio_b, var_b)) = match (arm_a, arm_b) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return JsValue::NULL,
+    };
+
+    let difference = ratio_b - ratio_a;
+    let std_error = (var_a + var_b).sqrt();
+    let z = difference / std_error;
+    let p = 2.0 * (1.0 - Normal::new(0.0, 1.0).unwrap().cdf(z.abs()));
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("a"),
+        &ratio_summary(ratio_a, var_a, alpha),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("b"),
+        &ratio_summary(ratio_b, var_b, alpha),
+    );
+    set_estimate(&obj, difference, z_bounds(difference, std_error, alpha));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &JsValue::from_f64(std_error),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn batch(a: Vec<f64>, b: Vec<f64>) -> JsValue {
+        let data = Object::new();
+        let _ = Reflect::set(&data, &JsValue::from_str("a"), &vec_to_jsvalue(a));
+        let _ = Reflect::set(&data, &JsValue::from_str("b"), &vec_to_jsvalue(b));
+        data.into()
+    }
+
+    fn get_f64(obj: &JsValue, key: &str) -> f64 {
+        Reflect::get(obj, &JsValue::from_str(key))
+            .unwrap()
+            .as_f64()
+            .unwrap()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_sequential_test_update() {
+        let first = sequential_test_update(
+            &JsValue::UNDEFINED,
+            &batch(
+                vec![5.1, 4.8, 5.3, 5.0, 4.9, 5.2, 5.1, 4.7],
+                vec![5.4, 5.6, 5.2, 5.8, 5.5, 5.3, 5.7, 5.6],
+            ),
+        );
+        let second = sequential_test_update(
+            &first,
+            &batch(
+                vec![5.0, 5.2, 4.9, 5.1, 4.8, 5.0],
+                vec![5.5, 5.9, 5.4, 5.6, 5.3, 5.7],
+            ),
+        );
+
+        let ci = js_array_to_vector(&Reflect::get(&second, &JsValue::from_str("ci")).unwrap());
+
+        assert!((get_f64(&first, "p") - 0.639806).abs() < 0.0001);
+        assert!((get_f64(&first, "tau2") - 0.0004125).abs() < 1e-7);
+        assert!((get_f64(&second, "estimate") - 0.528571).abs() < 0.0001);
+        assert!((get_f64(&second, "p") - 0.130082).abs() < 0.0001);
+        assert!((ci[0] - (-0.110095)).abs() < 0.0001);
+        assert!((ci[1] - 1.167237).abs() < 0.0001);
+        assert_eq!(get_f64(&second, "n_a"), 14.0);
+        assert_eq!(
+            Reflect::get(&second, &JsValue::from_str("reject")).unwrap(),
+            JsValue::FALSE
+        );
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_srm_test() {
+        let balanced = srm_test(
+            &vec_to_jsvalue(vec![5080.0, 4920.0, 5150.0]),
+            &vec_to_jsvalue(vec![1.0, 1.0, 1.0]),
+            &JsValue::UNDEFINED,
+        );
+        let skewed = srm_test(
+            &vec_to_jsvalue(vec![10321.0, 9679.0]),
+            &vec_to_jsvalue(vec![0.5, 0.5]),
+            &JsValue::UNDEFINED,
+        );
+
+        assert!((get_f64(&balanced, "x2") - 5.504950).abs() < 0.0001);
+        assert!((get_f64(&balanced, "p") - 0.063770).abs() < 0.0001);
+        assert_eq!(
+            Reflect::get(&balanced, &JsValue::from_str("mismatch")).unwrap(),
+            JsValue::FALSE
+        );
+        assert!((get_f64(&skewed, "x2") - 20.6082).abs() < 0.0001);
+        assert!((get_f64(&skewed, "p") - 0.00000564).abs() < 1e-7);
+        assert_eq!(
+            Reflect::get(&skewed, &JsValue::from_str("mismatch")).unwrap(),
+            JsValue::TRUE
+        );
+
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("alpha"),
+            &JsValue::from_f64(0.1),
+        );
+        let lenient = srm_test(
+            &vec_to_jsvalue(vec![5080.0, 4920.0, 5150.0]),
+            &vec_to_jsvalue(vec![1.0, 1.0, 1.0]),
+            &options.into(),
+        );
+        assert_eq!(
+            Reflect::get(&lenient, &JsValue::from_str("mismatch")).unwrap(),
+            JsValue::TRUE
+        );
+        assert!(srm_test(
+            &vec_to_jsvalue(vec![0.0, 0.0]),
+            &vec_to_jsvalue(vec![1.0, 1.0]),
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_create_bandit() {
+        let mut bandit = create_bandit(&JsValue::from_f64(3.0), &JsValue::UNDEFINED).unwrap();
+        for _ in 0..40 {
+            bandit.update(0, 0.0);
+            bandit.update(2, 1.0);
+        }
+        for i in 0..10 {
+            bandit.update(1, (i % 2) as f64);
+        }
+
+        let summary = bandit.summary(&JsValue::from_f64(0.05));
+        let means =
+            js_array_to_vector(&Reflect::get(&summary, &JsValue::from_str("means")).unwrap());
+        let lower =
+            js_array_to_vector(&Reflect::get(&summary, &JsValue::from_str("lower")).unwrap());
+
+        assert_eq!(bandit.kind(), "bernoulli");
+        assert!(!bandit.update(3, 1.0));
+        assert!(!bandit.update(0, 0.5));
+        assert!((means[1] - 0.5).abs() < 1e-12);
+        assert!((means[2] - 41.0 / 42.0).abs() < 1e-12);
+        // The 2.5% quantile of Beta(41, 1) is 0.025^(1/41)
+        assert!((lower[2] - 0.025_f64.powf(1.0 / 41.0)).abs() < 1e-6);
+        assert_eq!(bandit.select_arm(&JsValue::from_f64(1.0)), 2);
+
+        let mut gaussian =
+            create_bandit(&JsValue::from_f64(2.0), &JsValue::from_str("gaussian")).unwrap();
+        gaussian.update(0, 1.0);
+        gaussian.update(0, 2.0);
+        assert_eq!(gaussian.select_arm(&JsValue::UNDEFINED), 1);
+        for reward in [9.0, 10.0, 11.0] {
+            gaussian.update(1, reward);
+        }
+        assert_eq!(gaussian.select_arm(&JsValue::from_f64(4.0)), 1);
+        assert!(create_bandit(&JsValue::from_f64(1.0), &JsValue::UNDEFINED).is_none());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_group_sequential_boundaries() {
+        let fractions = vec_to_jsvalue(vec![0.2, 0.4, 0.6, 0.8, 1.0]);
+
+        let obrien_fleming =
+            group_sequential_boundaries(&fractions, &JsValue::from_f64(0.05), &JsValue::UNDEFINED);
+        let pocock = group_sequential_boundaries(
+            &fractions,
+            &JsValue::from_f64(0.05),
+            &JsValue::from_str("pocock"),
+        );
+
+        let of_z =
+            js_array_to_vector(&Reflect::get(&obrien_fleming, &JsValue::from_str("z")).unwrap());
+        let pocock_z = js_array_to_vector(&Reflect::get(&pocock, &JsValue::from_str("z")).unwrap());
+        let spent = js_array_to_vector(
+            &Reflect::get(&pocock, &JsValue::from_str("cumulative_alpha")).unwrap(),
+        );
+
+        assert!((of_z[0] - 4.3826).abs() < 0.001);
+        assert!((of_z[2] - 2.5534).abs() < 0.001);
+        assert!((of_z[4] - 2.0635).abs() < 0.001);
+        assert!((pocock_z[0] - 2.4380).abs() < 0.001);
+        assert!((pocock_z[2] - 2.4102).abs() < 0.001);
+        assert!((pocock_z[4] - 2.3860).abs() < 0.001);
+        assert!((spent[4] - 0.05).abs() < 1e-12);
+
+        // The first O'Brien-Fleming increment is only 1.86e-18 at 5% of the information
+        let early = group_sequential_boundaries(
+            &vec_to_jsvalue(vec![0.05, 1.0]),
+            &JsValue::from_f64(0.05),
+            &JsValue::UNDEFINED,
+        );
+        let early_z = js_array_to_vector(&Reflect::get(&early, &JsValue::from_str("z")).unwrap());
+        let early_p =
+            js_array_to_vector(&Reflect::get(&early, &JsValue::from_str("nominal_p")).unwrap());
+        assert!((early_z[0] - 8.765225).abs() < 0.001);
+        assert!((early_z[1] - 1.959964).abs() < 0.001);
+        assert!((early_p[0] / 1.864033e-18 - 1.0).abs() < 0.001);
+        assert!((early_p[1] - 0.05).abs() < 0.0001);
+
+        // At 0.1% of the information the increment underflows, so the look cannot stop
+        let earliest = group_sequential_boundaries(
+            &vec_to_jsvalue(vec![0.001, 1.0]),
+            &JsValue::from_f64(0.05),
+            &JsValue::UNDEFINED,
+        );
+        let earliest_z =
+            js_array_to_vector(&Reflect::get(&earliest, &JsValue::from_str("z")).unwrap());
+        assert_eq!(earliest_z[0], f64::INFINITY);
+        assert!((earliest_z[1] - 1.959964).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_interim_analysis() {
+        let looks = Object::new();
+        let _ = Reflect::set(
+            &looks,
+            &JsValue::from_str("information_fractions"),
+            &vec_to_jsvalue(vec![0.2, 0.4, 0.6, 0.8, 1.0]),
+        );
+        let looks: JsValue = looks.into();
+        let data = Object::new();
+        let _ = Reflect::set(
+            &data,
+            &JsValue::from_str("estimates"),
+            &vec_to_jsvalue(vec![0.5, 0.55, 0.6]),
+        );
+        let _ = Reflect::set(
+            &data,
+            &JsValue::from_str("std_errors"),
+            &vec_to_jsvalue(vec![0.4, 0.28, 0.22]),
+        );
+
+        let result = interim_analysis(&looks, &data.into());
+
+        let ci = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+
+        // z = 2.727 clears the third boundary of 2.553 after 1.25 and 1.96 fell short
+        assert_eq!(
+            Reflect::get(&result, &JsValue::from_str("crossed")).unwrap(),
+            JsValue::TRUE
+        );
+        assert_eq!(get_f64(&result, "stop_look"), 2.0);
+        assert!((get_f64(&result, "estimate") - 0.6).abs() < 1e-12);
+        assert!((ci[0] - (0.6 - 2.5534 * 0.22)).abs() < 0.001);
+        assert!((ci[1] - (0.6 + 2.5534 * 0.22)).abs() < 0.001);
+
+        // A z of 2.5 at an early look is far below its boundary of 8.765
+        let early_looks = Object::new();
+        let _ = Reflect::set(
+            &early_looks,
+            &JsValue::from_str("information_fractions"),
+            &vec_to_jsvalue(vec![0.05, 1.0]),
+        );
+        let early_data = Object::new();
+        let _ = Reflect::set(
+            &early_data,
+            &JsValue::from_str("estimates"),
+            &vec_to_jsvalue(vec![1.0]),
+        );
+        let _ = Reflect::set(
+            &early_data,
+            &JsValue::from_str("std_errors"),
+            &vec_to_jsvalue(vec![0.4]),
+        );
+        let early = interim_analysis(&early_looks.into(), &early_data.into());
+        let early_ci = js_array_to_vector(&Reflect::get(&early, &JsValue::from_str("ci")).unwrap());
+        assert_eq!(
+            Reflect::get(&early, &JsValue::from_str("crossed")).unwrap(),
+            JsValue::FALSE
+        );
+        assert!((early_ci[1] - (1.0 + 8.765225 * 0.4)).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_ratio_metric_test() {
+        let result = ratio_metric_test(
+            &vec_to_jsvalue(vec![12.0, 0.0, 5.5, 20.0, 3.0, 0.0, 8.0, 15.0, 2.0, 6.0]),
+            &vec_to_jsvalue(vec![3.0, 1.0, 2.0, 4.0, 1.0, 2.0, 2.0, 3.0, 1.0, 2.0]),
+            &vec_to_jsvalue(vec![15.0, 4.0, 7.0, 22.0, 0.0, 9.0, 11.0, 18.0, 5.0, 8.0]),
+            &vec_to_jsvalue(vec![3.0, 2.0, 2.0, 4.0, 1.0, 2.0, 3.0, 3.0, 1.0, 2.0]),
+            &JsValue::UNDEFINED,
+        );
+
+        let a = Reflect::get(&result, &JsValue::from_str("a")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+
+        assert!((get_f64(&a, "estimate") - 3.404762).abs() < 0.0001);
+        assert!((get_f64(&a, "std_error") - 0.573838).abs() < 0.0001);
+        assert!((get_f64(&result, "estimate") - 0.899586).abs() < 0.0001);
+        assert!((get_f64(&result, "std_error") - 0.734574).abs() < 0.0001);
+        assert!((get_f64(&result, "p") - 0.220713).abs() < 0.0001);
+        assert!((ci[0] - (-0.540153)).abs() < 0.0001);
+        assert!((ci[1] - 2.339325).abs() < 0.0001);
+    }
+}
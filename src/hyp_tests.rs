@@ -1,28 +1,47 @@
+use crate::conf_int::{f_ratio_bounds, t_bounds, variance_bounds, z_bounds};
+use crate::regression::{design_matrix, ols_fit};
 use crate::utils::*;
+use js_sys::Array;
 use js_sys::Object;
 use js_sys::Reflect;
 use statrs::distribution::ChiSquared;
+use statrs::distribution::Continuous;
 use statrs::distribution::ContinuousCDF;
+use statrs::distribution::Discrete;
+use statrs::distribution::DiscreteCDF;
+use statrs::distribution::Exp;
 use statrs::distribution::FisherSnedecor;
 use statrs::distribution::Normal;
+use statrs::distribution::Poisson;
 use statrs::distribution::StudentsT;
-use statrs::statistics::Statistics;
+use statrs::distribution::Uniform;
+use statrs::statistics::{Data, OrderStatistics, Statistics};
 use wasm_bindgen::prelude::*;
 
 /// Performs a one-sample z-test on a column of data represented as a JavaScript array.
-/// Uses sample standard error as an estimate for population standard deviation.
+/// Uses the known population standard deviation when `sigma` is given, and the sample
+/// standard deviation as an estimate otherwise.
 ///
 /// # Arguments
 ///
 /// * `column` - A reference to a JsValue representing a JavaScript array of f64 numbers.
 /// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
 /// * `mu0` - A reference to a JsValue representing the null hypothesis mean.
+/// * `options` - An optional object; `{ sigma: 2 }` supplies the known population standard
+///   deviation, and `{ effect_size: true, alpha: 0.05 }` adds the sample mean, its z-interval,
+///   and Cohen's d to the result.
 ///
 /// # Returns
 ///
-/// A reference to a JsValue representing the p-value and z-statistic of the one-sample z-test.
+/// A reference to a JsValue representing the p-value and z-statistic of the one-sample z-test,
+/// or null if `sigma` is not positive.
 #[wasm_bindgen]
-pub fn one_samp_z_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsValue {
+pub fn one_samp_z_test(
+    column: &JsValue,
+    tails: &JsValue,
+    mu0: &JsValue,
+    options: &JsValue,
+) -> JsValue {
     let tails = tails.as_string().unwrap(); // can be "two-sided", "less" or "greater"
     let mu0 = mu0.as_f64().unwrap();
 
@@ -38,11 +57,17 @@ pub fn one_samp_z_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsVa
     }
 
     let data = js_array_to_vector(column);
+    let sigma = get_f64_option(options, "sigma");
+    if sigma.is_some_and(|sigma| sigma.is_nan() || sigma <= 0.0) {
+        return JsValue::NULL;
+    }
 
     let n = data.len() as f64;
     let mean = data.iter().sum::<f64>() / n;
-    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
-    let std_dev = variance.sqrt();
+    let std_dev = match sigma {
+        Some(sigma) => sigma,
+        None => (data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt(),
+    };
 
     // Standard error of the mean
     let std_error = std_dev / n.sqrt();
@@ -70,11 +95,37 @@ pub fn one_samp_z_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsVa
     let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
 
+    if get_bool_option(options, "effect_size") {
+        let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+        set_estimate(&obj, mean, z_bounds(mean, std_error, alpha));
+        set_effect_size(&obj, "cohens_d", (mean - mu0) / std_dev);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        if data.len() < 30 {
+            if get_f64_option(options, "sigma").is_none() {
+                warnings.push(
+                    "The sample size is below 30 and sigma is unknown; consider one_samp_t_test."
+                        .to_string(),
+                );
+            }
+            check_normality(
+                &mut warnings,
+                &data,
+                "The data",
+                "the Wilcoxon signed-rank test",
+            );
+        }
+        set_warnings(&obj, warnings);
+    }
+
     obj.into()
 }
 
 /// Performs a two-sample z-test on two columns of data represented as JavaScript arrays.
-/// Uses sample standard errors as an estimate for population standard deviations.
+/// Uses the known population standard deviations when `sigma1` and `sigma2` are given, and
+/// the sample standard deviations as estimates otherwise.
 ///
 /// # Arguments
 ///
@@ -82,16 +133,22 @@ pub fn one_samp_z_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsVa
 /// * `column2` - A reference to a JsValue representing a JavaScript array of f64 numbers.
 /// * `tails` - A reference to a JsValue representing the tails of the test.
 /// * `delta0` - A reference to a JsValue representing the hypothesized difference in means.
+/// * `options` - An optional object; `{ sigma1: 2, sigma2: 3 }` supplies the known population
+///   standard deviations, and `{ effect_size: true, alpha: 0.05 }` adds the difference in
+///   means, its z-interval, and Cohen's d (standardized by the pooled standard deviation
+///   with `n1 + n2 - 2` degrees of freedom) to the result.
 ///
 /// # Returns
 ///
-/// * A reference to a JsValue representing the p-value and z-statistic of the test.
+/// * A reference to a JsValue representing the p-value and z-statistic of the test, or null
+///   if `sigma1` or `sigma2` is given and not positive.
 #[wasm_bindgen]
 pub fn two_samp_z_test(
     column1: &JsValue,
     column2: &JsValue,
     tails: &JsValue,
     delta0: &JsValue,
+    options: &JsValue,
 ) -> JsValue {
     let d0 = delta0.as_f64().unwrap();
     let tails = tails.as_string().unwrap();
@@ -105,14 +162,106 @@ pub fn two_samp_z_test(
     let mean1 = c1.iter().sum::<f64>() / n1;
     let mean2 = c2.iter().sum::<f64>() / n2;
 
-    let s1 = c1.iter().map(|x| (x - mean1).powi(2)).sum::<f64>() / (n1 - 1.0);
-    let s2 = c2.iter().map(|x| (x - mean2).powi(2)).sum::<f64>() / (n2 - 1.0);
+    let sigma1 = get_f64_option(options, "sigma1");
+    let sigma2 = get_f64_option(options, "sigma2");
+    if [sigma1, sigma2]
+        .iter()
+        .flatten()
+        .any(|sigma| sigma.is_nan() || *sigma <= 0.0)
+    {
+        return JsValue::NULL;
+    }
+    let s1 = match sigma1 {
+        Some(sigma) => sigma.powi(2),
+        None => c1.iter().map(|x| (x - mean1).powi(2)).sum::<f64>() / (n1 - 1.0),
+    };
+    let s2 = match sigma2 {
+        Some(sigma) => sigma.powi(2),
+        None => c2.iter().map(|x| (x - mean2).powi(2)).sum::<f64>() / (n2 - 1.0),
+    };
+
+    let obj = two_samp_z_result((mean1, s1, n1), (mean2, s2, n2), &tails, d0, options);
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        for (label, sample, key) in [
+            ("The first sample", &c1, "sigma1"),
+            ("The second sample", &c2, "sigma2"),
+        ] {
+            if sample.len() < 30 {
+                if get_f64_option(options, key).is_none() {
+                    warnings.push(format!(
+                        "{} has fewer than 30 values and {} is unknown; consider two_samp_t_test.",
+                        label, key
+                    ));
+                }
+                check_normality(&mut warnings, sample, label, "brunner_munzel_test");
+            }
+        }
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Performs a two-sample z-test from summary statistics, for when only the group means,
+/// standard deviations, and sizes are available.
+///
+/// # Arguments
+///
+/// * `summary1` - A reference to a JsValue representing an object `{ mean, sigma, n }` for
+///   the first sample, where `sigma` is the known population standard deviation (or the
+///   sample standard deviation for a large-sample test).
+/// * `summary2` - A reference to a JsValue representing the same object for the second
+///   sample.
+/// * `tails` - A reference to a JsValue representing the tails of the test.
+/// * `delta0` - A reference to a JsValue representing the hypothesized difference in means.
+/// * `options` - An optional object; `{ effect_size: true, alpha: 0.05 }` adds the difference
+///   in means, its z-interval, and Cohen's d to the result.
+///
+/// # Returns
+///
+/// * An object with the properties `z` and `p`, or null if a summary is missing a field or
+///   has a non-positive size or standard deviation.
+#[wasm_bindgen]
+pub fn two_samp_z_test_summary(
+    summary1: &JsValue,
+    summary2: &JsValue,
+    tails: &JsValue,
+    delta0: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let read = |summary: &JsValue| -> Option<(f64, f64, f64)> {
+        let mean = get_f64_option(summary, "mean")?;
+        let sigma = get_f64_option(summary, "sigma")?;
+        let n = get_f64_option(summary, "n")?;
+        (n > 0.0 && sigma > 0.0).then_some((mean, sigma.powi(2), n))
+    };
+    let (sample1, sample2) = match (read(summary1), read(summary2)) {
+        (Some(sample1), Some(sample2)) => (sample1, sample2),
+        _ => return JsValue::NULL,
+    };
+    let d0 = delta0.as_f64().unwrap_or(0.0);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    two_samp_z_result(sample1, sample2, &tails, d0, options).into()
+}
 
-    let z = (mean1 - mean2 - d0) / f64::sqrt(s1 / n1 + s2 / n2);
+/// Computes the two-sample z-statistic, its p-value, and the optional estimate and effect
+/// size from each sample's mean, variance, and size.
+fn two_samp_z_result(
+    (mean1, s1, n1): (f64, f64, f64),
+    (mean2, s2, n2): (f64, f64, f64),
+    tails: &str,
+    d0: f64,
+    options: &JsValue,
+) -> Object {
+    let std_error = f64::sqrt(s1 / n1 + s2 / n2);
+    let z = (mean1 - mean2 - d0) / std_error;
 
     let dist = Normal::new(0.0, 1.0).unwrap();
 
-    let p = match tails.as_str() {
+    let p = match tails {
         "two-sided" => 2.0 * (1.0 - dist.cdf(z.abs())),
         "less" => dist.cdf(z),
         "greater" => 1.0 - dist.cdf(z),
@@ -123,9 +272,133 @@ pub fn two_samp_z_test(
     let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
 
+    if get_bool_option(options, "effect_size") {
+        let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+        let diff = mean1 - mean2;
+        let pooled_sd = pooled_std_dev((s1, n1), (s2, n2));
+        set_estimate(&obj, diff, z_bounds(diff, std_error, alpha));
+        set_effect_size(&obj, "cohens_d", (diff - d0) / pooled_sd);
+    }
+
+    obj
+}
+
+/// Performs a two-proportion z-test using the pooled proportion for the standard error.
+///
+/// # Arguments
+///
+/// * `x1` - A reference to a JsValue representing the number of successes in the first sample.
+/// * `n1` - A reference to a JsValue representing the size of the first sample.
+/// * `x2` - A reference to a JsValue representing the number of successes in the second sample.
+/// * `n2` - A reference to a JsValue representing the size of the second sample.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `options` - An optional object; `{ continuity_correction: true }` applies Yates'
+///   correction, and `{ effect_size: true, alpha: 0.05 }` adds the difference in proportions,
+///   its unpooled Wald interval, and Cohen's h to the result.
+///
+/// # Returns
+///
+/// * An object with the properties `z`, `p`, `p1`, `p2`, `pooled`, and
+///   `continuity_correction`, null if the counts are invalid, or an object with the
+///   property `error` if `tails` is not recognized. When no sample has a success, or every
+///   sample is all successes, `z` is 0 and `p` is 1.
+#[wasm_bindgen]
+pub fn two_prop_z_test(
+    x1: &JsValue,
+    n1: &JsValue,
+    x2: &JsValue,
+    n2: &JsValue,
+    tails: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let (x1, n1, x2, n2) = match (x1.as_f64(), n1.as_f64(), x2.as_f64(), n2.as_f64()) {
+        (Some(x1), Some(n1), Some(x2), Some(n2)) => (x1, n1, x2, n2),
+        _ => return JsValue::NULL,
+    };
+    if n1 <= 0.0 || n2 <= 0.0 || x1 < 0.0 || x2 < 0.0 || x1 > n1 || x2 > n2 {
+        return JsValue::NULL;
+    }
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    let p1 = x1 / n1;
+    let p2 = x2 / n2;
+    let pooled = (x1 + x2) / (n1 + n2);
+    let std_error = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+
+    // Yates' correction shrinks the difference toward zero by at most its own size
+    let yates = get_bool_option(options, "continuity_correction");
+    let diff = p1 - p2;
+    let correction = if yates {
+        (0.5 * (1.0 / n1 + 1.0 / n2)).min(diff.abs())
+    } else {
+        0.0
+    };
+    // A pooled proportion of 0 or 1 leaves both samples identical and the standard error zero
+    let degenerate = std_error == 0.0;
+    let z = if degenerate {
+        0.0
+    } else {
+        (diff - diff.signum() * correction) / std_error
+    };
+
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let p = match tails.as_str() {
+        "two-sided" => 2.0 * (1.0 - dist.cdf(z.abs())),
+        "less" => dist.cdf(z),
+        "greater" => 1.0 - dist.cdf(z),
+        _ => return invalid_test_type(),
+    };
+    let p = if degenerate { 1.0 } else { p };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p1"), &JsValue::from_f64(p1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p2"), &JsValue::from_f64(p2));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("pooled"),
+        &JsValue::from_f64(pooled),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("continuity_correction"),
+        &JsValue::from_bool(yates),
+    );
+
+    if get_bool_option(options, "effect_size") {
+        let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+        let wald_se = (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt();
+        set_estimate(&obj, diff, z_bounds(diff, wald_se, alpha));
+        set_effect_size(
+            &obj,
+            "cohens_h",
+            2.0 * p1.sqrt().asin() - 2.0 * p2.sqrt().asin(),
+        );
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        let expected = [
+            n1 * pooled,
+            n1 * (1.0 - pooled),
+            n2 * pooled,
+            n2 * (1.0 - pooled),
+        ];
+        check_expected_counts(&mut warnings, &expected, "fisher_exact_test");
+        set_warnings(&obj, warnings);
+    }
+
     obj.into()
 }
 
+/// Reads the significance level for the interval attached to a test result: the `alpha`
+/// option when given, or 0.05 when only `effect_size` is requested.
+fn interval_alpha(options: &JsValue) -> Option<f64> {
+    get_f64_option(options, "alpha")
+        .or_else(|| get_bool_option(options, "effect_size").then_some(0.05))
+}
+
 /// Performs a one-sample t-test on a column of data represented as a JavaScript array.
 ///
 /// # Arguments
@@ -133,12 +406,19 @@ pub fn two_samp_z_test(
 /// * `column` - A reference to a JsValue representing a JavaScript array of f64 numbers.
 /// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
 /// * `mu0` - A reference to a JsValue representing the null hypothesis mean.
+/// * `options` - An optional object; `{ alpha: 0.05 }` adds the sample mean and its
+///   t-interval to the result, and `{ effect_size: true }` adds them along with Cohen's d.
 ///
 /// # Returns
 ///
 /// * A JsValue representing the test statistic and p-value of the one-sample t-test.
 #[wasm_bindgen]
-pub fn one_samp_t_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsValue {
+pub fn one_samp_t_test(
+    column: &JsValue,
+    tails: &JsValue,
+    mu0: &JsValue,
+    options: &JsValue,
+) -> JsValue {
     let tails = tails.as_string().unwrap(); // can be "two-sided", "less" or "greater"
     let mu0 = mu0.as_f64().unwrap();
 
@@ -184,6 +464,26 @@ pub fn one_samp_t_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsVa
     let _ = Reflect::set(&obj, &JsValue::from_str("t"), &JsValue::from_f64(t));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
 
+    if let Some(alpha) = interval_alpha(options) {
+        set_estimate(&obj, mean, t_bounds(mean, std_error, df, alpha));
+    }
+    if get_bool_option(options, "effect_size") {
+        set_effect_size(&obj, "cohens_d", (mean - mu0) / std_dev);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        if data.len() < 30 {
+            check_normality(
+                &mut warnings,
+                &data,
+                "The data",
+                "the Wilcoxon signed-rank test",
+            );
+        }
+        set_warnings(&obj, warnings);
+    }
+
     obj.into()
 }
 
@@ -195,6 +495,10 @@ pub fn one_samp_t_test(column: &JsValue, tails: &JsValue, mu0: &JsValue) -> JsVa
 /// * `column2` - A reference to a JsValue representing the second JavaScript array.
 /// * `delta0` - A reference to a JsValue representing the hypothesized difference in means.
 /// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `options` - An optional object; `{ alpha: 0.05 }` adds the difference in means and its
+///   Welch t-interval to the result, and `{ effect_size: true }` adds them along with
+///   Cohen's d, standardized by the pooled standard deviation with `n1 + n2 - 2` degrees
+///   of freedom.
 ///
 /// # Returns
 ///
@@ -205,6 +509,7 @@ pub fn two_samp_t_test(
     column2: &JsValue,
     delta0: &JsValue,
     tails: &JsValue,
+    options: &JsValue,
 ) -> JsValue {
     let d0 = delta0.as_f64().unwrap();
     let tails = tails.as_string().unwrap();
@@ -221,7 +526,8 @@ pub fn two_samp_t_test(
     let s1 = c1.iter().map(|x| (x - mean1).powi(2)).sum::<f64>() / (n1 - 1.0);
     let s2 = c2.iter().map(|x| (x - mean2).powi(2)).sum::<f64>() / (n2 - 1.0);
 
-    let t = (mean1 - mean2 - d0) / f64::sqrt(s1 / n1 + s2 / n2);
+    let std_error = f64::sqrt(s1 / n1 + s2 / n2);
+    let t = (mean1 - mean2 - d0) / std_error;
 
     let df = (s1 / n1 + s2 / n2).powi(2)
         / ((s1 / n1).powi(2) / (n1 - 1.0) + (s2 / n2).powi(2) / (n2 - 1.0));
@@ -239,298 +545,1932 @@ pub fn two_samp_t_test(
     let _ = Reflect::set(&obj, &JsValue::from_str("t"), &JsValue::from_f64(t));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
 
+    let diff = mean1 - mean2;
+    if let Some(alpha) = interval_alpha(options) {
+        set_estimate(&obj, diff, t_bounds(diff, std_error, df, alpha));
+    }
+    if get_bool_option(options, "effect_size") {
+        let pooled_sd = pooled_std_dev((s1, n1), (s2, n2));
+        set_effect_size(&obj, "cohens_d", (diff - d0) / pooled_sd);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        for (label, sample) in [("The first sample", &c1), ("The second sample", &c2)] {
+            if sample.len() < 30 {
+                check_normality(&mut warnings, sample, label, "brunner_munzel_test");
+            }
+        }
+        set_warnings(&obj, warnings);
+    }
+
     obj.into()
 }
 
-/// Performs a matched pairs t-test.
+/// Performs a two one-sided tests (TOST) equivalence test on the difference in means of two
+/// independent samples, using Welch's standard error and degrees of freedom.
 ///
 /// # Arguments
 ///
 /// * `column1` - A reference to a JsValue representing the first JavaScript array.
 /// * `column2` - A reference to a JsValue representing the second JavaScript array.
-/// * `delta0` - A reference to a JsValue representing the difference between the two means.
-/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `lower_bound` - A reference to a JsValue representing the lower equivalence margin for
+///   the difference `mean1 - mean2`.
+/// * `upper_bound` - A reference to a JsValue representing the upper equivalence margin.
+/// * `alpha` - A reference to a JsValue representing the significance level of each
+///   one-sided test; defaults to 0.05.
 ///
 /// # Returns
 ///
-/// * A JsValue object containing the test statistic t and p-value p.
+/// * An object with the properties `diff`, `t_lower`, `t_upper`, `p_lower`, `p_upper`,
+///   `p` (the larger of the two one-sided p-values), `df`, `ci` (the `1 - 2 * alpha`
+///   interval for the difference), and `equivalent`, or null if either sample has fewer
+///   than two values or the margins are not ordered.
 #[wasm_bindgen]
-pub fn matched_pairs_t_test(
+pub fn tost_equivalence_test(
     column1: &JsValue,
     column2: &JsValue,
-    delta0: &JsValue,
-    tails: &JsValue,
+    lower_bound: &JsValue,
+    upper_bound: &JsValue,
+    alpha: &JsValue,
 ) -> JsValue {
-    let data = subtract_jsvalue_arrays(column1, column2);
+    let c1 = js_array_to_vector(column1);
+    let c2 = js_array_to_vector(column2);
+    let lower = lower_bound.as_f64().unwrap_or(f64::NAN);
+    let upper = upper_bound.as_f64().unwrap_or(f64::NAN);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    if c1.len() < 2 || c2.len() < 2 || lower.is_nan() || upper.is_nan() || lower >= upper {
+        return JsValue::NULL;
+    }
+
+    let n1 = c1.len() as f64;
+    let n2 = c2.len() as f64;
+    let v1 = c1.iter().variance();
+    let v2 = c2.iter().variance();
+    let diff = c1.iter().mean() - c2.iter().mean();
+
+    let std_error = f64::sqrt(v1 / n1 + v2 / n2);
+    let df = (v1 / n1 + v2 / n2).powi(2)
+        / ((v1 / n1).powi(2) / (n1 - 1.0) + (v2 / n2).powi(2) / (n2 - 1.0));
+    let dist = StudentsT::new(0.0, 1.0, df).unwrap();
+
+    // Reject "diff <= lower" and "diff >= upper" separately
+    let t_lower = (diff - lower) / std_error;
+    let t_upper = (diff - upper) / std_error;
+    let p_lower = 1.0 - dist.cdf(t_lower);
+    let p_upper = dist.cdf(t_upper);
+    let p = p_lower.max(p_upper);
+    let (ci_lower, ci_upper) = t_bounds(diff, std_error, df, 2.0 * alpha);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("diff"), &JsValue::from_f64(diff));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("t_lower"),
+        &JsValue::from_f64(t_lower),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("t_upper"),
+        &JsValue::from_f64(t_upper),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p_lower"),
+        &JsValue::from_f64(p_lower),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p_upper"),
+        &JsValue::from_f64(p_upper),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("ci"),
+        &vec_to_jsvalue(vec![ci_lower, ci_upper]),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("equivalent"),
+        &JsValue::from_bool(p < alpha),
+    );
+    obj.into()
+}
 
-    one_samp_t_test(&data, tails, delta0)
+/// Computes the trimmed mean of a sample and the squared standard error term Yuen's test
+/// builds from its winsorized variance, along with the number of values kept.
+fn trimmed_summary(data: &[f64], trim: f64) -> (f64, f64, f64) {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len();
+    let g = (trim * n as f64).floor() as usize;
+    let kept = &sorted[g..n - g];
+    let h = kept.len() as f64;
+    let trimmed_mean = kept.iter().sum::<f64>() / h;
+
+    // Winsorize by pulling the trimmed tails in to the nearest kept value
+    let winsorized: Vec<f64> = sorted
+        .iter()
+        .map(|x| x.clamp(kept[0], kept[kept.len() - 1]))
+        .collect();
+    let winsorized_variance = winsorized.iter().variance();
+    let d = (n as f64 - 1.0) * winsorized_variance / (h * (h - 1.0));
+    (trimmed_mean, d, h)
 }
 
-/// Performs a variance test between two columns of data represented as JavaScript arrays.
+/// Performs Yuen's robust two-sample t-test comparing trimmed means with standard errors
+/// from winsorized variances.
 ///
 /// # Arguments
 ///
 /// * `column1` - A reference to a JsValue representing the first JavaScript array.
 /// * `column2` - A reference to a JsValue representing the second JavaScript array.
+/// * `trim_proportion` - A reference to a JsValue representing the proportion trimmed from
+///   each end of each sample; defaults to 0.2 and must be below 0.5.
 /// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
 ///
 /// # Returns
 ///
-/// * A JsValue object containing the test statistic f and p-value p.
+/// * An object with the properties `t`, `df`, `p`, `trimmed_mean1`, and `trimmed_mean2`, or
+///   null if the trim proportion is out of range or fewer than two values remain in a sample
+///   after trimming, or an object with the property `error` if `tails` is not recognized.
 #[wasm_bindgen]
-pub fn variance_test(column1: &JsValue, column2: &JsValue, tails: &JsValue) -> JsValue {
-    let tails = tails.as_string().unwrap(); // can be "two-sided", "less" or "greater"
-
-    let obj = Object::new();
-
-    if tails != "two-sided" && tails != "less" && tails != "greater" {
-        let _ = Reflect::set(
-            &obj,
-            &JsValue::from_str("error"),
-            &JsValue::from_str("Invalid test type"),
-        );
-        return obj.into();
-    }
-
+pub fn yuen_t_test(
+    column1: &JsValue,
+    column2: &JsValue,
+    trim_proportion: &JsValue,
+    tails: &JsValue,
+) -> JsValue {
     let c1 = js_array_to_vector(column1);
     let c2 = js_array_to_vector(column2);
-
-    let n1 = c1.len() as f64;
-    let n2 = c2.len() as f64;
-
-    let s1 = c1.std_dev();
-    let s2 = c2.std_dev();
-
-    // Check for division by zero
-    if s2 == 0.0 {
-        let _ = Reflect::set(
-            &obj,
-            &JsValue::from_str("error"),
-            &JsValue::from_str("Division by zero"),
-        );
-        return obj.into();
+    let trim = trim_proportion.as_f64().unwrap_or(0.2);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+    if !(0.0..0.5).contains(&trim) {
+        return JsValue::NULL;
+    }
+    let kept = |n: usize| n as f64 - 2.0 * (trim * n as f64).floor();
+    if kept(c1.len()) < 2.0 || kept(c2.len()) < 2.0 {
+        return JsValue::NULL;
     }
 
-    let f = s1.powi(2) / s2.powi(2);
+    let (mean1, d1, h1) = trimmed_summary(&c1, trim);
+    let (mean2, d2, h2) = trimmed_summary(&c2, trim);
+    let t = (mean1 - mean2) / (d1 + d2).sqrt();
+    let df = (d1 + d2).powi(2) / (d1.powi(2) / (h1 - 1.0) + d2.powi(2) / (h2 - 1.0));
 
-    // Set f and p in the object
-    let dist = FisherSnedecor::new(n1 - 1.0, n2 - 1.0).unwrap();
+    let dist = StudentsT::new(0.0, 1.0, df).unwrap();
     let p = match tails.as_str() {
-        "greater" => 1.0 - dist.cdf(f),
-        "less" => dist.cdf(f),
-        "two-sided" => 2.0 * f64::min(1.0 - dist.cdf(f), dist.cdf(f)),
-        _ => panic!("Invalid test type"),
+        "two-sided" => 2.0 * (1.0 - dist.cdf(t.abs())),
+        "less" => dist.cdf(t),
+        "greater" => 1.0 - dist.cdf(t),
+        _ => return invalid_test_type(),
     };
 
-    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("t"), &JsValue::from_f64(t));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
-
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("trimmed_mean1"),
+        &JsValue::from_f64(mean1),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("trimmed_mean2"),
+        &JsValue::from_f64(mean2),
+    );
     obj.into()
 }
 
-/// Computes the F-statistic and p-value for a one-way ANOVA test.
+/// Performs the Brunner-Munzel test of stochastic equality, which, unlike the
+/// Mann-Whitney U test, allows the two distributions to differ in variance and shape.
 ///
 /// # Arguments
 ///
-/// * `data` - A JavaScript array of arrays, where each subarray represents a
-///   group of data.
+/// * `column1` - A reference to a JsValue representing the first JavaScript array.
+/// * `column2` - A reference to a JsValue representing the second JavaScript array.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or
+///   "greater"), where "greater" means the first sample tends to be larger.
 ///
 /// # Returns
 ///
-/// * An object with two properties: `f` and `p`, the F-statistic and p-value,
-///   respectively.
+/// * An object with the properties `w`, `df`, `p`, and `relative_effect`, the estimate of
+///   `P(X < Y) + P(X = Y) / 2` for X from the first sample and Y from the second, or null
+///   if a sample has fewer than two values or the rank variances are both zero, or an
+///   object with the property `error` if `tails` is not recognized.
 #[wasm_bindgen]
-pub fn anova_1way_test(data: &JsValue) -> JsValue {
-    let columns = js_nested_array_to_vector(data);
-    let test_data: Vec<Vec<f64>> = columns
-        .iter()
-        .map(|item| js_array_to_vector(item))
-        .collect();
-
-    let n = test_data[0].len() as f64;
-    let k = test_data.len() as f64;
-
-    let mu_i = test_data.iter().map(|col| col.mean()).collect::<Vec<f64>>();
-    let mu_t = mu_i.iter().sum::<f64>() / k;
-
-    let sstr = n * mu_i.iter().map(|mi| (mi - mu_t).powi(2)).sum::<f64>();
-    let tss = test_data
-        .iter()
-        .map(|col| col.iter().map(|x| (x - mu_t).powi(2)).sum::<f64>())
-        .sum::<f64>();
-    let sse = tss - sstr;
-
-    let df_tr = k - 1.0;
-    let df_e = (n * k) - k;
+pub fn brunner_munzel_test(column1: &JsValue, column2: &JsValue, tails: &JsValue) -> JsValue {
+    let c1 = js_array_to_vector(column1);
+    let c2 = js_array_to_vector(column2);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+    if c1.len() < 2 || c2.len() < 2 {
+        return JsValue::NULL;
+    }
 
-    let ms_tr = sstr / df_tr;
-    let ms_e = sse / df_e;
+    let n1 = c1.len() as f64;
+    let n2 = c2.len() as f64;
+    let combined = average_ranks(&[c1.clone(), c2.clone()].concat());
+    let (ranks1, ranks2) = combined.split_at(c1.len());
+
+    // Variance of the placements: overall rank minus within-sample rank
+    let placement_variance = |overall: &[f64], sample: &[f64]| {
+        let n = sample.len() as f64;
+        let mean = overall.iter().sum::<f64>() / n;
+        overall
+            .iter()
+            .zip(average_ranks(sample))
+            .map(|(r, internal)| (r - internal - mean + (n + 1.0) / 2.0).powi(2))
+            .sum::<f64>()
+            / (n - 1.0)
+    };
+    let v1 = n1 * placement_variance(ranks1, &c1);
+    let v2 = n2 * placement_variance(ranks2, &c2);
+    if v1 + v2 == 0.0 {
+        return JsValue::NULL;
+    }
 
-    let f = ms_tr / ms_e;
+    let mean1 = ranks1.iter().sum::<f64>() / n1;
+    let mean2 = ranks2.iter().sum::<f64>() / n2;
+    let w = n1 * n2 * (mean2 - mean1) / ((n1 + n2) * (v1 + v2).sqrt());
+    let df = (v1 + v2).powi(2) / (v1.powi(2) / (n1 - 1.0) + v2.powi(2) / (n2 - 1.0));
+    let relative_effect = (mean2 - (n2 + 1.0) / 2.0) / n1;
 
-    let dist = FisherSnedecor::new(df_tr, df_e).unwrap();
-    let p = 1.0 - dist.cdf(f);
+    // A large w means the second sample tends to be larger
+    let dist = StudentsT::new(0.0, 1.0, df).unwrap();
+    let p = match tails.as_str() {
+        "two-sided" => 2.0 * (1.0 - dist.cdf(w.abs())),
+        "less" => 1.0 - dist.cdf(w),
+        "greater" => dist.cdf(w),
+        _ => return invalid_test_type(),
+    };
 
     let obj = Object::new();
-    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("w"), &JsValue::from_f64(w));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("relative_effect"),
+        &JsValue::from_f64(relative_effect),
+    );
     obj.into()
 }
 
-/// Computes the F-statistic and p-value for a linear regression test.
+/// Computes Cliff's delta, the probability that a value from the first sample exceeds one
+/// from the second minus the probability of the reverse, as a nonparametric effect size.
 ///
 /// # Arguments
 ///
-/// * `x` - A reference to a JsValue representing the independent variable.
-/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `column1` - A reference to a JsValue representing the first JavaScript array.
+/// * `column2` - A reference to a JsValue representing the second JavaScript array.
 ///
 /// # Returns
 ///
-/// * An object with two properties: `f` and `p`, the F-statistic and p-value,
-///   respectively.
+/// * An object with the properties `delta`, `greater` and `less` (the proportions of
+///   pairs in which the first value is larger or smaller), and `interpretation`
+///   ("negligible", "small", "medium", or "large" by the thresholds 0.147, 0.33, and 0.474
+///   of Romano et al.), or null if either sample is empty.
 #[wasm_bindgen]
-pub fn regression_test(x: &JsValue, y: &JsValue) -> JsValue {
-    let x_vec = js_array_to_vector(x);
-    let y_vec = js_array_to_vector(y);
+pub fn cliffs_delta(column1: &JsValue, column2: &JsValue) -> JsValue {
+    let c1 = js_array_to_vector(column1);
+    let c2 = js_array_to_vector(column2);
+    if c1.is_empty() || c2.is_empty() {
+        return JsValue::NULL;
+    }
+
+    let pairs = (c1.len() * c2.len()) as f64;
+    let count = |cmp: fn(&f64, &f64) -> bool| {
+        c1.iter()
+            .map(|a| c2.iter().filter(|b| cmp(a, b)).count())
+            .sum::<usize>() as f64
+            / pairs
+    };
+    let greater = count(|a, b| a > b);
+    let less = count(|a, b| a < b);
+    let delta = greater - less;
+
+    let interpretation = if delta.abs() >= 0.474 {
+        "large"
+    } else if delta.abs() >= 0.33 {
+        "medium"
+    } else if delta.abs() >= 0.147 {
+        "small"
+    } else {
+        "negligible"
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("delta"), &JsValue::from_f64(delta));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("greater"),
+        &JsValue::from_f64(greater),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("less"), &JsValue::from_f64(less));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("interpretation"),
+        &JsValue::from_str(interpretation),
+    );
+    obj.into()
+}
+
+/// Computes the rank-biserial correlation, the effect size of the Mann-Whitney U test for
+/// independent samples or of the Wilcoxon signed-rank test for paired samples.
+///
+/// # Arguments
+///
+/// * `column1` - A reference to a JsValue representing the first JavaScript array.
+/// * `column2` - A reference to a JsValue representing the second JavaScript array.
+/// * `paired` - A reference to a JsValue indicating whether the samples are paired
+///   (defaults to false).
+///
+/// # Returns
+///
+/// * An object with the property `r`, positive when the first sample tends to be larger,
+///   along with `u` (the Mann-Whitney statistic of the first sample) for independent
+///   samples or `t_plus` and `t_minus` (the signed rank sums, with zero differences
+///   dropped) for paired samples. Returns null if a sample is empty, paired samples differ
+///   in length, or every paired difference is zero.
+#[wasm_bindgen]
+pub fn rank_biserial(column1: &JsValue, column2: &JsValue, paired: &JsValue) -> JsValue {
+    let c1 = js_array_to_vector(column1);
+    let c2 = js_array_to_vector(column2);
+    let paired = paired.as_bool().unwrap_or(false);
+    if c1.is_empty() || c2.is_empty() || (paired && c1.len() != c2.len()) {
+        return JsValue::NULL;
+    }
+
+    let obj = Object::new();
+    let r = if paired {
+        let differences: Vec<f64> = c1
+            .iter()
+            .zip(&c2)
+            .map(|(a, b)| a - b)
+            .filter(|d| *d != 0.0)
+            .collect();
+        if differences.is_empty() {
+            return JsValue::NULL;
+        }
+        let ranks = average_ranks(&differences.iter().map(|d| d.abs()).collect::<Vec<_>>());
+        let signed_sum = |positive: bool| {
+            differences
+                .iter()
+                .zip(&ranks)
+                .filter(|(d, _)| (**d > 0.0) == positive)
+                .map(|(_, r)| r)
+                .sum::<f64>()
+        };
+        let t_plus = signed_sum(true);
+        let t_minus = signed_sum(false);
+        let _ = Reflect::set(
+            &obj,
+            &JsValue::from_str("t_plus"),
+            &JsValue::from_f64(t_plus),
+        );
+        let _ = Reflect::set(
+            &obj,
+            &JsValue::from_str("t_minus"),
+            &JsValue::from_f64(t_minus),
+        );
+        (t_plus - t_minus) / (t_plus + t_minus)
+    } else {
+        let n1 = c1.len() as f64;
+        let n2 = c2.len() as f64;
+        let ranks = average_ranks(&[c1.clone(), c2].concat());
+        let u = ranks[..c1.len()].iter().sum::<f64>() - n1 * (n1 + 1.0) / 2.0;
+        let _ = Reflect::set(&obj, &JsValue::from_str("u"), &JsValue::from_f64(u));
+        2.0 * u / (n1 * n2) - 1.0
+    };
+
+    let _ = Reflect::set(&obj, &JsValue::from_str("r"), &JsValue::from_f64(r));
+    obj.into()
+}
+
+/// Performs a matched pairs t-test.
+///
+/// # Arguments
+///
+/// * `column1` - A reference to a JsValue representing the first JavaScript array.
+/// * `column2` - A reference to a JsValue representing the second JavaScript array.
+/// * `delta0` - A reference to a JsValue representing the difference between the two means.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `options` - An optional object; `{ alpha: 0.05 }` adds the mean difference and its
+///   t-interval to the result, and `{ effect_size: true }` adds them along with Cohen's d of
+///   the differences.
+///
+/// # Returns
+///
+/// * A JsValue object containing the test statistic t and p-value p.
+#[wasm_bindgen]
+pub fn matched_pairs_t_test(
+    column1: &JsValue,
+    column2: &JsValue,
+    delta0: &JsValue,
+    tails: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let data = subtract_jsvalue_arrays(column1, column2);
+    let result = one_samp_t_test(&data, tails, delta0, options);
+
+    // Unmatched observations are dropped when the columns are subtracted
+    let n1 = Array::from(column1).length();
+    let n2 = Array::from(column2).length();
+    if n1 != n2 && get_bool_option(options, "check_assumptions") {
+        let warnings = Reflect::get(&result, &JsValue::from_str("warnings")).unwrap();
+        if let Some(warnings) = warnings.dyn_ref::<Array>() {
+            warnings.unshift(&JsValue::from_str(&format!(
+                "The columns have different lengths ({} and {}), so unmatched observations were ignored; consider two_samp_t_test if the samples are independent.",
+                n1, n2
+            )));
+        }
+    }
+
+    result
+}
+
+/// Performs a variance test between two columns of data represented as JavaScript arrays.
+///
+/// # Arguments
+///
+/// * `column1` - A reference to a JsValue representing the first JavaScript array.
+/// * `column2` - A reference to a JsValue representing the second JavaScript array.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `options` - An optional object; `{ alpha: 0.05 }` or `{ effect_size: true }` adds the
+///   variance ratio and its F-interval to the result.
+///
+/// # Returns
+///
+/// * A JsValue object containing the test statistic f and p-value p.
+#[wasm_bindgen]
+pub fn variance_test(
+    column1: &JsValue,
+    column2: &JsValue,
+    tails: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let tails = tails.as_string().unwrap(); // can be "two-sided", "less" or "greater"
+
+    let obj = Object::new();
+
+    if tails != "two-sided" && tails != "less" && tails != "greater" {
+        let _ = Reflect::set(
+            &obj,
+            &JsValue::from_str("error"),
+            &JsValue::from_str("Invalid test type"),
+        );
+        return obj.into();
+    }
+
+    let c1 = js_array_to_vector(column1);
+    let c2 = js_array_to_vector(column2);
+
+    let n1 = c1.len() as f64;
+    let n2 = c2.len() as f64;
+
+    let s1 = c1.iter().std_dev();
+    let s2 = c2.iter().std_dev();
+
+    // Check for division by zero
+    if s2 == 0.0 {
+        let _ = Reflect::set(
+            &obj,
+            &JsValue::from_str("error"),
+            &JsValue::from_str("Division by zero"),
+        );
+        return obj.into();
+    }
+
+    let f = s1.powi(2) / s2.powi(2);
+
+    // Set f and p in the object
+    let dist = FisherSnedecor::new(n1 - 1.0, n2 - 1.0).unwrap();
+    let p = match tails.as_str() {
+        "greater" => 1.0 - dist.cdf(f),
+        "less" => dist.cdf(f),
+        "two-sided" => 2.0 * f64::min(1.0 - dist.cdf(f), dist.cdf(f)),
+        _ => panic!("Invalid test type"),
+    };
+
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+
+    if let Some(alpha) = interval_alpha(options) {
+        set_estimate(&obj, f, f_ratio_bounds(f, n1 - 1.0, n2 - 1.0, alpha));
+    }
+    if get_bool_option(options, "effect_size") {
+        set_effect_size(&obj, "variance_ratio", f);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        // The F-test is sensitive to non-normality at any sample size
+        let mut warnings = Vec::new();
+        for (label, sample) in [("The first sample", &c1), ("The second sample", &c2)] {
+            check_normality(&mut warnings, sample, label, "levene_test");
+        }
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Performs a chi-square test of a single column's variance against a hypothesized value.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array.
+/// * `sigma0_squared` - A reference to a JsValue representing the hypothesized variance.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `options` - An optional object; `{ alpha: 0.05 }` or `{ effect_size: true }` adds the
+///   sample variance and its chi-square interval to the result.
+///
+/// # Returns
+///
+/// * An object with the properties `x2`, `df`, and `p`, null if the column has fewer
+///   than two values or the hypothesized variance is not positive, or an object with the
+///   property `error` if `tails` is not recognized.
+#[wasm_bindgen]
+pub fn one_samp_var_test(
+    column: &JsValue,
+    sigma0_squared: &JsValue,
+    tails: &JsValue,
+    options: &JsValue,
+) -> JsValue {
+    let data = js_array_to_vector(column);
+    let sigma0_squared = sigma0_squared.as_f64().unwrap_or(f64::NAN);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+    if data.len() < 2 || sigma0_squared.is_nan() || sigma0_squared <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    let df = data.len() as f64 - 1.0;
+    let variance = data.iter().variance();
+    let x2 = df * variance / sigma0_squared;
+
+    let dist = ChiSquared::new(df).unwrap();
+    let p = match tails.as_str() {
+        "greater" => 1.0 - dist.cdf(x2),
+        "less" => dist.cdf(x2),
+        "two-sided" => 2.0 * f64::min(1.0 - dist.cdf(x2), dist.cdf(x2)),
+        _ => return invalid_test_type(),
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+
+    if let Some(alpha) = interval_alpha(options) {
+        set_estimate(&obj, variance, variance_bounds(variance, df, alpha));
+    }
+    if get_bool_option(options, "effect_size") {
+        set_effect_size(&obj, "variance_ratio", variance / sigma0_squared);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        // Like the F-test, the chi-square variance test is sensitive to non-normality
+        let mut warnings = Vec::new();
+        check_normality(&mut warnings, &data, "The sample", "levene_test");
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Performs an exact test of a Poisson event rate against a hypothesized rate.
+///
+/// # Arguments
+///
+/// * `count` - A reference to a JsValue representing the observed number of events.
+/// * `exposure` - A reference to a JsValue representing the exposure over which the events
+///   were counted (e.g., person-years).
+/// * `rate0` - A reference to a JsValue representing the hypothesized rate per unit of
+///   exposure.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+///
+/// # Returns
+///
+/// * An object with the properties `rate` (the observed rate), `expected` (the expected
+///   count under the hypothesized rate), and `p`, null if the inputs are invalid, or an
+///   object with the property `error` if `tails` is not recognized. The two-sided p-value
+///   sums the probabilities of all counts no more likely than the observed one.
+#[wasm_bindgen]
+pub fn poisson_rate_test(
+    count: &JsValue,
+    exposure: &JsValue,
+    rate0: &JsValue,
+    tails: &JsValue,
+) -> JsValue {
+    let count = count.as_f64().unwrap_or(f64::NAN);
+    let exposure = exposure.as_f64().unwrap_or(f64::NAN);
+    let rate0 = rate0.as_f64().unwrap_or(f64::NAN);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+    if count.is_nan()
+        || count < 0.0
+        || count.fract() != 0.0
+        || exposure.is_nan()
+        || exposure <= 0.0
+        || rate0.is_nan()
+        || rate0 <= 0.0
+    {
+        return JsValue::NULL;
+    }
+
+    let expected = rate0 * exposure;
+    let dist = match Poisson::new(expected) {
+        Ok(dist) if expected.is_finite() => dist,
+        _ => return JsValue::NULL,
+    };
+    let k = count as u64;
+    let p = match tails.as_str() {
+        "less" => dist.cdf(k),
+        "greater" => {
+            if k == 0 {
+                1.0
+            } else {
+                dist.sf(k - 1)
+            }
+        }
+        "two-sided" => {
+            // The pmf rises to its mode and falls after it, so the counts no more likely than
+            // the observed one form a tail on each side, and each tail's edge is bisected
+            let observed = dist.pmf(k) * (1.0 + 1e-7);
+            let mode = expected.floor() as u64;
+            if dist.pmf(mode) <= observed {
+                1.0
+            } else {
+                let lower = if dist.pmf(0) > observed {
+                    0.0
+                } else {
+                    let (mut lo, mut hi) = (0, mode);
+                    while hi - lo > 1 {
+                        let mid = lo + (hi - lo) / 2;
+                        if dist.pmf(mid) <= observed {
+                            lo = mid;
+                        } else {
+                            hi = mid;
+                        }
+                    }
+                    dist.cdf(lo)
+                };
+                let mut step = 1;
+                while dist.pmf(mode.saturating_add(step)) > observed && step < u64::MAX / 4 {
+                    step *= 2;
+                }
+                let (mut lo, mut hi) = (mode, mode.saturating_add(step));
+                while hi - lo > 1 {
+                    let mid = lo + (hi - lo) / 2;
+                    if dist.pmf(mid) <= observed {
+                        hi = mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+                (lower + dist.sf(lo)).min(1.0)
+            }
+        }
+        _ => return invalid_test_type(),
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("rate"),
+        &JsValue::from_f64(count / exposure),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected"),
+        &JsValue::from_f64(expected),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Computes the F-statistic and p-value for a one-way ANOVA test.
+///
+/// # Arguments
+///
+/// * `data` - A JavaScript array of arrays, where each subarray represents a
+///   group of data. Groups may have different sizes.
+/// * `options` - An optional object; `{ effect_size: true }` adds eta squared to the result.
+///
+/// # Returns
+///
+/// * An object with four properties: `f`, `p`, `df1`, and `df2`, the F-statistic, p-value,
+///   and treatment and error degrees of freedom, respectively. Returns null if there are
+///   fewer than two groups, a group is empty, or there are no error degrees of freedom.
+#[wasm_bindgen]
+pub fn anova_1way_test(data: &JsValue, options: &JsValue) -> JsValue {
+    let columns = js_nested_array_to_vector(data);
+    let test_data: Vec<Vec<f64>> = columns.iter().map(js_array_to_vector).collect();
+
+    let k = test_data.len() as f64;
+    let n_total = test_data.iter().map(|col| col.len()).sum::<usize>() as f64;
+    if test_data.len() < 2 || test_data.iter().any(|col| col.is_empty()) || n_total <= k {
+        return JsValue::NULL;
+    }
+
+    // Grand mean weighted by group size
+    let mu_i = test_data.iter().map(|col| col.mean()).collect::<Vec<f64>>();
+    let mu_t = test_data.iter().flatten().sum::<f64>() / n_total;
+
+    let sstr = test_data
+        .iter()
+        .zip(&mu_i)
+        .map(|(col, mi)| col.len() as f64 * (mi - mu_t).powi(2))
+        .sum::<f64>();
+    let tss = test_data
+        .iter()
+        .map(|col| col.iter().map(|x| (x - mu_t).powi(2)).sum::<f64>())
+        .sum::<f64>();
+    let sse = tss - sstr;
+
+    let df_tr = k - 1.0;
+    let df_e = n_total - k;
+
+    let ms_tr = sstr / df_tr;
+    let ms_e = sse / df_e;
+
+    let f = ms_tr / ms_e;
+
+    let dist = FisherSnedecor::new(df_tr, df_e).unwrap();
+    let p = 1.0 - dist.cdf(f);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df1"), &JsValue::from_f64(df_tr));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df2"), &JsValue::from_f64(df_e));
+
+    if get_bool_option(options, "effect_size") {
+        set_effect_size(&obj, "eta_squared", sstr / tss);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        for (i, group) in test_data.iter().enumerate() {
+            if group.len() < 30 {
+                check_normality(
+                    &mut warnings,
+                    group,
+                    &format!("Group {}", i + 1),
+                    "the Kruskal-Wallis test",
+                );
+            }
+        }
+        if let Some((_, _, _, p)) = levene_values(&test_data, "median") {
+            if p < 0.05 {
+                warnings.push(format!(
+                    "The group variances may differ (Brown-Forsythe p = {:.4}); consider Welch's ANOVA.",
+                    p
+                ));
+            }
+        }
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Computes the F-statistic and p-value for a linear regression test.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the independent variable.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `options` - An optional object; `{ effect_size: true, alpha: 0.05 }` adds the slope, its
+///   t-interval, and R squared to the result.
+///
+/// # Returns
+///
+/// * An object with two properties: `f` and `p`, the F-statistic and p-value,
+///   respectively.
+#[wasm_bindgen]
+pub fn regression_test(x: &JsValue, y: &JsValue, options: &JsValue) -> JsValue {
+    let x_vec = js_array_to_vector(x);
+    let y_vec = js_array_to_vector(y);
+
+    let n = x_vec.len() as f64;
+
+    let sxy = x_vec
+        .iter()
+        .zip(y_vec.iter())
+        .map(|(x, y)| x * y)
+        .sum::<f64>()
+        - (1.0 / n) * x_vec.iter().sum::<f64>() * y_vec.iter().sum::<f64>();
+    let sxx = x_vec.iter().map(|x| x.powi(2)).sum::<f64>()
+        - (1.0 / n) * (x_vec.iter().sum::<f64>()).powi(2);
+    let syy = y_vec.iter().map(|y| y.powi(2)).sum::<f64>()
+        - (1.0 / n) * (y_vec.iter().sum::<f64>()).powi(2);
+
+    let tss = syy;
+    let ssr = sxy.powi(2) / sxx;
+    let sse = tss - ssr;
+
+    let df_tr = 1.0;
+    let df_e = n - 2.0;
+
+    let ms_tr = ssr / df_tr;
+    let ms_e = sse / df_e;
+
+    let f = ms_tr / ms_e;
+
+    let dist = FisherSnedecor::new(df_tr, df_e).unwrap();
+    let p = 1.0 - dist.cdf(f);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+
+    if get_bool_option(options, "effect_size") {
+        let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+        let slope = sxy / sxx;
+        let std_error = (ms_e / sxx).sqrt();
+        set_estimate(&obj, slope, t_bounds(slope, std_error, df_e, alpha));
+        set_effect_size(&obj, "r_squared", ssr / tss);
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        if x_vec.len() != y_vec.len() {
+            warnings.push(format!(
+                "x and y have different lengths ({} and {}); the test requires paired observations.",
+                x_vec.len(),
+                y_vec.len()
+            ));
+        }
+        if x_vec.len() < 30 {
+            let slope = sxy / sxx;
+            let intercept = (y_vec.iter().sum::<f64>() - slope * x_vec.iter().sum::<f64>()) / n;
+            let residuals: Vec<f64> = x_vec
+                .iter()
+                .zip(&y_vec)
+                .map(|(x, y)| y - intercept - slope * x)
+                .collect();
+            check_normality(
+                &mut warnings,
+                &residuals,
+                "The residuals",
+                "permutation_regression_test",
+            );
+        }
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Performs the Durbin-Watson test for first-order autocorrelation in the residuals of a
+/// simple linear regression. The p-value uses a normal approximation with the exact mean
+/// and variance of the statistic under independent errors for the given design.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the independent variable, in time order.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+///
+/// # Returns
+///
+/// * An object with the properties `d` (the Durbin-Watson statistic), `rho` (the lag-one
+///   autocorrelation of the residuals), `z`, and `p` (for positive autocorrelation), or
+///   null if the inputs differ in length, have fewer than four values, or `x` is constant.
+#[wasm_bindgen]
+pub fn durbin_watson_test(x: &JsValue, y: &JsValue) -> JsValue {
+    let x_vec = js_array_to_vector(x);
+    let y_vec = js_array_to_vector(y);
+    let n = y_vec.len();
+    if x_vec.len() != n || n < 4 {
+        return JsValue::NULL;
+    }
+
+    let design = design_matrix(&[x_vec], n);
+    let fit = match ols_fit(&design, &y_vec) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+    let e = &fit.residuals;
+    let d = e.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum::<f64>() / fit.sse;
+    let rho = e.windows(2).map(|w| w[1] * w[0]).sum::<f64>() / fit.sse;
+
+    // Moments of d come from traces of M * A, where A is the first-difference quadratic
+    // form and M the residual projection; only p x p products of the design are needed
+    let difference = |v: &[f64]| -> Vec<f64> {
+        (0..n)
+            .map(|t| {
+                let back = if t > 0 { v[t] - v[t - 1] } else { 0.0 };
+                let forward = if t + 1 < n { v[t] - v[t + 1] } else { 0.0 };
+                back + forward
+            })
+            .collect()
+    };
+    let columns: Vec<Vec<f64>> = (0..2)
+        .map(|j| design.iter().map(|row| row[j]).collect())
+        .collect();
+    let a_columns: Vec<Vec<f64>> = columns.iter().map(|c| difference(c)).collect();
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+    let gram = |left: &[Vec<f64>], right: &[Vec<f64>]| -> Vec<Vec<f64>> {
+        left.iter()
+            .map(|l| right.iter().map(|r| dot(l, r)).collect())
+            .collect()
+    };
+    let product = |a: &[Vec<f64>], b: &[Vec<f64>]| -> Vec<Vec<f64>> {
+        (0..2)
+            .map(|i| {
+                (0..2)
+                    .map(|j| a[i][0] * b[0][j] + a[i][1] * b[1][j])
+                    .collect()
+            })
+            .collect()
+    };
+    let trace = |m: &[Vec<f64>]| m[0][0] + m[1][1];
+
+    let xtx_inv = match invert_matrix(&gram(&columns, &columns)) {
+        Some(inverse) => inverse,
+        None => return JsValue::NULL,
+    };
+    let g = product(&xtx_inv, &gram(&columns, &a_columns));
+    let h = product(&xtx_inv, &gram(&a_columns, &a_columns));
+
+    let n_f = n as f64;
+    let tr_ma = 2.0 * (n_f - 1.0) - trace(&g);
+    let tr_ma2 = (6.0 * n_f - 8.0) - 2.0 * trace(&h) + trace(&product(&g, &g));
+    let r = fit.df_resid;
+    let mean = tr_ma / r;
+    let variance = 2.0 * (r * tr_ma2 - tr_ma.powi(2)) / (r.powi(2) * (r + 2.0));
+
+    let z = (d - mean) / variance.sqrt();
+    let p = Normal::new(0.0, 1.0).unwrap().cdf(z);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("d"), &JsValue::from_f64(d));
+    let _ = Reflect::set(&obj, &JsValue::from_str("rho"), &JsValue::from_f64(rho));
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
 
-    let n = x_vec.len() as f64;
+/// Performs a chi-square goodness-of-fit test of observed counts against a hypothesized
+/// distribution.
+///
+/// The expected values are always rescaled to the observed total, so they may be given as
+/// counts, as proportions, or as rounded proportions such as `[0.333, 0.333, 0.333]`.
+///
+/// # Arguments
+///
+/// * `observed` - A reference to a JsValue representing the observed frequencies.
+/// * `expected` - A reference to a JsValue representing the expected frequencies or
+///   proportions, which must be positive.
+/// * `options` - An optional object; `{ effect_size: true }` adds Cohen's w to the result.
+///
+/// # Returns
+///
+/// * An object with three properties: `x2`, `df`, and `p`, the chi-square statistic,
+///   degrees of freedom, and p-value, respectively, or null if the arrays differ in length,
+///   there are fewer than two categories, a count is negative, an expected value is not
+///   positive, or nothing was observed.
+#[wasm_bindgen]
+pub fn chi_square_gof_test(observed: &JsValue, expected: &JsValue, options: &JsValue) -> JsValue {
+    let actual = js_array_to_vector(observed);
+    let mut expected = js_array_to_vector(expected);
+    let total = actual.iter().sum::<f64>();
+    if actual.len() != expected.len()
+        || actual.len() < 2
+        || actual.iter().any(|a| !a.is_finite() || *a < 0.0)
+        || expected.iter().any(|e| !e.is_finite() || *e <= 0.0)
+        || total == 0.0
+    {
+        return JsValue::NULL;
+    }
 
-    let sxy = x_vec
+    let expected_total = expected.iter().sum::<f64>();
+    expected
+        .iter_mut()
+        .for_each(|e| *e *= total / expected_total);
+
+    let x2 = actual
         .iter()
-        .zip(y_vec.iter())
-        .map(|(x, y)| x * y)
+        .zip(&expected)
+        .fold(0.0, |x2, (a, e)| x2 + ((a - e).powi(2)) / e);
+
+    let df = (actual.len() - 1) as f64;
+    let dist = ChiSquared::new(df).unwrap();
+    let p = 1.0 - dist.cdf(x2);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+
+    if get_bool_option(options, "effect_size") {
+        set_effect_size(&obj, "cohens_w", (x2 / total).sqrt());
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        check_expected_counts(
+            &mut warnings,
+            &expected,
+            "combining categories or an exact multinomial test",
+        );
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Performs a chi-square goodness-of-fit test. This is the original name of
+/// `chi_square_gof_test`, kept for existing callers.
+///
+/// # Arguments
+///
+/// * `actual` - A reference to a JsValue representing the observed frequencies.
+/// * `expected` - A reference to a JsValue representing the expected frequencies or
+///   proportions.
+/// * `options` - An optional object, as in `chi_square_gof_test`.
+///
+/// # Returns
+///
+/// * The result of `chi_square_gof_test`.
+#[wasm_bindgen]
+pub fn chi2_gof_test(actual: &JsValue, expected: &JsValue, options: &JsValue) -> JsValue {
+    chi_square_gof_test(actual, expected, options)
+}
+
+/// Performs a chi-square independence test.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing the observed frequencies in a table.
+/// * `options` - An optional object; `{ effect_size: true }` adds Cramér's V to the result,
+///   and `{ continuity_correction: true }` applies Yates' correction to 2x2 tables.
+///
+/// # Returns
+///
+/// * An object with four properties: `x2`, `p`, `exp`, and `continuity_correction`, the chi-square statistic, p-value, expected counts, and whether Yates' correction was applied, respectively.
+#[wasm_bindgen]
+pub fn chi2_ind_test(data: &JsValue, options: &JsValue) -> JsValue {
+    let rows = js_nested_array_to_vector(data);
+    let test_data: Vec<Vec<f64>> = rows.iter().map(js_array_to_vector).collect();
+
+    // Total sum of all observations
+    let total_sum: f64 = test_data.iter().flatten().sum();
+
+    // Row and column totals
+    let row_totals: Vec<f64> = test_data.iter().map(|row| row.iter().sum()).collect();
+    let column_totals: Vec<f64> = (0..test_data[0].len())
+        .map(|col_idx| test_data.iter().map(|row| row[col_idx]).sum())
+        .collect();
+
+    // Calculate expected counts
+    let exp_counts: Vec<Vec<f64>> = row_totals
+        .iter()
+        .map(|&row_total| {
+            column_totals
+                .iter()
+                .map(|&col_total| (row_total * col_total) / total_sum)
+                .collect()
+        })
+        .collect();
+
+    // Yates' correction only applies to 2x2 tables
+    let yates = get_bool_option(options, "continuity_correction")
+        && test_data.len() == 2
+        && test_data[0].len() == 2;
+
+    // Calculate chi-squared statistic
+    let x2 = exp_counts
+        .iter()
+        .zip(&test_data)
+        .fold(0.0, |x2, (exp_row, obs_row)| {
+            exp_row.iter().zip(obs_row).fold(x2, |acc, (&exp, &obs)| {
+                // Avoid division by zero
+                if exp == 0.0 {
+                    acc
+                } else if yates {
+                    acc + ((obs - exp).abs() - 0.5).max(0.0).powi(2) / exp
+                } else {
+                    acc + ((obs - exp).powi(2) / exp)
+                }
+            })
+        });
+
+    // Degrees of freedom: (rows - 1) * (columns - 1)
+    let df = ((test_data.len() - 1) * (test_data[0].len() - 1)) as f64;
+
+    let dist = ChiSquared::new(df).unwrap();
+    let p = 1.0 - dist.cdf(x2);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("exp"),
+        &nested_vec_to_jsvalue(exp_counts.clone()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("continuity_correction"),
+        &JsValue::from_bool(yates),
+    );
+
+    if get_bool_option(options, "effect_size") {
+        let min_dim = test_data.len().min(test_data[0].len()) as f64;
+        set_effect_size(
+            &obj,
+            "cramers_v",
+            (x2 / (total_sum * (min_dim - 1.0))).sqrt(),
+        );
+    }
+
+    if get_bool_option(options, "check_assumptions") {
+        let mut warnings = Vec::new();
+        let alternative = if test_data.len() == 2 && test_data[0].len() == 2 {
+            "fisher_exact_test"
+        } else {
+            "combining categories"
+        };
+        let expected: Vec<f64> = exp_counts.iter().flatten().copied().collect();
+        check_expected_counts(&mut warnings, &expected, alternative);
+        set_warnings(&obj, warnings);
+    }
+
+    obj.into()
+}
+
+/// Computes the G statistic `2 * sum(O * ln(O / E))`, where empty cells contribute zero.
+fn g_statistic(observed: &[f64], expected: &[f64]) -> f64 {
+    2.0 * observed
+        .iter()
+        .zip(expected)
+        .filter(|(o, _)| **o > 0.0)
+        .map(|(o, e)| o * (o / e).ln())
         .sum::<f64>()
-        - (1.0 / n) * x_vec.iter().sum::<f64>() * y_vec.iter().sum::<f64>();
-    let sxx = x_vec.iter().map(|x| x.powi(2)).sum::<f64>()
-        - (1.0 / n) * (x_vec.iter().sum::<f64>()).powi(2);
-    let syy = y_vec.iter().map(|y| y.powi(2)).sum::<f64>()
-        - (1.0 / n) * (y_vec.iter().sum::<f64>()).powi(2);
+}
 
-    let tss = syy;
-    let ssr = sxy.powi(2) / sxx;
-    let sse = tss - ssr;
+/// Performs a likelihood-ratio (G) goodness-of-fit test.
+///
+/// As in `chi_square_gof_test`, the expected values are always rescaled to the observed
+/// total, so they may be counts or (possibly rounded) proportions.
+///
+/// # Arguments
+///
+/// * `observed` - A reference to a JsValue representing the observed frequencies.
+/// * `expected` - A reference to a JsValue representing the expected frequencies or
+///   proportions.
+/// * `options` - An optional object; `{ williams_correction: true }` divides G by Williams'
+///   factor `q = 1 + (k + 1) / (6n)`.
+///
+/// # Returns
+///
+/// * An object with the properties `g`, `df`, `p`, and `williams_correction`, or null if
+///   the arrays differ in length, have fewer than two categories, a count is negative, an
+///   expected frequency is not positive, or nothing was observed.
+#[wasm_bindgen]
+pub fn g_test(observed: &JsValue, expected: &JsValue, options: &JsValue) -> JsValue {
+    let observed = js_array_to_vector(observed);
+    let mut expected = js_array_to_vector(expected);
+    let total = observed.iter().sum::<f64>();
+    if observed.len() != expected.len()
+        || observed.len() < 2
+        || observed.iter().any(|o| !o.is_finite() || *o < 0.0)
+        || expected.iter().any(|e| !e.is_finite() || *e <= 0.0)
+        || total == 0.0
+    {
+        return JsValue::NULL;
+    }
 
-    let df_tr = 1.0;
-    let df_e = n - 2.0;
+    let expected_total = expected.iter().sum::<f64>();
+    expected
+        .iter_mut()
+        .for_each(|e| *e *= total / expected_total);
 
-    let ms_tr = ssr / df_tr;
-    let ms_e = sse / df_e;
+    let k = observed.len() as f64;
+    let williams = get_bool_option(options, "williams_correction");
+    let mut g = g_statistic(&observed, &expected);
+    if williams {
+        g /= 1.0 + (k + 1.0) / (6.0 * total);
+    }
+
+    let df = k - 1.0;
+    let p = 1.0 - ChiSquared::new(df).unwrap().cdf(g);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("g"), &JsValue::from_f64(g));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("williams_correction"),
+        &JsValue::from_bool(williams),
+    );
+    obj.into()
+}
+
+/// Performs a likelihood-ratio (G) test of independence on a contingency table.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing the observed frequencies in a table.
+/// * `options` - An optional object; `{ williams_correction: true }` divides G by Williams'
+///   factor for an r x c table.
+///
+/// # Returns
+///
+/// * An object with the properties `g`, `df`, `p`, `exp`, and `williams_correction`, or
+///   null if the table is smaller than 2x2, ragged, or has an empty row or column.
+#[wasm_bindgen]
+pub fn g_ind_test(data: &JsValue, options: &JsValue) -> JsValue {
+    let rows = js_nested_array_to_vector(data);
+    let table: Vec<Vec<f64>> = rows.iter().map(js_array_to_vector).collect();
+    let n_cols = table.first().map_or(0, |row| row.len());
+    if table.len() < 2 || n_cols < 2 || table.iter().any(|row| row.len() != n_cols) {
+        return JsValue::NULL;
+    }
+
+    let total: f64 = table.iter().flatten().sum();
+    let row_totals: Vec<f64> = table.iter().map(|row| row.iter().sum()).collect();
+    let column_totals: Vec<f64> = (0..n_cols)
+        .map(|j| table.iter().map(|row| row[j]).sum())
+        .collect();
+    if row_totals.contains(&0.0) || column_totals.contains(&0.0) {
+        return JsValue::NULL;
+    }
+
+    let exp_counts: Vec<Vec<f64>> = row_totals
+        .iter()
+        .map(|r| column_totals.iter().map(|c| r * c / total).collect())
+        .collect();
+    let df = ((table.len() - 1) * (n_cols - 1)) as f64;
+
+    let williams = get_bool_option(options, "williams_correction");
+    let mut g = g_statistic(
+        &table.concat(),
+        &exp_counts.iter().flatten().copied().collect::<Vec<f64>>(),
+    );
+    if williams {
+        let row_term = total * row_totals.iter().map(|r| 1.0 / r).sum::<f64>() - 1.0;
+        let column_term = total * column_totals.iter().map(|c| 1.0 / c).sum::<f64>() - 1.0;
+        g /= 1.0 + row_term * column_term / (6.0 * total * df);
+    }
+
+    let p = 1.0 - ChiSquared::new(df).unwrap().cdf(g);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("g"), &JsValue::from_f64(g));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("exp"),
+        &nested_vec_to_jsvalue(exp_counts),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("williams_correction"),
+        &JsValue::from_bool(williams),
+    );
+    obj.into()
+}
+
+/// Performs a test of Kendall's tau-b rank correlation using the normal approximation of
+/// Kendall's S with the variance corrected for ties in both variables.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the first variable.
+/// * `y` - A reference to a JsValue representing the second variable.
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+/// * `options` - An optional object; `{ continuity_correction: true }` moves S one unit
+///   toward zero before standardizing.
+///
+/// # Returns
+///
+/// * An object with the properties `tau`, `s`, `z`, `p`, and `continuity_correction`, or
+///   null if the variables differ in length, have fewer than three values, or one of them
+///   is constant, or an object with the property `error` if `tails` is not recognized.
+#[wasm_bindgen]
+pub fn kendall_tau_test(x: &JsValue, y: &JsValue, tails: &JsValue, options: &JsValue) -> JsValue {
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+    if x.len() != y.len() || x.len() < 3 {
+        return JsValue::NULL;
+    }
+
+    let n = x.len() as f64;
+    let mut s = 0.0;
+    for i in 0..x.len() {
+        for j in i + 1..x.len() {
+            let concordance = (x[j] - x[i]).signum() * (y[j] - y[i]).signum();
+            if x[j] != x[i] && y[j] != y[i] {
+                s += concordance;
+            }
+        }
+    }
+
+    // Tie group sizes and their sums t(t - 1), t(t - 1)(t - 2), and t(t - 1)(2t + 5)
+    let tie_sums = |values: &[f64]| {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        sorted
+            .chunk_by(|a, b| a == b)
+            .fold((0.0, 0.0, 0.0), |acc, group| {
+                let t = group.len() as f64;
+                (
+                    acc.0 + t * (t - 1.0),
+                    acc.1 + t * (t - 1.0) * (t - 2.0),
+                    acc.2 + t * (t - 1.0) * (2.0 * t + 5.0),
+                )
+            })
+    };
+    let (tx1, tx2, tx3) = tie_sums(&x);
+    let (ty1, ty2, ty3) = tie_sums(&y);
+
+    let pairs = n * (n - 1.0) / 2.0;
+    if tx1 / 2.0 == pairs || ty1 / 2.0 == pairs {
+        return JsValue::NULL;
+    }
+    let tau = s / ((pairs - tx1 / 2.0) * (pairs - ty1 / 2.0)).sqrt();
+
+    let var_s = (n * (n - 1.0) * (2.0 * n + 5.0) - tx3 - ty3) / 18.0
+        + tx1 * ty1 / (2.0 * n * (n - 1.0))
+        + tx2 * ty2 / (9.0 * n * (n - 1.0) * (n - 2.0));
+
+    let yates = get_bool_option(options, "continuity_correction");
+    let corrected = if yates && s != 0.0 { s - s.signum() } else { s };
+    let z = corrected / var_s.sqrt();
+
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let p = match tails.as_str() {
+        "two-sided" => 2.0 * (1.0 - dist.cdf(z.abs())),
+        "less" => dist.cdf(z),
+        "greater" => 1.0 - dist.cdf(z),
+        _ => return invalid_test_type(),
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("tau"), &JsValue::from_f64(tau));
+    let _ = Reflect::set(&obj, &JsValue::from_str("s"), &JsValue::from_f64(s));
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("continuity_correction"),
+        &JsValue::from_bool(yates),
+    );
+    obj.into()
+}
+
+/// Computes the asymptotic Kolmogorov-Smirnov p-value for an effective sample size and
+/// D statistic, using Stephens' small-sample adjustment.
+fn kolmogorov_p(effective_n: f64, d: f64) -> f64 {
+    let root = effective_n.sqrt();
+    let lambda = (root + 0.12 + 0.11 / root) * d;
+    if lambda < 1e-3 {
+        return 1.0;
+    }
+    let q = (1..=100)
+        .map(|k| {
+            let k = k as f64;
+            let sign = if k % 2.0 == 1.0 { 1.0 } else { -1.0 };
+            sign * (-2.0 * k * k * lambda * lambda).exp()
+        })
+        .sum::<f64>();
+    (2.0 * q).clamp(0.0, 1.0)
+}
+
+/// Performs the one-sample Kolmogorov-Smirnov test of a column against a fully specified
+/// theoretical distribution.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the sample.
+/// * `dist_name` - A reference to a JsValue naming the distribution: "normal" (the
+///   default), "uniform", or "exponential".
+/// * `params` - A reference to a JsValue representing the distribution parameters:
+///   `[mean, sd]` for the normal (default `[0, 1]`), `[min, max]` for the uniform
+///   (default `[0, 1]`), or `[rate]` for the exponential (default `[1]`).
+///
+/// # Returns
+///
+/// * An object with the properties `d` and `p`, or null if the sample is empty, the
+///   distribution is unknown, or the parameters are invalid.
+#[wasm_bindgen]
+pub fn ks_test(column: &JsValue, dist_name: &JsValue, params: &JsValue) -> JsValue {
+    let mut data = js_array_to_vector(column);
+    let dist_name = dist_name.as_string().unwrap_or("normal".to_string());
+    let params = if params.is_undefined() || params.is_null() {
+        Vec::new()
+    } else {
+        js_array_to_vector(params)
+    };
+    if data.is_empty() {
+        return JsValue::NULL;
+    }
+
+    let param = |i: usize, default: f64| params.get(i).copied().unwrap_or(default);
+    let cdf: Box<dyn Fn(f64) -> f64> = match dist_name.as_str() {
+        "normal" => match Normal::new(param(0, 0.0), param(1, 1.0)) {
+            Ok(dist) => Box::new(move |x| dist.cdf(x)),
+            Err(_) => return JsValue::NULL,
+        },
+        "uniform" => match Uniform::new(param(0, 0.0), param(1, 1.0)) {
+            Ok(dist) => Box::new(move |x| dist.cdf(x)),
+            Err(_) => return JsValue::NULL,
+        },
+        "exponential" => match Exp::new(param(0, 1.0)) {
+            Ok(dist) => Box::new(move |x| dist.cdf(x)),
+            Err(_) => return JsValue::NULL,
+        },
+        _ => return JsValue::NULL,
+    };
+
+    data.sort_by(|a, b| a.total_cmp(b));
+    let n = data.len() as f64;
+    let d = data
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let f = cdf(*x);
+            ((i as f64 + 1.0) / n - f).max(f - i as f64 / n)
+        })
+        .fold(0.0, f64::max);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("d"), &JsValue::from_f64(d));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p"),
+        &JsValue::from_f64(kolmogorov_p(n, d)),
+    );
+    obj.into()
+}
+
+/// Performs the two-sample Kolmogorov-Smirnov test comparing the empirical distributions
+/// of two columns.
+///
+/// # Arguments
+///
+/// * `column1` - A reference to a JsValue representing the first sample.
+/// * `column2` - A reference to a JsValue representing the second sample.
+///
+/// # Returns
+///
+/// * An object with the properties `d` and `p`, or null if either sample is empty.
+#[wasm_bindgen]
+pub fn ks_2samp_test(column1: &JsValue, column2: &JsValue) -> JsValue {
+    let mut data1 = js_array_to_vector(column1);
+    let mut data2 = js_array_to_vector(column2);
+    if data1.is_empty() || data2.is_empty() {
+        return JsValue::NULL;
+    }
+    data1.sort_by(|a, b| a.total_cmp(b));
+    data2.sort_by(|a, b| a.total_cmp(b));
+
+    let n1 = data1.len() as f64;
+    let n2 = data2.len() as f64;
+    let (mut i, mut j) = (0, 0);
+    let mut d: f64 = 0.0;
+    // Advance through both samples together, stepping past every copy of the next value
+    while i < data1.len() && j < data2.len() {
+        let next = data1[i].min(data2[j]);
+        while i < data1.len() && data1[i] == next {
+            i += 1;
+        }
+        while j < data2.len() && data2[j] == next {
+            j += 1;
+        }
+        d = d.max((i as f64 / n1 - j as f64 / n2).abs());
+    }
 
-    let f = ms_tr / ms_e;
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("d"), &JsValue::from_f64(d));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p"),
+        &JsValue::from_f64(kolmogorov_p(n1 * n2 / (n1 + n2), d)),
+    );
+    obj.into()
+}
 
-    let dist = FisherSnedecor::new(df_tr, df_e).unwrap();
-    let p = 1.0 - dist.cdf(f);
+/// Performs the D'Agostino-Pearson omnibus test for normality.
+///
+/// The sample skewness and kurtosis are transformed to approximately standard normal
+/// z-scores (D'Agostino, 1970; Anscombe and Glynn, 1983), and their sum of squares K² is
+/// compared with the chi-square distribution with 2 degrees of freedom.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing a JavaScript array of f64 numbers.
+///
+/// # Returns
+///
+/// * An object with the properties `k2`, `p`, `z_skewness`, `z_kurtosis`, `skewness`, and
+///   `kurtosis` (excess), or null if there are fewer than 8 values or they are all equal.
+#[wasm_bindgen]
+pub fn dagostino_test(column: &JsValue) -> JsValue {
+    let data = js_array_to_vector(column);
+    let (k2, p, z_skewness, z_kurtosis, skewness, kurtosis) = match dagostino_statistics(&data) {
+        Some(statistics) => statistics,
+        None => return JsValue::NULL,
+    };
 
     let obj = Object::new();
-    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("k2"), &JsValue::from_f64(k2));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("z_skewness"),
+        &JsValue::from_f64(z_skewness),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("z_kurtosis"),
+        &JsValue::from_f64(z_kurtosis),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("skewness"),
+        &JsValue::from_f64(skewness),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("kurtosis"),
+        &JsValue::from_f64(kurtosis),
+    );
     obj.into()
 }
 
-/// Performs a chi-square goodness-of-fit test.
+/// Computes the D'Agostino-Pearson omnibus statistic for normality.
 ///
 /// # Arguments
 ///
-/// * `actual` - A reference to a JsValue representing the observed frequencies.
-/// * `expected` - A reference to a JsValue representing the expected frequencies.
+/// * `data` - The sample to check.
 ///
 /// # Returns
 ///
-/// * An object with two properties: `x2` and `p`, the chi-square statistic and p-value,
-///   respectively.
+/// * A tuple of K², its p-value, the skewness and kurtosis z-scores, the skewness, and the
+///   excess kurtosis, or `None` if there are fewer than 8 values or they are all equal.
+fn dagostino_statistics(data: &[f64]) -> Option<(f64, f64, f64, f64, f64, f64)> {
+    if data.len() < 8 {
+        return None;
+    }
+
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let moment = |k: i32| data.iter().map(|x| (x - mean).powi(k)).sum::<f64>() / n;
+    let m2 = moment(2);
+    if m2 == 0.0 {
+        return None;
+    }
+    let skewness = moment(3) / m2.powf(1.5);
+    let kurtosis = moment(4) / m2.powi(2);
+
+    // Skewness z-score
+    let y = skewness * ((n + 1.0) * (n + 3.0) / (6.0 * (n - 2.0))).sqrt();
+    let beta2 = 3.0 * (n.powi(2) + 27.0 * n - 70.0) * (n + 1.0) * (n + 3.0)
+        / ((n - 2.0) * (n + 5.0) * (n + 7.0) * (n + 9.0));
+    let w2 = (2.0 * (beta2 - 1.0)).sqrt() - 1.0;
+    let delta = 1.0 / (0.5 * w2.ln()).sqrt();
+    let a = (2.0 / (w2 - 1.0)).sqrt();
+    let z_skewness = delta * (y / a).asinh();
+
+    // Kurtosis z-score
+    let expected = 3.0 * (n - 1.0) / (n + 1.0);
+    let variance = 24.0 * n * (n - 2.0) * (n - 3.0) / ((n + 1.0).powi(2) * (n + 3.0) * (n + 5.0));
+    let x = (kurtosis - expected) / variance.sqrt();
+    let sqrt_beta1 = 6.0 * (n.powi(2) - 5.0 * n + 2.0) / ((n + 7.0) * (n + 9.0))
+        * (6.0 * (n + 3.0) * (n + 5.0) / (n * (n - 2.0) * (n - 3.0))).sqrt();
+    let a = 6.0 + 8.0 / sqrt_beta1 * (2.0 / sqrt_beta1 + (1.0 + 4.0 / sqrt_beta1.powi(2)).sqrt());
+    let denom = 1.0 + x * (2.0 / (a - 4.0)).sqrt();
+    let term = ((1.0 - 2.0 / a) / denom).cbrt();
+    let z_kurtosis = (1.0 - 2.0 / (9.0 * a) - term) / (2.0 / (9.0 * a)).sqrt();
+
+    let k2 = z_skewness.powi(2) + z_kurtosis.powi(2);
+    let p = 1.0 - ChiSquared::new(2.0).unwrap().cdf(k2);
+
+    Some((k2, p, z_skewness, z_kurtosis, skewness, kurtosis - 3.0))
+}
+
+/// Performs Bartlett's test for homogeneity of variance across groups using the
+/// chi-square approximation.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups, each an
+///   array of f64 numbers.
+///
+/// # Returns
+///
+/// * An object with the properties `x2`, `df`, and `p`, or null if there are fewer than
+///   two groups, a group has fewer than two values, or a group has zero variance.
 #[wasm_bindgen]
-pub fn chi2_gof_test(actual: &JsValue, expected: &JsValue) -> JsValue {
-    let actual = js_array_to_vector(actual);
-    let expected = js_array_to_vector(expected);
-    if actual.len() != expected.len() {
+pub fn bartlett_test(data: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    if groups.len() < 2 || groups.iter().any(|group| group.len() < 2) {
+        return JsValue::NULL;
+    }
+
+    let variances: Vec<f64> = groups.iter().map(|group| group.iter().variance()).collect();
+    if variances.contains(&0.0) {
         return JsValue::NULL;
     }
 
-    let pairs: Vec<(f64, f64)> = actual
+    let k = groups.len() as f64;
+    let dfs: Vec<f64> = groups
         .iter()
-        .zip(expected.iter())
-        .map(|(a, e)| (*a, *e))
+        .map(|group| group.len() as f64 - 1.0)
         .collect();
-
-    let x2 = pairs
+    let df_total = dfs.iter().sum::<f64>();
+    let pooled = dfs
         .iter()
-        .fold(0.0, |x2, (a, e)| x2 + ((a - e).powi(2)) / e);
+        .zip(&variances)
+        .map(|(df, v)| df * v)
+        .sum::<f64>()
+        / df_total;
 
-    let dist = ChiSquared::new((pairs.len() - 1) as f64).unwrap();
-    let p = 1.0 - dist.cdf(x2);
+    let numerator = df_total * pooled.ln()
+        - dfs
+            .iter()
+            .zip(&variances)
+            .map(|(df, v)| df * v.ln())
+            .sum::<f64>();
+    let correction =
+        1.0 + (dfs.iter().map(|df| 1.0 / df).sum::<f64>() - 1.0 / df_total) / (3.0 * (k - 1.0));
+    let x2 = numerator / correction;
+
+    let df = k - 1.0;
+    let p = 1.0 - ChiSquared::new(df).unwrap().cdf(x2);
 
     let obj = Object::new();
     let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
     obj.into()
 }
 
-/// Performs a chi-square independence test.
+/// Performs Hartley's Fmax test for homogeneity of variance across groups of equal size.
+///
+/// The critical value and p-value come from the exact distribution of the ratio of the
+/// largest to the smallest of k independent sample variances, integrated numerically.
 ///
 /// # Arguments
 ///
-/// * `data` - A reference to a JsValue representing the observed frequencies in a table.
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups, each an
+///   array of f64 numbers with the same length.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
 ///
 /// # Returns
 ///
-/// * An object with three properties: `x2`, `p`, and `exp`, the chi-square statistic, p-value, and expected counts, respectively.
+/// * An object with the properties `fmax`, `critical` (infinite if `alpha` is too small for
+///   the numerical integration to reach), `p`, `df`, `k`, and `reject`, or null if `alpha`
+///   is not between 0 and 1, there are fewer than two groups, the groups differ in size or
+///   have fewer than two values, or a group has zero variance.
 #[wasm_bindgen]
-pub fn chi2_ind_test(data: &JsValue) -> JsValue {
-    let rows = js_nested_array_to_vector(data);
-    let test_data: Vec<Vec<f64>> = rows.iter().map(|item| js_array_to_vector(item)).collect();
-
-    // Total sum of all observations
-    let total_sum: f64 = test_data.iter().flatten().sum();
-
-    // Row and column totals
-    let row_totals: Vec<f64> = test_data.iter().map(|row| row.iter().sum()).collect();
-    let column_totals: Vec<f64> = (0..test_data[0].len())
-        .map(|col_idx| test_data.iter().map(|row| row[col_idx]).sum())
-        .collect();
-
-    // Calculate expected counts
-    let exp_counts: Vec<Vec<f64>> = row_totals
+pub fn fmax_test(data: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return JsValue::NULL;
+    }
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
         .iter()
-        .map(|&row_total| {
-            column_totals
-                .iter()
-                .map(|&col_total| (row_total * col_total) / total_sum)
-                .collect()
-        })
+        .map(js_array_to_vector)
         .collect();
 
-    // Calculate chi-squared statistic
-    let x2 = exp_counts
-        .iter()
-        .zip(&test_data)
-        .fold(0.0, |x2, (exp_row, obs_row)| {
-            exp_row.iter().zip(obs_row).fold(x2, |acc, (&exp, &obs)| {
-                // Avoid division by zero
-                if exp == 0.0 {
-                    acc
+    let n = match groups.first() {
+        Some(group) => group.len(),
+        None => return JsValue::NULL,
+    };
+    if groups.len() < 2 || n < 2 || groups.iter().any(|group| group.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let variances: Vec<f64> = groups.iter().map(|group| group.iter().variance()).collect();
+    let max = variances.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let min = variances.iter().cloned().fold(f64::INFINITY, f64::min);
+    if min == 0.0 {
+        return JsValue::NULL;
+    }
+    let fmax = max / min;
+
+    let k = groups.len() as f64;
+    let df = (n - 1) as f64;
+    let chi2 = ChiSquared::new(df).unwrap();
+
+    // P(Fmax <= x) = k * integral of g(s) * (G(x * s) - G(s))^(k - 1) over the smallest
+    // variance s, where g and G are the chi-square density and CDF; integrating over ln(s)
+    // keeps the integrand smooth near zero for small df
+    let steps = 1000;
+    let start = df.ln() - 46.0;
+    let end = (df + 20.0 * (2.0 * df).sqrt() + 50.0).ln();
+    let h = (end - start) / steps as f64;
+    let cdf = |x: f64| {
+        let sum = (0..=steps)
+            .map(|i| {
+                let s = (start + i as f64 * h).exp();
+                let weight = if i == 0 || i == steps {
+                    1.0
+                } else if i % 2 == 1 {
+                    4.0
                 } else {
-                    acc + ((obs - exp).powi(2) / exp)
-                }
+                    2.0
+                };
+                weight * chi2.pdf(s) * s * (chi2.cdf(x * s) - chi2.cdf(s)).powf(k - 1.0)
             })
-        });
-
-    // Degrees of freedom: (rows - 1) * (columns - 1)
-    let df = ((test_data.len() - 1) * (test_data[0].len() - 1)) as f64;
+            .sum::<f64>();
+        (k * sum * h / 3.0).min(1.0)
+    };
 
-    let dist = ChiSquared::new(df).unwrap();
-    let p = 1.0 - dist.cdf(x2);
+    // Bracket and bisect for the upper alpha critical value, giving up on the bracket
+    // once the ratio is far beyond anything the integration can resolve
+    let mut lower = 1.0;
+    let mut upper = 2.0;
+    let mut bracketed = false;
+    for _ in 0..60 {
+        if cdf(upper) >= 1.0 - alpha {
+            bracketed = true;
+            break;
+        }
+        lower = upper;
+        upper *= 2.0;
+    }
+    let critical = if bracketed {
+        for _ in 0..40 {
+            let mid = (lower + upper) / 2.0;
+            if cdf(mid) < 1.0 - alpha {
+                lower = mid;
+            } else {
+                upper = mid;
+            }
+        }
+        (lower + upper) / 2.0
+    } else {
+        f64::INFINITY
+    };
+    let p = 1.0 - cdf(fmax);
 
     let obj = Object::new();
-    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("fmax"), &JsValue::from_f64(fmax));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("critical"),
+        &JsValue::from_f64(critical),
+    );
     let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("k"), &JsValue::from_f64(k));
     let _ = Reflect::set(
         &obj,
-        &JsValue::from_str("exp"),
-        &nested_vec_to_jsvalue(exp_counts),
+        &JsValue::from_str("reject"),
+        &JsValue::from_bool(fmax > critical),
     );
     obj.into()
 }
 
+/// Performs Levene's test for homogeneity of variance across groups, or the
+/// Brown-Forsythe variant when deviations are measured from the group medians.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups, each an
+///   array of f64 numbers.
+/// * `center` - A reference to a JsValue representing the group center ("mean" for
+///   Levene's test or "median" for the Brown-Forsythe test; defaults to "mean").
+///
+/// # Returns
+///
+/// * An object with the properties `w`, `df1`, `df2`, and `p`, or null if there are fewer
+///   than two groups, a group is empty, or the center is invalid.
+#[wasm_bindgen]
+pub fn levene_test(data: &JsValue, center: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let center = center.as_string().unwrap_or("mean".to_string());
+
+    let (w, df1, df2, p) = match levene_values(&groups, &center) {
+        Some(values) => values,
+        None => return JsValue::NULL,
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("w"), &JsValue::from_f64(w));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df1"), &JsValue::from_f64(df1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df2"), &JsValue::from_f64(df2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Adds a warning if a sample fails the D'Agostino-Pearson normality check at the 5%
+/// level, or if it has fewer than 8 values and is too small for the check.
+///
+/// # Arguments
+///
+/// * `warnings` - The warnings collected so far.
+/// * `data` - The sample to check.
+/// * `label` - A description of the sample used in the message.
+/// * `alternative` - The suggested alternative test, named by its function when this crate
+///   provides one.
+fn check_normality(warnings: &mut Vec<String>, data: &[f64], label: &str, alternative: &str) {
+    if data.len() < 8 {
+        warnings.push(format!(
+            "{} has too few values ({}) to check normality; consider {}.",
+            label,
+            data.len(),
+            alternative
+        ));
+    } else if let Some((_, p, ..)) = dagostino_statistics(data) {
+        if p < 0.05 {
+            warnings.push(format!(
+                "{} may not be normally distributed (D'Agostino-Pearson p = {:.4}); consider {}.",
+                label, p, alternative
+            ));
+        }
+    }
+}
+
+/// Adds a warning if any expected count of a chi-square test is below 5.
+///
+/// # Arguments
+///
+/// * `warnings` - The warnings collected so far.
+/// * `expected` - The expected counts.
+/// * `alternative` - The suggested alternative test.
+fn check_expected_counts(warnings: &mut Vec<String>, expected: &[f64], alternative: &str) {
+    let small = expected.iter().filter(|&&e| e < 5.0).count();
+    if small > 0 {
+        warnings.push(format!(
+            "{} of {} expected counts are below 5; consider {}.",
+            small,
+            expected.len(),
+            alternative
+        ));
+    }
+}
+
+/// Computes Levene's test statistic for homogeneity of variance across groups.
+///
+/// # Arguments
+///
+/// * `groups` - A slice of groups of observations.
+/// * `center` - The group center to measure deviations from ("mean" or "median").
+///
+/// # Returns
+///
+/// * A tuple of the W statistic, numerator df, denominator df, and p-value, or `None`
+///   if there are fewer than two groups or the center is unknown.
+pub(crate) fn levene_values(groups: &[Vec<f64>], center: &str) -> Option<(f64, f64, f64, f64)> {
+    if groups.len() < 2 || groups.iter().any(|group| group.is_empty()) {
+        return None;
+    }
+
+    // Absolute deviations from each group's center
+    let deviations: Vec<Vec<f64>> = groups
+        .iter()
+        .map(|group| {
+            let c = match center {
+                "mean" => Some(group.mean()),
+                "median" => Some(Data::new(group.clone()).median()),
+                _ => None,
+            };
+            c.map(|c| group.iter().map(|x| (x - c).abs()).collect())
+        })
+        .collect::<Option<Vec<Vec<f64>>>>()?;
+
+    let k = groups.len() as f64;
+    let n_total = groups.iter().map(|group| group.len()).sum::<usize>() as f64;
+    let group_means: Vec<f64> = deviations.iter().map(|z| z.mean()).collect();
+    let grand_mean = deviations.iter().flatten().sum::<f64>() / n_total;
+
+    let between = deviations
+        .iter()
+        .zip(&group_means)
+        .map(|(z, m)| z.len() as f64 * (m - grand_mean).powi(2))
+        .sum::<f64>();
+    let within = deviations
+        .iter()
+        .zip(&group_means)
+        .map(|(z, m)| z.iter().map(|x| (x - m).powi(2)).sum::<f64>())
+        .sum::<f64>();
+
+    let df1 = k - 1.0;
+    let df2 = n_total - k;
+    let w = (df2 / df1) * between / within;
+    let p = 1.0 - FisherSnedecor::new(df1, df2).unwrap().cdf(w);
+
+    Some((w, df1, df2, p))
+}
+
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
@@ -548,16 +2488,19 @@ mod tests {
             &column1,
             &JsValue::from_str("two-sided"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
         let result2 = one_samp_z_test(
             &column1,
             &JsValue::from_str("greater"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
         let result3 = one_samp_z_test(
             &column1,
             &JsValue::from_str("less"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
 
         let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
@@ -580,18 +2523,21 @@ mod tests {
             &column2,
             &JsValue::from_str("two-sided"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
         let result2 = two_samp_z_test(
             &column1,
             &column2,
             &JsValue::from_str("greater"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
         let result3 = two_samp_z_test(
             &column1,
             &column2,
             &JsValue::from_str("less"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
 
         let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
@@ -603,6 +2549,150 @@ mod tests {
         assert!((p3.as_f64().unwrap() - 0.1587).abs() < 0.01);
     }
 
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_two_samp_z_test_summary() {
+        let summary = |mean: f64| {
+            let obj = Object::new();
+            for (key, value) in [("mean", mean), ("sigma", 2.0), ("n", 5.0)] {
+                let _ = Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_f64(value));
+            }
+            JsValue::from(obj)
+        };
+
+        let result = two_samp_z_test_summary(
+            &summary(3.0),
+            &summary(4.0),
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
+        );
+
+        let z = Reflect::get(&result, &JsValue::from_str("z")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        assert!((z.as_f64().unwrap() + 0.790569).abs() < 0.001);
+        assert!((p.as_f64().unwrap() - 0.429195).abs() < 0.001);
+        assert!(two_samp_z_test_summary(
+            &summary(3.0),
+            &JsValue::UNDEFINED,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+        let zero_sigma = summary(4.0);
+        let _ = Reflect::set(
+            &zero_sigma,
+            &JsValue::from_str("sigma"),
+            &JsValue::from_f64(0.0),
+        );
+        assert!(two_samp_z_test_summary(
+            &summary(3.0),
+            &zero_sigma,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_two_prop_z_test() {
+        let count = |x: f64| JsValue::from_f64(x);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("continuity_correction"),
+            &JsValue::from_bool(true),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("effect_size"),
+            &JsValue::from_bool(true),
+        );
+
+        let result1 = two_prop_z_test(
+            &count(45.0),
+            &count(100.0),
+            &count(30.0),
+            &count(100.0),
+            &JsValue::from_str("two-sided"),
+            &JsValue::UNDEFINED,
+        );
+        let result2 = two_prop_z_test(
+            &count(45.0),
+            &count(100.0),
+            &count(30.0),
+            &count(100.0),
+            &JsValue::from_str("greater"),
+            &JsValue::UNDEFINED,
+        );
+        let result3 = two_prop_z_test(
+            &count(45.0),
+            &count(100.0),
+            &count(30.0),
+            &count(100.0),
+            &JsValue::from_str("two-sided"),
+            &options,
+        );
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+        let ci3 = js_array_to_vector(&Reflect::get(&result3, &JsValue::from_str("ci")).unwrap());
+
+        assert!((get(&result1, "z") - 2.190890).abs() < 0.0001);
+        assert!((get(&result1, "p") - 0.028460).abs() < 0.0001);
+        assert!((get(&result1, "pooled") - 0.375).abs() < 1e-9);
+        assert!((get(&result2, "p") - 0.014230).abs() < 0.0001);
+        assert!((get(&result3, "z") - 2.044831).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.040872).abs() < 0.0001);
+        assert!((get(&result3, "effect_size") - 0.311349).abs() < 0.0001);
+        assert!((ci3[0] - 0.017430).abs() < 0.0001);
+        assert!((ci3[1] - 0.282570).abs() < 0.0001);
+        assert!(two_prop_z_test(
+            &count(5.0),
+            &count(4.0),
+            &count(1.0),
+            &count(4.0),
+            &JsValue::from_str("two-sided"),
+            &JsValue::UNDEFINED,
+        )
+        .is_null());
+        let invalid = two_prop_z_test(
+            &count(45.0),
+            &count(100.0),
+            &count(30.0),
+            &count(100.0),
+            &JsValue::from_str("sideways"),
+            &JsValue::UNDEFINED,
+        );
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
+        );
+        for (x, tails) in [(0.0, "two-sided"), (0.0, "less"), (20.0, "greater")] {
+            let result = two_prop_z_test(
+                &count(x),
+                &count(20.0),
+                &count(x * 1.5),
+                &count(30.0),
+                &JsValue::from_str(tails),
+                &options,
+            );
+            assert_eq!(get(&result, "z"), 0.0);
+            assert_eq!(get(&result, "p"), 1.0);
+        }
+    }
+
     #[allow(unused)]
     #[wasm_bindgen_test]
     fn test_one_samp_t_test() {
@@ -612,16 +2702,19 @@ mod tests {
             &column1,
             &JsValue::from_str("two-sided"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
         let result2 = one_samp_t_test(
             &column1,
             &JsValue::from_str("greater"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
         let result3 = one_samp_t_test(
             &column1,
             &JsValue::from_str("less"),
             &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
         );
 
         let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
@@ -644,27 +2737,195 @@ mod tests {
             &column2,
             &JsValue::from_f64(0.0),
             &JsValue::from_str("two-sided"),
+            &JsValue::UNDEFINED,
         );
         let result2 = two_samp_t_test(
             &column1,
             &column2,
             &JsValue::from_f64(0.0),
             &JsValue::from_str("greater"),
+            &JsValue::UNDEFINED,
         );
         let result3 = two_samp_t_test(
             &column1,
             &column2,
             &JsValue::from_f64(0.0),
             &JsValue::from_str("less"),
+            &JsValue::UNDEFINED,
+        );
+
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+        let p3 = Reflect::get(&result3, &JsValue::from_str("p")).unwrap();
+
+        assert!((p1.as_f64().unwrap() - 0.3466).abs() < 0.01);
+        assert!((p2.as_f64().unwrap() - 0.8267).abs() < 0.01);
+        assert!((p3.as_f64().unwrap() - 0.1733).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_tost_equivalence_test() {
+        let column1 = vec_to_jsvalue(vec![10.2, 9.8, 10.5, 10.1, 9.9, 10.4, 10.0, 10.3]);
+        let column2 = vec_to_jsvalue(vec![10.1, 10.0, 10.6, 9.7, 10.2, 10.3, 9.9, 10.4, 10.0]);
+
+        let result1 = tost_equivalence_test(
+            &column1,
+            &column2,
+            &JsValue::from_f64(-0.5),
+            &JsValue::from_f64(0.5),
+            &JsValue::UNDEFINED,
+        );
+        let result2 = tost_equivalence_test(
+            &column1,
+            &column2,
+            &JsValue::from_f64(-0.1),
+            &JsValue::from_f64(0.1),
+            &JsValue::from_f64(0.05),
+        );
+
+        let get =
+            |result: &JsValue, key: &str| Reflect::get(result, &JsValue::from_str(key)).unwrap();
+        let ci = js_array_to_vector(&get(&result1, "ci"));
+
+        assert!((get(&result1, "diff").as_f64().unwrap() - 0.016667).abs() < 0.0001);
+        assert!((get(&result1, "df").as_f64().unwrap() - 14.997033).abs() < 0.0001);
+        assert!((get(&result1, "t_lower").as_f64().unwrap() - 4.106050).abs() < 0.0001);
+        assert!((get(&result1, "t_upper").as_f64().unwrap() + 3.841144).abs() < 0.0001);
+        assert!((get(&result1, "p_lower").as_f64().unwrap() - 0.000468).abs() < 0.0001);
+        assert!((get(&result1, "p_upper").as_f64().unwrap() - 0.000802).abs() < 0.0001);
+        assert!((ci[0] + 0.203924).abs() < 0.0001);
+        assert!((ci[1] - 0.237257).abs() < 0.0001);
+        assert_eq!(get(&result1, "equivalent").as_bool(), Some(true));
+        assert!((get(&result2, "p_lower").as_f64().unwrap() - 0.184259).abs() < 0.0001);
+        assert!((get(&result2, "p").as_f64().unwrap() - 0.258925).abs() < 0.0001);
+        assert_eq!(get(&result2, "equivalent").as_bool(), Some(false));
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_yuen_t_test() {
+        let column1 = vec_to_jsvalue(vec![5.1, 4.8, 6.2, 5.5, 5.9, 4.9, 12.0, 5.3, 5.6, 5.0]);
+        let column2 = vec_to_jsvalue(vec![6.3, 6.8, 5.9, 7.1, 6.5, 6.9, 1.0, 6.6, 7.4, 6.2, 6.0]);
+        let two_sided = JsValue::from_str("two-sided");
+
+        let result1 = yuen_t_test(&column1, &column2, &JsValue::UNDEFINED, &two_sided);
+        let result2 = yuen_t_test(
+            &column1,
+            &column2,
+            &JsValue::from_f64(0.2),
+            &JsValue::from_str("less"),
+        );
+        let result3 = yuen_t_test(&column1, &column2, &JsValue::from_f64(0.1), &two_sided);
+        let result4 = yuen_t_test(&column1, &column2, &JsValue::from_f64(0.5), &two_sided);
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "trimmed_mean1") - 5.4).abs() < 0.0001);
+        assert!((get(&result1, "trimmed_mean2") - 6.471429).abs() < 0.0001);
+        assert!((get(&result1, "t") + 3.781407).abs() < 0.0001);
+        assert!((get(&result1, "df") - 10.426565).abs() < 0.0001);
+        assert!((get(&result1, "p") - 0.003338).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.001669).abs() < 0.0001);
+        assert!((get(&result3, "t") + 3.932407).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.001472).abs() < 0.0001);
+        assert!(result4.is_null());
+        let invalid = yuen_t_test(
+            &column1,
+            &column2,
+            &JsValue::UNDEFINED,
+            &JsValue::from_str("sideways"),
+        );
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
+        );
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_brunner_munzel_test() {
+        let column1 = vec_to_jsvalue(vec![
+            1.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 4.0, 1.0, 1.0,
+        ]);
+        let column2 = vec_to_jsvalue(vec![3.0, 3.0, 4.0, 3.0, 1.0, 2.0, 3.0, 1.0, 1.0, 5.0, 4.0]);
+
+        let result1 = brunner_munzel_test(&column1, &column2, &JsValue::from_str("two-sided"));
+        let result2 = brunner_munzel_test(&column1, &column2, &JsValue::from_str("less"));
+        let result3 = brunner_munzel_test(&column1, &column2, &JsValue::from_str("greater"));
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "w") - 3.137467).abs() < 0.0001);
+        assert!((get(&result1, "df") - 17.682842).abs() < 0.0001);
+        assert!((get(&result1, "p") - 0.005786).abs() < 0.0001);
+        assert!((get(&result1, "relative_effect") - 0.788961).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.002893).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.997107).abs() < 0.0001);
+        let invalid = brunner_munzel_test(&column1, &column2, &JsValue::from_str("sideways"));
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
         );
+    }
 
-        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
-        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
-        let p3 = Reflect::get(&result3, &JsValue::from_str("p")).unwrap();
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_cliffs_delta() {
+        let column1 = vec_to_jsvalue(vec![1.2, 3.4, 2.2, 5.1, 4.4, 3.3, 2.9]);
+        let column2 = vec_to_jsvalue(vec![0.8, 2.1, 1.9, 3.3, 2.5, 1.0]);
 
-        assert!((p1.as_f64().unwrap() - 0.3466).abs() < 0.01);
-        assert!((p2.as_f64().unwrap() - 0.8267).abs() < 0.01);
-        assert!((p3.as_f64().unwrap() - 0.1733).abs() < 0.01);
+        let result = cliffs_delta(&column1, &column2);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+
+        assert!((get("delta").as_f64().unwrap() - 27.0 / 42.0).abs() < 1e-10);
+        assert!((get("greater").as_f64().unwrap() - 34.0 / 42.0).abs() < 1e-10);
+        assert!((get("less").as_f64().unwrap() - 7.0 / 42.0).abs() < 1e-10);
+        assert_eq!(get("interpretation").as_string().unwrap(), "large");
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_rank_biserial() {
+        let column1 = vec_to_jsvalue(vec![1.2, 3.4, 2.2, 5.1, 4.4, 3.3, 2.9]);
+        let column2 = vec_to_jsvalue(vec![0.8, 2.1, 1.9, 3.3, 2.5, 1.0]);
+        let before = vec_to_jsvalue(vec![12.0, 15.0, 9.0, 20.0, 14.0, 11.0, 16.0, 13.0]);
+        let after = vec_to_jsvalue(vec![10.0, 15.0, 11.0, 14.0, 13.0, 8.0, 12.0, 13.0]);
+
+        let independent = rank_biserial(&column1, &column2, &JsValue::UNDEFINED);
+        let paired = rank_biserial(&before, &after, &JsValue::TRUE);
+
+        let get = |obj: &JsValue, key: &str| {
+            Reflect::get(obj, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        // Matches Cliff's delta for independent samples
+        assert!((get(&independent, "r") - 27.0 / 42.0).abs() < 1e-10);
+        assert_eq!(get(&independent, "u"), 34.5);
+        assert_eq!(get(&paired, "t_plus"), 18.5);
+        assert_eq!(get(&paired, "t_minus"), 2.5);
+        assert!((get(&paired, "r") - 16.0 / 21.0).abs() < 1e-10);
+        assert!(rank_biserial(&before, &column2, &JsValue::TRUE).is_null());
     }
 
     #[allow(unused)]
@@ -678,18 +2939,21 @@ mod tests {
             &column2,
             &JsValue::from_f64(0.0),
             &JsValue::from_str("two-sided"),
+            &JsValue::UNDEFINED,
         );
         let result2 = matched_pairs_t_test(
             &column1,
             &column2,
             &JsValue::from_f64(0.0),
             &JsValue::from_str("greater"),
+            &JsValue::UNDEFINED,
         );
         let result3 = matched_pairs_t_test(
             &column1,
             &column2,
             &JsValue::from_f64(0.0),
             &JsValue::from_str("less"),
+            &JsValue::UNDEFINED,
         );
 
         let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
@@ -707,9 +2971,24 @@ mod tests {
         let column1 = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
         let column2 = vec_to_jsvalue(vec![2.0, 3.0, 4.0, 5.0, 6.0]);
 
-        let result1 = variance_test(&column1, &column2, &JsValue::from_str("less"));
-        let result2 = variance_test(&column1, &column2, &JsValue::from_str("greater"));
-        let result3 = variance_test(&column1, &column2, &JsValue::from_str("two-sided"));
+        let result1 = variance_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("less"),
+            &JsValue::UNDEFINED,
+        );
+        let result2 = variance_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("greater"),
+            &JsValue::UNDEFINED,
+        );
+        let result3 = variance_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("two-sided"),
+            &JsValue::UNDEFINED,
+        );
 
         let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
         let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
@@ -720,6 +2999,121 @@ mod tests {
         assert!((p3.as_f64().unwrap() - 1.0).abs() < 0.01);
     }
 
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_one_samp_var_test() {
+        let column = vec_to_jsvalue(vec![4.2, 5.1, 3.8, 6.0, 5.5, 4.9, 5.3, 4.4]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("alpha"),
+            &JsValue::from_f64(0.05),
+        );
+
+        let result1 = one_samp_var_test(
+            &column,
+            &JsValue::from_f64(0.5),
+            &JsValue::from_str("two-sided"),
+            &options,
+        );
+        let result2 = one_samp_var_test(
+            &column,
+            &JsValue::from_f64(0.5),
+            &JsValue::from_str("greater"),
+            &JsValue::UNDEFINED,
+        );
+        let result3 = one_samp_var_test(
+            &column,
+            &JsValue::from_f64(1.5),
+            &JsValue::from_str("less"),
+            &JsValue::UNDEFINED,
+        );
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+        let ci = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+
+        assert!((get(&result1, "x2") - 7.44).abs() < 0.0001);
+        assert_eq!(get(&result1, "df"), 7.0);
+        assert!((get(&result1, "p") - 0.769094).abs() < 0.0001);
+        assert!((get(&result1, "estimate") - 0.531429).abs() < 0.0001);
+        assert!((ci[0] - 0.232315).abs() < 0.0001);
+        assert!((ci[1] - 2.201354).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.384547).abs() < 0.0001);
+        assert!((get(&result3, "x2") - 2.48).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.071404).abs() < 0.0001);
+        let invalid = one_samp_var_test(
+            &column,
+            &JsValue::from_f64(1.0),
+            &JsValue::from_str("sideways"),
+            &JsValue::UNDEFINED,
+        );
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
+        );
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_poisson_rate_test() {
+        let exposure = JsValue::from_f64(4.5);
+        let rate0 = JsValue::from_f64(1.5);
+        let twelve = JsValue::from_f64(12.0);
+        let three = JsValue::from_f64(3.0);
+
+        let two_sided = JsValue::from_str("two-sided");
+
+        let result1 = poisson_rate_test(&twelve, &exposure, &rate0, &two_sided);
+        let result2 = poisson_rate_test(&twelve, &exposure, &rate0, &JsValue::from_str("greater"));
+        let result3 = poisson_rate_test(&twelve, &exposure, &rate0, &JsValue::from_str("less"));
+        let result4 = poisson_rate_test(&three, &exposure, &rate0, &two_sided);
+        let result5 = poisson_rate_test(&three, &exposure, &rate0, &JsValue::from_str("less"));
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "rate") - 2.666667).abs() < 0.0001);
+        assert!((get(&result1, "expected") - 6.75).abs() < 1e-9);
+        assert!((get(&result1, "p") - 0.051925).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.042850).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.979018).abs() < 0.0001);
+        assert!((get(&result4, "p") - 0.177493).abs() < 0.0001);
+        assert!((get(&result5, "p") - 0.095765).abs() < 0.0001);
+        let million = JsValue::from_f64(1e6);
+        let one = JsValue::from_f64(1.0);
+        let result6 = poisson_rate_test(&JsValue::from_f64(1002500.0), &one, &million, &two_sided);
+        let result7 = poisson_rate_test(&JsValue::from_f64(997400.0), &one, &million, &two_sided);
+        assert!((get(&result6, "p") - 0.012454).abs() < 0.0001);
+        assert!((get(&result7, "p") - 0.009309).abs() < 0.0001);
+        assert!(poisson_rate_test(
+            &twelve,
+            &JsValue::from_f64(1e308),
+            &JsValue::from_f64(1e308),
+            &two_sided
+        )
+        .is_null());
+        let invalid = poisson_rate_test(&twelve, &exposure, &rate0, &JsValue::from_str("sideways"));
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
+        );
+    }
+
     #[allow(unused)]
     #[wasm_bindgen_test]
     fn test_anova_1way_test() {
@@ -728,13 +3122,29 @@ mod tests {
         let data = vec![column1, column2];
         let data_js = nested_vec_to_jsvalue(data);
 
-        let result = anova_1way_test(&data_js);
+        let result = anova_1way_test(&data_js, &JsValue::UNDEFINED);
 
         let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
         let f = Reflect::get(&result, &JsValue::from_str("f")).unwrap();
 
         assert!((f.as_f64().unwrap() - 1.0).abs() < 0.01);
         assert!((p.as_f64().unwrap() - 0.3465).abs() < 0.01);
+
+        let unbalanced = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0, 7.0],
+            vec![8.0, 9.0, 10.0, 12.0, 9.0],
+        ]);
+
+        let result = anova_1way_test(&unbalanced, &JsValue::UNDEFINED);
+
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+        let f = Reflect::get(&result, &JsValue::from_str("f")).unwrap();
+        let df2 = Reflect::get(&result, &JsValue::from_str("df2")).unwrap();
+
+        assert!((f.as_f64().unwrap() - 31.240741).abs() < 0.0001);
+        assert!((p.as_f64().unwrap() - 0.0000892).abs() < 0.000001);
+        assert_eq!(df2.as_f64(), Some(9.0));
     }
 
     #[allow(unused)]
@@ -746,7 +3156,7 @@ mod tests {
         let x = vec_to_jsvalue(column1);
         let y = vec_to_jsvalue(column2);
 
-        let result = regression_test(&x, &y);
+        let result = regression_test(&x, &y, &JsValue::UNDEFINED);
 
         let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
         let f = Reflect::get(&result, &JsValue::from_str("f")).unwrap();
@@ -755,19 +3165,95 @@ mod tests {
         assert!((p.as_f64().unwrap() - 0.7335).abs() < 0.01);
     }
 
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_durbin_watson_test() {
+        let x = vec_to_jsvalue((1..=12).map(|i| i as f64).collect());
+        let y1 = vec_to_jsvalue(vec![
+            2.1, 2.9, 4.2, 4.8, 5.1, 6.3, 7.4, 7.9, 8.2, 9.6, 10.9, 11.3,
+        ]);
+        let y2 = vec_to_jsvalue(vec![
+            1.0, 2.5, 3.9, 4.1, 4.0, 4.3, 6.8, 8.2, 8.9, 9.0, 9.1, 9.4,
+        ]);
+
+        let result1 = durbin_watson_test(&x, &y1);
+        let result2 = durbin_watson_test(&x, &y2);
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "d") - 1.984733).abs() < 0.0001);
+        assert!((get(&result1, "rho") - 0.005851).abs() < 0.0001);
+        assert!((get(&result1, "z") + 0.403029).abs() < 0.0001);
+        assert!((get(&result1, "p") - 0.343464).abs() < 0.0001);
+        assert!((get(&result2, "d") - 1.055686).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.013662).abs() < 0.0001);
+    }
+
     #[allow(unused)]
     #[wasm_bindgen_test]
     fn test_chi2_gof_test() {
         let obs = vec_to_jsvalue(vec![30.0, 25.0, 20.0, 15.0, 25.0, 35.0]);
         let exp = vec_to_jsvalue(vec![25.0, 25.0, 25.0, 25.0, 25.0, 25.0]);
 
-        let result = chi2_gof_test(&obs, &exp);
+        let result = chi2_gof_test(&obs, &exp, &JsValue::UNDEFINED);
 
         let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
         let x2 = Reflect::get(&result, &JsValue::from_str("x2")).unwrap();
 
         assert!((p.as_f64().unwrap() - 0.07524).abs() < 0.01);
         assert!((x2.as_f64().unwrap() - 10.0).abs() < 0.01);
+
+        let obs = vec_to_jsvalue(vec![30.0, 50.0, 20.0]);
+        let probs = vec_to_jsvalue(vec![0.25, 0.5, 0.25]);
+
+        let result = chi2_gof_test(&obs, &probs, &JsValue::UNDEFINED);
+
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+        let x2 = Reflect::get(&result, &JsValue::from_str("x2")).unwrap();
+        let df = Reflect::get(&result, &JsValue::from_str("df")).unwrap();
+
+        assert!((x2.as_f64().unwrap() - 2.0).abs() < 1e-9);
+        assert_eq!(df.as_f64(), Some(2.0));
+        assert!((p.as_f64().unwrap() - 0.367879).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_chi_square_gof_test() {
+        let obs = vec_to_jsvalue(vec![30.0, 50.0, 20.0]);
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        // Rounded proportions are rescaled rather than read as counts
+        let rounded = chi_square_gof_test(
+            &obs,
+            &vec_to_jsvalue(vec![0.333, 0.333, 0.333]),
+            &JsValue::UNDEFINED,
+        );
+        let counts = chi_square_gof_test(
+            &obs,
+            &vec_to_jsvalue(vec![25.0, 50.0, 25.0]),
+            &JsValue::UNDEFINED,
+        );
+
+        assert!((get(&rounded, "x2") - 14.0).abs() < 1e-9);
+        assert!((get(&rounded, "p") - 0.000912).abs() < 0.00001);
+        assert!((get(&counts, "x2") - 2.0).abs() < 1e-9);
+        assert!(chi_square_gof_test(
+            &obs,
+            &vec_to_jsvalue(vec![0.5, 0.5, 0.0]),
+            &JsValue::UNDEFINED
+        )
+        .is_null());
     }
 
     #[allow(unused)]
@@ -779,7 +3265,7 @@ mod tests {
             vec![5.0, 10.0, 15.0],
         ]);
 
-        let result = chi2_ind_test(&data);
+        let result = chi2_ind_test(&data, &JsValue::UNDEFINED);
 
         let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
         let x2 = Reflect::get(&result, &JsValue::from_str("x2")).unwrap();
@@ -787,10 +3273,7 @@ mod tests {
 
         let exp_rows = js_nested_array_to_vector(&exp);
 
-        let exp_cells: Vec<Vec<f64>> = exp_rows
-            .iter()
-            .map(|item| js_array_to_vector(item))
-            .collect();
+        let exp_cells: Vec<Vec<f64>> = exp_rows.iter().map(js_array_to_vector).collect();
 
         assert!((p.as_f64().unwrap() - 0.3746).abs() < 0.01);
         assert!((x2.as_f64().unwrap() - 4.2395).abs() < 0.01);
@@ -802,5 +3285,558 @@ mod tests {
                     vec![6.0, 11.0, 13.0]
                 ]
         );
+
+        let table = nested_vec_to_jsvalue(vec![vec![12.0, 5.0], vec![7.0, 15.0]]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("continuity_correction"),
+            &JsValue::from_bool(true),
+        );
+
+        let plain = chi2_ind_test(&table, &JsValue::UNDEFINED);
+        let corrected = chi2_ind_test(&table, &options);
+        let correction_ignored = chi2_ind_test(&data, &options);
+
+        let x2 = Reflect::get(&plain, &JsValue::from_str("x2")).unwrap();
+        let x2_yates = Reflect::get(&corrected, &JsValue::from_str("x2")).unwrap();
+        let p_yates = Reflect::get(&corrected, &JsValue::from_str("p")).unwrap();
+        let applied =
+            Reflect::get(&corrected, &JsValue::from_str("continuity_correction")).unwrap();
+        let ignored = Reflect::get(
+            &correction_ignored,
+            &JsValue::from_str("continuity_correction"),
+        )
+        .unwrap();
+
+        assert!((x2.as_f64().unwrap() - 5.769596).abs() < 0.001);
+        assert!((x2_yates.as_f64().unwrap() - 4.322120).abs() < 0.001);
+        assert!((p_yates.as_f64().unwrap() - 0.037620).abs() < 0.001);
+        assert_eq!(applied.as_bool(), Some(true));
+        assert_eq!(ignored.as_bool(), Some(false));
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_g_test() {
+        let observed = vec_to_jsvalue(vec![18.0, 25.0, 32.0, 25.0]);
+        let proportions = vec_to_jsvalue(vec![0.25, 0.25, 0.25, 0.25]);
+        let counts = vec_to_jsvalue(vec![20.0, 30.0, 30.0, 20.0]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("williams_correction"),
+            &JsValue::from_bool(true),
+        );
+
+        let result1 = g_test(&observed, &proportions, &JsValue::UNDEFINED);
+        let result2 = g_test(&observed, &proportions, &options);
+        let result3 = g_test(&observed, &counts, &JsValue::UNDEFINED);
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "g") - 3.972899).abs() < 0.0001);
+        assert_eq!(get(&result1, "df"), 3.0);
+        assert!((get(&result1, "p") - 0.264406).abs() < 0.0001);
+        assert!((get(&result2, "g") - 3.940065).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.268009).abs() < 0.0001);
+        assert!((get(&result3, "g") - 2.378587).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.497633).abs() < 0.0001);
+
+        // Rounded proportions are rescaled rather than read as counts
+        let rounded = g_test(
+            &vec_to_jsvalue(vec![30.0, 50.0, 20.0]),
+            &vec_to_jsvalue(vec![0.333, 0.333, 0.333]),
+            &JsValue::UNDEFINED,
+        );
+        assert!((get(&rounded, "g") - 13.791855).abs() < 0.0001);
+        assert!((get(&rounded, "p") - 0.001012).abs() < 0.00001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_g_ind_test() {
+        let table = nested_vec_to_jsvalue(vec![vec![12.0, 5.0, 9.0], vec![7.0, 15.0, 11.0]]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("williams_correction"),
+            &JsValue::from_bool(true),
+        );
+
+        let result1 = g_ind_test(&table, &JsValue::UNDEFINED);
+        let result2 = g_ind_test(&table, &options);
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "g") - 5.931761).abs() < 0.0001);
+        assert_eq!(get(&result1, "df"), 2.0);
+        assert!((get(&result1, "p") - 0.051515).abs() < 0.0001);
+        assert!((get(&result2, "g") - 5.733573).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.056881).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_kendall_tau_test() {
+        let x = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let y = vec_to_jsvalue(vec![3.0, 1.0, 2.0, 5.0, 4.0, 7.0, 8.0, 6.0]);
+        let x_tied = vec_to_jsvalue(vec![1.0, 2.0, 2.0, 3.0, 4.0, 5.0, 5.0, 6.0]);
+        let y_tied = vec_to_jsvalue(vec![2.0, 1.0, 3.0, 3.0, 5.0, 4.0, 6.0, 6.0]);
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("continuity_correction"),
+            &JsValue::from_bool(true),
+        );
+
+        let two_sided = JsValue::from_str("two-sided");
+        let result1 = kendall_tau_test(&x, &y, &two_sided, &JsValue::UNDEFINED);
+        let result2 = kendall_tau_test(&x, &y, &JsValue::from_str("greater"), &JsValue::UNDEFINED);
+        let result3 = kendall_tau_test(&x_tied, &y_tied, &two_sided, &JsValue::UNDEFINED);
+        let result4 = kendall_tau_test(&x_tied, &y_tied, &two_sided, &options);
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "tau") - 0.642857).abs() < 0.0001);
+        assert!((get(&result1, "z") - 2.226922).abs() < 0.0001);
+        assert!((get(&result1, "p") - 0.025952).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.012976).abs() < 0.0001);
+        assert_eq!(get(&result3, "s"), 20.0);
+        assert!((get(&result3, "tau") - 0.769231).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.010748).abs() < 0.0001);
+        assert!((get(&result4, "z") - 2.423261).abs() < 0.0001);
+        assert!((get(&result4, "p") - 0.015382).abs() < 0.0001);
+        let invalid = kendall_tau_test(&x, &y, &JsValue::from_str("sideways"), &JsValue::UNDEFINED);
+        assert_eq!(
+            Reflect::get(&invalid, &JsValue::from_str("error"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Invalid test type"
+        );
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_ks_test() {
+        let normal = vec_to_jsvalue(vec![
+            0.61, -0.42, 1.35, 0.08, -1.17, 0.93, 2.10, -0.26, 0.47, -0.71,
+        ]);
+        let uniform = vec_to_jsvalue(vec![0.12, 0.35, 0.41, 0.58, 0.66, 0.73, 0.89, 0.95]);
+        let exponential = vec_to_jsvalue(vec![0.2, 0.5, 0.9, 1.4, 2.2, 3.1, 0.05, 0.7]);
+
+        let result1 = ks_test(&normal, &JsValue::from_str("normal"), &JsValue::UNDEFINED);
+        let result2 = ks_test(
+            &normal,
+            &JsValue::from_str("normal"),
+            &vec_to_jsvalue(vec![0.5, 1.0]),
+        );
+        let result3 = ks_test(&uniform, &JsValue::from_str("uniform"), &JsValue::UNDEFINED);
+        let result4 = ks_test(
+            &exponential,
+            &JsValue::from_str("exponential"),
+            &vec_to_jsvalue(vec![0.8]),
+        );
+        let result5 = ks_test(&normal, &JsValue::from_str("cauchy"), &JsValue::UNDEFINED);
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result1, "d") - 0.180822).abs() < 0.0001);
+        assert!((get(&result1, "p") - 0.864548).abs() < 0.0001);
+        assert!((get(&result2, "d") - 0.176373).abs() < 0.0001);
+        assert!((get(&result2, "p") - 0.883451).abs() < 0.0001);
+        assert!((get(&result3, "d") - 0.225).abs() < 0.0001);
+        assert!((get(&result3, "p") - 0.756961).abs() < 0.0001);
+        assert!((get(&result4, "d") - 0.111752).abs() < 0.0001);
+        assert!((get(&result4, "p") - 0.999883).abs() < 0.0001);
+        assert!(result5.is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_ks_2samp_test() {
+        let column1 = vec_to_jsvalue(vec![
+            0.61, -0.42, 1.35, 0.08, -1.17, 0.93, 2.10, -0.26, 0.47, -0.71,
+        ]);
+        let column2 = vec_to_jsvalue(vec![1.2, 0.9, 2.4, 1.8, 0.3, 1.5, 2.9, 1.1]);
+
+        let result = ks_2samp_test(&column1, &column2);
+
+        let d = Reflect::get(&result, &JsValue::from_str("d")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        assert!((d.as_f64().unwrap() - 0.575).abs() < 0.0001);
+        assert!((p.as_f64().unwrap() - 0.064221).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_dagostino_test() {
+        let data = vec_to_jsvalue(vec![
+            2.1, 2.5, 2.8, 3.0, 3.1, 3.3, 3.6, 3.8, 4.0, 4.4, 4.9, 5.3, 5.9, 6.6, 7.4, 8.5, 9.9,
+            11.8, 14.6, 19.2,
+        ]);
+
+        let result = dagostino_test(&data);
+
+        let get = |key: &str| {
+            Reflect::get(&result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get("k2") - 12.372794).abs() < 0.0001);
+        assert!((get("p") - 0.002057).abs() < 0.0001);
+        assert!((get("z_skewness") - 2.914787).abs() < 0.0001);
+        assert!((get("z_kurtosis") - 1.968962).abs() < 0.0001);
+        assert!((get("skewness") - 1.527963).abs() < 0.0001);
+        assert!((get("kurtosis") - 1.635912).abs() < 0.0001);
+        assert!(dagostino_test(&vec_to_jsvalue(vec![1.0, 2.0, 3.0])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bartlett_test() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![10.0, 20.0, 30.0, 40.0, 50.0],
+            vec![2.0, 3.0, 4.0, 5.0, 16.0],
+        ]);
+
+        let result = bartlett_test(&data);
+
+        let x2 = Reflect::get(&result, &JsValue::from_str("x2")).unwrap();
+        let df = Reflect::get(&result, &JsValue::from_str("df")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        assert!((x2.as_f64().unwrap() - 13.473500).abs() < 0.0001);
+        assert_eq!(df.as_f64(), Some(2.0));
+        assert!((p.as_f64().unwrap() - 0.001186).abs() < 0.00001);
+        assert!(bartlett_test(&nested_vec_to_jsvalue(vec![vec![1.0, 2.0]])).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_fmax_test() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![2.0, 4.0, 6.0, 8.0, 10.0],
+            vec![1.0, 3.0, 5.0, 7.0, 9.0],
+        ]);
+
+        let result = fmax_test(&data, &JsValue::UNDEFINED);
+        let strict = fmax_test(&data, &JsValue::from_f64(0.01));
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&result, "fmax") - 4.0).abs() < 1e-9);
+        assert!((get(&result, "critical") - 15.4577).abs() < 0.01);
+        assert!((get(&result, "p") - 0.408779).abs() < 0.001);
+        assert!((get(&strict, "critical") - 36.6960).abs() < 0.05);
+        assert_eq!(
+            Reflect::get(&result, &JsValue::from_str("reject"))
+                .unwrap()
+                .as_bool(),
+            Some(false)
+        );
+
+        let unequal = nested_vec_to_jsvalue(vec![vec![1.0, 2.0, 3.0], vec![1.0, 2.0]]);
+        assert!(fmax_test(&unequal, &JsValue::UNDEFINED).is_null());
+        assert!(fmax_test(&data, &JsValue::from_f64(0.0)).is_null());
+        assert!(fmax_test(&data, &JsValue::from_f64(1.0)).is_null());
+        let tiny = fmax_test(&data, &JsValue::from_f64(1e-300));
+        assert!(get(&tiny, "critical") > get(&strict, "critical"));
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_levene_test() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![10.0, 20.0, 30.0, 40.0, 50.0],
+            vec![2.0, 3.0, 4.0, 5.0, 16.0],
+        ]);
+
+        let levene = levene_test(&data, &JsValue::UNDEFINED);
+        let brown_forsythe = levene_test(&data, &JsValue::from_str("median"));
+
+        let get = |result: &JsValue, key: &str| {
+            Reflect::get(result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get(&levene, "w") - 5.663462).abs() < 0.0001);
+        assert!((get(&levene, "p") - 0.018533).abs() < 0.0001);
+        assert!((get(&brown_forsythe, "w") - 5.190776).abs() < 0.0001);
+        assert!((get(&brown_forsythe, "p") - 0.023755).abs() < 0.0001);
+        assert_eq!(get(&levene, "df1"), 2.0);
+        assert_eq!(get(&levene, "df2"), 12.0);
+        assert!(levene_test(&data, &JsValue::from_str("mode")).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_effect_size_option() {
+        let column1 = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let column2 = vec_to_jsvalue(vec![2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("effect_size"),
+            &JsValue::from_bool(true),
+        );
+
+        let result1 = one_samp_t_test(
+            &column1,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &options,
+        );
+        let result2 = two_samp_t_test(
+            &column1,
+            &column2,
+            &JsValue::from_f64(0.0),
+            &JsValue::from_str("two-sided"),
+            &options,
+        );
+        let result3 = variance_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("two-sided"),
+            &options,
+        );
+        let result4 = one_samp_t_test(
+            &column1,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &JsValue::UNDEFINED,
+        );
+
+        let d1 = Reflect::get(&result1, &JsValue::from_str("effect_size")).unwrap();
+        let ci1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let d2 = Reflect::get(&result2, &JsValue::from_str("effect_size")).unwrap();
+        let est2 = Reflect::get(&result2, &JsValue::from_str("estimate")).unwrap();
+        let ci2 = js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("ci")).unwrap());
+        let ci3 = js_array_to_vector(&Reflect::get(&result3, &JsValue::from_str("ci")).unwrap());
+        let d4 = Reflect::get(&result4, &JsValue::from_str("effect_size")).unwrap();
+
+        assert!((d1.as_f64().unwrap() - 1.8974).abs() < 0.01);
+        assert!((ci1[0] - 1.036757).abs() < 0.01);
+        assert!((ci1[1] - 4.963243).abs() < 0.01);
+        assert!((d2.as_f64().unwrap() + 0.6325).abs() < 0.01);
+        assert!((est2.as_f64().unwrap() + 1.0).abs() < 0.01);
+        assert!((ci2[0] + 3.306004).abs() < 0.01);
+        assert!((ci2[1] - 1.306004).abs() < 0.01);
+        assert!((ci3[0] - 0.1041175).abs() < 0.01);
+        assert!((ci3[1] - 9.60453).abs() < 0.01);
+        assert!(d4.is_undefined());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_alpha_option() {
+        let column1 = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let column2 = vec_to_jsvalue(vec![2.0, 2.0, 4.0, 3.0, 7.0]);
+
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("alpha"),
+            &JsValue::from_f64(0.1),
+        );
+
+        let result1 = one_samp_t_test(
+            &column1,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &options,
+        );
+        let result2 = matched_pairs_t_test(
+            &column1,
+            &column2,
+            &JsValue::from_f64(0.0),
+            &JsValue::from_str("two-sided"),
+            &options,
+        );
+        let result3 = variance_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("two-sided"),
+            &options,
+        );
+
+        let ci1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let d1 = Reflect::get(&result1, &JsValue::from_str("effect_size")).unwrap();
+        let est2 = Reflect::get(&result2, &JsValue::from_str("estimate")).unwrap();
+        let ci2 = js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("ci")).unwrap());
+        let ci3 = js_array_to_vector(&Reflect::get(&result3, &JsValue::from_str("ci")).unwrap());
+
+        assert!((ci1[0] - 1.492530).abs() < 0.001);
+        assert!((ci1[1] - 4.507470).abs() < 0.001);
+        assert!(d1.is_undefined());
+        assert!((est2.as_f64().unwrap() + 0.6).abs() < 0.001);
+        assert!((ci2[0] + 1.687033).abs() < 0.001);
+        assert!((ci2[1] - 0.487033).abs() < 0.001);
+        assert!((ci3[0] - 0.091010).abs() < 0.001);
+        assert!((ci3[1] - 3.714089).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_check_assumptions_option() {
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("check_assumptions"),
+            &JsValue::from_bool(true),
+        );
+        let warnings = |result: &JsValue| -> Vec<String> {
+            Array::from(&Reflect::get(result, &JsValue::from_str("warnings")).unwrap())
+                .iter()
+                .map(|w| w.as_string().unwrap())
+                .collect()
+        };
+
+        let skewed = vec_to_jsvalue(vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 20.0]);
+        let column1 = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let column2 = vec_to_jsvalue(vec![2.0, 2.0, 4.0, 3.0, 7.0, 8.0]);
+        let tails = JsValue::from_str("two-sided");
+        let zero = JsValue::from_f64(0.0);
+
+        let t = warnings(&one_samp_t_test(&skewed, &tails, &zero, &options));
+        let z = warnings(&one_samp_z_test(&column1, &tails, &zero, &options));
+        let paired = warnings(&matched_pairs_t_test(
+            &column1, &column2, &zero, &tails, &options,
+        ));
+        let anova = warnings(&anova_1way_test(
+            &nested_vec_to_jsvalue(vec![
+                vec![1.0, 2.0, 3.0, 4.0, 5.0],
+                vec![10.0, 20.0, 30.0, 40.0, 50.0],
+                vec![2.0, 3.0, 4.0, 5.0, 6.0],
+            ]),
+            &options,
+        ));
+        let chi2 = warnings(&chi2_ind_test(
+            &nested_vec_to_jsvalue(vec![vec![1.0, 2.0], vec![3.0, 4.0]]),
+            &options,
+        ));
+        let unchecked = one_samp_t_test(&skewed, &tails, &zero, &JsValue::UNDEFINED);
+
+        assert_eq!(t.len(), 1);
+        assert!(t[0].contains("D'Agostino-Pearson p = 0.0"));
+        assert!(t[0].contains("Wilcoxon signed-rank"));
+        assert_eq!(z.len(), 2);
+        assert!(z[0].contains("one_samp_t_test"));
+        assert!(z[1].contains("too few values (5) to check normality"));
+        assert!(paired[0].contains("different lengths (5 and 6)"));
+        assert_eq!(anova.len(), 4);
+        assert_eq!(
+            anova
+                .iter()
+                .filter(|w| w.contains("too few values (5) to check normality"))
+                .count(),
+            3
+        );
+        assert!(anova
+            .iter()
+            .any(|w| w.contains("Brown-Forsythe p = 0.0058")));
+        assert_eq!(chi2.len(), 1);
+        assert!(chi2[0].contains("4 of 4 expected counts"));
+        assert!(chi2[0].contains("fisher_exact_test"));
+        assert!(Reflect::get(&unchecked, &JsValue::from_str("warnings"))
+            .unwrap()
+            .is_undefined());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_known_sigma_option() {
+        let column1 = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let column2 = vec_to_jsvalue(vec![2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let options = Object::new();
+        for key in ["sigma", "sigma1", "sigma2"] {
+            let _ = Reflect::set(&options, &JsValue::from_str(key), &JsValue::from_f64(2.0));
+        }
+
+        let result1 = one_samp_z_test(
+            &column1,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(2.0),
+            &options,
+        );
+        let result2 = two_samp_z_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &options,
+        );
+
+        let z1 = Reflect::get(&result1, &JsValue::from_str("z")).unwrap();
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let z2 = Reflect::get(&result2, &JsValue::from_str("z")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+
+        assert!((z1.as_f64().unwrap() - 1.118034).abs() < 0.001);
+        assert!((p1.as_f64().unwrap() - 0.263552).abs() < 0.001);
+        assert!((z2.as_f64().unwrap() + 0.790569).abs() < 0.001);
+        assert!((p2.as_f64().unwrap() - 0.429195).abs() < 0.001);
+
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("sigma"),
+            &JsValue::from_f64(0.0),
+        );
+        assert!(one_samp_z_test(
+            &column1,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(2.0),
+            &options,
+        )
+        .is_null());
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("sigma2"),
+            &JsValue::from_f64(-2.0),
+        );
+        assert!(two_samp_z_test(
+            &column1,
+            &column2,
+            &JsValue::from_str("two-sided"),
+            &JsValue::from_f64(0.0),
+            &options,
+        )
+        .is_null());
     }
 }
@@ -1,6 +1,40 @@
+mod bayes;
+mod categorical;
+mod classification;
 mod conf_int;
+mod descriptive;
+mod distributions;
+mod experiments;
 mod hyp_tests;
+mod meta_analysis;
+mod multiple_comparisons;
+mod multivariate;
+mod power;
+mod regression;
+mod reliability;
+mod resampling;
+mod simulate;
+mod spc;
+mod survival;
+mod time_series;
 mod utils;
 
+pub use bayes::*;
+pub use categorical::*;
+pub use classification::*;
 pub use conf_int::*;
+pub use descriptive::*;
+pub use distributions::*;
+pub use experiments::*;
 pub use hyp_tests::*;
+pub use meta_analysis::*;
+pub use multiple_comparisons::*;
+pub use multivariate::*;
+pub use power::*;
+pub use regression::*;
+pub use reliability::*;
+pub use resampling::*;
+pub use simulate::*;
+pub use spc::*;
+pub use survival::*;
+pub use time_series::*;
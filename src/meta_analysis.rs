@@ -0,0 +1,648 @@
+use crate::conf_int::z_bounds;
+use crate::regression::ols_fit;
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+use wasm_bindgen::prelude::*;
+
+/// A study's effect estimate and its standard error.
+#[derive(Clone)]
+pub(crate) struct Study {
+    pub label: String,
+    pub effect: f64,
+    pub std_error: f64,
+}
+
+/// The result of pooling study effects by inverse-variance weighting.
+pub(crate) struct Pooled {
+    pub estimate: f64,
+    pub std_error: f64,
+    pub weights: Vec<f64>,
+    pub q: f64,
+    pub tau2: f64,
+    pub i2: f64,
+}
+
+/// Reads a JavaScript array of study objects with `effect`, `std_error`, and optional
+/// `label` properties.
+///
+/// # Returns
+///
+/// * The studies, or `None` if there are fewer than two or any has a missing effect or a
+///   non-positive standard error. Unlabeled studies are named by their position.
+pub(crate) fn read_studies(studies: &JsValue) -> Option<Vec<Study>> {
+    let studies: Option<Vec<Study>> = Array::from(studies)
+        .iter()
+        .enumerate()
+        .map(|(i, study)| {
+            let effect = get_f64_option(&study, "effect")?;
+            let std_error = get_f64_option(&study, "std_error").filter(|se| *se > 0.0)?;
+            let label =
+                get_string_option(&study, "label").unwrap_or_else(|| format!("Study {}", i + 1));
+            Some(Study {
+                label,
+                effect,
+                std_error,
+            })
+        })
+        .collect();
+    studies.filter(|studies| studies.len() >= 2)
+}
+
+/// Pools study effects with fixed-effect or DerSimonian-Laird random-effects weights.
+///
+/// # Arguments
+///
+/// * `studies` - The studies to pool.
+/// * `random` - Whether to add the between-study variance to each study's variance.
+///
+/// # Returns
+///
+/// * The pooled estimate, its standard error, the weights, Cochran's Q, τ², and I².
+pub(crate) fn pool(studies: &[Study], random: bool) -> Pooled {
+    let k = studies.len() as f64;
+    let fixed_weights: Vec<f64> = studies.iter().map(|s| 1.0 / s.std_error.powi(2)).collect();
+    let sum_w: f64 = fixed_weights.iter().sum();
+    let fixed_estimate = studies
+        .iter()
+        .zip(&fixed_weights)
+        .map(|(s, w)| w * s.effect)
+        .sum::<f64>()
+        / sum_w;
+
+    let q: f64 = studies
+        .iter()
+        .zip(&fixed_weights)
+        .map(|(s, w)| w * (s.effect - fixed_estimate).powi(2))
+        .sum();
+    let c = sum_w - fixed_weights.iter().map(|w| w * w).sum::<f64>() / sum_w;
+    let tau2 = ((q - (k - 1.0)) / c).max(0.0);
+    let i2 = if q > 0.0 {
+        ((q - (k - 1.0)) / q).max(0.0)
+    } else {
+        0.0
+    };
+
+    let weights: Vec<f64> = if random {
+        studies
+            .iter()
+            .map(|s| 1.0 / (s.std_error.powi(2) + tau2))
+            .collect()
+    } else {
+        fixed_weights
+    };
+    let total: f64 = weights.iter().sum();
+    let estimate = studies
+        .iter()
+        .zip(&weights)
+        .map(|(s, w)| w * s.effect)
+        .sum::<f64>()
+        / total;
+
+    Pooled {
+        estimate,
+        std_error: total.sqrt().recip(),
+        weights,
+        q,
+        tau2,
+        i2,
+    }
+}
+
+/// Generates the data for a forest plot of a meta-analysis.
+///
+/// # Arguments
+///
+/// * `studies` - A reference to a JsValue representing a JavaScript array of objects with
+///   the properties `effect`, `std_error`, and optionally `label`.
+/// * `options` - An optional object with the properties `model` ("random" for
+///   DerSimonian-Laird random effects or "fixed"; defaults to "random") and `alpha`
+///   (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `studies` (one row per study with `label`, `effect`,
+///   `lower`, `upper`, `weight` as a percentage, and `y`, the plotting row counting down
+///   from the number of studies), `pooled` (with `estimate`, `lower`, `upper`, and the
+///   `diamond` polygon's `x` and `y` vertices at row 0), and `heterogeneity` (with `q`,
+///   `tau2`, and `i2`), or null if there are fewer than two valid studies.
+#[wasm_bindgen]
+pub fn forest_plot_data(studies: &JsValue, options: &JsValue) -> JsValue {
+    let studies = match read_studies(studies) {
+        Some(studies) => studies,
+        None => return JsValue::NULL,
+    };
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let random = match get_string_option(options, "model").as_deref() {
+        None | Some("random") => true,
+        Some("fixed") => false,
+        _ => return JsValue::NULL,
+    };
+
+    let pooled = pool(&studies, random);
+    let total_weight: f64 = pooled.weights.iter().sum();
+    let k = studies.len();
+
+    let rows = Array::new();
+    for (i, (study, weight)) in studies.iter().zip(&pooled.weights).enumerate() {
+        let (lower, upper) = z_bounds(study.effect, study.std_error, alpha);
+        let row = Object::new();
+        let _ = Reflect::set(
+            &row,
+            &JsValue::from_str("label"),
+            &JsValue::from_str(&study.label),
+        );
+        let _ = Reflect::set(
+            &row,
+            &JsValue::from_str("effect"),
+            &JsValue::from_f64(study.effect),
+        );
+        let _ = Reflect::set(&row, &JsValue::from_str("lower"), &JsValue::from_f64(lower));
+        let _ = Reflect::set(&row, &JsValue::from_str("upper"), &JsValue::from_f64(upper));
+        let _ = Reflect::set(
+            &row,
+            &JsValue::from_str("weight"),
+            &JsValue::from_f64(100.0 * weight / total_weight),
+        );
+        let _ = Reflect::set(
+            &row,
+            &JsValue::from_str("y"),
+            &JsValue::from_f64((k - i) as f64),
+        );
+        rows.push(&row);
+    }
+
+    let (lower, upper) = z_bounds(pooled.estimate, pooled.std_error, alpha);
+    let diamond = Object::new();
+    let _ = Reflect::set(
+        &diamond,
+        &JsValue::from_str("x"),
+        &vec_to_jsvalue(vec![lower, pooled.estimate, upper, pooled.estimate]),
+    );
+    let _ = Reflect::set(
+        &diamond,
+        &JsValue::from_str("y"),
+        &vec_to_jsvalue(vec![0.0, 0.4, 0.0, -0.4]),
+    );
+    let summary = Object::new();
+    let _ = Reflect::set(
+        &summary,
+        &JsValue::from_str("estimate"),
+        &JsValue::from_f64(pooled.estimate),
+    );
+    let _ = Reflect::set(
+        &summary,
+        &JsValue::from_str("lower"),
+        &JsValue::from_f64(lower),
+    );
+    let _ = Reflect::set(
+        &summary,
+        &JsValue::from_str("upper"),
+        &JsValue::from_f64(upper),
+    );
+    let _ = Reflect::set(&summary, &JsValue::from_str("diamond"), &diamond);
+
+    let heterogeneity = Object::new();
+    let _ = Reflect::set(
+        &heterogeneity,
+        &JsValue::from_str("q"),
+        &JsValue::from_f64(pooled.q),
+    );
+    let _ = Reflect::set(
+        &heterogeneity,
+        &JsValue::from_str("tau2"),
+        &JsValue::from_f64(pooled.tau2),
+    );
+    let _ = Reflect::set(
+        &heterogeneity,
+        &JsValue::from_str("i2"),
+        &JsValue::from_f64(pooled.i2),
+    );
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("studies"), &rows);
+    let _ = Reflect::set(&obj, &JsValue::from_str("pooled"), &summary);
+    let _ = Reflect::set(&obj, &JsValue::from_str("heterogeneity"), &heterogeneity);
+    obj.into()
+}
+
+/// Performs Egger's regression test for funnel plot asymmetry, which regresses each
+/// study's standardized effect on its precision and tests whether the intercept is zero.
+///
+/// # Arguments
+///
+/// * `studies` - A reference to a JsValue representing a JavaScript array of studies.
+///
+/// # Returns
+///
+/// * An object with the properties `intercept`, `std_error`, `t`, `df`, and `p`, or null if
+///   there are fewer than three valid studies or all have the same precision.
+#[wasm_bindgen]
+pub fn egger_test(studies: &JsValue) -> JsValue {
+    let studies = match read_studies(studies) {
+        Some(studies) if studies.len() >= 3 => studies,
+        _ => return JsValue::NULL,
+    };
+    let design: Vec<Vec<f64>> = studies
+        .iter()
+        .map(|s| vec![1.0, 1.0 / s.std_error])
+        .collect();
+    let standardized: Vec<f64> = studies.iter().map(|s| s.effect / s.std_error).collect();
+    let fit = match ols_fit(&design, &standardized) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+
+    let intercept = fit.coefficients[0];
+    let std_error = fit.std_errors[0];
+    let t = intercept / std_error;
+    let p = 2.0 * (1.0 - StudentsT::new(0.0, 1.0, fit.df_resid).unwrap().cdf(t.abs()));
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("intercept"),
+        &JsValue::from_f64(intercept),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &JsValue::from_f64(std_error),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("t"), &JsValue::from_f64(t));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("df"),
+        &JsValue::from_f64(fit.df_resid),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Performs Begg and Mazumdar's rank correlation test for funnel plot asymmetry, which
+/// computes Kendall's tau between the standardized effects and their variances.
+///
+/// # Arguments
+///
+/// * `studies` - A reference to a JsValue representing a JavaScript array of studies.
+///
+/// # Returns
+///
+/// * An object with the properties `tau`, `z`, and `p` (from the normal approximation
+///   without a tie correction), or null if there are fewer than three valid studies.
+#[wasm_bindgen]
+pub fn begg_test(studies: &JsValue) -> JsValue {
+    let studies = match read_studies(studies) {
+        Some(studies) if studies.len() >= 3 => studies,
+        _ => return JsValue::NULL,
+    };
+    let pooled = pool(&studies, false);
+    let pooled_variance = pooled.std_error.powi(2);
+    let variances: Vec<f64> = studies.iter().map(|s| s.std_error.powi(2)).collect();
+    let deviates: Vec<f64> = studies
+        .iter()
+        .zip(&variances)
+        .map(|(s, v)| (s.effect - pooled.estimate) / (v - pooled_variance).sqrt())
+        .collect();
+
+    let k = studies.len();
+    let mut score = 0.0;
+    for i in 0..k {
+        for j in i + 1..k {
+            let product = (deviates[i] - deviates[j]) * (variances[i] - variances[j]);
+            if product > 0.0 {
+                score += 1.0;
+            } else if product < 0.0 {
+                score -= 1.0;
+            }
+        }
+    }
+    let k = k as f64;
+    let tau = score / (k * (k - 1.0) / 2.0);
+    let z = score / (k * (k - 1.0) * (2.0 * k + 5.0) / 18.0).sqrt();
+    let p = 2.0 * (1.0 - Normal::new(0.0, 1.0).unwrap().cdf(z.abs()));
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("tau"), &JsValue::from_f64(tau));
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Builds an object holding parallel arrays of study effects and standard errors.
+fn funnel_points(studies: &[Study]) -> JsValue {
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("effect"),
+        &vec_to_jsvalue(studies.iter().map(|s| s.effect).collect()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &vec_to_jsvalue(studies.iter().map(|s| s.std_error).collect()),
+    );
+    obj.into()
+}
+
+/// Estimates the number of studies missing from one side of a funnel plot with the
+/// iterative L0 estimator of Duval and Tweedie.
+///
+/// # Arguments
+///
+/// * `studies` - The observed studies.
+/// * `missing_left` - Whether the studies are missing from the left (so the excess is
+///   trimmed from the right).
+///
+/// # Returns
+///
+/// * The number of missing studies and the indices of the studies to trim, most extreme
+///   first.
+fn trim_and_fill(studies: &[Study], missing_left: bool) -> (usize, Vec<usize>) {
+    let k = studies.len();
+    let side = if missing_left { 1.0 } else { -1.0 };
+    let mut order: Vec<usize> = (0..k).collect();
+    order.sort_by(|a, b| (side * studies[*b].effect).total_cmp(&(side * studies[*a].effect)));
+
+    let mut k0 = 0;
+    for _ in 0..100 {
+        let kept: Vec<Study> = order[k0..].iter().map(|i| studies[*i].clone()).collect();
+        let center = pool(&kept, false).estimate;
+
+        let deviations: Vec<f64> = studies.iter().map(|s| side * (s.effect - center)).collect();
+        let ranks = average_ranks(&deviations.iter().map(|d| d.abs()).collect::<Vec<f64>>());
+        let t_n: f64 = deviations
+            .iter()
+            .zip(&ranks)
+            .filter(|(d, _)| **d > 0.0)
+            .map(|(_, r)| r)
+            .sum();
+        let l0 = (4.0 * t_n - (k * (k + 1)) as f64) / (2.0 * k as f64 - 1.0);
+        let next = (l0.round().max(0.0) as usize).min(k - 2);
+        if next == k0 {
+            break;
+        }
+        k0 = next;
+    }
+    (k0, order[..k0].to_vec())
+}
+
+/// Generates the data for a funnel plot, with pseudo-confidence contours around the
+/// fixed-effect estimate and a trim-and-fill adjustment for missing studies.
+///
+/// # Arguments
+///
+/// * `studies` - A reference to a JsValue representing a JavaScript array of studies.
+/// * `options` - An optional object with the properties `alpha` (the level of the
+///   contours and the adjusted interval; defaults to 0.05) and `side` ("left" or "right",
+///   the side on which studies are assumed missing; defaults to the side implied by the
+///   sign of Egger's intercept).
+///
+/// # Returns
+///
+/// * An object with the properties `points` (the studies' `effect` and `std_error`
+///   arrays), `estimate` (the fixed-effect estimate), `contour` (the `std_error`, `lower`,
+///   and `upper` coordinates of the two contour lines from a standard error of zero to
+///   the largest observed), and `trim_and_fill` (with `side`, `missing`, the imputed
+///   `filled` studies, and the adjusted `estimate` and `ci`), or null if there are fewer
+///   than three valid studies.
+#[wasm_bindgen]
+pub fn funnel_plot_data(studies: &JsValue, options: &JsValue) -> JsValue {
+    let list = match read_studies(studies) {
+        Some(list) if list.len() >= 3 => list,
+        _ => return JsValue::NULL,
+    };
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let missing_left = match get_string_option(options, "side").as_deref() {
+        Some("left") => true,
+        Some("right") => false,
+        None => {
+            let egger = egger_test(studies);
+            get_f64_option(&egger, "intercept").unwrap_or(0.0) >= 0.0
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let pooled = pool(&list, false);
+    let max_se = list.iter().map(|s| s.std_error).fold(0.0, f64::max);
+    let z = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let contour = Object::new();
+    let _ = Reflect::set(
+        &contour,
+        &JsValue::from_str("std_error"),
+        &vec_to_jsvalue(vec![0.0, max_se]),
+    );
+    let _ = Reflect::set(
+        &contour,
+        &JsValue::from_str("lower"),
+        &vec_to_jsvalue(vec![pooled.estimate, pooled.estimate - z * max_se]),
+    );
+    let _ = Reflect::set(
+        &contour,
+        &JsValue::from_str("upper"),
+        &vec_to_jsvalue(vec![pooled.estimate, pooled.estimate + z * max_se]),
+    );
+
+    let (missing, trimmed) = trim_and_fill(&list, missing_left);
+    let kept: Vec<Study> = (0..list.len())
+        .filter(|i| !trimmed.contains(i))
+        .map(|i| list[i].clone())
+        .collect();
+    let center = pool(&kept, false).estimate;
+    let filled: Vec<Study> = trimmed
+        .iter()
+        .map(|i| Study {
+            label: format!("Filled {}", list[*i].label),
+            effect: 2.0 * center - list[*i].effect,
+            std_error: list[*i].std_error,
+        })
+        .collect();
+    let augmented: Vec<Study> = list.iter().chain(&filled).cloned().collect();
+    let adjusted = pool(&augmented, false);
+
+    let adjustment = Object::new();
+    let _ = Reflect::set(
+        &adjustment,
+        &JsValue::from_str("side"),
+        &JsValue::from_str(if missing_left { "left" } else { "right" }),
+    );
+    let _ = Reflect::set(
+        &adjustment,
+        &JsValue::from_str("missing"),
+        &JsValue::from_f64(missing as f64),
+    );
+    let _ = Reflect::set(
+        &adjustment,
+        &JsValue::from_str("filled"),
+        &funnel_points(&filled),
+    );
+    set_estimate(
+        &adjustment,
+        adjusted.estimate,
+        z_bounds(adjusted.estimate, adjusted.std_error, alpha),
+    );
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("points"), &funnel_points(&list));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("estimate"),
+        &JsValue::from_f64(pooled.estimate),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("contour"), &contour);
+    let _ = Reflect::set(&obj, &JsValue::from_str("trim_and_fill"), &adjustment);
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn studies() -> JsValue {
+        let studies = Array::new();
+        for (effect, std_error) in [
+            (0.30, 0.12),
+            (0.10, 0.15),
+            (0.55, 0.20),
+            (0.20, 0.10),
+            (0.42, 0.18),
+        ] {
+            let study = Object::new();
+            let _ = Reflect::set(
+                &study,
+                &JsValue::from_str("effect"),
+                &JsValue::from_f64(effect),
+            );
+            let _ = Reflect::set(
+                &study,
+                &JsValue::from_str("std_error"),
+                &JsValue::from_f64(std_error),
+            );
+            studies.push(&study);
+        }
+        studies.into()
+    }
+
+    fn get_f64(obj: &JsValue, key: &str) -> f64 {
+        Reflect::get(obj, &JsValue::from_str(key))
+            .unwrap()
+            .as_f64()
+            .unwrap()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_forest_plot_data() {
+        let result = forest_plot_data(&studies(), &JsValue::UNDEFINED);
+
+        let rows = Array::from(&Reflect::get(&result, &JsValue::from_str("studies")).unwrap());
+        let pooled = Reflect::get(&result, &JsValue::from_str("pooled")).unwrap();
+        let heterogeneity = Reflect::get(&result, &JsValue::from_str("heterogeneity")).unwrap();
+        let diamond = Reflect::get(&pooled, &JsValue::from_str("diamond")).unwrap();
+        let diamond_x =
+            js_array_to_vector(&Reflect::get(&diamond, &JsValue::from_str("x")).unwrap());
+        let first = rows.get(0);
+
+        assert_eq!(rows.length(), 5);
+        assert_eq!(
+            Reflect::get(&first, &JsValue::from_str("label"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "Study 1"
+        );
+        assert_eq!(get_f64(&first, "y"), 5.0);
+        assert!((get_f64(&first, "weight") - 25.582984).abs() < 0.0001);
+        assert!((get_f64(&first, "lower") - 0.064804).abs() < 0.0001);
+        assert!((get_f64(&pooled, "estimate") - 0.271014).abs() < 0.0001);
+        assert!((get_f64(&pooled, "lower") - 0.142430).abs() < 0.0001);
+        assert!((get_f64(&pooled, "upper") - 0.399598).abs() < 0.0001);
+        assert_eq!(diamond_x[1], get_f64(&pooled, "estimate"));
+        assert!((get_f64(&heterogeneity, "q") - 4.488757).abs() < 0.0001);
+        assert!((get_f64(&heterogeneity, "tau2") - 0.002424).abs() < 0.00001);
+        assert!((get_f64(&heterogeneity, "i2") - 0.108885).abs() < 0.0001);
+    }
+
+    fn asymmetric_studies() -> JsValue {
+        let studies = Array::new();
+        for (effect, std_error) in [
+            (0.62, 0.30),
+            (0.45, 0.25),
+            (0.58, 0.28),
+            (0.30, 0.15),
+            (0.41, 0.22),
+            (0.25, 0.10),
+            (0.33, 0.18),
+            (0.21, 0.08),
+            (0.27, 0.12),
+            (0.18, 0.07),
+            (0.52, 0.26),
+        ] {
+            let study = Object::new();
+            let _ = Reflect::set(
+                &study,
+                &JsValue::from_str("effect"),
+                &JsValue::from_f64(effect),
+            );
+            let _ = Reflect::set(
+                &study,
+                &JsValue::from_str("std_error"),
+                &JsValue::from_f64(std_error),
+            );
+            studies.push(&study);
+        }
+        studies.into()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_egger_test() {
+        let result = egger_test(&asymmetric_studies());
+
+        assert!((get_f64(&result, "intercept") - 1.659899).abs() < 0.0001);
+        assert!((get_f64(&result, "std_error") - 0.094848).abs() < 0.0001);
+        assert!((get_f64(&result, "t") - 17.500616).abs() < 0.001);
+        assert_eq!(get_f64(&result, "df"), 9.0);
+        assert!(get_f64(&result, "p") < 1e-6);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_begg_test() {
+        let result = begg_test(&asymmetric_studies());
+
+        assert!((get_f64(&result, "tau") - 1.0).abs() < 1e-9);
+        assert!((get_f64(&result, "z") - 4.281744).abs() < 0.0001);
+        assert!((get_f64(&result, "p") - 0.0000185).abs() < 0.000001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_funnel_plot_data() {
+        let result = funnel_plot_data(&asymmetric_studies(), &JsValue::UNDEFINED);
+
+        let adjustment = Reflect::get(&result, &JsValue::from_str("trim_and_fill")).unwrap();
+        let filled = Reflect::get(&adjustment, &JsValue::from_str("filled")).unwrap();
+        let filled_effects =
+            js_array_to_vector(&Reflect::get(&filled, &JsValue::from_str("effect")).unwrap());
+        let contour = Reflect::get(&result, &JsValue::from_str("contour")).unwrap();
+        let upper =
+            js_array_to_vector(&Reflect::get(&contour, &JsValue::from_str("upper")).unwrap());
+
+        assert!((get_f64(&result, "estimate") - 0.256415).abs() < 0.0001);
+        assert!((upper[1] - (0.256415 + 1.959964 * 0.30)).abs() < 0.0001);
+        assert_eq!(
+            Reflect::get(&adjustment, &JsValue::from_str("side"))
+                .unwrap()
+                .as_string()
+                .unwrap(),
+            "left"
+        );
+        assert_eq!(get_f64(&adjustment, "missing"), 5.0);
+        assert!((filled_effects[0] - (-0.167780)).abs() < 0.0001);
+        assert!((get_f64(&adjustment, "estimate") - 0.226110).abs() < 0.0001);
+    }
+}
@@ -0,0 +1,728 @@
+use crate::hyp_tests::{anova_1way_test, levene_values};
+use crate::utils::*;
+use js_sys::Object;
+use js_sys::Reflect;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+use statrs::statistics::Statistics;
+use wasm_bindgen::prelude::*;
+
+/// Adjusts a set of p-values for multiple comparisons.
+///
+/// # Arguments
+///
+/// * `p_values` - The unadjusted p-values.
+/// * `method` - The adjustment method ("bonferroni", "holm", "hochberg", "bh", or "by").
+///
+/// # Returns
+///
+/// * The adjusted p-values in the original order, or `None` if the method is unknown.
+pub(crate) fn adjust_p(p_values: &[f64], method: &str) -> Option<Vec<f64>> {
+    let n = p_values.len();
+    let m = n as f64;
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+
+    let mut adjusted = vec![0.0; n];
+    match method.to_lowercase().as_str() {
+        "bonferroni" => {
+            for (adj, p) in adjusted.iter_mut().zip(p_values) {
+                *adj = (m * p).min(1.0);
+            }
+        }
+        "holm" => {
+            // Step-down: running maximum from the smallest p-value upward
+            let mut running = 0.0_f64;
+            for (rank, &i) in order.iter().enumerate() {
+                running = running.max(((m - rank as f64) * p_values[i]).min(1.0));
+                adjusted[i] = running;
+            }
+        }
+        "hochberg" | "bh" | "by" => {
+            let method = method.to_lowercase();
+            let q = if method == "by" {
+                (1..=n).map(|k| 1.0 / k as f64).sum::<f64>()
+            } else {
+                1.0
+            };
+
+            // Step-up: running minimum from the largest p-value downward
+            let mut running = 1.0_f64;
+            for (rank, &i) in order.iter().enumerate().rev() {
+                let scaled = if method == "hochberg" {
+                    (m - rank as f64) * p_values[i]
+                } else {
+                    q * m / (rank as f64 + 1.0) * p_values[i]
+                };
+                running = running.min(scaled);
+                adjusted[i] = running.min(1.0);
+            }
+        }
+        _ => return None,
+    }
+
+    Some(adjusted)
+}
+
+/// Adjusts an array of p-values for multiple comparisons.
+///
+/// # Arguments
+///
+/// * `p_array` - A reference to a JsValue representing a JavaScript array of p-values.
+/// * `method` - A reference to a JsValue naming the adjustment method ("bonferroni", "holm",
+///   "hochberg", "bh" for Benjamini-Hochberg, or "by" for Benjamini-Yekutieli).
+///
+/// # Returns
+///
+/// * A JavaScript array of adjusted p-values in the original order, or null if the method
+///   is unknown.
+#[wasm_bindgen]
+pub fn adjust_p_values(p_array: &JsValue, method: &JsValue) -> JsValue {
+    let p_values = js_array_to_vector(p_array);
+    let method = method.as_string().unwrap_or_default();
+
+    match adjust_p(&p_values, &method) {
+        Some(adjusted) => vec_to_jsvalue(adjusted),
+        None => JsValue::NULL,
+    }
+}
+
+/// Performs two-sided t-tests between every pair of groups with a multiplicity
+/// adjustment of the resulting p-values.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups.
+/// * `adjustment` - A reference to a JsValue naming the p-value adjustment method (see
+///   `adjust_p_values`; defaults to "holm").
+/// * `pooled` - A reference to a JsValue indicating whether to use the pooled standard
+///   deviation of all groups (defaults to true). Otherwise, Welch t-tests are used.
+///
+/// # Returns
+///
+/// * An object with three k-by-k matrices: `p` (raw p-values), `p_adjusted`, and
+///   `mean_diff` (row group mean minus column group mean). Diagonal p-values are 1, and
+///   a pair's p-values are NaN when it has no standard error, as for two constant groups.
+///   Returns null if there are fewer than two groups or the method is unknown.
+#[wasm_bindgen]
+pub fn pairwise_t_tests(data: &JsValue, adjustment: &JsValue, pooled: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let method = adjustment.as_string().unwrap_or("holm".to_string());
+    let pooled = pooled.as_bool().unwrap_or(true);
+
+    let k = groups.len();
+    if k < 2 || groups.iter().any(|group| group.len() < 2) {
+        return JsValue::NULL;
+    }
+
+    let means: Vec<f64> = groups.iter().map(|group| group.mean()).collect();
+    let variances: Vec<f64> = groups.iter().map(|group| group.variance()).collect();
+    let sizes: Vec<f64> = groups.iter().map(|group| group.len() as f64).collect();
+
+    let df_pooled = sizes.iter().sum::<f64>() - k as f64;
+    let var_pooled = sizes
+        .iter()
+        .zip(&variances)
+        .map(|(n, v)| (n - 1.0) * v)
+        .sum::<f64>()
+        / df_pooled;
+
+    let mut pairs = Vec::new();
+    let mut raw = Vec::new();
+    for i in 0..k {
+        for j in (i + 1)..k {
+            let diff = means[i] - means[j];
+            let (se, df) = if pooled {
+                (
+                    (var_pooled * (1.0 / sizes[i] + 1.0 / sizes[j])).sqrt(),
+                    df_pooled,
+                )
+            } else {
+                let a = variances[i] / sizes[i];
+                let b = variances[j] / sizes[j];
+                (
+                    (a + b).sqrt(),
+                    (a + b).powi(2) / (a.powi(2) / (sizes[i] - 1.0) + b.powi(2) / (sizes[j] - 1.0)),
+                )
+            };
+
+            // Two constant groups leave no standard error and, for Welch, no df
+            pairs.push((i, j));
+            raw.push(match StudentsT::new(0.0, 1.0, df) {
+                Ok(dist) if se > 0.0 && df.is_finite() => 2.0 * (1.0 - dist.cdf((diff / se).abs())),
+                _ => f64::NAN,
+            });
+        }
+    }
+
+    // NaN p-values sort last, so they leave the other adjustments unchanged
+    let adjusted = match adjust_p(&raw, &method) {
+        Some(adjusted) => adjusted
+            .iter()
+            .zip(&raw)
+            .map(|(adj, p)| if p.is_nan() { f64::NAN } else { *adj })
+            .collect::<Vec<f64>>(),
+        None => return JsValue::NULL,
+    };
+
+    let mut p_matrix = vec![vec![1.0; k]; k];
+    let mut adj_matrix = vec![vec![1.0; k]; k];
+    let mut diff_matrix = vec![vec![0.0; k]; k];
+    for (idx, &(i, j)) in pairs.iter().enumerate() {
+        p_matrix[i][j] = raw[idx];
+        p_matrix[j][i] = raw[idx];
+        adj_matrix[i][j] = adjusted[idx];
+        adj_matrix[j][i] = adjusted[idx];
+        diff_matrix[i][j] = means[i] - means[j];
+        diff_matrix[j][i] = means[j] - means[i];
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p"),
+        &nested_vec_to_jsvalue(p_matrix),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p_adjusted"),
+        &nested_vec_to_jsvalue(adj_matrix),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("mean_diff"),
+        &nested_vec_to_jsvalue(diff_matrix),
+    );
+    obj.into()
+}
+
+/// Performs Dunn's test, comparing the mean ranks of every pair of groups from a pooled
+/// ranking, as a post-hoc procedure after a Kruskal-Wallis test.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups.
+/// * `adjustment` - A reference to a JsValue naming the p-value adjustment method (see
+///   `adjust_p_values`; defaults to "holm").
+///
+/// # Returns
+///
+/// * An object with three k-by-k matrices, `z` (row group mean rank minus column group
+///   mean rank, standardized), `p` (raw two-sided p-values), and `p_adjusted`, along with
+///   the `mean_ranks` of the groups. The standard errors are corrected for ties. Diagonal
+///   p-values are 1. Returns null if there are fewer than two groups, a group is empty,
+///   every value is tied, or the method is unknown.
+#[wasm_bindgen]
+pub fn dunn_test(data: &JsValue, adjustment: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let method = adjustment.as_string().unwrap_or("holm".to_string());
+
+    let k = groups.len();
+    if k < 2 || groups.iter().any(|group| group.is_empty()) {
+        return JsValue::NULL;
+    }
+
+    let pooled = groups.concat();
+    let ranks = average_ranks(&pooled);
+    let n = pooled.len() as f64;
+    let sizes: Vec<f64> = groups.iter().map(|group| group.len() as f64).collect();
+    let mut offset = 0;
+    let mean_ranks: Vec<f64> = groups
+        .iter()
+        .map(|group| {
+            let sum = ranks[offset..offset + group.len()].iter().sum::<f64>();
+            offset += group.len();
+            sum / group.len() as f64
+        })
+        .collect();
+
+    // Tie correction from the sizes of the groups of tied values
+    let mut sorted = pooled.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let ties = sorted
+        .chunk_by(|a, b| a == b)
+        .map(|tied| (tied.len() as f64).powi(3) - tied.len() as f64)
+        .sum::<f64>();
+    let variance = n * (n + 1.0) / 12.0 - ties / (12.0 * (n - 1.0));
+    if variance <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let mut pairs = Vec::new();
+    let mut z_values = Vec::new();
+    let mut raw = Vec::new();
+    for i in 0..k {
+        for j in (i + 1)..k {
+            let se = (variance * (1.0 / sizes[i] + 1.0 / sizes[j])).sqrt();
+            let z = (mean_ranks[i] - mean_ranks[j]) / se;
+            pairs.push((i, j));
+            z_values.push(z);
+            raw.push(2.0 * (1.0 - normal.cdf(z.abs())));
+        }
+    }
+
+    let adjusted = match adjust_p(&raw, &method) {
+        Some(adjusted) => adjusted,
+        None => return JsValue::NULL,
+    };
+
+    let mut z_matrix = vec![vec![0.0; k]; k];
+    let mut p_matrix = vec![vec![1.0; k]; k];
+    let mut adj_matrix = vec![vec![1.0; k]; k];
+    for (idx, &(i, j)) in pairs.iter().enumerate() {
+        z_matrix[i][j] = z_values[idx];
+        z_matrix[j][i] = -z_values[idx];
+        p_matrix[i][j] = raw[idx];
+        p_matrix[j][i] = raw[idx];
+        adj_matrix[i][j] = adjusted[idx];
+        adj_matrix[j][i] = adjusted[idx];
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("z"),
+        &nested_vec_to_jsvalue(z_matrix),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p"),
+        &nested_vec_to_jsvalue(p_matrix),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p_adjusted"),
+        &nested_vec_to_jsvalue(adj_matrix),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("mean_ranks"),
+        &vec_to_jsvalue(mean_ranks),
+    );
+    obj.into()
+}
+
+/// Runs a one-way ANOVA together with assumption checks and, if the omnibus test is
+/// significant, a post-hoc procedure.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups.
+/// * `options` - An optional object with the properties `alpha` (defaults to 0.05),
+///   `posthoc` ("pairwise_t" or "none"; defaults to "pairwise_t"), and `adjustment`
+///   (the p-value adjustment method; defaults to "holm").
+///
+/// # Returns
+///
+/// * An object with the properties `anova` (`f` and `p`), `assumptions` (the
+///   Brown-Forsythe `levene` test, `equal_variances`, and `balanced`), `significant`,
+///   and `posthoc`. Pairwise t-tests use the pooled standard deviation only when the
+///   equal-variance check passes, and `posthoc` is null when the ANOVA is not
+///   significant. Returns null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn anova_with_posthoc(data: &JsValue, options: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let posthoc = get_string_option(options, "posthoc").unwrap_or("pairwise_t".to_string());
+    let adjustment = get_string_option(options, "adjustment").unwrap_or("holm".to_string());
+
+    if groups.len() < 2 || groups.iter().any(|group| group.len() < 2) {
+        return JsValue::NULL;
+    }
+    if posthoc != "pairwise_t" && posthoc != "none" {
+        return JsValue::NULL;
+    }
+
+    let anova = anova_1way_test(data, &JsValue::UNDEFINED);
+    let anova_p = Reflect::get(&anova, &JsValue::from_str("p"))
+        .ok()
+        .and_then(|p| p.as_f64())
+        .unwrap_or(f64::NAN);
+    let significant = anova_p < alpha;
+
+    let (w, df1, df2, levene_p) = match levene_values(&groups, "median") {
+        Some(values) => values,
+        None => return JsValue::NULL,
+    };
+    let equal_variances = levene_p >= alpha || levene_p.is_nan();
+    let balanced = groups.iter().all(|group| group.len() == groups[0].len());
+
+    let levene = Object::new();
+    let _ = Reflect::set(&levene, &JsValue::from_str("w"), &JsValue::from_f64(w));
+    let _ = Reflect::set(&levene, &JsValue::from_str("df1"), &JsValue::from_f64(df1));
+    let _ = Reflect::set(&levene, &JsValue::from_str("df2"), &JsValue::from_f64(df2));
+    let _ = Reflect::set(
+        &levene,
+        &JsValue::from_str("p"),
+        &JsValue::from_f64(levene_p),
+    );
+
+    let assumptions = Object::new();
+    let _ = Reflect::set(&assumptions, &JsValue::from_str("levene"), &levene);
+    let _ = Reflect::set(
+        &assumptions,
+        &JsValue::from_str("equal_variances"),
+        &JsValue::from_bool(equal_variances),
+    );
+    let _ = Reflect::set(
+        &assumptions,
+        &JsValue::from_str("balanced"),
+        &JsValue::from_bool(balanced),
+    );
+
+    let posthoc_result = if significant && posthoc == "pairwise_t" {
+        let result = pairwise_t_tests(
+            data,
+            &JsValue::from_str(&adjustment),
+            &JsValue::from_bool(equal_variances),
+        );
+        if result.is_null() {
+            return JsValue::NULL;
+        }
+        let _ = Reflect::set(
+            &result,
+            &JsValue::from_str("method"),
+            &JsValue::from_str("pairwise_t"),
+        );
+        let _ = Reflect::set(
+            &result,
+            &JsValue::from_str("adjustment"),
+            &JsValue::from_str(&adjustment),
+        );
+        let _ = Reflect::set(
+            &result,
+            &JsValue::from_str("pooled"),
+            &JsValue::from_bool(equal_variances),
+        );
+        result
+    } else {
+        JsValue::NULL
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("anova"), &anova);
+    let _ = Reflect::set(&obj, &JsValue::from_str("assumptions"), &assumptions);
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("significant"),
+        &JsValue::from_bool(significant),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("posthoc"), &posthoc_result);
+    obj.into()
+}
+
+/// Performs Scheffé tests and simultaneous confidence intervals for arbitrary linear
+/// contrasts of group means.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of groups.
+/// * `contrasts` - A reference to a JsValue representing a JavaScript array of contrasts,
+///   each an array with one coefficient per group. Coefficients should sum to zero.
+/// * `alpha` - A reference to a JsValue representing the family-wise significance level
+///   (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `se`, `f`, and `p` (one entry per contrast),
+///   `ci` (the simultaneous intervals), `df1`, `df2`, `mse`, and `critical_value` (the
+///   Scheffé multiplier). Returns null if the inputs are invalid, including when there
+///   are no more observations than groups, which leaves no degrees of freedom for error.
+#[wasm_bindgen]
+pub fn scheffe_test(data: &JsValue, contrasts: &JsValue, alpha: &JsValue) -> JsValue {
+    let groups: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let contrasts: Vec<Vec<f64>> = js_nested_array_to_vector(contrasts)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+
+    let k = groups.len();
+    if k < 2
+        || groups.iter().any(|group| group.is_empty())
+        || groups.iter().map(|group| group.len()).sum::<usize>() <= k
+        || contrasts.is_empty()
+        || contrasts.iter().any(|c| c.len() != k)
+    {
+        return JsValue::NULL;
+    }
+
+    let means: Vec<f64> = groups.iter().map(|group| group.mean()).collect();
+    let sizes: Vec<f64> = groups.iter().map(|group| group.len() as f64).collect();
+
+    let df1 = k as f64 - 1.0;
+    let df2 = sizes.iter().sum::<f64>() - k as f64;
+    let sse = groups
+        .iter()
+        .zip(&means)
+        .map(|(group, m)| group.iter().map(|x| (x - m).powi(2)).sum::<f64>())
+        .sum::<f64>();
+    let mse = sse / df2;
+
+    let dist = FisherSnedecor::new(df1, df2).unwrap();
+    let critical_value = (df1 * dist.inverse_cdf(1.0 - alpha)).sqrt();
+
+    let mut estimates = Vec::new();
+    let mut std_errors = Vec::new();
+    let mut f_values = Vec::new();
+    let mut p_values = Vec::new();
+    let mut intervals = Vec::new();
+    for c in &contrasts {
+        let estimate = c.iter().zip(&means).map(|(ci, m)| ci * m).sum::<f64>();
+        let se = (mse
+            * c.iter()
+                .zip(&sizes)
+                .map(|(ci, n)| ci.powi(2) / n)
+                .sum::<f64>())
+        .sqrt();
+        let f = estimate.powi(2) / (df1 * se.powi(2));
+
+        estimates.push(estimate);
+        std_errors.push(se);
+        f_values.push(f);
+        p_values.push(1.0 - dist.cdf(f));
+        intervals.push(vec![
+            estimate - critical_value * se,
+            estimate + critical_value * se,
+        ]);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("estimate"),
+        &vec_to_jsvalue(estimates),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("se"), &vec_to_jsvalue(std_errors));
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &vec_to_jsvalue(f_values));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &vec_to_jsvalue(p_values));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("ci"),
+        &nested_vec_to_jsvalue(intervals),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("df1"), &JsValue::from_f64(df1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df2"), &JsValue::from_f64(df2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("mse"), &JsValue::from_f64(mse));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("critical_value"),
+        &JsValue::from_f64(critical_value),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_adjust_p_values() {
+        let p = vec_to_jsvalue(vec![0.01, 0.04, 0.03, 0.005]);
+
+        let bonferroni = js_array_to_vector(&adjust_p_values(&p, &JsValue::from_str("bonferroni")));
+        let holm = js_array_to_vector(&adjust_p_values(&p, &JsValue::from_str("holm")));
+        let hochberg = js_array_to_vector(&adjust_p_values(&p, &JsValue::from_str("hochberg")));
+        let bh = js_array_to_vector(&adjust_p_values(&p, &JsValue::from_str("bh")));
+        let by = js_array_to_vector(&adjust_p_values(&p, &JsValue::from_str("by")));
+        let invalid = adjust_p_values(&p, &JsValue::from_str("sidak"));
+
+        let expected = [
+            (bonferroni, [0.04, 0.16, 0.12, 0.02]),
+            (holm, [0.03, 0.06, 0.06, 0.02]),
+            (hochberg, [0.03, 0.04, 0.04, 0.02]),
+            (bh, [0.02, 0.04, 0.04, 0.02]),
+            (by, [0.041667, 0.083333, 0.083333, 0.041667]),
+        ];
+        for (actual, wanted) in expected.iter() {
+            for (a, w) in actual.iter().zip(wanted.iter()) {
+                assert!((a - w).abs() < 0.0001, "Expected {}, got {}", w, a);
+            }
+        }
+        assert!(invalid.is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_pairwise_t_tests() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![2.0, 3.0, 4.0, 5.0, 6.0],
+            vec![5.0, 6.0, 7.0, 8.0, 9.0],
+        ]);
+
+        let result = pairwise_t_tests(&data, &JsValue::from_str("holm"), &JsValue::from_bool(true));
+
+        let p: Vec<Vec<f64>> =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("p")).unwrap())
+                .iter()
+                .map(js_array_to_vector)
+                .collect();
+        let p_adj: Vec<Vec<f64>> = js_nested_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("p_adjusted")).unwrap(),
+        )
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+        let diff: Vec<Vec<f64>> = js_nested_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("mean_diff")).unwrap(),
+        )
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+
+        assert!((p[0][1] - 0.33705).abs() < 0.0001);
+        assert!((p[0][2] - 0.0017617).abs() < 0.0001);
+        assert!((p[2][1] - 0.011067).abs() < 0.0001);
+        assert!((p_adj[0][1] - 0.33705).abs() < 0.0001);
+        assert!((p_adj[0][2] - 0.0052851).abs() < 0.0001);
+        assert!((p_adj[1][2] - 0.022133).abs() < 0.0001);
+        assert!((diff[0][2] + 4.0).abs() < 1e-9);
+        assert!((diff[2][0] - 4.0).abs() < 1e-9);
+
+        let constant = nested_vec_to_jsvalue(vec![
+            vec![1.0, 1.0, 1.0],
+            vec![2.0, 2.0, 2.0],
+            vec![1.0, 2.0, 3.0],
+        ]);
+        let welch = pairwise_t_tests(
+            &constant,
+            &JsValue::from_str("holm"),
+            &JsValue::from_bool(false),
+        );
+        let matrix = |key: &str| -> Vec<Vec<f64>> {
+            js_nested_array_to_vector(&Reflect::get(&welch, &JsValue::from_str(key)).unwrap())
+                .iter()
+                .map(js_array_to_vector)
+                .collect()
+        };
+        assert!(matrix("p")[0][1].is_nan());
+        assert!(matrix("p_adjusted")[0][1].is_nan());
+        assert!((matrix("p")[0][2] - 0.225403).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_dunn_test() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![2.9, 3.0, 2.5, 2.6, 3.2],
+            vec![3.8, 2.7, 4.0, 2.4],
+            vec![2.8, 3.4, 3.7, 2.2, 2.0],
+            vec![4.1, 4.3, 3.9, 4.0, 4.5],
+        ]);
+
+        let holm = dunn_test(&data, &JsValue::UNDEFINED);
+        let bh = dunn_test(&data, &JsValue::from_str("bh"));
+
+        let matrix = |result: &JsValue, key: &str| -> Vec<Vec<f64>> {
+            js_nested_array_to_vector(&Reflect::get(result, &JsValue::from_str(key)).unwrap())
+                .iter()
+                .map(js_array_to_vector)
+                .collect()
+        };
+        let z = matrix(&holm, "z");
+        let p = matrix(&holm, "p");
+        let p_holm = matrix(&holm, "p_adjusted");
+        let p_bh = matrix(&bh, "p_adjusted");
+        let mean_ranks =
+            js_array_to_vector(&Reflect::get(&holm, &JsValue::from_str("mean_ranks")).unwrap());
+
+        assert!((mean_ranks[1] - 9.375).abs() < 1e-9);
+        assert!((mean_ranks[3] - 16.7).abs() < 1e-9);
+        assert!((z[0][3] + 2.670441).abs() < 0.0001);
+        assert!((z[3][2] - 2.839101).abs() < 0.0001);
+        assert!((p[0][1] - 0.564328).abs() < 0.0001);
+        assert!((p[1][3] - 0.052223).abs() < 0.0001);
+        assert!((p_holm[0][3] - 0.037876).abs() < 0.0001);
+        assert!((p_holm[2][3] - 0.027145).abs() < 0.0001);
+        assert!((p_bh[0][1] - 0.677194).abs() < 0.0001);
+        assert!((p_bh[2][3] - 0.022726).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_anova_with_posthoc() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![2.0, 3.0, 4.0, 5.0, 6.0],
+            vec![5.0, 6.0, 7.0, 8.0, 9.0],
+        ]);
+        let data2 = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![2.0, 3.0, 4.0, 5.0, 6.0],
+        ]);
+
+        let result = anova_with_posthoc(&data, &JsValue::UNDEFINED);
+        let result2 = anova_with_posthoc(&data2, &JsValue::UNDEFINED);
+
+        let anova = Reflect::get(&result, &JsValue::from_str("anova")).unwrap();
+        let f = Reflect::get(&anova, &JsValue::from_str("f")).unwrap();
+        let p = Reflect::get(&anova, &JsValue::from_str("p")).unwrap();
+        let assumptions = Reflect::get(&result, &JsValue::from_str("assumptions")).unwrap();
+        let equal_variances =
+            Reflect::get(&assumptions, &JsValue::from_str("equal_variances")).unwrap();
+        let posthoc = Reflect::get(&result, &JsValue::from_str("posthoc")).unwrap();
+        let p_adj: Vec<Vec<f64>> = js_nested_array_to_vector(
+            &Reflect::get(&posthoc, &JsValue::from_str("p_adjusted")).unwrap(),
+        )
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+        let posthoc2 = Reflect::get(&result2, &JsValue::from_str("posthoc")).unwrap();
+
+        assert!((f.as_f64().unwrap() - 8.6667).abs() < 0.01);
+        assert!((p.as_f64().unwrap() - 0.004687).abs() < 0.001);
+        assert_eq!(equal_variances.as_bool(), Some(true));
+        assert!((p_adj[0][2] - 0.0052851).abs() < 0.0001);
+        assert!(posthoc2.is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_scheffe_test() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0],
+            vec![2.0, 3.0, 4.0, 5.0, 6.0],
+            vec![5.0, 6.0, 7.0, 8.0, 9.0],
+        ]);
+        let contrasts = nested_vec_to_jsvalue(vec![vec![1.0, -0.5, -0.5], vec![1.0, -1.0, 0.0]]);
+
+        let result = scheffe_test(&data, &contrasts, &JsValue::from_f64(0.05));
+
+        let estimate =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("estimate")).unwrap());
+        let f = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("f")).unwrap());
+        let p = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("p")).unwrap());
+        let ci: Vec<Vec<f64>> =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap())
+                .iter()
+                .map(js_array_to_vector)
+                .collect();
+
+        assert!((estimate[0] + 2.5).abs() < 1e-9);
+        assert!((f[0] - 4.1667).abs() < 0.001);
+        assert!((p[0] - 0.042251).abs() < 0.0001);
+        assert!((p[1] - 0.61862).abs() < 0.0001);
+        assert!((ci[0][0] + 4.91411).abs() < 0.001);
+        assert!((ci[0][1] + 0.08589).abs() < 0.001);
+        assert!((ci[1][0] + 3.78758).abs() < 0.001);
+        assert!((ci[1][1] - 1.78758).abs() < 0.001);
+        let singletons = nested_vec_to_jsvalue(vec![vec![1.0], vec![2.0], vec![4.0]]);
+        assert!(scheffe_test(&singletons, &contrasts, &JsValue::UNDEFINED).is_null());
+    }
+}
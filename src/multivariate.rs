@@ -0,0 +1,618 @@
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use rand::seq::SliceRandom;
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+use wasm_bindgen::prelude::*;
+
+/// Computes the Pearson correlation matrix of a set of equal-length columns.
+///
+/// # Returns
+///
+/// * The correlation matrix, or `None` if there are fewer than two columns, fewer than
+///   three observations, the columns differ in length, or a column is constant.
+fn correlation_matrix(columns: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = columns.first()?.len();
+    if columns.len() < 2 || n < 3 || columns.iter().any(|col| col.len() != n) {
+        return None;
+    }
+    let centered: Vec<Vec<f64>> = columns
+        .iter()
+        .map(|col| {
+            let mean = col.iter().sum::<f64>() / n as f64;
+            col.iter().map(|v| v - mean).collect()
+        })
+        .collect();
+    let norms: Vec<f64> = centered
+        .iter()
+        .map(|col| col.iter().map(|v| v * v).sum::<f64>().sqrt())
+        .collect();
+    if norms.contains(&0.0) {
+        return None;
+    }
+    Some(
+        (0..columns.len())
+            .map(|i| {
+                (0..columns.len())
+                    .map(|j| {
+                        centered[i]
+                            .iter()
+                            .zip(&centered[j])
+                            .map(|(a, b)| a * b)
+                            .sum::<f64>()
+                            / (norms[i] * norms[j])
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Computes the determinant of a square matrix by Gaussian elimination with partial
+/// pivoting.
+fn determinant(matrix: &[Vec<f64>]) -> f64 {
+    let mut a = matrix.to_vec();
+    let n = a.len();
+    let mut det = 1.0;
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|x, y| a[*x][col].abs().total_cmp(&a[*y][col].abs()))
+            .unwrap();
+        if a[pivot][col] == 0.0 {
+            return 0.0;
+        }
+        if pivot != col {
+            a.swap(pivot, col);
+            det = -det;
+        }
+        det *= a[col][col];
+        let pivot_row = a[col].clone();
+        for row in a.iter_mut().skip(col + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    det
+}
+
+/// Reads a JavaScript array of columns into a vector of columns.
+fn read_columns(columns: &JsValue) -> Vec<Vec<f64>> {
+    js_nested_array_to_vector(columns)
+        .iter()
+        .map(js_array_to_vector)
+        .collect()
+}
+
+/// Performs Bartlett's test of sphericity, which tests whether the correlation matrix of a
+/// set of variables is an identity matrix.
+///
+/// # Arguments
+///
+/// * `columns` - A reference to a JsValue representing a JavaScript array of variable
+///   columns.
+///
+/// # Returns
+///
+/// * An object with the properties `x2`, `df`, `p`, and `determinant` (of the correlation
+///   matrix), or null if the inputs are invalid or the correlation matrix is singular.
+#[wasm_bindgen]
+pub fn bartlett_sphericity(columns: &JsValue) -> JsValue {
+    let columns = read_columns(columns);
+    let r = match correlation_matrix(&columns) {
+        Some(r) => r,
+        None => return JsValue::NULL,
+    };
+    let det = determinant(&r);
+    if det <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    let n = columns[0].len() as f64;
+    let p = columns.len() as f64;
+    let x2 = -(n - 1.0 - (2.0 * p + 5.0) / 6.0) * det.ln();
+    let df = p * (p - 1.0) / 2.0;
+    let p_value = 1.0 - ChiSquared::new(df).unwrap().cdf(x2);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p_value));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("determinant"),
+        &JsValue::from_f64(det),
+    );
+    obj.into()
+}
+
+/// Performs Mauchly's test of sphericity for a repeated-measures design, along with the
+/// Greenhouse-Geisser and Huynh-Feldt corrections to the degrees of freedom.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of condition
+///   columns, each holding one measurement per subject in the same subject order.
+///
+/// # Returns
+///
+/// * An object with the properties `w`, `x2`, `df`, `p`, `gg_epsilon`, and `hf_epsilon`
+///   (capped at 1), or null if there are fewer than three conditions, the columns differ
+///   in length, or there are too few subjects for the contrast covariance to be
+///   nonsingular.
+#[wasm_bindgen]
+pub fn mauchly_test(data: &JsValue) -> JsValue {
+    let columns = read_columns(data);
+    let k = columns.len();
+    let n = columns.first().map_or(0, |col| col.len());
+    if k < 3 || n < k || columns.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    // Normalized Helmert contrasts project the conditions onto k - 1 orthonormal
+    // within-subject differences
+    let contrasts: Vec<Vec<f64>> = (1..k)
+        .map(|r| {
+            let norm = ((r * (r + 1)) as f64).sqrt();
+            (0..k)
+                .map(|c| match c.cmp(&r) {
+                    std::cmp::Ordering::Less => 1.0 / norm,
+                    std::cmp::Ordering::Equal => -(r as f64) / norm,
+                    std::cmp::Ordering::Greater => 0.0,
+                })
+                .collect()
+        })
+        .collect();
+    let scores: Vec<Vec<f64>> = contrasts
+        .iter()
+        .map(|weights| {
+            (0..n)
+                .map(|t| {
+                    weights
+                        .iter()
+                        .zip(&columns)
+                        .map(|(w, col)| w * col[t])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect();
+    let centered: Vec<Vec<f64>> = scores
+        .iter()
+        .map(|col| {
+            let mean = col.iter().sum::<f64>() / n as f64;
+            col.iter().map(|v| v - mean).collect()
+        })
+        .collect();
+    let covariance: Vec<Vec<f64>> = centered
+        .iter()
+        .map(|a| {
+            centered
+                .iter()
+                .map(|b| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>() / (n as f64 - 1.0))
+                .collect()
+        })
+        .collect();
+
+    let p = (k - 1) as f64;
+    let trace = (0..k - 1).map(|i| covariance[i][i]).sum::<f64>();
+    let trace_squared = covariance.iter().flatten().map(|v| v * v).sum::<f64>();
+    let det = determinant(&covariance);
+    if det <= 0.0 || trace <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    let n_f = n as f64;
+    let w = det / (trace / p).powf(p);
+    let correction = 1.0 - (2.0 * p * p + p + 2.0) / (6.0 * p * (n_f - 1.0));
+    let x2 = -(n_f - 1.0) * correction * w.ln();
+    let df = p * (p + 1.0) / 2.0 - 1.0;
+    let p_value = 1.0 - ChiSquared::new(df).unwrap().cdf(x2);
+
+    let gg_epsilon = trace.powi(2) / (p * trace_squared);
+    let hf_epsilon = ((n_f * p * gg_epsilon - 2.0) / (p * (n_f - 1.0 - p * gg_epsilon))).min(1.0);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("w"), &JsValue::from_f64(w));
+    let _ = Reflect::set(&obj, &JsValue::from_str("x2"), &JsValue::from_f64(x2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p_value));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("gg_epsilon"),
+        &JsValue::from_f64(gg_epsilon),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("hf_epsilon"),
+        &JsValue::from_f64(hf_epsilon),
+    );
+    obj.into()
+}
+
+/// Computes the Kaiser-Meyer-Olkin measure of sampling adequacy, which compares the
+/// correlations between variables to their partial correlations.
+///
+/// # Arguments
+///
+/// * `columns` - A reference to a JsValue representing a JavaScript array of variable
+///   columns.
+///
+/// # Returns
+///
+/// * An object with the properties `kmo` (the overall measure) and `msa` (the measure for
+///   each variable), or null if the inputs are invalid or the correlation matrix is
+///   singular.
+#[wasm_bindgen]
+pub fn kmo(columns: &JsValue) -> JsValue {
+    let columns = read_columns(columns);
+    let r = match correlation_matrix(&columns) {
+        Some(r) => r,
+        None => return JsValue::NULL,
+    };
+    let r_inv = match invert_matrix(&r) {
+        Some(r_inv) => r_inv,
+        None => return JsValue::NULL,
+    };
+
+    let p = r.len();
+    let mut total_r2 = 0.0;
+    let mut total_a2 = 0.0;
+    let msa: Vec<f64> = (0..p)
+        .map(|i| {
+            let mut r2 = 0.0;
+            let mut a2 = 0.0;
+            for j in (0..p).filter(|j| *j != i) {
+                let partial = -r_inv[i][j] / (r_inv[i][i] * r_inv[j][j]).sqrt();
+                r2 += r[i][j].powi(2);
+                a2 += partial.powi(2);
+            }
+            total_r2 += r2;
+            total_a2 += a2;
+            r2 / (r2 + a2)
+        })
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("kmo"),
+        &JsValue::from_f64(total_r2 / (total_r2 + total_a2)),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("msa"), &vec_to_jsvalue(msa));
+    obj.into()
+}
+
+/// Reads a sample as a list of points from either a flat array of univariate values or a
+/// JavaScript array of variable columns.
+fn read_points(sample: &JsValue) -> Option<Vec<Vec<f64>>> {
+    let array = Array::from(sample);
+    if array.length() == 0 {
+        return None;
+    }
+    if !Array::is_array(&array.get(0)) {
+        return Some(
+            js_array_to_vector(sample)
+                .into_iter()
+                .map(|v| vec![v])
+                .collect(),
+        );
+    }
+    let columns = read_columns(sample);
+    let n = columns[0].len();
+    if n == 0 || columns.iter().any(|col| col.len() != n) {
+        return None;
+    }
+    Some(
+        (0..n)
+            .map(|i| columns.iter().map(|col| col[i]).collect())
+            .collect(),
+    )
+}
+
+/// Computes the matrix of Euclidean distances between a list of points.
+fn distance_matrix(points: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    points
+        .iter()
+        .map(|a| {
+            points
+                .iter()
+                .map(|b| {
+                    a.iter()
+                        .zip(b)
+                        .map(|(u, v)| (u - v).powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Double-centers a distance matrix by subtracting its row and column means and adding
+/// back its grand mean.
+fn double_center(distances: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = distances.len() as f64;
+    let row_means: Vec<f64> = distances
+        .iter()
+        .map(|row| row.iter().sum::<f64>() / n)
+        .collect();
+    let grand_mean = row_means.iter().sum::<f64>() / n;
+    distances
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, d)| d - row_means[i] - row_means[j] + grand_mean)
+                .collect()
+        })
+        .collect()
+}
+
+/// Computes the squared sample distance covariance of two double-centered distance
+/// matrices, with the rows and columns of the second indexed through `order`.
+fn distance_covariance_sq(a: &[Vec<f64>], b: &[Vec<f64>], order: &[usize]) -> f64 {
+    let n = a.len() as f64;
+    a.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, value)| value * b[order[i]][order[j]])
+                .sum::<f64>()
+        })
+        .sum::<f64>()
+        / (n * n)
+}
+
+/// Tests for dependence between two samples of paired observations using distance
+/// correlation, which is zero only under independence and so detects nonlinear
+/// associations.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the first sample, either a flat array or
+///   a JavaScript array of variable columns.
+/// * `y` - A reference to a JsValue representing the second sample, in the same layout.
+/// * `options` - An optional object with the properties `permutations` (defaults to 999)
+///   and `seed`.
+///
+/// # Returns
+///
+/// * An object with the properties `dcor`, `dcov`, and `p` (from permuting the pairing of
+///   the samples), or null if the samples are invalid or differ in size.
+#[wasm_bindgen]
+pub fn distance_correlation_test(x: &JsValue, y: &JsValue, options: &JsValue) -> JsValue {
+    let (x, y) = match (read_points(x), read_points(y)) {
+        (Some(x), Some(y)) if x.len() == y.len() && x.len() > 2 => (x, y),
+        _ => return JsValue::NULL,
+    };
+    let permutations = get_f64_option(options, "permutations").unwrap_or(999.0) as usize;
+    let mut rng = seeded_rng(get_f64_option(options, "seed"));
+
+    let a = double_center(&distance_matrix(&x));
+    let b = double_center(&distance_matrix(&y));
+    let identity: Vec<usize> = (0..x.len()).collect();
+    let dcov_sq = distance_covariance_sq(&a, &b, &identity);
+    let dvar_x = distance_covariance_sq(&a, &a, &identity);
+    let dvar_y = distance_covariance_sq(&b, &b, &identity);
+    let dcor = if dvar_x > 0.0 && dvar_y > 0.0 {
+        (dcov_sq / (dvar_x * dvar_y).sqrt()).sqrt()
+    } else {
+        0.0
+    };
+
+    let mut order = identity.clone();
+    let extreme = (0..permutations)
+        .filter(|_| {
+            order.shuffle(&mut rng);
+            distance_covariance_sq(&a, &b, &order) >= dcov_sq - 1e-12
+        })
+        .count();
+    let p = (extreme as f64 + 1.0) / (permutations as f64 + 1.0);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("dcor"), &JsValue::from_f64(dcor));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("dcov"),
+        &JsValue::from_f64(dcov_sq.sqrt()),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Computes the scaled energy distance between the two groups of a pooled distance
+/// matrix, with the first `n1` entries of `order` forming the first group.
+fn energy_statistic(distances: &[Vec<f64>], order: &[usize], n1: usize) -> f64 {
+    let (first, second) = order.split_at(n1);
+    let mean_distance = |a: &[usize], b: &[usize]| {
+        a.iter()
+            .map(|i| b.iter().map(|j| distances[*i][*j]).sum::<f64>())
+            .sum::<f64>()
+            / (a.len() * b.len()) as f64
+    };
+    let n1 = first.len() as f64;
+    let n2 = second.len() as f64;
+    n1 * n2 / (n1 + n2)
+        * (2.0 * mean_distance(first, second)
+            - mean_distance(first, first)
+            - mean_distance(second, second))
+}
+
+/// Performs the energy-distance test of whether two multivariate samples come from the
+/// same distribution.
+///
+/// # Arguments
+///
+/// * `sample1` - A reference to a JsValue representing the first sample, either a flat
+///   array or a JavaScript array of variable columns.
+/// * `sample2` - A reference to a JsValue representing the second sample, in the same
+///   layout and with the same number of variables.
+/// * `options` - An optional object with the properties `permutations` (defaults to 999)
+///   and `seed`.
+///
+/// # Returns
+///
+/// * An object with the properties `e` (the energy statistic) and `p` (from permuting the
+///   group labels of the pooled sample), or null if the samples are invalid.
+#[wasm_bindgen]
+pub fn energy_test(sample1: &JsValue, sample2: &JsValue, options: &JsValue) -> JsValue {
+    let (sample1, sample2) = match (read_points(sample1), read_points(sample2)) {
+        (Some(a), Some(b)) if a[0].len() == b[0].len() => (a, b),
+        _ => return JsValue::NULL,
+    };
+    let permutations = get_f64_option(options, "permutations").unwrap_or(999.0) as usize;
+    let mut rng = seeded_rng(get_f64_option(options, "seed"));
+
+    let n1 = sample1.len();
+    let pooled: Vec<Vec<f64>> = sample1.into_iter().chain(sample2).collect();
+    let distances = distance_matrix(&pooled);
+    let mut order: Vec<usize> = (0..pooled.len()).collect();
+    let e = energy_statistic(&distances, &order, n1);
+
+    let extreme = (0..permutations)
+        .filter(|_| {
+            order.shuffle(&mut rng);
+            energy_statistic(&distances, &order, n1) >= e - 1e-12
+        })
+        .count();
+    let p = (extreme as f64 + 1.0) / (permutations as f64 + 1.0);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("e"), &JsValue::from_f64(e));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn columns() -> JsValue {
+        nested_vec_to_jsvalue(vec![
+            vec![2.0, 4.0, 3.0, 5.0, 6.0, 5.0, 7.0, 8.0, 6.0, 9.0],
+            vec![1.0, 3.0, 3.0, 4.0, 5.0, 6.0, 6.0, 7.0, 7.0, 8.0],
+            vec![5.0, 3.0, 4.0, 2.0, 3.0, 1.0, 2.0, 1.0, 2.0, 0.0],
+            vec![3.0, 5.0, 2.0, 6.0, 4.0, 3.0, 5.0, 4.0, 6.0, 5.0],
+        ])
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_bartlett_sphericity() {
+        let result = bartlett_sphericity(&columns());
+
+        let x2 = Reflect::get(&result, &JsValue::from_str("x2")).unwrap();
+        let df = Reflect::get(&result, &JsValue::from_str("df")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+        let determinant = Reflect::get(&result, &JsValue::from_str("determinant")).unwrap();
+
+        assert!((x2.as_f64().unwrap() - 27.31814).abs() < 0.0001);
+        assert_eq!(df.as_f64().unwrap(), 6.0);
+        assert!((p.as_f64().unwrap() - 0.000126).abs() < 0.00001);
+        assert!((determinant.as_f64().unwrap() - 0.018356).abs() < 0.00001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_mauchly_test() {
+        let data = nested_vec_to_jsvalue(vec![
+            vec![30.0, 28.0, 34.0, 26.0, 32.0, 29.0, 35.0, 31.0],
+            vec![27.0, 22.0, 30.0, 24.0, 26.0, 25.0, 31.0, 28.0],
+            vec![25.0, 20.0, 28.0, 23.0, 22.0, 22.0, 27.0, 24.0],
+            vec![22.0, 19.0, 26.0, 18.0, 21.0, 20.0, 24.0, 23.0],
+        ]);
+
+        let result = mauchly_test(&data);
+
+        let get = |key: &str| {
+            Reflect::get(&result, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert!((get("w") - 0.325216).abs() < 0.0001);
+        assert!((get("x2") - 6.427572).abs() < 0.0001);
+        assert_eq!(get("df"), 5.0);
+        assert!((get("p") - 0.266808).abs() < 0.0001);
+        assert!((get("gg_epsilon") - 0.605306).abs() < 0.0001);
+        assert!((get("hf_epsilon") - 0.805502).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_kmo() {
+        let result = kmo(&columns());
+
+        let overall = Reflect::get(&result, &JsValue::from_str("kmo")).unwrap();
+        let msa = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("msa")).unwrap());
+
+        assert!((overall.as_f64().unwrap() - 0.779675).abs() < 0.0001);
+        assert!((msa[0] - 0.779609).abs() < 0.0001);
+        assert!((msa[1] - 0.710984).abs() < 0.0001);
+        assert!((msa[2] - 0.837511).abs() < 0.0001);
+        assert!((msa[3] - 0.861376).abs() < 0.0001);
+    }
+
+    fn permutation_options() -> JsValue {
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("permutations"),
+            &JsValue::from_f64(999.0),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("seed"),
+            &JsValue::from_f64(5.0),
+        );
+        options.into()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_distance_correlation_test() {
+        let x: Vec<f64> = (1..=30).map(|i| i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|v| (v - 15.5).powi(2)).collect();
+
+        let result = distance_correlation_test(
+            &vec_to_jsvalue(x),
+            &vec_to_jsvalue(y),
+            &permutation_options(),
+        );
+
+        let dcor = Reflect::get(&result, &JsValue::from_str("dcor")).unwrap();
+        let dcov = Reflect::get(&result, &JsValue::from_str("dcov")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        // Pearson's r is exactly zero for this symmetric relationship
+        assert!((dcor.as_f64().unwrap() - 0.492306).abs() < 0.0001);
+        assert!((dcov.as_f64().unwrap() - 8.707936).abs() < 0.0001);
+        assert!(p.as_f64().unwrap() < 0.05);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_energy_test() {
+        let sample1 = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 2.0, 1.0],
+            vec![2.0, 1.0, 3.0, 2.0, 1.0],
+        ]);
+        let sample2 = nested_vec_to_jsvalue(vec![
+            vec![4.0, 5.0, 6.0, 5.0, 4.0, 6.0],
+            vec![5.0, 4.0, 6.0, 5.0, 3.0, 5.0],
+        ]);
+
+        let result = energy_test(&sample1, &sample2, &permutation_options());
+
+        let e = Reflect::get(&result, &JsValue::from_str("e")).unwrap();
+        let p = Reflect::get(&result, &JsValue::from_str("p")).unwrap();
+
+        assert!((e.as_f64().unwrap() - 16.356719).abs() < 0.0001);
+        assert!(p.as_f64().unwrap() < 0.01);
+    }
+}
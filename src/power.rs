@@ -0,0 +1,724 @@
+use js_sys::Object;
+use js_sys::Reflect;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor, Normal};
+use statrs::function::beta::beta_reg;
+use statrs::function::gamma::ln_gamma;
+use wasm_bindgen::prelude::*;
+
+/// Computes the cumulative distribution function of the noncentral F-distribution
+/// as a Poisson mixture of regularized incomplete beta functions.
+///
+/// # Arguments
+///
+/// * `x` - The point at which to evaluate the CDF.
+/// * `df1` - The numerator degrees of freedom.
+/// * `df2` - The denominator degrees of freedom.
+/// * `lambda` - The noncentrality parameter.
+///
+/// # Returns
+///
+/// * The probability that a noncentral F random variable is less than or equal to `x`.
+pub(crate) fn noncentral_f_cdf(x: f64, df1: f64, df2: f64, lambda: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let y = df1 * x / (df1 * x + df2);
+    let half_lambda = lambda / 2.0;
+    let max_j = (half_lambda + 12.0 * half_lambda.sqrt() + 50.0).ceil() as usize;
+
+    (0..=max_j)
+        .map(|j| {
+            let j = j as f64;
+            let log_weight = if half_lambda > 0.0 {
+                -half_lambda + j * half_lambda.ln() - ln_gamma(j + 1.0)
+            } else if j == 0.0 {
+                0.0
+            } else {
+                f64::NEG_INFINITY
+            };
+            log_weight.exp() * beta_reg(df1 / 2.0 + j, df2 / 2.0, y)
+        })
+        .sum::<f64>()
+        .clamp(0.0, 1.0)
+}
+
+/// Computes the power of a balanced one-way ANOVA F-test.
+///
+/// # Arguments
+///
+/// * `k` - The number of groups.
+/// * `n` - The number of observations per group.
+/// * `f_effect` - Cohen's f effect size.
+/// * `alpha` - The significance level.
+///
+/// # Returns
+///
+/// * A tuple of the power, noncentrality parameter, numerator df, denominator df, and
+///   critical F value.
+fn anova_power_values(k: f64, n: f64, f_effect: f64, alpha: f64) -> (f64, f64, f64, f64, f64) {
+    let df1 = k - 1.0;
+    let df2 = k * (n - 1.0);
+    let lambda = f_effect.powi(2) * k * n;
+
+    let f_crit = FisherSnedecor::new(df1, df2)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha);
+    let power = 1.0 - noncentral_f_cdf(f_crit, df1, df2, lambda);
+
+    (power, lambda, df1, df2, f_crit)
+}
+
+/// Computes the statistical power of a balanced one-way ANOVA using the noncentral
+/// F-distribution.
+///
+/// # Arguments
+///
+/// * `k` - A reference to a JsValue representing the number of groups.
+/// * `n_per_group` - A reference to a JsValue representing the number of observations per group.
+/// * `f_effect` - A reference to a JsValue representing Cohen's f effect size.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `power`, `lambda`, `df1`, `df2`, and `f_crit`, or null
+///   if the inputs are invalid.
+#[wasm_bindgen]
+pub fn anova_power(
+    k: &JsValue,
+    n_per_group: &JsValue,
+    f_effect: &JsValue,
+    alpha: &JsValue,
+) -> JsValue {
+    let k = k.as_f64().unwrap_or(0.0);
+    let n = n_per_group.as_f64().unwrap_or(0.0);
+    let f_effect = f_effect.as_f64().unwrap_or(0.0);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+
+    if k < 2.0 || n < 2.0 || f_effect < 0.0 || alpha <= 0.0 || alpha >= 1.0 {
+        return JsValue::NULL;
+    }
+
+    let (power, lambda, df1, df2, f_crit) = anova_power_values(k, n, f_effect, alpha);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("power"), &JsValue::from_f64(power));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("lambda"),
+        &JsValue::from_f64(lambda),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("df1"), &JsValue::from_f64(df1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df2"), &JsValue::from_f64(df2));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("f_crit"),
+        &JsValue::from_f64(f_crit),
+    );
+    obj.into()
+}
+
+/// Finds the smallest number of observations per group for which a balanced one-way
+/// ANOVA reaches the requested power.
+///
+/// # Arguments
+///
+/// * `k` - A reference to a JsValue representing the number of groups.
+/// * `f_effect` - A reference to a JsValue representing Cohen's f effect size.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+/// * `power` - A reference to a JsValue representing the target power (defaults to 0.8).
+///
+/// # Returns
+///
+/// * An object with the properties `n_per_group`, `n_total`, and `power` (the achieved
+///   power), or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn anova_sample_size(
+    k: &JsValue,
+    f_effect: &JsValue,
+    alpha: &JsValue,
+    power: &JsValue,
+) -> JsValue {
+    let k = k.as_f64().unwrap_or(0.0);
+    let f_effect = f_effect.as_f64().unwrap_or(0.0);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let target = power.as_f64().unwrap_or(0.8);
+
+    if k < 2.0 || f_effect <= 0.0 || alpha <= 0.0 || alpha >= 1.0 || target <= 0.0 || target >= 1.0
+    {
+        return JsValue::NULL;
+    }
+
+    // Double the upper bound until the target is reached, then bisect
+    let achieved = |n: f64| anova_power_values(k, n, f_effect, alpha).0;
+    let mut low = 2.0;
+    let mut high = 4.0;
+    while achieved(high) < target {
+        low = high;
+        high *= 2.0;
+        if high > 1e7 {
+            return JsValue::NULL;
+        }
+    }
+    while high - low > 1.0 {
+        let mid = ((low + high) / 2.0).floor();
+        if achieved(mid) >= target {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    let n = if achieved(low) >= target { low } else { high };
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("n_per_group"),
+        &JsValue::from_f64(n),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("n_total"),
+        &JsValue::from_f64(n * k),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("power"),
+        &JsValue::from_f64(achieved(n)),
+    );
+    obj.into()
+}
+
+/// Computes the power of a test of zero correlation using the Fisher z approximation.
+///
+/// # Arguments
+///
+/// * `n` - The sample size.
+/// * `r` - The correlation to detect.
+/// * `alpha` - The significance level.
+/// * `tails` - The type of test ("two-sided", "less", or "greater").
+///
+/// # Returns
+///
+/// * The power of the test, or `None` if the test type is invalid.
+fn correlation_power_value(n: f64, r: f64, alpha: f64, tails: &str) -> Option<f64> {
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let shift = r.atanh() * (n - 3.0).sqrt();
+
+    match tails {
+        "two-sided" => {
+            let z_crit = dist.inverse_cdf(1.0 - alpha / 2.0);
+            Some(dist.cdf(shift - z_crit) + dist.cdf(-shift - z_crit))
+        }
+        "greater" => Some(dist.cdf(shift - dist.inverse_cdf(1.0 - alpha))),
+        "less" => Some(dist.cdf(-shift - dist.inverse_cdf(1.0 - alpha))),
+        _ => None,
+    }
+}
+
+/// Computes the power of a test of zero correlation for a given sample size using the
+/// Fisher z approximation.
+///
+/// # Arguments
+///
+/// * `n` - A reference to a JsValue representing the sample size.
+/// * `r` - A reference to a JsValue representing the correlation to detect.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+///
+/// # Returns
+///
+/// * An object with the property `power`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn correlation_power(n: &JsValue, r: &JsValue, alpha: &JsValue, tails: &JsValue) -> JsValue {
+    let n = n.as_f64().unwrap_or(0.0);
+    let r = r.as_f64().unwrap_or(0.0);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    if n < 4.0 || r.abs() >= 1.0 || alpha <= 0.0 || alpha >= 1.0 {
+        return JsValue::NULL;
+    }
+
+    let power = match correlation_power_value(n, r, alpha, &tails) {
+        Some(power) => power,
+        None => return JsValue::NULL,
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("power"), &JsValue::from_f64(power));
+    obj.into()
+}
+
+/// Finds the smallest sample size for which a test of zero correlation reaches the
+/// requested power using the Fisher z approximation.
+///
+/// # Arguments
+///
+/// * `r` - A reference to a JsValue representing the correlation to detect.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+/// * `power` - A reference to a JsValue representing the target power (defaults to 0.8).
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or "greater").
+///
+/// # Returns
+///
+/// * An object with the properties `n` and `power` (the achieved power), or null if the
+///   inputs are invalid.
+#[wasm_bindgen]
+pub fn correlation_sample_size(
+    r: &JsValue,
+    alpha: &JsValue,
+    power: &JsValue,
+    tails: &JsValue,
+) -> JsValue {
+    let r = r.as_f64().unwrap_or(0.0);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let target = power.as_f64().unwrap_or(0.8);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    if r == 0.0 || r.abs() >= 1.0 || alpha <= 0.0 || alpha >= 1.0 || target <= 0.0 || target >= 1.0
+    {
+        return JsValue::NULL;
+    }
+
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let z_alpha = match tails.as_str() {
+        "two-sided" => dist.inverse_cdf(1.0 - alpha / 2.0),
+        "less" | "greater" => dist.inverse_cdf(1.0 - alpha),
+        _ => return JsValue::NULL,
+    };
+    let z_beta = dist.inverse_cdf(target);
+
+    // The closed form ignores the opposite rejection region, so step down while the
+    // exact power still meets the target
+    let mut n = (((z_alpha + z_beta) / r.atanh()).powi(2) + 3.0)
+        .ceil()
+        .max(4.0);
+    while n > 4.0 && correlation_power_value(n - 1.0, r, alpha, &tails).unwrap_or(0.0) >= target {
+        n -= 1.0;
+    }
+
+    let achieved = correlation_power_value(n, r, alpha, &tails).unwrap_or(0.0);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("n"), &JsValue::from_f64(n));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("power"),
+        &JsValue::from_f64(achieved),
+    );
+    obj.into()
+}
+
+/// Computes the power of a two-sample test of proportions using the normal approximation,
+/// with the pooled proportion for the null standard error.
+///
+/// # Arguments
+///
+/// * `n1` - The size of the first group.
+/// * `n2` - The size of the second group.
+/// * `p1` - The proportion in the first group.
+/// * `p2` - The proportion in the second group.
+/// * `alpha` - The significance level.
+/// * `tails` - The type of test ("two-sided", "less", or "greater").
+///
+/// # Returns
+///
+/// * The power of the test, or `None` if the test type is invalid.
+fn prop_power_value(n1: f64, n2: f64, p1: f64, p2: f64, alpha: f64, tails: &str) -> Option<f64> {
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let pooled = (n1 * p1 + n2 * p2) / (n1 + n2);
+    let null_se = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+    let se = (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt();
+    let diff = p1 - p2;
+
+    match tails {
+        "two-sided" => {
+            let z_crit = dist.inverse_cdf(1.0 - alpha / 2.0);
+            Some(
+                dist.cdf((diff - z_crit * null_se) / se)
+                    + dist.cdf((-diff - z_crit * null_se) / se),
+            )
+        }
+        "greater" => Some(dist.cdf((diff - dist.inverse_cdf(1.0 - alpha) * null_se) / se)),
+        "less" => Some(dist.cdf((-diff - dist.inverse_cdf(1.0 - alpha) * null_se) / se)),
+        _ => None,
+    }
+}
+
+/// Computes the power of a two-sample test of proportions for given group sizes using
+/// the normal approximation.
+///
+/// # Arguments
+///
+/// * `n1` - A reference to a JsValue representing the size of the first group.
+/// * `n2` - A reference to a JsValue representing the size of the second group.
+/// * `p1` - A reference to a JsValue representing the proportion in the first group.
+/// * `p2` - A reference to a JsValue representing the proportion in the second group.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or
+///   "greater"), where "greater" means `p1` exceeds `p2`.
+///
+/// # Returns
+///
+/// * An object with the properties `power` and `effect_size` (Cohen's h), or null if the
+///   inputs are invalid.
+#[wasm_bindgen]
+pub fn prop_test_power(
+    n1: &JsValue,
+    n2: &JsValue,
+    p1: &JsValue,
+    p2: &JsValue,
+    alpha: &JsValue,
+    tails: &JsValue,
+) -> JsValue {
+    let n1 = n1.as_f64().unwrap_or(0.0);
+    let n2 = n2.as_f64().unwrap_or(0.0);
+    let p1 = p1.as_f64().unwrap_or(f64::NAN);
+    let p2 = p2.as_f64().unwrap_or(f64::NAN);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    let in_unit = |p: f64| p > 0.0 && p < 1.0;
+    if n1 < 1.0 || n2 < 1.0 || !in_unit(p1) || !in_unit(p2) || !in_unit(alpha) {
+        return JsValue::NULL;
+    }
+
+    let power = match prop_power_value(n1, n2, p1, p2, alpha, &tails) {
+        Some(power) => power,
+        None => return JsValue::NULL,
+    };
+    let h = 2.0 * p1.sqrt().asin() - 2.0 * p2.sqrt().asin();
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("power"), &JsValue::from_f64(power));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("effect_size"),
+        &JsValue::from_f64(h),
+    );
+    obj.into()
+}
+
+/// Finds the smallest equal group size for which a two-sample test of proportions
+/// reaches the requested power using the normal approximation.
+///
+/// # Arguments
+///
+/// * `p1` - A reference to a JsValue representing the proportion in the first group.
+/// * `p2` - A reference to a JsValue representing the proportion in the second group.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+/// * `power` - A reference to a JsValue representing the target power (defaults to 0.8).
+/// * `tails` - A reference to a JsValue indicating the type of test ("two-sided", "less", or
+///   "greater"), where "greater" means `p1` exceeds `p2`.
+///
+/// # Returns
+///
+/// * An object with the properties `n_per_group`, `n_total`, and `power` (the achieved
+///   power), or null if the inputs are invalid or the proportions are equal.
+#[wasm_bindgen]
+pub fn prop_test_sample_size(
+    p1: &JsValue,
+    p2: &JsValue,
+    alpha: &JsValue,
+    power: &JsValue,
+    tails: &JsValue,
+) -> JsValue {
+    let p1 = p1.as_f64().unwrap_or(f64::NAN);
+    let p2 = p2.as_f64().unwrap_or(f64::NAN);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let target = power.as_f64().unwrap_or(0.8);
+    let tails = tails.as_string().unwrap_or("two-sided".to_string());
+
+    let in_unit = |p: f64| p > 0.0 && p < 1.0;
+    if !in_unit(p1) || !in_unit(p2) || p1 == p2 || !in_unit(alpha) || !in_unit(target) {
+        return JsValue::NULL;
+    }
+
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let z_alpha = match tails.as_str() {
+        "two-sided" => dist.inverse_cdf(1.0 - alpha / 2.0),
+        "less" | "greater" => dist.inverse_cdf(1.0 - alpha),
+        _ => return JsValue::NULL,
+    };
+    let z_beta = dist.inverse_cdf(target);
+    let pooled = (p1 + p2) / 2.0;
+
+    // Start from the closed form and step to the smallest size meeting the target, which
+    // also covers a one-sided test in the direction opposite the difference
+    let mut n = ((z_alpha * (2.0 * pooled * (1.0 - pooled)).sqrt()
+        + z_beta * (p1 * (1.0 - p1) + p2 * (1.0 - p2)).sqrt())
+        / (p1 - p2))
+        .powi(2)
+        .ceil()
+        .max(2.0);
+    let power_at = |n: f64| prop_power_value(n, n, p1, p2, alpha, &tails).unwrap_or(0.0);
+    if power_at(n) < target && power_at(1e9) < target {
+        return JsValue::NULL;
+    }
+    while power_at(n) < target {
+        n += 1.0;
+    }
+    while n > 2.0 && power_at(n - 1.0) >= target {
+        n -= 1.0;
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("n_per_group"),
+        &JsValue::from_f64(n),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("n_total"),
+        &JsValue::from_f64(2.0 * n),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("power"),
+        &JsValue::from_f64(power_at(n)),
+    );
+    obj.into()
+}
+
+/// Computes the minimum detectable effect of a two-sided, two-group comparison with
+/// equal group sizes, for either a mean or a proportion metric.
+///
+/// # Arguments
+///
+/// * `n` - A reference to a JsValue representing the sample size per group.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to 0.05).
+/// * `power` - A reference to a JsValue representing the desired power (defaults to 0.8).
+/// * `baseline` - A reference to a JsValue representing the control group's mean or proportion.
+/// * `sd` - A reference to a JsValue representing the standard deviation of a mean metric.
+///   If undefined, `baseline` is treated as a proportion.
+///
+/// # Returns
+///
+/// * An object with the properties `mde` (absolute), `relative_mde` (relative to the
+///   baseline), and `effect_size` (Cohen's d for means or Cohen's h for proportions),
+///   or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn mde(
+    n: &JsValue,
+    alpha: &JsValue,
+    power: &JsValue,
+    baseline: &JsValue,
+    sd: &JsValue,
+) -> JsValue {
+    let n = n.as_f64().unwrap_or(0.0);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let power = power.as_f64().unwrap_or(0.8);
+    let baseline = baseline.as_f64().unwrap_or(f64::NAN);
+
+    if n < 2.0 || alpha <= 0.0 || alpha >= 1.0 || power <= 0.0 || power >= 1.0 || baseline.is_nan()
+    {
+        return JsValue::NULL;
+    }
+
+    let dist = Normal::new(0.0, 1.0).unwrap();
+    let z = dist.inverse_cdf(1.0 - alpha / 2.0) + dist.inverse_cdf(power);
+
+    let (mde, effect_size) = match sd.as_f64() {
+        Some(sd) if sd > 0.0 => {
+            let mde = z * sd * (2.0 / n).sqrt();
+            (mde, mde / sd)
+        }
+        Some(_) => return JsValue::NULL,
+        None => {
+            if baseline <= 0.0 || baseline >= 1.0 {
+                return JsValue::NULL;
+            }
+
+            // The variance depends on the treatment proportion, so iterate to a fixed point
+            let base_var = baseline * (1.0 - baseline);
+            let mut mde = z * (2.0 * base_var / n).sqrt();
+            for _ in 0..100 {
+                let p1 = (baseline + mde).min(1.0);
+                let next = z * ((base_var + p1 * (1.0 - p1)) / n).sqrt();
+                if (next - mde).abs() < 1e-12 {
+                    break;
+                }
+                mde = next;
+            }
+
+            let p1 = (baseline + mde).min(1.0);
+            let h = 2.0 * p1.sqrt().asin() - 2.0 * baseline.sqrt().asin();
+            (mde, h)
+        }
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("mde"), &JsValue::from_f64(mde));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("relative_mde"),
+        &JsValue::from_f64(mde / baseline),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("effect_size"),
+        &JsValue::from_f64(effect_size),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_anova_power() {
+        let result = anova_power(
+            &JsValue::from_f64(4.0),
+            &JsValue::from_f64(45.0),
+            &JsValue::from_f64(0.25),
+            &JsValue::from_f64(0.05),
+        );
+
+        let power = Reflect::get(&result, &JsValue::from_str("power")).unwrap();
+        let lambda = Reflect::get(&result, &JsValue::from_str("lambda")).unwrap();
+
+        assert!((power.as_f64().unwrap() - 0.8040).abs() < 0.01);
+        assert!((lambda.as_f64().unwrap() - 11.25).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_anova_sample_size() {
+        let result = anova_sample_size(
+            &JsValue::from_f64(4.0),
+            &JsValue::from_f64(0.25),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_f64(0.8),
+        );
+
+        let n = Reflect::get(&result, &JsValue::from_str("n_per_group")).unwrap();
+        let n_total = Reflect::get(&result, &JsValue::from_str("n_total")).unwrap();
+
+        assert_eq!(n.as_f64().unwrap(), 45.0);
+        assert_eq!(n_total.as_f64().unwrap(), 180.0);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_correlation_power() {
+        let result = correlation_power(
+            &JsValue::from_f64(85.0),
+            &JsValue::from_f64(0.3),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_str("two-sided"),
+        );
+
+        let power = Reflect::get(&result, &JsValue::from_str("power")).unwrap();
+
+        assert!((power.as_f64().unwrap() - 0.8003).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_correlation_sample_size() {
+        let result = correlation_sample_size(
+            &JsValue::from_f64(0.3),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_f64(0.8),
+            &JsValue::from_str("two-sided"),
+        );
+
+        let n = Reflect::get(&result, &JsValue::from_str("n")).unwrap();
+
+        assert_eq!(n.as_f64().unwrap(), 85.0);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_prop_test_power() {
+        let result1 = prop_test_power(
+            &JsValue::from_f64(50.0),
+            &JsValue::from_f64(50.0),
+            &JsValue::from_f64(0.5),
+            &JsValue::from_f64(0.75),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_str("two-sided"),
+        );
+        let result2 = prop_test_power(
+            &JsValue::from_f64(60.0),
+            &JsValue::from_f64(40.0),
+            &JsValue::from_f64(0.3),
+            &JsValue::from_f64(0.15),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_str("greater"),
+        );
+
+        let power1 = Reflect::get(&result1, &JsValue::from_str("power")).unwrap();
+        let power2 = Reflect::get(&result2, &JsValue::from_str("power")).unwrap();
+        let h = Reflect::get(&result1, &JsValue::from_str("effect_size")).unwrap();
+
+        assert!((power1.as_f64().unwrap() - 0.740167).abs() < 0.0001);
+        assert!((power2.as_f64().unwrap() - 0.532187).abs() < 0.0001);
+        assert!((h.as_f64().unwrap() + std::f64::consts::FRAC_PI_6).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_prop_test_sample_size() {
+        let result1 = prop_test_sample_size(
+            &JsValue::from_f64(0.5),
+            &JsValue::from_f64(0.75),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_f64(0.9),
+            &JsValue::from_str("two-sided"),
+        );
+        let result2 = prop_test_sample_size(
+            &JsValue::from_f64(0.1),
+            &JsValue::from_f64(0.12),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_f64(0.8),
+            &JsValue::from_str("less"),
+        );
+
+        let get = |obj: &JsValue, key: &str| {
+            Reflect::get(obj, &JsValue::from_str(key))
+                .unwrap()
+                .as_f64()
+                .unwrap()
+        };
+
+        assert_eq!(get(&result1, "n_per_group"), 77.0);
+        assert_eq!(get(&result1, "n_total"), 154.0);
+        assert!((get(&result1, "power") - 0.901104).abs() < 0.0001);
+        assert_eq!(get(&result2, "n_per_group"), 3026.0);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_mde() {
+        let result1 = mde(
+            &JsValue::from_f64(100.0),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_f64(0.8),
+            &JsValue::from_f64(10.0),
+            &JsValue::from_f64(1.0),
+        );
+        let result2 = mde(
+            &JsValue::from_f64(1000.0),
+            &JsValue::from_f64(0.05),
+            &JsValue::from_f64(0.8),
+            &JsValue::from_f64(0.1),
+            &JsValue::UNDEFINED,
+        );
+
+        let mde1 = Reflect::get(&result1, &JsValue::from_str("mde")).unwrap();
+        let rel1 = Reflect::get(&result1, &JsValue::from_str("relative_mde")).unwrap();
+        let mde2 = Reflect::get(&result2, &JsValue::from_str("mde")).unwrap();
+        let rel2 = Reflect::get(&result2, &JsValue::from_str("relative_mde")).unwrap();
+
+        assert!((mde1.as_f64().unwrap() - 0.3962).abs() < 0.001);
+        assert!((rel1.as_f64().unwrap() - 0.03962).abs() < 0.001);
+        assert!((mde2.as_f64().unwrap() - 0.04069).abs() < 0.001);
+        assert!((rel2.as_f64().unwrap() - 0.4069).abs() < 0.001);
+    }
+}
@@ -0,0 +1,1720 @@
+use crate::conf_int::t_bounds;
+use crate::utils::*;
+use js_sys::Object;
+use js_sys::Reflect;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+use wasm_bindgen::prelude::*;
+
+/// The result of an ordinary least squares fit.
+pub(crate) struct OlsFit {
+    pub coefficients: Vec<f64>,
+    pub std_errors: Vec<f64>,
+    pub residuals: Vec<f64>,
+    pub sse: f64,
+    pub sst: f64,
+    pub df_resid: f64,
+}
+
+/// Builds a design matrix with a leading intercept column from predictor columns.
+///
+/// # Arguments
+///
+/// * `predictors` - A slice of predictor columns of equal length.
+/// * `n` - The number of observations.
+///
+/// # Returns
+///
+/// * The design matrix as a vector of rows.
+pub(crate) fn design_matrix(predictors: &[Vec<f64>], n: usize) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| {
+            std::iter::once(1.0)
+                .chain(predictors.iter().map(|col| col[i]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Fits an ordinary least squares regression by solving the normal equations.
+///
+/// # Arguments
+///
+/// * `design` - The design matrix as a slice of rows.
+/// * `y` - The response values.
+///
+/// # Returns
+///
+/// * The fitted model, or `None` if the design matrix is rank deficient or there are
+///   no residual degrees of freedom.
+pub(crate) fn ols_fit(design: &[Vec<f64>], y: &[f64]) -> Option<OlsFit> {
+    let n = design.len();
+    let p = design.first()?.len();
+    if n <= p {
+        return None;
+    }
+
+    let xtx: Vec<Vec<f64>> = (0..p)
+        .map(|a| {
+            (0..p)
+                .map(|b| design.iter().map(|row| row[a] * row[b]).sum())
+                .collect()
+        })
+        .collect();
+    let xty: Vec<f64> = (0..p)
+        .map(|a| design.iter().zip(y).map(|(row, yi)| row[a] * yi).sum())
+        .collect();
+
+    let xtx_inv = invert_matrix(&xtx)?;
+    let coefficients: Vec<f64> = xtx_inv
+        .iter()
+        .map(|row| row.iter().zip(&xty).map(|(a, b)| a * b).sum())
+        .collect();
+
+    let residuals: Vec<f64> = design
+        .iter()
+        .zip(y)
+        .map(|(row, yi)| {
+            yi - row
+                .iter()
+                .zip(&coefficients)
+                .map(|(x, b)| x * b)
+                .sum::<f64>()
+        })
+        .collect();
+
+    let y_mean = y.iter().sum::<f64>() / n as f64;
+    let sse = residuals.iter().map(|e| e.powi(2)).sum::<f64>();
+    let sst = y.iter().map(|yi| (yi - y_mean).powi(2)).sum::<f64>();
+    let df_resid = (n - p) as f64;
+
+    let mse = sse / df_resid;
+    let std_errors = (0..p).map(|j| (mse * xtx_inv[j][j]).sqrt()).collect();
+
+    Some(OlsFit {
+        coefficients,
+        std_errors,
+        residuals,
+        sse,
+        sst,
+        df_resid,
+    })
+}
+
+/// Sets the coefficient tests and overall fit statistics of an OLS fit on a result
+/// object.
+///
+/// # Arguments
+///
+/// * `obj` - The result object.
+/// * `fit` - The fitted model, whose first coefficient is the intercept.
+/// * `n` - The number of observations.
+pub(crate) fn set_fit_statistics(obj: &Object, fit: &OlsFit, n: usize) {
+    let k = (fit.coefficients.len() - 1) as f64;
+    let r2 = 1.0 - fit.sse / fit.sst;
+    let adj_r2 = 1.0 - (1.0 - r2) * (n as f64 - 1.0) / fit.df_resid;
+
+    let f = ((fit.sst - fit.sse) / k) / (fit.sse / fit.df_resid);
+    let p = 1.0 - FisherSnedecor::new(k, fit.df_resid).unwrap().cdf(f);
+
+    let t_dist = StudentsT::new(0.0, 1.0, fit.df_resid).unwrap();
+    let t_values: Vec<f64> = fit
+        .coefficients
+        .iter()
+        .zip(&fit.std_errors)
+        .map(|(b, se)| b / se)
+        .collect();
+    let p_values: Vec<f64> = t_values
+        .iter()
+        .map(|t| 2.0 * (1.0 - t_dist.cdf(t.abs())))
+        .collect();
+
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(fit.coefficients.clone()),
+    );
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("std_errors"),
+        &vec_to_jsvalue(fit.std_errors.clone()),
+    );
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("t_values"),
+        &vec_to_jsvalue(t_values),
+    );
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("p_values"),
+        &vec_to_jsvalue(p_values),
+    );
+    let _ = Reflect::set(obj, &JsValue::from_str("r2"), &JsValue::from_f64(r2));
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("adj_r2"),
+        &JsValue::from_f64(adj_r2),
+    );
+    let _ = Reflect::set(obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(obj, &JsValue::from_str("df1"), &JsValue::from_f64(k));
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("df2"),
+        &JsValue::from_f64(fit.df_resid),
+    );
+}
+
+/// Computes the median of values that are already sorted in ascending order.
+fn sorted_median(sorted: &[f64]) -> f64 {
+    let m = sorted.len();
+    if m % 2 == 1 {
+        sorted[m / 2]
+    } else {
+        (sorted[m / 2 - 1] + sorted[m / 2]) / 2.0
+    }
+}
+
+/// Fits a multiple linear regression with an intercept and reports coefficient tests,
+/// overall fit statistics, and Cohen's f² effect sizes.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients`, `std_errors`, `t_values`, and `p_values`
+///   (intercept first), `r2`, `adj_r2`, `f`, `p`, `df1`, `df2`, the overall Cohen's `f2`,
+///   its `f2_interpretation`, the per-predictor `f2_partial` values computed from R²
+///   increments, and `metadata` holding the conventional f² thresholds. Returns null if
+///   the inputs are invalid or the predictors are collinear.
+#[wasm_bindgen]
+pub fn multiple_regression(x_matrix: &JsValue, y: &JsValue) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let fit = match ols_fit(&design_matrix(&predictors, n), &y) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+
+    let obj = Object::new();
+    set_fit_statistics(&obj, &fit, n);
+    let r2 = 1.0 - fit.sse / fit.sst;
+
+    // Cohen's f² overall and for each predictor from the R² increment over the model
+    // without that predictor
+    let f2 = r2 / (1.0 - r2);
+    let f2_partial: Vec<f64> = (0..predictors.len())
+        .map(|j| {
+            let reduced: Vec<Vec<f64>> = predictors
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != j)
+                .map(|(_, col)| col.clone())
+                .collect();
+            let r2_reduced = match ols_fit(&design_matrix(&reduced, n), &y) {
+                Some(reduced_fit) => 1.0 - reduced_fit.sse / reduced_fit.sst,
+                None => 0.0,
+            };
+            (r2 - r2_reduced) / (1.0 - r2)
+        })
+        .collect();
+
+    let interpretation = if f2 >= 0.35 {
+        "large"
+    } else if f2 >= 0.15 {
+        "medium"
+    } else if f2 >= 0.02 {
+        "small"
+    } else {
+        "negligible"
+    };
+
+    let thresholds = Object::new();
+    let _ = Reflect::set(
+        &thresholds,
+        &JsValue::from_str("small"),
+        &JsValue::from_f64(0.02),
+    );
+    let _ = Reflect::set(
+        &thresholds,
+        &JsValue::from_str("medium"),
+        &JsValue::from_f64(0.15),
+    );
+    let _ = Reflect::set(
+        &thresholds,
+        &JsValue::from_str("large"),
+        &JsValue::from_f64(0.35),
+    );
+    let metadata = Object::new();
+    let _ = Reflect::set(&metadata, &JsValue::from_str("f2_thresholds"), &thresholds);
+
+    let _ = Reflect::set(&obj, &JsValue::from_str("f2"), &JsValue::from_f64(f2));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("f2_interpretation"),
+        &JsValue::from_str(interpretation),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("f2_partial"),
+        &vec_to_jsvalue(f2_partial),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("metadata"), &metadata);
+    obj.into()
+}
+
+/// Fits a polynomial regression of `y` on powers of `x` up to the given degree.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the independent variable.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `degree` - A reference to a JsValue representing the degree of the polynomial
+///   (defaults to 2).
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients`, `std_errors`, `t_values`, and
+///   `p_values` (constant term first, then increasing powers), `r2`, `adj_r2`, `f`, `p`,
+///   `df1`, `df2`, and `fitted` (the fitted values), or null if the inputs differ in
+///   length, the degree is not a positive whole number, there are no more values than
+///   coefficients, or there are too few distinct `x` values for the degree.
+#[wasm_bindgen]
+pub fn polynomial_regression(x: &JsValue, y: &JsValue, degree: &JsValue) -> JsValue {
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let degree = degree.as_f64().unwrap_or(2.0);
+    let n = y.len();
+
+    if x.len() != n || degree < 1.0 || degree.fract() != 0.0 || degree + 1.0 >= n as f64 {
+        return JsValue::NULL;
+    }
+
+    let powers: Vec<Vec<f64>> = (1..=degree as i32)
+        .map(|d| x.iter().map(|v| v.powi(d)).collect())
+        .collect();
+    let fit = match ols_fit(&design_matrix(&powers, n), &y) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+    let fitted: Vec<f64> = y.iter().zip(&fit.residuals).map(|(yi, e)| yi - e).collect();
+
+    let obj = Object::new();
+    set_fit_statistics(&obj, &fit, n);
+    let _ = Reflect::set(&obj, &JsValue::from_str("fitted"), &vec_to_jsvalue(fitted));
+    obj.into()
+}
+
+/// Fits a simple linear regression and reports confidence intervals and t-tests for the
+/// intercept and slope.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the independent variable.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients`, `std_errors`, `t_values`, and
+///   `p_values` (intercept first), `ci` (the `1 - alpha` interval for each coefficient),
+///   and `df`, or null if the inputs differ in length, have fewer than three values, or
+///   `x` is constant.
+#[wasm_bindgen]
+pub fn regression_interval(x: &JsValue, y: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if x.len() != n {
+        return JsValue::NULL;
+    }
+
+    let fit = match ols_fit(&design_matrix(&[x], n), &y) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+
+    let t_values: Vec<f64> = fit
+        .coefficients
+        .iter()
+        .zip(&fit.std_errors)
+        .map(|(b, se)| b / se)
+        .collect();
+    let t_dist = StudentsT::new(0.0, 1.0, fit.df_resid).unwrap();
+    let p_values: Vec<f64> = t_values
+        .iter()
+        .map(|t| 2.0 * (1.0 - t_dist.cdf(t.abs())))
+        .collect();
+    let ci: Vec<Vec<f64>> = fit
+        .coefficients
+        .iter()
+        .zip(&fit.std_errors)
+        .map(|(b, se)| {
+            let (lower, upper) = t_bounds(*b, *se, fit.df_resid, alpha);
+            vec![lower, upper]
+        })
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(fit.coefficients),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_errors"),
+        &vec_to_jsvalue(fit.std_errors),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("t_values"),
+        &vec_to_jsvalue(t_values),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p_values"),
+        &vec_to_jsvalue(p_values),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("ci"), &nested_vec_to_jsvalue(ci));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("df"),
+        &JsValue::from_f64(fit.df_resid),
+    );
+    obj.into()
+}
+
+/// Fits the Theil-Sen line, whose slope is the median of the slopes between all pairs of
+/// points with distinct `x` values and whose intercept is the median of `y - slope * x`.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the independent variable.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `alpha` - A reference to a JsValue representing the significance level of the slope
+///   interval (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `slope`, `intercept`, and `ci` (Sen's rank-based
+///   interval for the slope, using the variance of Kendall's S corrected for ties in `x`),
+///   or null if the inputs differ in length, have fewer than three values, or `x` is
+///   constant.
+#[wasm_bindgen]
+pub fn theil_sen_regression(x: &JsValue, y: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if x.len() != n || n < 3 {
+        return JsValue::NULL;
+    }
+
+    let mut slopes: Vec<f64> = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| x[i] != x[j])
+        .map(|(i, j)| (y[j] - y[i]) / (x[j] - x[i]))
+        .collect();
+    if slopes.is_empty() {
+        return JsValue::NULL;
+    }
+    slopes.sort_by(|a, b| a.total_cmp(b));
+    let slope = sorted_median(&slopes);
+
+    let mut offsets: Vec<f64> = x.iter().zip(&y).map(|(xi, yi)| yi - slope * xi).collect();
+    offsets.sort_by(|a, b| a.total_cmp(b));
+    let intercept = sorted_median(&offsets);
+
+    // Correct the variance of Kendall's S for groups of tied x values
+    let mut sorted_x = x.clone();
+    sorted_x.sort_by(|a, b| a.total_cmp(b));
+    let ties = sorted_x
+        .chunk_by(|a, b| a == b)
+        .map(|group| {
+            let t = group.len() as f64;
+            t * (t - 1.0) * (2.0 * t + 5.0)
+        })
+        .sum::<f64>();
+    let n_f = n as f64;
+    let var_s = (n_f * (n_f - 1.0) * (2.0 * n_f + 5.0) - ties) / 18.0;
+
+    let count = slopes.len();
+    let c = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0)
+        * var_s.sqrt();
+    let rank = |r: f64| slopes[(r.round() as usize).clamp(1, count) - 1];
+    let ci = vec![
+        rank((count as f64 - c) / 2.0),
+        rank((count as f64 + c) / 2.0 + 1.0),
+    ];
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("slope"), &JsValue::from_f64(slope));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("intercept"),
+        &JsValue::from_f64(intercept),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("ci"), &vec_to_jsvalue(ci));
+    obj.into()
+}
+
+/// Fits a robust linear regression with an intercept by M-estimation, using iteratively
+/// reweighted least squares starting from the OLS fit. The residual scale is re-estimated
+/// on each iteration as the median absolute residual divided by 0.6745.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `method` - A reference to a JsValue representing the weight function, either "huber"
+///   (the default, with tuning constant 1.345) or "bisquare" (Tukey's biweight, with
+///   tuning constant 4.685).
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients` (intercept first), `weights` (the final
+///   observation weights), `residuals`, `scale`, `iterations`, and `converged`, or null if
+///   the inputs are invalid, the method is unknown, or the predictors are collinear.
+#[wasm_bindgen]
+pub fn robust_regression(x_matrix: &JsValue, y: &JsValue, method: &JsValue) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+    let method = method.as_string().unwrap_or("huber".to_string());
+
+    if predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let weight: fn(f64) -> f64 = match method.as_str() {
+        "huber" => |u| {
+            if u.abs() <= 1.345 {
+                1.0
+            } else {
+                1.345 / u.abs()
+            }
+        },
+        "bisquare" => |u| {
+            if u.abs() < 4.685 {
+                (1.0 - (u / 4.685).powi(2)).powi(2)
+            } else {
+                0.0
+            }
+        },
+        _ => return JsValue::NULL,
+    };
+
+    let design = design_matrix(&predictors, n);
+    let residuals_of = |coefficients: &[f64]| -> Vec<f64> {
+        design
+            .iter()
+            .zip(&y)
+            .map(|(row, yi)| {
+                yi - row
+                    .iter()
+                    .zip(coefficients)
+                    .map(|(x, b)| x * b)
+                    .sum::<f64>()
+            })
+            .collect()
+    };
+    let robust_scale = |residuals: &[f64]| {
+        let mut abs: Vec<f64> = residuals.iter().map(|e| e.abs()).collect();
+        abs.sort_by(|a, b| a.total_cmp(b));
+        sorted_median(&abs) / 0.6745
+    };
+
+    let mut coefficients = match ols_fit(&design, &y) {
+        Some(fit) => fit.coefficients,
+        None => return JsValue::NULL,
+    };
+    let max_iterations = 50;
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < max_iterations {
+        let residuals = residuals_of(&coefficients);
+        let scale = robust_scale(&residuals);
+        if scale <= 0.0 {
+            converged = true;
+            break;
+        }
+
+        // Weighted least squares on rows scaled by the square root of their weights
+        let root_weights: Vec<f64> = residuals.iter().map(|e| weight(e / scale).sqrt()).collect();
+        let weighted_design: Vec<Vec<f64>> = design
+            .iter()
+            .zip(&root_weights)
+            .map(|(row, w)| row.iter().map(|x| x * w).collect())
+            .collect();
+        let weighted_y: Vec<f64> = y.iter().zip(&root_weights).map(|(yi, w)| yi * w).collect();
+        let updated = match ols_fit(&weighted_design, &weighted_y) {
+            Some(fit) => fit.coefficients,
+            None => return JsValue::NULL,
+        };
+
+        iterations += 1;
+        let change = updated
+            .iter()
+            .zip(&coefficients)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max);
+        coefficients = updated;
+        if change < 1e-10 {
+            converged = true;
+            break;
+        }
+    }
+
+    let residuals = residuals_of(&coefficients);
+    let scale = robust_scale(&residuals);
+    let weights: Vec<f64> = residuals
+        .iter()
+        .map(|e| if scale > 0.0 { weight(e / scale) } else { 1.0 })
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(coefficients),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("weights"),
+        &vec_to_jsvalue(weights),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("residuals"),
+        &vec_to_jsvalue(residuals),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("scale"), &JsValue::from_f64(scale));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("iterations"),
+        &JsValue::from_f64(iterations as f64),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("converged"),
+        &JsValue::from_bool(converged),
+    );
+    obj.into()
+}
+
+/// Centers predictor columns and the response on their means, as used by the penalized
+/// fits so that the intercept is left unpenalized.
+///
+/// # Arguments
+///
+/// * `predictors` - A slice of predictor columns.
+/// * `y` - The response values.
+///
+/// # Returns
+///
+/// * The predictor means, the centered predictor columns, the response mean, and the
+///   centered response.
+fn center_columns(predictors: &[Vec<f64>], y: &[f64]) -> (Vec<f64>, Vec<Vec<f64>>, f64, Vec<f64>) {
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+    let means: Vec<f64> = predictors.iter().map(|col| mean(col)).collect();
+    let centered = predictors
+        .iter()
+        .zip(&means)
+        .map(|(col, m)| col.iter().map(|v| v - m).collect())
+        .collect();
+    let y_mean = mean(y);
+    let y_centered = y.iter().map(|v| v - y_mean).collect();
+    (means, centered, y_mean, y_centered)
+}
+
+/// Computes the intercept and R² of a penalized fit from its slope coefficients.
+///
+/// # Arguments
+///
+/// * `predictors` - The original predictor columns.
+/// * `y` - The response values.
+/// * `slopes` - The fitted slope coefficients.
+/// * `means` - The predictor means.
+/// * `y_mean` - The response mean.
+///
+/// # Returns
+///
+/// * The intercept and R².
+fn penalized_summary(
+    predictors: &[Vec<f64>],
+    y: &[f64],
+    slopes: &[f64],
+    means: &[f64],
+    y_mean: f64,
+) -> (f64, f64) {
+    let intercept = y_mean - slopes.iter().zip(means).map(|(b, m)| b * m).sum::<f64>();
+    let sse = y
+        .iter()
+        .enumerate()
+        .map(|(i, yi)| {
+            let fitted = intercept
+                + slopes
+                    .iter()
+                    .zip(predictors)
+                    .map(|(b, col)| b * col[i])
+                    .sum::<f64>();
+            (yi - fitted).powi(2)
+        })
+        .sum::<f64>();
+    let sst = y.iter().map(|yi| (yi - y_mean).powi(2)).sum::<f64>();
+    (intercept, 1.0 - sse / sst)
+}
+
+/// Fits a ridge regression with an unpenalized intercept, minimizing the residual sum of
+/// squares plus `lambda` times the sum of squared slopes. Predictors are not
+/// standardized, so they should be put on comparable scales beforehand.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `lambda` - A reference to a JsValue representing the non-negative penalty.
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients` (intercept first), `r2`, and `df` (the
+///   effective degrees of freedom of the slopes), or null if the inputs are invalid or the
+///   penalized system is singular.
+#[wasm_bindgen]
+pub fn ridge_regression(x_matrix: &JsValue, y: &JsValue, lambda: &JsValue) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+    let lambda = match lambda.as_f64() {
+        Some(lambda) if lambda >= 0.0 => lambda,
+        _ => return JsValue::NULL,
+    };
+
+    if n < 2 || predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let (means, centered, y_mean, y_centered) = center_columns(&predictors, &y);
+    let p = centered.len();
+    let gram: Vec<Vec<f64>> = (0..p)
+        .map(|a| {
+            (0..p)
+                .map(|b| {
+                    centered[a]
+                        .iter()
+                        .zip(&centered[b])
+                        .map(|(u, v)| u * v)
+                        .sum()
+                })
+                .collect()
+        })
+        .collect();
+    let penalized: Vec<Vec<f64>> = gram
+        .iter()
+        .enumerate()
+        .map(|(a, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(b, v)| if a == b { v + lambda } else { *v })
+                .collect()
+        })
+        .collect();
+    let inverse = match invert_matrix(&penalized) {
+        Some(inverse) => inverse,
+        None => return JsValue::NULL,
+    };
+
+    let xty: Vec<f64> = centered
+        .iter()
+        .map(|col| col.iter().zip(&y_centered).map(|(u, v)| u * v).sum())
+        .collect();
+    let slopes: Vec<f64> = inverse
+        .iter()
+        .map(|row| row.iter().zip(&xty).map(|(a, b)| a * b).sum())
+        .collect();
+    // Effective degrees of freedom: trace of X'X (X'X + lambda I)^-1
+    let df = (0..p)
+        .map(|a| (0..p).map(|b| gram[a][b] * inverse[b][a]).sum::<f64>())
+        .sum::<f64>();
+
+    let (intercept, r2) = penalized_summary(&predictors, &y, &slopes, &means, y_mean);
+    let coefficients: Vec<f64> = std::iter::once(intercept).chain(slopes).collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(coefficients),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("r2"), &JsValue::from_f64(r2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df"), &JsValue::from_f64(df));
+    obj.into()
+}
+
+/// Fits a LASSO regression with an unpenalized intercept by cyclic coordinate descent,
+/// minimizing the residual sum of squares divided by `2n` plus `lambda` times the sum of
+/// absolute slopes. Predictors are not standardized, so they should be put on comparable
+/// scales beforehand.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `lambda` - A reference to a JsValue representing the non-negative penalty.
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients` (intercept first, with slopes shrunk
+///   exactly to zero where the penalty removes a predictor), `r2`, `iterations`, and
+///   `converged`, or null if the inputs are invalid or a predictor is constant.
+#[wasm_bindgen]
+pub fn lasso_regression(x_matrix: &JsValue, y: &JsValue, lambda: &JsValue) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+    let lambda = match lambda.as_f64() {
+        Some(lambda) if lambda >= 0.0 => lambda,
+        _ => return JsValue::NULL,
+    };
+
+    if n < 2 || predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let (means, centered, y_mean, y_centered) = center_columns(&predictors, &y);
+    let n_f = n as f64;
+    let scales: Vec<f64> = centered
+        .iter()
+        .map(|col| col.iter().map(|v| v * v).sum::<f64>() / n_f)
+        .collect();
+    if scales.iter().any(|z| *z <= 0.0) {
+        return JsValue::NULL;
+    }
+
+    let p = centered.len();
+    let mut slopes = vec![0.0; p];
+    let mut residuals = y_centered;
+    let max_iterations = 10000;
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < max_iterations {
+        iterations += 1;
+        let mut change: f64 = 0.0;
+        for j in 0..p {
+            // Correlation of predictor j with the partial residual excluding it
+            let rho = centered[j]
+                .iter()
+                .zip(&residuals)
+                .map(|(x, e)| x * (e + slopes[j] * x))
+                .sum::<f64>()
+                / n_f;
+            let updated = rho.signum() * (rho.abs() - lambda).max(0.0) / scales[j];
+            let delta = updated - slopes[j];
+            if delta != 0.0 {
+                for (e, x) in residuals.iter_mut().zip(&centered[j]) {
+                    *e -= delta * x;
+                }
+            }
+            change = change.max(delta.abs());
+            slopes[j] = updated;
+        }
+        if change < 1e-10 {
+            converged = true;
+            break;
+        }
+    }
+
+    let (intercept, r2) = penalized_summary(&predictors, &y, &slopes, &means, y_mean);
+    let coefficients: Vec<f64> = std::iter::once(intercept).chain(slopes).collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(coefficients),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("r2"), &JsValue::from_f64(r2));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("iterations"),
+        &JsValue::from_f64(iterations as f64),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("converged"),
+        &JsValue::from_bool(converged),
+    );
+    obj.into()
+}
+
+/// Builds an object describing the information criteria of an OLS fit, counting the
+/// error variance as an estimated parameter.
+///
+/// # Arguments
+///
+/// * `fit` - The fitted model.
+/// * `n` - The number of observations.
+///
+/// # Returns
+///
+/// * An object with the properties `log_likelihood`, `aic`, `bic`, `sse`, and `df`.
+fn information_criteria(fit: &OlsFit, n: usize) -> Object {
+    let n_f = n as f64;
+    let k = fit.coefficients.len() as f64 + 1.0;
+    let log_likelihood =
+        -n_f / 2.0 * ((2.0 * std::f64::consts::PI).ln() + (fit.sse / n_f).ln() + 1.0);
+    let aic = 2.0 * k - 2.0 * log_likelihood;
+    let bic = k * n_f.ln() - 2.0 * log_likelihood;
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("log_likelihood"),
+        &JsValue::from_f64(log_likelihood),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("aic"), &JsValue::from_f64(aic));
+    let _ = Reflect::set(&obj, &JsValue::from_str("bic"), &JsValue::from_f64(bic));
+    let _ = Reflect::set(&obj, &JsValue::from_str("sse"), &JsValue::from_f64(fit.sse));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("df"),
+        &JsValue::from_f64(fit.df_resid),
+    );
+    obj
+}
+
+/// Compares a reduced linear model against a full model containing additional
+/// predictors, using information criteria and the partial F test for the added terms.
+///
+/// # Arguments
+///
+/// * `x_matrix_reduced` - A reference to a JsValue representing a JavaScript array of the
+///   reduced model's predictor columns (may be empty for an intercept-only model).
+/// * `x_matrix_full` - A reference to a JsValue representing a JavaScript array of the full
+///   model's predictor columns, which should include those of the reduced model.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+///
+/// # Returns
+///
+/// * An object with the properties `reduced` and `full` (each holding `log_likelihood`,
+///   `aic`, `bic`, `sse`, and `df`), and `f`, `df1`, `df2`, and `p` for the partial F test,
+///   or null if the inputs are invalid, the full model does not have more predictors, or
+///   either model's predictors are collinear.
+#[wasm_bindgen]
+pub fn compare_models(x_matrix_reduced: &JsValue, x_matrix_full: &JsValue, y: &JsValue) -> JsValue {
+    let to_columns = |matrix: &JsValue| -> Vec<Vec<f64>> {
+        if matrix.is_undefined() || matrix.is_null() {
+            Vec::new()
+        } else {
+            js_nested_array_to_vector(matrix)
+                .iter()
+                .map(js_array_to_vector)
+                .collect()
+        }
+    };
+    let reduced = to_columns(x_matrix_reduced);
+    let full = to_columns(x_matrix_full);
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if full.len() <= reduced.len() || reduced.iter().chain(&full).any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let (reduced_fit, full_fit) = match (
+        ols_fit(&design_matrix(&reduced, n), &y),
+        ols_fit(&design_matrix(&full, n), &y),
+    ) {
+        (Some(reduced_fit), Some(full_fit)) => (reduced_fit, full_fit),
+        _ => return JsValue::NULL,
+    };
+
+    let df1 = reduced_fit.df_resid - full_fit.df_resid;
+    let df2 = full_fit.df_resid;
+    let f = ((reduced_fit.sse - full_fit.sse) / df1) / (full_fit.sse / df2);
+    let p = 1.0 - FisherSnedecor::new(df1, df2).unwrap().cdf(f);
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("reduced"),
+        &information_criteria(&reduced_fit, n),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("full"),
+        &information_criteria(&full_fit, n),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df1"), &JsValue::from_f64(df1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df2"), &JsValue::from_f64(df2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Fits a linear quantile regression of `y` on `x` by minimizing the asymmetric absolute
+/// (check) loss, approximated with iteratively reweighted least squares. Each iteration
+/// weights an observation by `tau` (or `1 - tau` below the line) divided by its absolute
+/// residual, floored at 1e-6.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the independent variable.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `tau` - A reference to a JsValue representing the quantile to estimate, strictly
+///   between 0 and 1 (defaults to 0.5, median regression).
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients` (intercept and slope), `loss` (the
+///   check loss at the solution), `iterations`, and `converged`, or null if the inputs
+///   differ in length, have fewer than three values, `x` is constant, or `tau` is out of
+///   range. When the minimizer is not unique, any point of the optimal set may be returned.
+#[wasm_bindgen]
+pub fn quantile_regression(x: &JsValue, y: &JsValue, tau: &JsValue) -> JsValue {
+    let tau = tau.as_f64().unwrap_or(0.5);
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if x.len() != n || n < 3 || tau.is_nan() || tau <= 0.0 || tau >= 1.0 {
+        return JsValue::NULL;
+    }
+
+    let design = design_matrix(&[x], n);
+    let residuals_of = |coefficients: &[f64]| -> Vec<f64> {
+        design
+            .iter()
+            .zip(&y)
+            .map(|(row, yi)| yi - row[0] * coefficients[0] - row[1] * coefficients[1])
+            .collect()
+    };
+
+    let mut coefficients = match ols_fit(&design, &y) {
+        Some(fit) => fit.coefficients,
+        None => return JsValue::NULL,
+    };
+    let max_iterations = 1000;
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < max_iterations {
+        let root_weights: Vec<f64> = residuals_of(&coefficients)
+            .iter()
+            .map(|e| {
+                let side = if *e >= 0.0 { tau } else { 1.0 - tau };
+                (side / e.abs().max(1e-6)).sqrt()
+            })
+            .collect();
+        let weighted_design: Vec<Vec<f64>> = design
+            .iter()
+            .zip(&root_weights)
+            .map(|(row, w)| row.iter().map(|x| x * w).collect())
+            .collect();
+        let weighted_y: Vec<f64> = y.iter().zip(&root_weights).map(|(yi, w)| yi * w).collect();
+        let updated = match ols_fit(&weighted_design, &weighted_y) {
+            Some(fit) => fit.coefficients,
+            None => return JsValue::NULL,
+        };
+
+        iterations += 1;
+        let change = updated
+            .iter()
+            .zip(&coefficients)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max);
+        coefficients = updated;
+        if change < 1e-9 {
+            converged = true;
+            break;
+        }
+    }
+
+    let loss = residuals_of(&coefficients)
+        .iter()
+        .map(|e| if *e >= 0.0 { tau * e } else { (tau - 1.0) * e })
+        .sum::<f64>();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(coefficients),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("loss"), &JsValue::from_f64(loss));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("iterations"),
+        &JsValue::from_f64(iterations as f64),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("converged"),
+        &JsValue::from_bool(converged),
+    );
+    obj.into()
+}
+
+/// A multiple linear regression model fitted by ordinary least squares, kept so that it
+/// can be summarized and used for predictions without re-estimating it.
+#[wasm_bindgen]
+pub struct RegressionModel {
+    fit: OlsFit,
+    n: usize,
+}
+
+#[wasm_bindgen]
+impl RegressionModel {
+    /// The estimated coefficients, intercept first.
+    #[wasm_bindgen(getter)]
+    pub fn coefficients(&self) -> JsValue {
+        vec_to_jsvalue(self.fit.coefficients.clone())
+    }
+
+    /// The residuals of the fitted observations.
+    #[wasm_bindgen(getter)]
+    pub fn residuals(&self) -> JsValue {
+        vec_to_jsvalue(self.fit.residuals.clone())
+    }
+
+    /// Summarizes the coefficient tests and overall fit of the model.
+    ///
+    /// # Returns
+    ///
+    /// * An object with the properties `coefficients`, `std_errors`, `t_values`, and
+    ///   `p_values` (intercept first), `r2`, `adj_r2`, `f`, `p`, `df1`, and `df2`.
+    pub fn summary(&self) -> JsValue {
+        let obj = Object::new();
+        set_fit_statistics(&obj, &self.fit, self.n);
+        obj.into()
+    }
+
+    /// Predicts the response for new observations.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_x` - A reference to a JsValue representing a JavaScript array of predictor
+    ///   columns in the same order as those used to fit the model.
+    ///
+    /// # Returns
+    ///
+    /// * A JavaScript array of predicted values, or null if the number of columns does not
+    ///   match the model or the columns differ in length.
+    pub fn predict(&self, new_x: &JsValue) -> JsValue {
+        let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(new_x)
+            .iter()
+            .map(js_array_to_vector)
+            .collect();
+        if predictors.len() + 1 != self.fit.coefficients.len() {
+            return JsValue::NULL;
+        }
+        let m = predictors.first().map_or(0, |col| col.len());
+        if predictors.iter().any(|col| col.len() != m) {
+            return JsValue::NULL;
+        }
+
+        let predictions: Vec<f64> = design_matrix(&predictors, m)
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&self.fit.coefficients)
+                    .map(|(x, b)| x * b)
+                    .sum()
+            })
+            .collect();
+        vec_to_jsvalue(predictions)
+    }
+}
+
+/// Fits a multiple linear regression with an intercept and returns the model for reuse.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+///
+/// # Returns
+///
+/// * The fitted model, with the properties `coefficients` and `residuals` and the methods
+///   `summary()` and `predict(new_x)`, or undefined if the inputs are invalid or the
+///   predictors are collinear.
+#[wasm_bindgen]
+pub fn fit_regression_model(x_matrix: &JsValue, y: &JsValue) -> Option<RegressionModel> {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return None;
+    }
+
+    let fit = ols_fit(&design_matrix(&predictors, n), &y)?;
+    Some(RegressionModel { fit, n })
+}
+
+/// Fits a Deming regression, which allows for measurement error in both variables, for
+/// comparing two measurement methods. Confidence intervals use jackknife standard errors
+/// with `n - 2` degrees of freedom.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the measurements of the first method.
+/// * `y` - A reference to a JsValue representing the measurements of the second method.
+/// * `lambda` - A reference to a JsValue representing the ratio of the error variance of
+///   `y` to that of `x` (defaults to 1, orthogonal regression).
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `slope`, `intercept`, `slope_ci`, and `intercept_ci`,
+///   or null if the inputs differ in length, have fewer than three values, `lambda` is
+///   not positive, or the variables are uncorrelated.
+#[wasm_bindgen]
+pub fn deming_regression(x: &JsValue, y: &JsValue, lambda: &JsValue, alpha: &JsValue) -> JsValue {
+    let lambda = lambda.as_f64().unwrap_or(1.0);
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if x.len() != n || n < 3 || lambda.is_nan() || lambda <= 0.0 {
+        return JsValue::NULL;
+    }
+
+    let fit = |indices: &mut dyn Iterator<Item = usize>| -> Option<(f64, f64)> {
+        let points: Vec<(f64, f64)> = indices.map(|i| (x[i], y[i])).collect();
+        let m = points.len() as f64;
+        let x_mean = points.iter().map(|p| p.0).sum::<f64>() / m;
+        let y_mean = points.iter().map(|p| p.1).sum::<f64>() / m;
+        let sxx = points.iter().map(|p| (p.0 - x_mean).powi(2)).sum::<f64>();
+        let syy = points.iter().map(|p| (p.1 - y_mean).powi(2)).sum::<f64>();
+        let sxy = points
+            .iter()
+            .map(|p| (p.0 - x_mean) * (p.1 - y_mean))
+            .sum::<f64>();
+        if sxy == 0.0 {
+            return None;
+        }
+        let spread = syy - lambda * sxx;
+        let slope = (spread + (spread.powi(2) + 4.0 * lambda * sxy.powi(2)).sqrt()) / (2.0 * sxy);
+        Some((slope, y_mean - slope * x_mean))
+    };
+
+    let (slope, intercept) = match fit(&mut (0..n)) {
+        Some(estimates) => estimates,
+        None => return JsValue::NULL,
+    };
+
+    // Jackknife pseudo-values from the leave-one-out fits
+    let n_f = n as f64;
+    let mut slope_pseudo = Vec::with_capacity(n);
+    let mut intercept_pseudo = Vec::with_capacity(n);
+    for i in 0..n {
+        let (slope_i, intercept_i) = match fit(&mut (0..n).filter(|&j| j != i)) {
+            Some(estimates) => estimates,
+            None => return JsValue::NULL,
+        };
+        slope_pseudo.push(n_f * slope - (n_f - 1.0) * slope_i);
+        intercept_pseudo.push(n_f * intercept - (n_f - 1.0) * intercept_i);
+    }
+    let jackknife_se = |pseudo: &[f64]| {
+        let mean = pseudo.iter().sum::<f64>() / n_f;
+        (pseudo.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / ((n_f - 1.0) * n_f)).sqrt()
+    };
+
+    let (slope_lower, slope_upper) = t_bounds(slope, jackknife_se(&slope_pseudo), n_f - 2.0, alpha);
+    let (intercept_lower, intercept_upper) =
+        t_bounds(intercept, jackknife_se(&intercept_pseudo), n_f - 2.0, alpha);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("slope"), &JsValue::from_f64(slope));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("intercept"),
+        &JsValue::from_f64(intercept),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("slope_ci"),
+        &vec_to_jsvalue(vec![slope_lower, slope_upper]),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("intercept_ci"),
+        &vec_to_jsvalue(vec![intercept_lower, intercept_upper]),
+    );
+    obj.into()
+}
+
+/// Fits a Passing-Bablok regression for comparing two measurement methods. The slope is
+/// the median of the pairwise slopes shifted by the number of slopes below -1, and its
+/// interval follows from the ranks of those slopes; slopes of exactly -1 are discarded
+/// and vertical pairs count as infinite slopes.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the measurements of the first method.
+/// * `y` - A reference to a JsValue representing the measurements of the second method.
+/// * `alpha` - A reference to a JsValue representing the significance level (defaults to
+///   0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `slope`, `intercept`, `slope_ci`, and `intercept_ci`,
+///   or null if the inputs differ in length, have fewer than three values, or leave no
+///   usable pairwise slopes.
+#[wasm_bindgen]
+pub fn passing_bablok_regression(x: &JsValue, y: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let n = y.len();
+
+    if x.len() != n || n < 3 {
+        return JsValue::NULL;
+    }
+
+    let mut slopes: Vec<f64> = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| x[i] != x[j] || y[i] != y[j])
+        .map(|(i, j)| {
+            let dy = y[j] - y[i];
+            if x[i] == x[j] {
+                f64::INFINITY.copysign(dy)
+            } else {
+                dy / (x[j] - x[i])
+            }
+        })
+        .filter(|s| *s != -1.0)
+        .collect();
+    if slopes.is_empty() {
+        return JsValue::NULL;
+    }
+    slopes.sort_by(|a, b| a.total_cmp(b));
+
+    let count = slopes.len();
+    let shift = slopes.iter().filter(|s| **s < -1.0).count();
+    // Ranks are one-based and clamped to the available slopes
+    let rank = |r: usize| slopes[(r + shift).clamp(1, count) - 1];
+    let slope = if count % 2 == 1 {
+        rank(count.div_ceil(2))
+    } else {
+        (rank(count / 2) + rank(count / 2 + 1)) / 2.0
+    };
+
+    let c = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0)
+        * (n as f64 * (n as f64 - 1.0) * (2.0 * n as f64 + 5.0) / 18.0).sqrt();
+    let m1 = ((count as f64 - c) / 2.0).round().max(1.0) as usize;
+    let m2 = count + 1 - m1;
+    let (slope_lower, slope_upper) = (rank(m1), rank(m2));
+
+    let intercept_at = |b: f64| {
+        let mut offsets: Vec<f64> = x.iter().zip(&y).map(|(xi, yi)| yi - b * xi).collect();
+        offsets.sort_by(|a, b| a.total_cmp(b));
+        sorted_median(&offsets)
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("slope"), &JsValue::from_f64(slope));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("intercept"),
+        &JsValue::from_f64(intercept_at(slope)),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("slope_ci"),
+        &vec_to_jsvalue(vec![slope_lower, slope_upper]),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("intercept_ci"),
+        &vec_to_jsvalue(vec![intercept_at(slope_upper), intercept_at(slope_lower)]),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_multiple_regression() {
+        let x = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 9.0],
+        ]);
+        let y = vec_to_jsvalue(vec![3.0, 4.0, 8.0, 9.0, 12.0, 14.0, 15.0, 19.0]);
+
+        let result = multiple_regression(&x, &y);
+
+        let coefficients =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("coefficients")).unwrap());
+        let r2 = Reflect::get(&result, &JsValue::from_str("r2")).unwrap();
+        let f = Reflect::get(&result, &JsValue::from_str("f")).unwrap();
+        let f2 = Reflect::get(&result, &JsValue::from_str("f2")).unwrap();
+        let f2_partial =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("f2_partial")).unwrap());
+
+        assert!((coefficients[0] - 0.45098).abs() < 0.001);
+        assert!((coefficients[1] - 1.90196).abs() < 0.001);
+        assert!((coefficients[2] - 0.31373).abs() < 0.001);
+        assert!((r2.as_f64().unwrap() - 0.98644).abs() < 0.001);
+        assert!((f.as_f64().unwrap() - 181.8581).abs() < 0.01);
+        assert!((f2.as_f64().unwrap() - 72.7432).abs() < 0.01);
+        assert!((f2_partial[0] - 6.8729).abs() < 0.01);
+        assert!((f2_partial[1] - 0.2471).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_polynomial_regression() {
+        let x = vec_to_jsvalue((1..=10).map(|i| i as f64).collect());
+        let y = vec_to_jsvalue(vec![2.3, 3.1, 5.2, 8.9, 13.8, 19.6, 27.1, 35.4, 44.2, 55.9]);
+
+        let result = polynomial_regression(&x, &y, &JsValue::from_f64(2.0));
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let coefficients = js_array_to_vector(&get("coefficients"));
+        let std_errors = js_array_to_vector(&get("std_errors"));
+        let fitted = js_array_to_vector(&get("fitted"));
+
+        assert!((coefficients[0] - 2.3).abs() < 0.0001);
+        assert!((coefficients[1] + 0.784848).abs() < 0.0001);
+        assert!((coefficients[2] - 0.612121).abs() < 0.0001);
+        assert!((std_errors[2] - 0.013648).abs() < 0.0001);
+        assert!((get("r2").as_f64().unwrap() - 0.999779).abs() < 0.0001);
+        assert!((get("adj_r2").as_f64().unwrap() - 0.999716).abs() < 0.0001);
+        assert!((get("f").as_f64().unwrap() - 15845.9519).abs() < 0.01);
+        assert_eq!(get("df1").as_f64(), Some(2.0));
+        assert!((fitted[0] - 2.127273).abs() < 0.0001);
+        assert!((fitted[2] - 5.454545).abs() < 0.0001);
+        assert!(polynomial_regression(&x, &y, &JsValue::from_f64(1.5)).is_null());
+        assert!(polynomial_regression(&x, &y, &JsValue::from_f64(1e9)).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_regression_interval() {
+        let x = vec_to_jsvalue(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let y = vec_to_jsvalue(vec![3.0, 4.0, 8.0, 9.0, 12.0, 14.0, 15.0, 19.0]);
+
+        let result = regression_interval(&x, &y, &JsValue::from_f64(0.05));
+
+        let get = |key: &str| {
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str(key)).unwrap())
+        };
+        let coefficients = get("coefficients");
+        let std_errors = get("std_errors");
+        let t_values = get("t_values");
+        let p_values = get("p_values");
+        let ci: Vec<Vec<f64>> =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap())
+                .iter()
+                .map(js_array_to_vector)
+                .collect();
+
+        assert!((coefficients[0] - 0.428571).abs() < 0.0001);
+        assert!((coefficients[1] - 2.238095).abs() < 0.0001);
+        assert!((std_errors[0] - 0.605155).abs() < 0.0001);
+        assert!((std_errors[1] - 0.119839).abs() < 0.0001);
+        assert!((t_values[0] - 0.708201).abs() < 0.0001);
+        assert!((t_values[1] - 18.675906).abs() < 0.001);
+        assert!((p_values[0] - 0.505389).abs() < 0.0001);
+        assert!((ci[0][0] + 1.052191).abs() < 0.0001);
+        assert!((ci[0][1] - 1.909334).abs() < 0.0001);
+        assert!((ci[1][0] - 1.944861).abs() < 0.0001);
+        assert!((ci[1][1] - 2.531330).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_theil_sen_regression() {
+        let x = vec_to_jsvalue((1..=12).map(|i| i as f64).collect());
+        let y = vec_to_jsvalue(vec![
+            2.0, 4.1, 5.9, 8.2, 9.9, 30.0, 14.1, 15.8, 18.2, 20.1, 1.0, 24.0,
+        ]);
+
+        let result1 = theil_sen_regression(&x, &y, &JsValue::UNDEFINED);
+        let result2 = theil_sen_regression(&x, &y, &JsValue::from_f64(0.1));
+
+        let slope = Reflect::get(&result1, &JsValue::from_str("slope")).unwrap();
+        let intercept = Reflect::get(&result1, &JsValue::from_str("intercept")).unwrap();
+        let ci1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let ci2 = js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("ci")).unwrap());
+
+        assert!((slope.as_f64().unwrap() - 1.986667).abs() < 0.0001);
+        assert!((intercept.as_f64().unwrap() - 0.143333).abs() < 0.0001);
+        assert!((ci1[0] - 1.9).abs() < 0.0001);
+        assert!((ci1[1] - 2.04).abs() < 0.0001);
+        assert!((ci2[0] - 1.933333).abs() < 0.0001);
+        assert!((ci2[1] - 2.025).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_robust_regression() {
+        let x = nested_vec_to_jsvalue(vec![
+            (1..=12).map(|i| i as f64).collect(),
+            vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0],
+        ]);
+        let y = vec_to_jsvalue(vec![
+            5.1, 6.9, 10.2, 10.8, 14.1, 19.0, 14.9, 19.2, 20.8, 21.1, 40.0, 27.9,
+        ]);
+
+        let huber = robust_regression(&x, &y, &JsValue::UNDEFINED);
+        let bisquare = robust_regression(&x, &y, &JsValue::from_str("bisquare"));
+
+        let get =
+            |result: &JsValue, key: &str| Reflect::get(result, &JsValue::from_str(key)).unwrap();
+        let huber_coefficients = js_array_to_vector(&get(&huber, "coefficients"));
+        let huber_weights = js_array_to_vector(&get(&huber, "weights"));
+        let bisquare_coefficients = js_array_to_vector(&get(&bisquare, "coefficients"));
+        let bisquare_weights = js_array_to_vector(&get(&bisquare, "weights"));
+
+        assert!((huber_coefficients[0] - 2.343618).abs() < 0.0001);
+        assert!((huber_coefficients[1] - 1.720392).abs() < 0.0001);
+        assert!((huber_coefficients[2] - 0.638604).abs() < 0.0001);
+        assert!((get(&huber, "scale").as_f64().unwrap() - 0.788429).abs() < 0.0001);
+        assert!((huber_weights[10] - 0.0682).abs() < 0.001);
+        assert_eq!(get(&huber, "converged").as_bool(), Some(true));
+        assert!((bisquare_coefficients[0] - 2.476542).abs() < 0.0001);
+        assert!((bisquare_coefficients[1] - 1.660751).abs() < 0.0001);
+        assert!((bisquare_coefficients[2] - 0.670953).abs() < 0.0001);
+        assert_eq!(bisquare_weights[10], 0.0);
+        assert!(robust_regression(&x, &y, &JsValue::from_str("cauchy")).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_ridge_regression() {
+        let x = nested_vec_to_jsvalue(vec![
+            (1..=12).map(|i| i as f64).collect(),
+            vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0],
+        ]);
+        let y = vec_to_jsvalue(vec![
+            5.1, 6.9, 10.2, 10.8, 14.1, 19.0, 14.9, 19.2, 20.8, 21.1, 40.0, 27.9,
+        ]);
+
+        let result = ridge_regression(&x, &y, &JsValue::from_f64(5.0));
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let coefficients = js_array_to_vector(&get("coefficients"));
+
+        assert!((coefficients[0] - 1.526355).abs() < 0.0001);
+        assert!((coefficients[1] - 2.175188).abs() < 0.0001);
+        assert!((coefficients[2] - 0.423444).abs() < 0.0001);
+        assert!((get("r2").as_f64().unwrap() - 0.809420).abs() < 0.0001);
+        assert!((get("df").as_f64().unwrap() - 1.871436).abs() < 0.0001);
+        assert!(ridge_regression(&x, &y, &JsValue::from_f64(-1.0)).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_lasso_regression() {
+        let x = nested_vec_to_jsvalue(vec![
+            (1..=12).map(|i| i as f64).collect(),
+            vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0],
+        ]);
+        let y = vec_to_jsvalue(vec![
+            5.1, 6.9, 10.2, 10.8, 14.1, 19.0, 14.9, 19.2, 20.8, 21.1, 40.0, 27.9,
+        ]);
+
+        let result1 = lasso_regression(&x, &y, &JsValue::from_f64(1.0));
+        let result2 = lasso_regression(&x, &y, &JsValue::from_f64(3.0));
+
+        let coefficients1 = js_array_to_vector(
+            &Reflect::get(&result1, &JsValue::from_str("coefficients")).unwrap(),
+        );
+        let coefficients2 = js_array_to_vector(
+            &Reflect::get(&result2, &JsValue::from_str("coefficients")).unwrap(),
+        );
+        let r2 = Reflect::get(&result1, &JsValue::from_str("r2")).unwrap();
+        let converged = Reflect::get(&result1, &JsValue::from_str("converged")).unwrap();
+
+        assert!((coefficients1[0] - 1.945126).abs() < 0.0001);
+        assert!((coefficients1[1] - 2.229716).abs() < 0.0001);
+        assert!((coefficients1[2] - 0.245012).abs() < 0.0001);
+        assert!((r2.as_f64().unwrap() - 0.808181).abs() < 0.0001);
+        assert_eq!(converged.as_bool(), Some(true));
+        assert!((coefficients2[1] - 2.147552).abs() < 0.0001);
+        assert_eq!(coefficients2[2], 0.0);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_compare_models() {
+        let x1: Vec<f64> = (1..=12).map(|i| i as f64).collect();
+        let x2 = vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0];
+        let x3 = vec![2.0, 2.0, 3.0, 5.0, 4.0, 4.0, 6.0, 7.0, 6.0, 8.0, 9.0, 9.0];
+        let reduced = nested_vec_to_jsvalue(vec![x1.clone()]);
+        let full = nested_vec_to_jsvalue(vec![x1, x2, x3]);
+        let y = vec_to_jsvalue(vec![
+            5.1, 6.9, 10.2, 10.8, 14.1, 19.0, 14.9, 19.2, 20.8, 21.1, 40.0, 27.9,
+        ]);
+
+        let result = compare_models(&reduced, &full, &y);
+
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+        let reduced_model = get(&result, "reduced");
+        let full_model = get(&result, "full");
+
+        assert!(
+            (get(&reduced_model, "log_likelihood").as_f64().unwrap() + 33.991734).abs() < 0.0001
+        );
+        assert!((get(&reduced_model, "aic").as_f64().unwrap() - 73.983467).abs() < 0.0001);
+        assert!((get(&reduced_model, "bic").as_f64().unwrap() - 75.438187).abs() < 0.0001);
+        assert!((get(&full_model, "aic").as_f64().unwrap() - 76.763389).abs() < 0.0001);
+        assert!((get(&full_model, "bic").as_f64().unwrap() - 79.187922).abs() < 0.0001);
+        assert!((get(&result, "f").as_f64().unwrap() - 0.428087).abs() < 0.0001);
+        assert!((get(&result, "p").as_f64().unwrap() - 0.665849).abs() < 0.0001);
+        assert_eq!(get(&result, "df1").as_f64(), Some(2.0));
+        assert!(compare_models(&full, &reduced, &y).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_quantile_regression() {
+        let x = vec_to_jsvalue((1..=11).map(|i| i as f64).collect());
+        let y = vec_to_jsvalue(vec![
+            2.0, 4.1, 5.9, 8.2, 9.9, 30.0, 14.1, 15.8, 18.2, 20.1, 1.0,
+        ]);
+
+        let median = quantile_regression(&x, &y, &JsValue::UNDEFINED);
+        let lower = quantile_regression(&x, &y, &JsValue::from_f64(0.25));
+        let upper = quantile_regression(&x, &y, &JsValue::from_f64(0.8));
+
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+        let lower_coefficients = js_array_to_vector(&get(&lower, "coefficients"));
+        let upper_coefficients = js_array_to_vector(&get(&upper, "coefficients"));
+
+        assert!((get(&median, "loss").as_f64().unwrap() - 20.0).abs() < 0.001);
+        assert!((lower_coefficients[0] - 0.028571).abs() < 0.001);
+        assert!((lower_coefficients[1] - 1.971429).abs() < 0.001);
+        assert!((get(&lower, "loss").as_f64().unwrap() - 20.475).abs() < 0.001);
+        assert!((upper_coefficients[0] - 0.2).abs() < 0.001);
+        assert!((upper_coefficients[1] - 2.0).abs() < 0.001);
+        assert_eq!(get(&upper, "converged").as_bool(), Some(true));
+        assert!(quantile_regression(&x, &y, &JsValue::from_f64(1.0)).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_fit_regression_model() {
+        let x = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 9.0],
+        ]);
+        let y = vec_to_jsvalue(vec![3.0, 4.0, 8.0, 9.0, 12.0, 14.0, 15.0, 19.0]);
+
+        let model = fit_regression_model(&x, &y).unwrap();
+
+        let coefficients = js_array_to_vector(&model.coefficients());
+        let summary = model.summary();
+        let r2 = Reflect::get(&summary, &JsValue::from_str("r2")).unwrap();
+        let predictions = js_array_to_vector(&model.predict(&nested_vec_to_jsvalue(vec![
+            vec![9.0, 10.0],
+            vec![10.0, 7.0],
+        ])));
+
+        assert!((coefficients[1] - 1.901961).abs() < 0.0001);
+        assert!((r2.as_f64().unwrap() - 0.986439).abs() < 0.0001);
+        assert!((predictions[0] - 20.705882).abs() < 0.0001);
+        assert!((predictions[1] - 21.666667).abs() < 0.0001);
+        assert!(model
+            .predict(&nested_vec_to_jsvalue(vec![vec![9.0, 10.0]]))
+            .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_deming_regression() {
+        let x = vec_to_jsvalue(vec![
+            5.2, 6.1, 7.3, 8.0, 9.4, 10.1, 11.5, 12.2, 13.8, 15.0, 16.1, 17.9,
+        ]);
+        let y = vec_to_jsvalue(vec![
+            5.6, 6.0, 7.9, 8.7, 9.5, 10.9, 12.3, 12.6, 14.9, 15.8, 17.4, 19.0,
+        ]);
+
+        let result1 = deming_regression(&x, &y, &JsValue::UNDEFINED, &JsValue::UNDEFINED);
+        let result2 = deming_regression(&x, &y, &JsValue::from_f64(2.0), &JsValue::UNDEFINED);
+
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+        let slope_ci = js_array_to_vector(&get(&result1, "slope_ci"));
+        let intercept_ci = js_array_to_vector(&get(&result1, "intercept_ci"));
+
+        assert!((get(&result1, "slope").as_f64().unwrap() - 1.078545).abs() < 0.0001);
+        assert!((get(&result1, "intercept").as_f64().unwrap() + 0.201258).abs() < 0.0001);
+        assert!((slope_ci[0] - 1.031509).abs() < 0.0001);
+        assert!((slope_ci[1] - 1.125581).abs() < 0.0001);
+        assert!((intercept_ci[0] + 0.813811).abs() < 0.0001);
+        assert!((intercept_ci[1] - 0.411295).abs() < 0.0001);
+        assert!((get(&result2, "slope").as_f64().unwrap() - 1.077834).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_passing_bablok_regression() {
+        let x = vec_to_jsvalue(vec![
+            5.2, 6.1, 7.3, 8.0, 9.4, 10.1, 11.5, 12.2, 13.8, 15.0, 16.1, 17.9,
+        ]);
+        let y = vec_to_jsvalue(vec![
+            5.6, 6.0, 7.9, 8.7, 9.5, 10.9, 12.3, 12.6, 14.9, 15.8, 17.4, 19.0,
+        ]);
+
+        let result = passing_bablok_regression(&x, &y, &JsValue::UNDEFINED);
+
+        let get = |key: &str| Reflect::get(&result, &JsValue::from_str(key)).unwrap();
+        let slope_ci = js_array_to_vector(&get("slope_ci"));
+        let intercept_ci = js_array_to_vector(&get("intercept_ci"));
+
+        assert!((get("slope").as_f64().unwrap() - 1.080313).abs() < 0.0001);
+        assert!((get("intercept").as_f64().unwrap() + 0.070616).abs() < 0.0001);
+        assert!((slope_ci[0] - 1.038462).abs() < 0.0001);
+        assert!((slope_ci[1] - 1.117647).abs() < 0.0001);
+        assert!((intercept_ci[0] + 0.573529).abs() < 0.0001);
+        assert!((intercept_ci[1] - 0.338462).abs() < 0.0001);
+    }
+}
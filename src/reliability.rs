@@ -0,0 +1,535 @@
+use crate::categorical::{group_indices, group_indices_by};
+use crate::conf_int::z_bounds;
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+use wasm_bindgen::prelude::*;
+
+/// Computes the sample variance of a slice of values.
+fn sample_variance(values: &[f64]) -> f64 {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() as f64 - 1.0)
+}
+
+/// Computes Cronbach's alpha for a set of item columns answered by the same respondents.
+fn alpha_coefficient(items: &[&Vec<f64>]) -> f64 {
+    let k = items.len() as f64;
+    let item_variance: f64 = items.iter().map(|item| sample_variance(item)).sum();
+    let totals: Vec<f64> = (0..items[0].len())
+        .map(|i| items.iter().map(|item| item[i]).sum())
+        .collect();
+    k / (k - 1.0) * (1.0 - item_variance / sample_variance(&totals))
+}
+
+/// Computes Cronbach's alpha as a measure of the internal consistency of a scale.
+///
+/// # Arguments
+///
+/// * `items_matrix` - A reference to a JsValue representing a JavaScript array of item
+///   columns, each holding one score per respondent.
+/// * `alpha` - A reference to a JsValue representing the significance level for the
+///   confidence interval (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci` (Feldt's F-based interval), and
+///   `alpha_if_deleted` (the coefficient recomputed without each item, which is NaN for
+///   two-item scales), or null if there are fewer than two items or respondents or the
+///   columns differ in length.
+#[wasm_bindgen]
+pub fn cronbach_alpha(items_matrix: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let items: Vec<Vec<f64>> = js_nested_array_to_vector(items_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let k = items.len();
+    if k < 2 {
+        return JsValue::NULL;
+    }
+    let n = items[0].len();
+    if n < 2 || items.iter().any(|item| item.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let estimate = alpha_coefficient(&items.iter().collect::<Vec<_>>());
+
+    let df1 = n as f64 - 1.0;
+    let df2 = df1 * (k as f64 - 1.0);
+    let f_dist = FisherSnedecor::new(df1, df2).unwrap();
+    let lower = 1.0 - (1.0 - estimate) * f_dist.inverse_cdf(1.0 - alpha / 2.0);
+    let upper = 1.0 - (1.0 - estimate) * f_dist.inverse_cdf(alpha / 2.0);
+
+    let alpha_if_deleted: Vec<f64> = (0..k)
+        .map(|j| {
+            if k < 3 {
+                return f64::NAN;
+            }
+            let remaining: Vec<&Vec<f64>> = items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != j)
+                .map(|(_, item)| item)
+                .collect();
+            alpha_coefficient(&remaining)
+        })
+        .collect();
+
+    let obj = Object::new();
+    set_estimate(&obj, estimate, (lower, upper));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("alpha_if_deleted"),
+        &vec_to_jsvalue(alpha_if_deleted),
+    );
+    obj.into()
+}
+
+/// Computes Cohen's kappa for the agreement of two raters on categorical ratings, with
+/// optional agreement weights for ordered categories.
+///
+/// # Arguments
+///
+/// * `rater1` - A reference to a JsValue representing the JavaScript array of the first
+///   rater's ratings (strings or numbers).
+/// * `rater2` - A reference to a JsValue representing the JavaScript array of the second
+///   rater's ratings, one per subject rated by the first.
+/// * `weights` - A reference to a JsValue representing the weighting, one of "none" (the
+///   default), "linear", or "quadratic".
+/// * `alpha` - A reference to a JsValue representing the significance level for the
+///   confidence interval (defaults to 0.05).
+/// * `categories` - An optional reference to a JsValue representing the JavaScript array
+///   of categories in order. Weighted forms use this order, or otherwise the numeric
+///   order of numeric ratings; string ratings have no natural order, so weighting them
+///   requires `categories`.
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci` (using the large-sample standard error
+///   of Fleiss, Cohen, and Everitt), `se`, `observed_agreement`, and `expected_agreement`,
+///   or null if the ratings differ in length, are empty, include a missing value, or use
+///   fewer than two categories, a rating is not among `categories`, the weighting is
+///   unknown, or string ratings are weighted without `categories`.
+#[wasm_bindgen]
+pub fn cohens_kappa(
+    rater1: &JsValue,
+    rater2: &JsValue,
+    weights: &JsValue,
+    alpha: &JsValue,
+    categories: &JsValue,
+) -> JsValue {
+    let weights = weights.as_string().unwrap_or("none".to_string());
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let first = Array::from(rater1);
+    let second = Array::from(rater2);
+    let n = first.length() as usize;
+    if n == 0 || second.length() as usize != n {
+        return JsValue::NULL;
+    }
+
+    // Categories are shared by the raters, so classify both sets of ratings together
+    let ratings = first.concat(&second);
+    let ordered = !(categories.is_undefined() || categories.is_null());
+    if weights != "none" && !ordered && ratings.iter().any(|v| v.is_string()) {
+        return JsValue::NULL;
+    }
+    let groups = if ordered {
+        group_indices_by(&ratings, categories)
+    } else {
+        group_indices(&ratings)
+    };
+    let groups = match groups {
+        Some(groups) if groups.len() >= 2 => groups,
+        _ => return JsValue::NULL,
+    };
+    let k = groups.len();
+    let mut category = vec![0; 2 * n];
+    for (level, group) in groups.iter().enumerate() {
+        for &i in group {
+            category[i] = level;
+        }
+    }
+
+    let mut p = vec![vec![0.0; k]; k];
+    for i in 0..n {
+        p[category[i]][category[n + i]] += 1.0 / n as f64;
+    }
+    let row: Vec<f64> = p.iter().map(|r| r.iter().sum()).collect();
+    let column: Vec<f64> = (0..k).map(|j| p.iter().map(|r| r[j]).sum()).collect();
+
+    let weight = |i: usize, j: usize| {
+        let distance = i.abs_diff(j) as f64 / (k - 1) as f64;
+        match weights.as_str() {
+            "none" => Some(if i == j { 1.0 } else { 0.0 }),
+            "linear" => Some(1.0 - distance),
+            "quadratic" => Some(1.0 - distance.powi(2)),
+            _ => None,
+        }
+    };
+    let w: Option<Vec<Vec<f64>>> = (0..k)
+        .map(|i| (0..k).map(|j| weight(i, j)).collect())
+        .collect();
+    let w = match w {
+        Some(w) => w,
+        None => return JsValue::NULL,
+    };
+
+    let cells = || (0..k).flat_map(|i| (0..k).map(move |j| (i, j)));
+    let observed = cells().map(|(i, j)| w[i][j] * p[i][j]).sum::<f64>();
+    let expected = cells()
+        .map(|(i, j)| w[i][j] * row[i] * column[j])
+        .sum::<f64>();
+    let kappa = (observed - expected) / (1.0 - expected);
+
+    let row_weight: Vec<f64> = (0..k)
+        .map(|i| (0..k).map(|j| column[j] * w[i][j]).sum())
+        .collect();
+    let column_weight: Vec<f64> = (0..k)
+        .map(|j| (0..k).map(|i| row[i] * w[i][j]).sum())
+        .collect();
+    let variance = (cells()
+        .map(|(i, j)| {
+            p[i][j] * (w[i][j] - (row_weight[i] + column_weight[j]) * (1.0 - kappa)).powi(2)
+        })
+        .sum::<f64>()
+        - (kappa - expected * (1.0 - kappa)).powi(2))
+        / (n as f64 * (1.0 - expected).powi(2));
+    let se = variance.max(0.0).sqrt();
+
+    let obj = Object::new();
+    set_estimate(&obj, kappa, z_bounds(kappa, se, alpha));
+    let _ = Reflect::set(&obj, &JsValue::from_str("se"), &JsValue::from_f64(se));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("observed_agreement"),
+        &JsValue::from_f64(observed),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("expected_agreement"),
+        &JsValue::from_f64(expected),
+    );
+    obj.into()
+}
+
+/// Computes an intraclass correlation coefficient in the forms of Shrout and Fleiss from
+/// the mean squares of the subjects-by-raters layout.
+///
+/// # Arguments
+///
+/// * `data` - A reference to a JsValue representing a JavaScript array of rater columns,
+///   each holding one score per subject.
+/// * `model` - A reference to a JsValue representing the model: 1 for raters nested in
+///   subjects (one-way random), 2 for a random sample of raters (two-way random), or 3 for
+///   the only raters of interest (two-way mixed, consistency). Defaults to 1.
+/// * `icc_type` - A reference to a JsValue representing whether the reliability is of a
+///   "single" rating (the default) or the "average" of the raters' ratings.
+/// * `alpha` - A reference to a JsValue representing the significance level for the
+///   confidence interval (defaults to 0.05).
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci`, and `f`, `df1`, `df2`, and `p` for the
+///   F test that the coefficient is zero, or null if there are fewer than two raters or
+///   subjects, the columns differ in length, or the model or type is unknown. The interval
+///   is NaN when the raters agree perfectly, leaving no error variance.
+#[wasm_bindgen]
+pub fn icc(data: &JsValue, model: &JsValue, icc_type: &JsValue, alpha: &JsValue) -> JsValue {
+    let model = model.as_f64().unwrap_or(1.0);
+    let average = match icc_type.as_string().as_deref().unwrap_or("single") {
+        "single" => false,
+        "average" => true,
+        _ => return JsValue::NULL,
+    };
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let raters: Vec<Vec<f64>> = js_nested_array_to_vector(data)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let k = raters.len();
+    if k < 2 || (model != 1.0 && model != 2.0 && model != 3.0) {
+        return JsValue::NULL;
+    }
+    let n = raters[0].len();
+    if n < 2 || raters.iter().any(|rater| rater.len() != n) {
+        return JsValue::NULL;
+    }
+
+    let (n_f, k_f) = (n as f64, k as f64);
+    let grand_mean = raters.iter().flatten().sum::<f64>() / (n_f * k_f);
+    let subject_means: Vec<f64> = (0..n)
+        .map(|i| raters.iter().map(|rater| rater[i]).sum::<f64>() / k_f)
+        .collect();
+    let sst = raters
+        .iter()
+        .flatten()
+        .map(|v| (v - grand_mean).powi(2))
+        .sum::<f64>();
+    let ssr = k_f
+        * subject_means
+            .iter()
+            .map(|m| (m - grand_mean).powi(2))
+            .sum::<f64>();
+    let ssc = n_f
+        * raters
+            .iter()
+            .map(|rater| (rater.iter().sum::<f64>() / n_f - grand_mean).powi(2))
+            .sum::<f64>();
+
+    let msr = ssr / (n_f - 1.0);
+    let msw = (sst - ssr) / (n_f * (k_f - 1.0));
+    let msc = ssc / (k_f - 1.0);
+    let mse = (sst - ssr - ssc) / ((n_f - 1.0) * (k_f - 1.0));
+
+    let df1 = n_f - 1.0;
+    let (f, df2) = if model == 1.0 {
+        (msr / msw, n_f * (k_f - 1.0))
+    } else {
+        (msr / mse, (n_f - 1.0) * (k_f - 1.0))
+    };
+    let p = if f.is_nan() {
+        f64::NAN
+    } else {
+        1.0 - FisherSnedecor::new(df1, df2).unwrap().cdf(f)
+    };
+    // Degrees of freedom that are not finite and positive give a NaN quantile
+    let upper_quantile = |d1: f64, d2: f64| match FisherSnedecor::new(d1, d2) {
+        Ok(dist) => dist.inverse_cdf(1.0 - alpha / 2.0),
+        Err(_) => f64::NAN,
+    };
+    // Steps a single-rating coefficient up to the reliability of the average of k ratings
+    let step_up = |r: f64| k_f * r / (1.0 + (k_f - 1.0) * r);
+
+    let (estimate, lower, upper) = if model == 2.0 {
+        let single = (msr - mse) / (msr + (k_f - 1.0) * mse + k_f * (msc - mse) / n_f);
+
+        // Satterthwaite degrees of freedom for the interval of the two-way random model,
+        // which are undefined without error variance
+        let fj = msc / mse;
+        let spread = n_f * (1.0 + (k_f - 1.0) * single) - k_f * single;
+        let v = if mse > 0.0 {
+            (k_f - 1.0) * (n_f - 1.0) * (k_f * single * fj + spread).powi(2)
+                / ((n_f - 1.0) * k_f.powi(2) * single.powi(2) * fj.powi(2) + spread.powi(2))
+        } else {
+            f64::NAN
+        };
+        let f_upper = upper_quantile(n_f - 1.0, v);
+        let f_lower = upper_quantile(v, n_f - 1.0);
+        let pooled = k_f * msc + (k_f * n_f - k_f - n_f) * mse;
+        let lower = n_f * (msr - f_upper * mse) / (f_upper * pooled + n_f * msr);
+        let upper = n_f * (f_lower * msr - mse) / (pooled + n_f * f_lower * msr);
+
+        if average {
+            (step_up(single), step_up(lower), step_up(upper))
+        } else {
+            (single, lower, upper)
+        }
+    } else {
+        let error = if model == 1.0 { msw } else { mse };
+        let f_lower = f / upper_quantile(df1, df2);
+        let f_upper = f * upper_quantile(df2, df1);
+        if average {
+            (
+                (msr - error) / msr,
+                1.0 - 1.0 / f_lower,
+                1.0 - 1.0 / f_upper,
+            )
+        } else {
+            (
+                (msr - error) / (msr + (k_f - 1.0) * error),
+                (f_lower - 1.0) / (f_lower + k_f - 1.0),
+                (f_upper - 1.0) / (f_upper + k_f - 1.0),
+            )
+        }
+    };
+
+    let obj = Object::new();
+    set_estimate(&obj, estimate, (lower, upper));
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df1"), &JsValue::from_f64(df1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("df2"), &JsValue::from_f64(df2));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_cronbach_alpha() {
+        let items = nested_vec_to_jsvalue(vec![
+            vec![4.0, 5.0, 3.0, 4.0, 2.0, 5.0, 4.0, 3.0],
+            vec![3.0, 5.0, 4.0, 4.0, 2.0, 4.0, 5.0, 3.0],
+            vec![4.0, 4.0, 3.0, 5.0, 1.0, 5.0, 4.0, 2.0],
+            vec![2.0, 4.0, 3.0, 3.0, 2.0, 4.0, 3.0, 3.0],
+        ]);
+
+        let result = cronbach_alpha(&items, &JsValue::from_f64(0.05));
+
+        let estimate = Reflect::get(&result, &JsValue::from_str("estimate")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+        let alpha_if_deleted = js_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("alpha_if_deleted")).unwrap(),
+        );
+
+        assert!((estimate.as_f64().unwrap() - 0.893333).abs() < 0.0001);
+        assert!((ci[0] - 0.683346).abs() < 0.0001);
+        assert!((ci[1] - 0.976041).abs() < 0.0001);
+        assert!((alpha_if_deleted[0] - 0.810811).abs() < 0.0001);
+        assert!((alpha_if_deleted[3] - 0.895833).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_cohens_kappa() {
+        let rater1 = vec_to_jsvalue(vec![
+            1.0, 2.0, 3.0, 1.0, 2.0, 2.0, 3.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 2.0, 3.0,
+        ]);
+        let rater2 = vec_to_jsvalue(vec![
+            1.0, 2.0, 3.0, 2.0, 2.0, 1.0, 3.0, 2.0, 1.0, 2.0, 3.0, 1.0, 3.0, 2.0, 3.0,
+        ]);
+
+        let unweighted = cohens_kappa(
+            &rater1,
+            &rater2,
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        );
+        let linear = cohens_kappa(
+            &rater1,
+            &rater2,
+            &JsValue::from_str("linear"),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        );
+        let quadratic = cohens_kappa(
+            &rater1,
+            &rater2,
+            &JsValue::from_str("quadratic"),
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        );
+
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+        let ci = js_array_to_vector(&get(&unweighted, "ci"));
+
+        assert!((get(&unweighted, "estimate").as_f64().unwrap() - 0.594595).abs() < 0.0001);
+        assert!((get(&unweighted, "se").as_f64().unwrap() - 0.175690).abs() < 0.0001);
+        assert!((ci[0] - 0.250249).abs() < 0.0001);
+        assert!((ci[1] - 0.938940).abs() < 0.0001);
+        assert!(
+            (get(&unweighted, "expected_agreement").as_f64().unwrap() - 0.342222).abs() < 0.0001
+        );
+        assert!((get(&linear, "estimate").as_f64().unwrap() - 0.680851).abs() < 0.0001);
+        assert!((get(&linear, "se").as_f64().unwrap() - 0.144722).abs() < 0.0001);
+        assert!((get(&quadratic, "estimate").as_f64().unwrap() - 0.776119).abs() < 0.0001);
+        assert!((get(&quadratic, "se").as_f64().unwrap() - 0.108932).abs() < 0.0001);
+
+        // "low" < "medium" < "high" is not alphabetical, so the order must be given
+        let label = |ratings: &JsValue| {
+            let names = ["low", "medium", "high"];
+            js_array_to_vector(ratings)
+                .iter()
+                .map(|r| JsValue::from_str(names[*r as usize - 1]))
+                .collect::<Array>()
+                .into()
+        };
+        let (labels1, labels2): (JsValue, JsValue) = (label(&rater1), label(&rater2));
+        let order: JsValue = ["low", "medium", "high"]
+            .iter()
+            .map(|c| JsValue::from_str(c))
+            .collect::<Array>()
+            .into();
+        let linear = JsValue::from_str("linear");
+        let ordered = cohens_kappa(&labels1, &labels2, &linear, &JsValue::UNDEFINED, &order);
+        assert!((get(&ordered, "estimate").as_f64().unwrap() - 0.680851).abs() < 0.0001);
+        assert!(cohens_kappa(
+            &labels1,
+            &labels2,
+            &linear,
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED
+        )
+        .is_null());
+        let unweighted = cohens_kappa(
+            &labels1,
+            &labels2,
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+            &JsValue::UNDEFINED,
+        );
+        assert!((get(&unweighted, "estimate").as_f64().unwrap() - 0.594595).abs() < 0.0001);
+        let incomplete: JsValue = ["low", "high"]
+            .iter()
+            .map(|c| JsValue::from_str(c))
+            .collect::<Array>()
+            .into();
+        assert!(cohens_kappa(
+            &labels1,
+            &labels2,
+            &linear,
+            &JsValue::UNDEFINED,
+            &incomplete
+        )
+        .is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_icc() {
+        // Shrout and Fleiss (1979): six subjects rated by four judges
+        let data = nested_vec_to_jsvalue(vec![
+            vec![9.0, 6.0, 8.0, 7.0, 10.0, 6.0],
+            vec![2.0, 1.0, 4.0, 1.0, 5.0, 2.0],
+            vec![5.0, 3.0, 6.0, 2.0, 6.0, 4.0],
+            vec![8.0, 2.0, 8.0, 6.0, 9.0, 7.0],
+        ]);
+        let fit = |model: f64, icc_type: &str| {
+            icc(
+                &data,
+                &JsValue::from_f64(model),
+                &JsValue::from_str(icc_type),
+                &JsValue::UNDEFINED,
+            )
+        };
+        let get = |obj: &JsValue, key: &str| Reflect::get(obj, &JsValue::from_str(key)).unwrap();
+        let check = |obj: &JsValue, estimate: f64, lower: f64, upper: f64| {
+            let ci = js_array_to_vector(&get(obj, "ci"));
+            assert!((get(obj, "estimate").as_f64().unwrap() - estimate).abs() < 0.0001);
+            assert!((ci[0] - lower).abs() < 0.0001);
+            assert!((ci[1] - upper).abs() < 0.0001);
+        };
+
+        let icc1 = fit(1.0, "single");
+        let icc3 = fit(3.0, "single");
+
+        check(&icc1, 0.165742, -0.132932, 0.722560);
+        check(&fit(1.0, "average"), 0.442797, -0.884442, 0.912415);
+        check(&fit(2.0, "single"), 0.289764, 0.018787, 0.761084);
+        check(&fit(2.0, "average"), 0.620051, 0.071137, 0.927232);
+        check(&icc3, 0.714841, 0.342465, 0.945858);
+        check(&fit(3.0, "average"), 0.909316, 0.675675, 0.985892);
+        assert!((get(&icc1, "f").as_f64().unwrap() - 1.794678).abs() < 0.0001);
+        assert_eq!(get(&icc1, "df2").as_f64(), Some(18.0));
+        assert!((get(&icc3, "f").as_f64().unwrap() - 11.027248).abs() < 0.0001);
+        assert!((get(&icc3, "p").as_f64().unwrap() - 0.000135).abs() < 0.00001);
+        assert!(fit(4.0, "single").is_null());
+
+        let agreeing = nested_vec_to_jsvalue(vec![vec![1.0, 2.0, 3.0, 4.0, 5.0]; 3]);
+        for model in [1.0, 2.0, 3.0] {
+            let result = icc(
+                &agreeing,
+                &JsValue::from_f64(model),
+                &JsValue::UNDEFINED,
+                &JsValue::UNDEFINED,
+            );
+            let ci = js_array_to_vector(&get(&result, "ci"));
+            assert_eq!(get(&result, "estimate").as_f64().unwrap(), 1.0);
+            assert!(ci[0].is_nan() && ci[1].is_nan());
+        }
+    }
+}
@@ -0,0 +1,544 @@
+use crate::categorical::group_indices;
+use crate::regression::{design_matrix, ols_fit};
+use crate::utils::*;
+use js_sys::{Object, Reflect};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use statrs::statistics::{Data, OrderStatistics};
+use wasm_bindgen::prelude::*;
+
+/// Reads the number of resamples and the random number generator from an options object.
+fn resampling_options(options: &JsValue) -> (usize, StdRng) {
+    (
+        get_f64_option(options, "permutations").unwrap_or(9999.0) as usize,
+        seeded_rng(get_f64_option(options, "seed")),
+    )
+}
+
+/// Computes the permutation p-value of an observed statistic from its permutation
+/// distribution, counting the observed arrangement as one of the permutations.
+fn permutation_p(observed: f64, permuted: &[f64]) -> f64 {
+    let extreme = permuted
+        .iter()
+        .filter(|value| value.abs() >= observed.abs() - 1e-12)
+        .count();
+    (extreme as f64 + 1.0) / (permuted.len() as f64 + 1.0)
+}
+
+/// Tests a Pearson or Spearman correlation by permutation, with a bootstrap percentile
+/// confidence interval.
+///
+/// # Arguments
+///
+/// * `x` - A reference to a JsValue representing the first variable.
+/// * `y` - A reference to a JsValue representing the second variable.
+/// * `options` - An optional object with the properties `method` ("pearson" or
+///   "spearman"; defaults to "pearson"), `permutations` (the number of permutations and
+///   bootstrap resamples; defaults to 9999), `alpha` (defaults to 0.05), and `seed`.
+///
+/// # Returns
+///
+/// * An object with the properties `estimate`, `ci`, and `p` (two-sided), or null if the
+///   inputs differ in length, have fewer than three pairs, or either variable is constant
+///   (so the correlation is undefined). The paired observations are resampled for the
+///   interval, since the permutation distribution only describes the null of no
+///   association.
+#[wasm_bindgen]
+pub fn permutation_correlation_test(x: &JsValue, y: &JsValue, options: &JsValue) -> JsValue {
+    let x = js_array_to_vector(x);
+    let y = js_array_to_vector(y);
+    let n = x.len();
+    if n != y.len() || n < 3 {
+        return JsValue::NULL;
+    }
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let (x, y) = match get_string_option(options, "method").as_deref() {
+        None | Some("pearson") => (x, y),
+        Some("spearman") => (average_ranks(&x), average_ranks(&y)),
+        _ => return JsValue::NULL,
+    };
+    let (permutations, mut rng) = resampling_options(options);
+
+    let estimate = pearson(&x, &y);
+    if !estimate.is_finite() {
+        return JsValue::NULL;
+    }
+
+    let mut shuffled = y.clone();
+    let permuted: Vec<f64> = (0..permutations)
+        .map(|_| {
+            shuffled.shuffle(&mut rng);
+            pearson(&x, &shuffled)
+        })
+        .collect();
+    let p = permutation_p(estimate, &permuted);
+
+    let bootstrap: Vec<f64> = (0..permutations)
+        .map(|_| {
+            let indices: Vec<usize> = (0..n).map(|_| rng.gen_range(0..n)).collect();
+            let xs: Vec<f64> = indices.iter().map(|i| x[*i]).collect();
+            let ys: Vec<f64> = indices.iter().map(|i| y[*i]).collect();
+            pearson(&xs, &ys)
+        })
+        .filter(|r| r.is_finite())
+        .collect();
+    let mut bootstrap = Data::new(bootstrap);
+    let ci = (
+        bootstrap.quantile(alpha / 2.0),
+        bootstrap.quantile(1.0 - alpha / 2.0),
+    );
+
+    let obj = Object::new();
+    set_estimate(&obj, estimate, ci);
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    obj.into()
+}
+
+/// Tests the slopes of a linear regression by permutation using the Freedman-Lane
+/// procedure, which permutes the residuals of the model without the tested predictor.
+///
+/// # Arguments
+///
+/// * `x_matrix` - A reference to a JsValue representing a JavaScript array of predictor
+///   columns.
+/// * `y` - A reference to a JsValue representing the dependent variable.
+/// * `options` - An optional object with the properties `permutations` (defaults to 9999),
+///   `alpha` (defaults to 0.05), and `seed`.
+///
+/// # Returns
+///
+/// * An object with the properties `coefficients`, `t_values`, `p_values`, and `ci` (one
+///   `[lower, upper]` pair per slope), or null if the inputs are invalid or the predictors
+///   are collinear. Each interval uses the permutation quantile of |t| in place of the
+///   Student's t critical value. The intercept is not reported, and with a single
+///   predictor the procedure reduces to permuting the response.
+#[wasm_bindgen]
+pub fn permutation_regression_test(x_matrix: &JsValue, y: &JsValue, options: &JsValue) -> JsValue {
+    let predictors: Vec<Vec<f64>> = js_nested_array_to_vector(x_matrix)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let y = js_array_to_vector(y);
+    let n = y.len();
+    if predictors.is_empty() || predictors.iter().any(|col| col.len() != n) {
+        return JsValue::NULL;
+    }
+    let alpha = get_f64_option(options, "alpha").unwrap_or(0.05);
+    let (permutations, mut rng) = resampling_options(options);
+
+    let design = design_matrix(&predictors, n);
+    let fit = match ols_fit(&design, &y) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+
+    let k = predictors.len();
+    let mut coefficients = Vec::with_capacity(k);
+    let mut t_values = Vec::with_capacity(k);
+    let mut p_values = Vec::with_capacity(k);
+    let mut ci = Vec::with_capacity(k);
+    for j in 0..k {
+        let coefficient = fit.coefficients[j + 1];
+        let std_error = fit.std_errors[j + 1];
+        let t = coefficient / std_error;
+
+        let reduced: Vec<Vec<f64>> = predictors
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != j)
+            .map(|(_, col)| col.clone())
+            .collect();
+        let reduced_fit = match ols_fit(&design_matrix(&reduced, n), &y) {
+            Some(reduced_fit) => reduced_fit,
+            None => return JsValue::NULL,
+        };
+        let fitted: Vec<f64> = y
+            .iter()
+            .zip(&reduced_fit.residuals)
+            .map(|(yi, e)| yi - e)
+            .collect();
+
+        let mut residuals = reduced_fit.residuals.clone();
+        let permuted: Vec<f64> = (0..permutations)
+            .filter_map(|_| {
+                residuals.shuffle(&mut rng);
+                let y_star: Vec<f64> = fitted.iter().zip(&residuals).map(|(f, e)| f + e).collect();
+                let star = ols_fit(&design, &y_star)?;
+                Some(star.coefficients[j + 1] / star.std_errors[j + 1])
+            })
+            .collect();
+
+        let critical =
+            Data::new(permuted.iter().map(|t| t.abs()).collect::<Vec<f64>>()).quantile(1.0 - alpha);
+        coefficients.push(coefficient);
+        t_values.push(t);
+        p_values.push(permutation_p(t, &permuted));
+        ci.push(vec![
+            coefficient - critical * std_error,
+            coefficient + critical * std_error,
+        ]);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("coefficients"),
+        &vec_to_jsvalue(coefficients),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("t_values"),
+        &vec_to_jsvalue(t_values),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("p_values"),
+        &vec_to_jsvalue(p_values),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("ci"), &nested_vec_to_jsvalue(ci));
+    obj.into()
+}
+
+/// Selects the rows at the given indices from every column.
+fn select_rows(columns: &[Vec<f64>], indices: &[usize]) -> Vec<Vec<f64>> {
+    columns
+        .iter()
+        .map(|col| indices.iter().map(|i| col[*i]).collect())
+        .collect()
+}
+
+/// Randomly splits a set of columns into training and test sets, optionally stratified so
+/// that each group is represented in the same proportion in both sets.
+///
+/// # Arguments
+///
+/// * `columns` - A reference to a JsValue representing a JavaScript array of equal-length
+///   columns, split row-wise.
+/// * `test_fraction` - A reference to a JsValue representing the fraction of rows to place
+///   in the test set (defaults to 0.25).
+/// * `stratify` - A reference to a JsValue representing an array of group labels (strings
+///   or numbers) with one label per row, or undefined for a simple random split.
+/// * `seed` - A reference to a JsValue representing the random seed, or undefined.
+///
+/// # Returns
+///
+/// * An object with the properties `train` and `test` (the split columns) and
+///   `train_indices` and `test_indices` (the selected rows in ascending order), or null if
+///   the inputs are invalid. The test size of each group is rounded to the nearest row.
+#[wasm_bindgen]
+pub fn train_test_split(
+    columns: &JsValue,
+    test_fraction: &JsValue,
+    stratify: &JsValue,
+    seed: &JsValue,
+) -> JsValue {
+    let columns: Vec<Vec<f64>> = js_nested_array_to_vector(columns)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let test_fraction = test_fraction.as_f64().unwrap_or(0.25);
+    let n = match columns.first() {
+        Some(col) => col.len(),
+        None => return JsValue::NULL,
+    };
+    if n == 0 || columns.iter().any(|col| col.len() != n) || !(0.0..=1.0).contains(&test_fraction) {
+        return JsValue::NULL;
+    }
+
+    let groups = if stratify.is_undefined() || stratify.is_null() {
+        vec![(0..n).collect()]
+    } else {
+        match group_indices(stratify) {
+            Some(groups) if groups.iter().map(Vec::len).sum::<usize>() == n => groups,
+            _ => return JsValue::NULL,
+        }
+    };
+
+    let mut rng = seeded_rng(seed.as_f64());
+    let mut train_indices = Vec::new();
+    let mut test_indices = Vec::new();
+    for mut group in groups {
+        group.shuffle(&mut rng);
+        let test_size = (group.len() as f64 * test_fraction).round() as usize;
+        test_indices.extend_from_slice(&group[..test_size]);
+        train_indices.extend_from_slice(&group[test_size..]);
+    }
+    train_indices.sort_unstable();
+    test_indices.sort_unstable();
+
+    let to_indices =
+        |indices: &[usize]| vec_to_jsvalue(indices.iter().map(|i| *i as f64).collect());
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("train"),
+        &nested_vec_to_jsvalue(select_rows(&columns, &train_indices)),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("test"),
+        &nested_vec_to_jsvalue(select_rows(&columns, &test_indices)),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("train_indices"),
+        &to_indices(&train_indices),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("test_indices"),
+        &to_indices(&test_indices),
+    );
+    obj.into()
+}
+
+/// Draws a stratified random sample without replacement, allocating the sample across
+/// groups in proportion to their sizes.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the values to sample from.
+/// * `groups` - A reference to a JsValue representing the group label (string or number)
+///   of each value.
+/// * `n` - A reference to a JsValue representing the total sample size.
+/// * `seed` - A reference to a JsValue representing the random seed, or undefined.
+///
+/// # Returns
+///
+/// * An object with the properties `sample` and `indices` (the sampled positions in
+///   ascending order), or null if the inputs are invalid or `n` exceeds the number of
+///   values. Fractional allocations are resolved by the largest remainder method.
+#[wasm_bindgen]
+pub fn stratified_sample(
+    column: &JsValue,
+    groups: &JsValue,
+    n: &JsValue,
+    seed: &JsValue,
+) -> JsValue {
+    let values = js_array_to_vector(column);
+    let total = values.len();
+    let n = match n.as_f64() {
+        Some(n) if n >= 0.0 && n as usize <= total => n as usize,
+        _ => return JsValue::NULL,
+    };
+    let groups = match group_indices(groups) {
+        Some(groups) if groups.iter().map(Vec::len).sum::<usize>() == total => groups,
+        _ => return JsValue::NULL,
+    };
+
+    // Largest remainder allocation of the sample across the groups
+    let quotas: Vec<f64> = groups
+        .iter()
+        .map(|group| n as f64 * group.len() as f64 / total as f64)
+        .collect();
+    let mut allocation: Vec<usize> = quotas.iter().map(|q| q.floor() as usize).collect();
+    let mut by_remainder: Vec<usize> = (0..groups.len()).collect();
+    by_remainder.sort_by(|a, b| {
+        (quotas[*b] - quotas[*b].floor()).total_cmp(&(quotas[*a] - quotas[*a].floor()))
+    });
+    let remaining = n - allocation.iter().sum::<usize>();
+    for g in by_remainder.into_iter().take(remaining) {
+        allocation[g] += 1;
+    }
+
+    let mut rng = seeded_rng(seed.as_f64());
+    let mut indices: Vec<usize> = groups
+        .iter()
+        .zip(&allocation)
+        .flat_map(|(group, size)| {
+            group
+                .choose_multiple(&mut rng, *size)
+                .copied()
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    indices.sort_unstable();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("sample"),
+        &vec_to_jsvalue(indices.iter().map(|i| values[*i]).collect()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("indices"),
+        &vec_to_jsvalue(indices.iter().map(|i| *i as f64).collect()),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn options(method: &str) -> JsValue {
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("method"),
+            &JsValue::from_str(method),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("permutations"),
+            &JsValue::from_f64(1999.0),
+        );
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("seed"),
+            &JsValue::from_f64(7.0),
+        );
+        options.into()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_permutation_correlation_test() {
+        let x = vec_to_jsvalue((1..=12).map(|i| i as f64).collect());
+        let y = vec_to_jsvalue(vec![
+            2.1, 2.5, 3.9, 3.1, 5.2, 4.8, 6.9, 5.5, 7.2, 8.8, 7.9, 9.4,
+        ]);
+        let y_null = vec_to_jsvalue(vec![
+            5.0, 3.0, 6.0, 2.0, 7.0, 4.0, 4.0, 6.0, 3.0, 5.0, 6.0, 4.0,
+        ]);
+
+        let pearson = permutation_correlation_test(&x, &y, &options("pearson"));
+        let spearman = permutation_correlation_test(&x, &y, &options("spearman"));
+        let null = permutation_correlation_test(&x, &y_null, &options("pearson"));
+
+        let r = Reflect::get(&pearson, &JsValue::from_str("estimate")).unwrap();
+        let ci = js_array_to_vector(&Reflect::get(&pearson, &JsValue::from_str("ci")).unwrap());
+        let p = Reflect::get(&pearson, &JsValue::from_str("p")).unwrap();
+        let rho = Reflect::get(&spearman, &JsValue::from_str("estimate")).unwrap();
+        let null_p = Reflect::get(&null, &JsValue::from_str("p")).unwrap();
+
+        assert!((r.as_f64().unwrap() - 0.960679).abs() < 0.0001);
+        assert!(ci[0] > 0.85 && ci[0] < r.as_f64().unwrap() && ci[1] <= 1.0);
+        assert_eq!(p.as_f64().unwrap(), 1.0 / 2000.0);
+        assert!((rho.as_f64().unwrap() - 0.972028).abs() < 0.0001);
+        // The parametric t-test gives p = 0.7758
+        assert!((null_p.as_f64().unwrap() - 0.7758).abs() < 0.05);
+        let constant = vec_to_jsvalue(vec![3.0; 12]);
+        assert!(permutation_correlation_test(&constant, &y, &options("pearson")).is_null());
+        assert!(permutation_correlation_test(&x, &constant, &options("spearman")).is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_permutation_regression_test() {
+        let x = nested_vec_to_jsvalue(vec![
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 9.0],
+        ]);
+        let y = vec_to_jsvalue(vec![3.0, 4.0, 8.0, 9.0, 12.0, 14.0, 15.0, 19.0]);
+
+        let result = permutation_regression_test(&x, &y, &options("pearson"));
+
+        let coefficients =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("coefficients")).unwrap());
+        let p_values =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("p_values")).unwrap());
+        let ci =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("ci")).unwrap());
+        let first_ci = js_array_to_vector(&ci[0]);
+
+        assert!((coefficients[0] - 1.90196).abs() < 0.001);
+        assert!((coefficients[1] - 0.31373).abs() < 0.001);
+        assert!(p_values[0] < 0.05);
+        assert!(p_values[1] > 0.1);
+        assert!(first_ci[0] > 0.0 && first_ci[0] < 1.90196 && first_ci[1] > 1.90196);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_train_test_split() {
+        let columns = nested_vec_to_jsvalue(vec![
+            (0..20).map(|i| i as f64).collect(),
+            (0..20).map(|i| (i * 10) as f64).collect(),
+        ]);
+        let stratify = js_sys::Array::new();
+        for i in 0..20 {
+            stratify.push(&JsValue::from_str(if i < 12 { "a" } else { "b" }));
+        }
+        let seed = JsValue::from_f64(3.0);
+
+        let result = train_test_split(&columns, &JsValue::from_f64(0.25), &stratify.into(), &seed);
+        let repeat = train_test_split(
+            &columns,
+            &JsValue::from_f64(0.25),
+            &JsValue::UNDEFINED,
+            &seed,
+        );
+
+        let test_indices =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("test_indices")).unwrap());
+        let train_indices = js_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("train_indices")).unwrap(),
+        );
+        let test =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("test")).unwrap());
+        let second_column = js_array_to_vector(&test[1]);
+        let unstratified =
+            js_array_to_vector(&Reflect::get(&repeat, &JsValue::from_str("test_indices")).unwrap());
+
+        assert_eq!(test_indices.len(), 5);
+        assert_eq!(train_indices.len(), 15);
+        assert_eq!(test_indices.iter().filter(|i| **i < 12.0).count(), 3);
+        assert_eq!(
+            second_column,
+            test_indices.iter().map(|i| i * 10.0).collect::<Vec<f64>>()
+        );
+        assert_eq!(unstratified.len(), 5);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_stratified_sample() {
+        let column = vec_to_jsvalue((0..10).map(|i| i as f64 + 0.5).collect());
+        let groups = vec_to_jsvalue(vec![1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0]);
+
+        let result = stratified_sample(
+            &column,
+            &groups,
+            &JsValue::from_f64(6.0),
+            &JsValue::from_f64(11.0),
+        );
+        let repeat = stratified_sample(
+            &column,
+            &groups,
+            &JsValue::from_f64(6.0),
+            &JsValue::from_f64(11.0),
+        );
+
+        let sample =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("sample")).unwrap());
+        let indices =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("indices")).unwrap());
+        let repeated =
+            js_array_to_vector(&Reflect::get(&repeat, &JsValue::from_str("indices")).unwrap());
+
+        // Quotas of 3, 1.8, and 1.2 round to 3, 2, and 1 by largest remainder
+        assert_eq!(indices.iter().filter(|i| **i < 5.0).count(), 3);
+        assert_eq!(
+            indices.iter().filter(|i| **i >= 5.0 && **i < 8.0).count(),
+            2
+        );
+        assert_eq!(indices.iter().filter(|i| **i >= 8.0).count(), 1);
+        assert_eq!(
+            sample,
+            indices.iter().map(|i| i + 0.5).collect::<Vec<f64>>()
+        );
+        assert_eq!(indices, repeated);
+        assert!(stratified_sample(
+            &column,
+            &groups,
+            &JsValue::from_f64(11.0),
+            &JsValue::UNDEFINED
+        )
+        .is_null());
+    }
+}
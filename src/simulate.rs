@@ -0,0 +1,365 @@
+use crate::utils::*;
+use js_sys::{Array, Object, Reflect};
+use rand::distributions::Distribution;
+use rand::Rng;
+use statrs::distribution::Normal;
+use wasm_bindgen::prelude::*;
+
+/// Reads a positive sample size from a JavaScript number.
+fn read_size(n: &JsValue) -> Option<usize> {
+    match n.as_f64() {
+        Some(n) if n >= 1.0 => Some(n as usize),
+        _ => None,
+    }
+}
+
+/// Reads a non-negative standard deviation option, defaulting to 1.
+fn read_sd(options: &JsValue, key: &str) -> Option<f64> {
+    match get_f64_option(options, key) {
+        Some(sd) if sd < 0.0 => None,
+        Some(sd) => Some(sd),
+        None => Some(1.0),
+    }
+}
+
+/// Simulates two normally distributed groups whose means differ by a given Cohen's d.
+///
+/// # Arguments
+///
+/// * `n1` - A reference to a JsValue representing the size of the first group.
+/// * `n2` - A reference to a JsValue representing the size of the second group.
+/// * `options` - An optional object `{ mean, sd, effect_size, seed }`; the first group has
+///   mean `mean` (default 0) and the second `mean + effect_size * sd`, both with standard
+///   deviation `sd` (default 1). `effect_size` defaults to 0.5.
+///
+/// # Returns
+///
+/// * An object with the properties `group1` and `group2`, or null if a group size is
+///   below 1 or `sd` is negative.
+#[wasm_bindgen]
+pub fn simulate_two_groups(n1: &JsValue, n2: &JsValue, options: &JsValue) -> JsValue {
+    let (n1, n2) = match (read_size(n1), read_size(n2)) {
+        (Some(n1), Some(n2)) => (n1, n2),
+        _ => return JsValue::NULL,
+    };
+    let sd = match read_sd(options, "sd") {
+        Some(sd) => sd,
+        None => return JsValue::NULL,
+    };
+    let mean = get_f64_option(options, "mean").unwrap_or(0.0);
+    let effect_size = get_f64_option(options, "effect_size").unwrap_or(0.5);
+
+    let mut rng = seeded_rng(get_f64_option(options, "seed"));
+    let standard = Normal::new(0.0, 1.0).unwrap();
+    let group1: Vec<f64> = (0..n1)
+        .map(|_| mean + sd * standard.sample(&mut rng))
+        .collect();
+    let group2: Vec<f64> = (0..n2)
+        .map(|_| mean + effect_size * sd + sd * standard.sample(&mut rng))
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("group1"), &vec_to_jsvalue(group1));
+    let _ = Reflect::set(&obj, &JsValue::from_str("group2"), &vec_to_jsvalue(group2));
+    obj.into()
+}
+
+/// Simulates simple linear regression data with uniformly distributed predictors and
+/// normal errors.
+///
+/// # Arguments
+///
+/// * `n` - A reference to a JsValue representing the number of observations.
+/// * `options` - An optional object `{ intercept, slope, noise, x_min, x_max, seed }`; `y` is
+///   `intercept + slope * x` (defaults 0 and 1) plus normal errors with standard deviation
+///   `noise` (default 1), and `x` is uniform on `[x_min, x_max)` (defaults 0 and 10).
+///
+/// # Returns
+///
+/// * An object with the properties `x` and `y`, or null if `n` is below 1, `noise` is
+///   negative, or `x_max` does not exceed `x_min`.
+#[wasm_bindgen]
+pub fn simulate_regression(n: &JsValue, options: &JsValue) -> JsValue {
+    let n = match read_size(n) {
+        Some(n) => n,
+        None => return JsValue::NULL,
+    };
+    let noise = match read_sd(options, "noise") {
+        Some(noise) => noise,
+        None => return JsValue::NULL,
+    };
+    let intercept = get_f64_option(options, "intercept").unwrap_or(0.0);
+    let slope = get_f64_option(options, "slope").unwrap_or(1.0);
+    let x_min = get_f64_option(options, "x_min").unwrap_or(0.0);
+    let x_max = get_f64_option(options, "x_max").unwrap_or(10.0);
+    if x_max <= x_min {
+        return JsValue::NULL;
+    }
+
+    let mut rng = seeded_rng(get_f64_option(options, "seed"));
+    let standard = Normal::new(0.0, 1.0).unwrap();
+    let x: Vec<f64> = (0..n).map(|_| rng.gen_range(x_min..x_max)).collect();
+    let y: Vec<f64> = x
+        .iter()
+        .map(|xi| intercept + slope * xi + noise * standard.sample(&mut rng))
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("x"), &vec_to_jsvalue(x));
+    let _ = Reflect::set(&obj, &JsValue::from_str("y"), &vec_to_jsvalue(y));
+    obj.into()
+}
+
+/// Simulates a balanced full factorial design with normal errors.
+///
+/// Cells are ordered with the last factor varying fastest. Each response is the grand mean
+/// plus the main effect of each of its factor levels, the effect of its cell (to express
+/// interactions), and a normal error.
+///
+/// # Arguments
+///
+/// * `levels` - A reference to a JsValue representing the number of levels of each factor.
+/// * `n` - A reference to a JsValue representing the number of replicates per cell.
+/// * `options` - An optional object `{ mean, sd, effects, cell_effects, seed }`, where
+///   `effects[f][l]` is the main effect of level `l` of factor `f`, `cell_effects` holds one
+///   effect per cell, and `mean` and `sd` default to 0 and 1. Missing effects are 0.
+///
+/// # Returns
+///
+/// * An object with the properties `y`, `factors` (the 0-based level of each response for
+///   each factor), and `cells` (the responses grouped by cell, ready for
+///   `anova_1way_test`), or null if there are no factors, a factor has fewer than 2 levels,
+///   `n` is below 1, or `sd` is negative.
+#[wasm_bindgen]
+pub fn simulate_factorial(levels: &JsValue, n: &JsValue, options: &JsValue) -> JsValue {
+    let levels: Vec<usize> = js_array_to_vector(levels)
+        .iter()
+        .map(|l| *l as usize)
+        .collect();
+    let n = match read_size(n) {
+        Some(n) => n,
+        None => return JsValue::NULL,
+    };
+    let sd = match read_sd(options, "sd") {
+        Some(sd) => sd,
+        None => return JsValue::NULL,
+    };
+    if levels.is_empty() || levels.iter().any(|l| *l < 2) {
+        return JsValue::NULL;
+    }
+    let mean = get_f64_option(options, "mean").unwrap_or(0.0);
+
+    let read_array =
+        |key: &str| Reflect::get(options, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED);
+    let effects = read_array("effects");
+    let effects: Vec<Vec<f64>> = if Array::is_array(&effects) {
+        js_nested_array_to_vector(&effects)
+            .iter()
+            .map(js_array_to_vector)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let cell_effects = read_array("cell_effects");
+    let cell_effects = if Array::is_array(&cell_effects) {
+        js_array_to_vector(&cell_effects)
+    } else {
+        Vec::new()
+    };
+
+    let mut rng = seeded_rng(get_f64_option(options, "seed"));
+    let standard = Normal::new(0.0, 1.0).unwrap();
+    let cell_count: usize = levels.iter().product();
+
+    let mut y = Vec::with_capacity(cell_count * n);
+    let mut factors = vec![Vec::with_capacity(cell_count * n); levels.len()];
+    let mut cells = Vec::with_capacity(cell_count);
+    for cell in 0..cell_count {
+        // Decode the cell index into one level per factor, last factor fastest
+        let mut remainder = cell;
+        let mut cell_levels = vec![0; levels.len()];
+        for (f, l) in levels.iter().enumerate().rev() {
+            cell_levels[f] = remainder % l;
+            remainder /= l;
+        }
+
+        let main_effects: f64 = cell_levels
+            .iter()
+            .enumerate()
+            .map(|(f, l)| {
+                effects
+                    .get(f)
+                    .and_then(|e| e.get(*l))
+                    .copied()
+                    .unwrap_or(0.0)
+            })
+            .sum();
+        let center = mean + main_effects + cell_effects.get(cell).copied().unwrap_or(0.0);
+
+        let responses: Vec<f64> = (0..n)
+            .map(|_| center + sd * standard.sample(&mut rng))
+            .collect();
+        for (column, l) in factors.iter_mut().zip(&cell_levels) {
+            column.extend(std::iter::repeat_n(*l as f64, n));
+        }
+        y.extend(&responses);
+        cells.push(responses);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("y"), &vec_to_jsvalue(y));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("factors"),
+        &nested_vec_to_jsvalue(factors),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("cells"),
+        &nested_vec_to_jsvalue(cells),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn get_vec(obj: &JsValue, key: &str) -> Vec<f64> {
+        js_array_to_vector(&Reflect::get(obj, &JsValue::from_str(key)).unwrap())
+    }
+
+    fn seeded(pairs: &[(&str, f64)]) -> JsValue {
+        let options = Object::new();
+        for (key, value) in pairs {
+            let _ = Reflect::set(
+                &options,
+                &JsValue::from_str(key),
+                &JsValue::from_f64(*value),
+            );
+        }
+        options.into()
+    }
+
+    fn mean(values: &[f64]) -> f64 {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_simulate_two_groups() {
+        let options = seeded(&[
+            ("mean", 10.0),
+            ("sd", 2.0),
+            ("effect_size", 1.0),
+            ("seed", 7.0),
+        ]);
+
+        let result = simulate_two_groups(
+            &JsValue::from_f64(2000.0),
+            &JsValue::from_f64(1500.0),
+            &options,
+        );
+        let repeat = simulate_two_groups(
+            &JsValue::from_f64(2000.0),
+            &JsValue::from_f64(1500.0),
+            &options,
+        );
+
+        let group1 = get_vec(&result, "group1");
+        let group2 = get_vec(&result, "group2");
+
+        assert_eq!(group1.len(), 2000);
+        assert_eq!(group2.len(), 1500);
+        assert_eq!(group1, get_vec(&repeat, "group1"));
+        assert!((mean(&group1) - 10.0).abs() < 0.2);
+        assert!((mean(&group2) - 12.0).abs() < 0.2);
+        assert!(
+            simulate_two_groups(&JsValue::from_f64(0.0), &JsValue::from_f64(5.0), &options)
+                .is_null()
+        );
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_simulate_regression() {
+        let options = seeded(&[
+            ("intercept", 2.0),
+            ("slope", 0.5),
+            ("noise", 0.1),
+            ("seed", 3.0),
+        ]);
+
+        let result = simulate_regression(&JsValue::from_f64(500.0), &options);
+
+        let x = get_vec(&result, "x");
+        let y = get_vec(&result, "y");
+        let (mean_x, mean_y) = (mean(&x), mean(&y));
+        let sxy: f64 = x
+            .iter()
+            .zip(&y)
+            .map(|(a, b)| (a - mean_x) * (b - mean_y))
+            .sum();
+        let sxx: f64 = x.iter().map(|a| (a - mean_x).powi(2)).sum();
+        let slope = sxy / sxx;
+
+        assert_eq!(y.len(), 500);
+        assert!(x.iter().all(|xi| (0.0..10.0).contains(xi)));
+        assert!((slope - 0.5).abs() < 0.01);
+        assert!((mean_y - slope * mean_x - 2.0).abs() < 0.05);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_simulate_factorial() {
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("effects"),
+            &nested_vec_to_jsvalue(vec![vec![0.0, 5.0], vec![0.0, 1.0, 2.0]]),
+        );
+        let _ = Reflect::set(&options, &JsValue::from_str("sd"), &JsValue::from_f64(0.0));
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("cell_effects"),
+            &vec_to_jsvalue(vec![0.0, 0.0, 0.0, 0.0, 0.0, 10.0]),
+        );
+
+        let result = simulate_factorial(
+            &vec_to_jsvalue(vec![2.0, 3.0]),
+            &JsValue::from_f64(2.0),
+            &options,
+        );
+
+        let y = get_vec(&result, "y");
+        let factors: Vec<Vec<f64>> = js_nested_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("factors")).unwrap(),
+        )
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+        let cells =
+            js_nested_array_to_vector(&Reflect::get(&result, &JsValue::from_str("cells")).unwrap());
+
+        assert_eq!(
+            y,
+            vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 5.0, 5.0, 6.0, 6.0, 17.0, 17.0]
+        );
+        assert_eq!(
+            factors[0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            factors[1],
+            vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0]
+        );
+        assert_eq!(cells.len(), 6);
+        assert!(simulate_factorial(
+            &vec_to_jsvalue(vec![1.0]),
+            &JsValue::from_f64(2.0),
+            &options
+        )
+        .is_null());
+    }
+}
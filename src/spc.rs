@@ -0,0 +1,482 @@
+use crate::utils::*;
+use js_sys::{Object, Reflect};
+use statrs::function::gamma::ln_gamma;
+use wasm_bindgen::prelude::*;
+
+/// The d2 control chart constants (the expected range of a standard normal sample) for
+/// subgroup sizes 2 through 25.
+const D2: [f64; 24] = [
+    1.128, 1.693, 2.059, 2.326, 2.534, 2.704, 2.847, 2.970, 3.078, 3.173, 3.258, 3.336, 3.407,
+    3.472, 3.532, 3.588, 3.640, 3.689, 3.735, 3.778, 3.819, 3.858, 3.895, 3.931,
+];
+
+/// The d3 control chart constants (the standard deviation of the range of a standard
+/// normal sample) for subgroup sizes 2 through 25.
+const D3: [f64; 24] = [
+    0.853, 0.888, 0.880, 0.864, 0.848, 0.833, 0.820, 0.808, 0.797, 0.787, 0.778, 0.770, 0.763,
+    0.756, 0.750, 0.744, 0.739, 0.734, 0.729, 0.724, 0.720, 0.716, 0.712, 0.708,
+];
+
+/// Reads a JavaScript array of subgroups, requiring equal sizes between 2 and 25.
+fn read_subgroups(subgroups: &JsValue) -> Option<(Vec<Vec<f64>>, usize)> {
+    let subgroups: Vec<Vec<f64>> = js_nested_array_to_vector(subgroups)
+        .iter()
+        .map(js_array_to_vector)
+        .collect();
+    let n = subgroups.first()?.len();
+    if !(2..=25).contains(&n) || subgroups.iter().any(|group| group.len() != n) {
+        return None;
+    }
+    Some((subgroups, n))
+}
+
+/// Finds the points that violate each of the four Western Electric rules, with the zones
+/// on each side of the center line set at thirds of the distance to that side's limit.
+///
+/// # Returns
+///
+/// * For each rule, the indices of the points that complete a violating pattern:
+///   1. one point beyond a control limit;
+///   2. two of three consecutive points beyond two thirds of the way to a limit;
+///   3. four of five consecutive points beyond one third of the way to a limit;
+///   4. eight consecutive points on the same side of the center line.
+fn western_electric_violations(
+    points: &[f64],
+    center: f64,
+    lower: f64,
+    upper: f64,
+) -> [Vec<usize>; 4] {
+    let zone = |value: f64| {
+        if value >= center {
+            (value - center) / ((upper - center) / 3.0)
+        } else {
+            -(center - value) / ((center - lower) / 3.0)
+        }
+    };
+    let zones: Vec<f64> = points.iter().map(|p| zone(*p)).collect();
+
+    // Counts the points in the window ending at `i` beyond `threshold` zones on one side
+    let beyond = |i: usize, window: usize, threshold: f64, side: f64| {
+        zones[(i + 1).saturating_sub(window)..=i]
+            .iter()
+            .filter(|z| *z * side > threshold)
+            .count()
+    };
+
+    let mut violations: [Vec<usize>; 4] = Default::default();
+    for (i, z) in zones.iter().enumerate() {
+        if z.abs() > 3.0 {
+            violations[0].push(i);
+        }
+        for side in [1.0, -1.0] {
+            if z * side > 2.0 && i >= 2 && beyond(i, 3, 2.0, side) >= 2 {
+                violations[1].push(i);
+            }
+            if z * side > 1.0 && i >= 4 && beyond(i, 5, 1.0, side) >= 4 {
+                violations[2].push(i);
+            }
+            if i >= 7 && beyond(i, 8, 0.0, side) == 8 {
+                violations[3].push(i);
+            }
+        }
+    }
+    violations
+}
+
+/// Builds the result object for a single control chart.
+fn chart_object(points: Vec<f64>, center: f64, lower: f64, upper: f64) -> JsValue {
+    let violations = western_electric_violations(&points, center, lower, upper);
+    let rules = Object::new();
+    for (rule, indices) in violations.iter().enumerate() {
+        let _ = Reflect::set(
+            &rules,
+            &JsValue::from_str(&format!("rule{}", rule + 1)),
+            &vec_to_jsvalue(indices.iter().map(|i| *i as f64).collect()),
+        );
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("center"),
+        &JsValue::from_f64(center),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("lower"), &JsValue::from_f64(lower));
+    let _ = Reflect::set(&obj, &JsValue::from_str("upper"), &JsValue::from_f64(upper));
+    let _ = Reflect::set(&obj, &JsValue::from_str("points"), &vec_to_jsvalue(points));
+    let _ = Reflect::set(&obj, &JsValue::from_str("violations"), &rules);
+    obj.into()
+}
+
+/// Computes the mean of a slice of values.
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Computes X-bar and R control charts from rational subgroups.
+///
+/// # Arguments
+///
+/// * `subgroups` - A reference to a JsValue representing a JavaScript array of subgroups,
+///   each an array of 2 to 25 measurements, all of the same size.
+///
+/// # Returns
+///
+/// * An object with the properties `xbar` and `range`, each holding the chart's `center`,
+///   `lower` and `upper` control limits, per-subgroup `points`, and `violations` (the
+///   indices breaking each Western Electric rule, as `rule1` through `rule4`), or null if
+///   the subgroups are invalid.
+#[wasm_bindgen]
+pub fn xbar_r_chart(subgroups: &JsValue) -> JsValue {
+    let (subgroups, n) = match read_subgroups(subgroups) {
+        Some(subgroups) => subgroups,
+        None => return JsValue::NULL,
+    };
+    let d2 = D2[n - 2];
+    let d3 = D3[n - 2];
+
+    let means: Vec<f64> = subgroups.iter().map(|group| mean(group)).collect();
+    let ranges: Vec<f64> = subgroups
+        .iter()
+        .map(|group| {
+            group.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+                - group.iter().cloned().fold(f64::INFINITY, f64::min)
+        })
+        .collect();
+    let grand_mean = mean(&means);
+    let mean_range = mean(&ranges);
+
+    let a2 = 3.0 / (d2 * (n as f64).sqrt());
+    let d3_factor = (1.0 - 3.0 * d3 / d2).max(0.0);
+    let d4_factor = 1.0 + 3.0 * d3 / d2;
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("xbar"),
+        &chart_object(
+            means,
+            grand_mean,
+            grand_mean - a2 * mean_range,
+            grand_mean + a2 * mean_range,
+        ),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("range"),
+        &chart_object(
+            ranges,
+            mean_range,
+            d3_factor * mean_range,
+            d4_factor * mean_range,
+        ),
+    );
+    obj.into()
+}
+
+/// Computes X-bar and S control charts from rational subgroups.
+///
+/// # Arguments
+///
+/// * `subgroups` - A reference to a JsValue representing a JavaScript array of subgroups,
+///   each an array of 2 to 25 measurements, all of the same size.
+///
+/// # Returns
+///
+/// * An object with the properties `xbar` and `s`, each holding the chart's `center`,
+///   `lower` and `upper` control limits, per-subgroup `points`, and `violations` (the
+///   indices breaking each Western Electric rule, as `rule1` through `rule4`), or null if
+///   the subgroups are invalid.
+#[wasm_bindgen]
+pub fn xbar_s_chart(subgroups: &JsValue) -> JsValue {
+    let (subgroups, n) = match read_subgroups(subgroups) {
+        Some(subgroups) => subgroups,
+        None => return JsValue::NULL,
+    };
+    let n_f = n as f64;
+    let c4 = (2.0 / (n_f - 1.0)).sqrt() * (ln_gamma(n_f / 2.0) - ln_gamma((n_f - 1.0) / 2.0)).exp();
+
+    let means: Vec<f64> = subgroups.iter().map(|group| mean(group)).collect();
+    let std_devs: Vec<f64> = subgroups
+        .iter()
+        .zip(&means)
+        .map(|(group, m)| (group.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (n_f - 1.0)).sqrt())
+        .collect();
+    let grand_mean = mean(&means);
+    let mean_sd = mean(&std_devs);
+
+    let a3 = 3.0 / (c4 * n_f.sqrt());
+    let spread = 3.0 * (1.0 - c4 * c4).sqrt() / c4;
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("xbar"),
+        &chart_object(
+            means,
+            grand_mean,
+            grand_mean - a3 * mean_sd,
+            grand_mean + a3 * mean_sd,
+        ),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("s"),
+        &chart_object(
+            std_devs,
+            mean_sd,
+            (1.0 - spread).max(0.0) * mean_sd,
+            (1.0 + spread) * mean_sd,
+        ),
+    );
+    obj.into()
+}
+
+/// Reads the in-control target and standard deviation for an individuals chart, defaulting
+/// to the sample mean and the average moving range divided by d2 = 1.128.
+fn process_parameters(values: &[f64], options: &JsValue) -> (f64, f64) {
+    let target = get_f64_option(options, "target").unwrap_or_else(|| mean(values));
+    let sigma = get_f64_option(options, "sigma").unwrap_or_else(|| {
+        let moving_ranges: Vec<f64> = values.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+        mean(&moving_ranges) / D2[0]
+    });
+    (target, sigma)
+}
+
+/// Computes a tabular CUSUM chart for individual observations.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the observations in time order.
+/// * `options` - An optional object with the properties `target` (the in-control mean;
+///   defaults to the sample mean), `sigma` (the in-control standard deviation; defaults
+///   to the moving-range estimate), `k` (the allowance in standard deviations; defaults
+///   to 0.5), and `h` (the decision interval in standard deviations; defaults to 5).
+///
+/// # Returns
+///
+/// * An object with the properties `upper` and `lower` (the one-sided cumulative sums),
+///   `limit` (the decision interval `h * sigma`), and `signals` (the indices at which
+///   either sum exceeds the limit), or null if there are fewer than two observations or
+///   the standard deviation is not positive.
+#[wasm_bindgen]
+pub fn cusum_chart(column: &JsValue, options: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    if values.len() < 2 {
+        return JsValue::NULL;
+    }
+    let (target, sigma) = process_parameters(&values, options);
+    if sigma <= 0.0 || !sigma.is_finite() {
+        return JsValue::NULL;
+    }
+    let k = get_f64_option(options, "k").unwrap_or(0.5) * sigma;
+    let limit = get_f64_option(options, "h").unwrap_or(5.0) * sigma;
+
+    let mut upper = Vec::with_capacity(values.len());
+    let mut lower = Vec::with_capacity(values.len());
+    let mut signals = Vec::new();
+    let (mut c_plus, mut c_minus) = (0.0_f64, 0.0_f64);
+    for (i, x) in values.iter().enumerate() {
+        c_plus = (x - (target + k) + c_plus).max(0.0);
+        c_minus = (target - k - x + c_minus).max(0.0);
+        if c_plus > limit || c_minus > limit {
+            signals.push(i as f64);
+        }
+        upper.push(c_plus);
+        lower.push(c_minus);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("upper"), &vec_to_jsvalue(upper));
+    let _ = Reflect::set(&obj, &JsValue::from_str("lower"), &vec_to_jsvalue(lower));
+    let _ = Reflect::set(&obj, &JsValue::from_str("limit"), &JsValue::from_f64(limit));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("signals"),
+        &vec_to_jsvalue(signals),
+    );
+    obj.into()
+}
+
+/// Computes an exponentially weighted moving average (EWMA) chart for individual
+/// observations, using the exact time-varying control limits.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the observations in time order.
+/// * `options` - An optional object with the properties `target` (the in-control mean;
+///   defaults to the sample mean), `sigma` (the in-control standard deviation; defaults
+///   to the moving-range estimate), `lambda` (the smoothing weight; defaults to 0.2), and
+///   `l` (the width of the limits in standard deviations; defaults to 3).
+///
+/// # Returns
+///
+/// * An object with the properties `points` (the EWMA statistics, starting from the
+///   target), `center`, `lower` and `upper` (the limits at each point), and `signals` (the
+///   indices of points outside the limits), or null if there are fewer than two
+///   observations, the standard deviation is not positive, or `lambda` is not in (0, 1].
+#[wasm_bindgen]
+pub fn ewma_chart(column: &JsValue, options: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    if values.len() < 2 {
+        return JsValue::NULL;
+    }
+    let (target, sigma) = process_parameters(&values, options);
+    let lambda = get_f64_option(options, "lambda").unwrap_or(0.2);
+    let width = get_f64_option(options, "l").unwrap_or(3.0);
+    if sigma <= 0.0 || !sigma.is_finite() || lambda <= 0.0 || lambda > 1.0 {
+        return JsValue::NULL;
+    }
+
+    let mut points = Vec::with_capacity(values.len());
+    let mut lower = Vec::with_capacity(values.len());
+    let mut upper = Vec::with_capacity(values.len());
+    let mut signals = Vec::new();
+    let mut z = target;
+    for (i, x) in values.iter().enumerate() {
+        z = lambda * x + (1.0 - lambda) * z;
+        let margin = width
+            * sigma
+            * (lambda / (2.0 - lambda) * (1.0 - (1.0 - lambda).powi(2 * (i as i32 + 1)))).sqrt();
+        if (z - target).abs() > margin {
+            signals.push(i as f64);
+        }
+        points.push(z);
+        lower.push(target - margin);
+        upper.push(target + margin);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("points"), &vec_to_jsvalue(points));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("center"),
+        &JsValue::from_f64(target),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("lower"), &vec_to_jsvalue(lower));
+    let _ = Reflect::set(&obj, &JsValue::from_str("upper"), &vec_to_jsvalue(upper));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("signals"),
+        &vec_to_jsvalue(signals),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn subgroups() -> JsValue {
+        nested_vec_to_jsvalue(vec![
+            vec![10.2, 9.8, 10.1, 10.0, 9.9],
+            vec![10.1, 10.3, 9.7, 10.0, 10.2],
+            vec![9.9, 10.0, 10.1, 9.8, 10.2],
+            vec![10.4, 10.6, 10.5, 10.3, 10.7],
+            vec![10.0, 9.9, 10.2, 10.1, 9.8],
+            vec![9.7, 9.9, 10.0, 9.8, 10.1],
+            vec![10.1, 10.0, 9.9, 10.2, 10.0],
+            vec![10.0, 10.1, 9.8, 9.9, 10.1],
+        ])
+    }
+
+    fn get_f64(obj: &JsValue, key: &str) -> f64 {
+        Reflect::get(obj, &JsValue::from_str(key))
+            .unwrap()
+            .as_f64()
+            .unwrap()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_xbar_r_chart() {
+        let result = xbar_r_chart(&subgroups());
+
+        let xbar = Reflect::get(&result, &JsValue::from_str("xbar")).unwrap();
+        let range = Reflect::get(&result, &JsValue::from_str("range")).unwrap();
+        let violations = Reflect::get(&xbar, &JsValue::from_str("violations")).unwrap();
+        let rule1 =
+            js_array_to_vector(&Reflect::get(&violations, &JsValue::from_str("rule1")).unwrap());
+        let rule4 =
+            js_array_to_vector(&Reflect::get(&violations, &JsValue::from_str("rule4")).unwrap());
+
+        assert!((get_f64(&xbar, "center") - 10.06).abs() < 1e-9);
+        assert!((get_f64(&xbar, "lower") - 9.829279).abs() < 0.0001);
+        assert!((get_f64(&xbar, "upper") - 10.290721).abs() < 0.0001);
+        assert!((get_f64(&range, "center") - 0.4).abs() < 1e-9);
+        assert_eq!(get_f64(&range, "lower"), 0.0);
+        assert!((get_f64(&range, "upper") - 0.845744).abs() < 0.0001);
+        assert_eq!(rule1, vec![3.0]);
+        assert!(rule4.is_empty());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_xbar_s_chart() {
+        let result = xbar_s_chart(&subgroups());
+
+        let xbar = Reflect::get(&result, &JsValue::from_str("xbar")).unwrap();
+        let s = Reflect::get(&result, &JsValue::from_str("s")).unwrap();
+
+        assert!((get_f64(&xbar, "lower") - 9.834275).abs() < 0.0001);
+        assert!((get_f64(&xbar, "upper") - 10.285725).abs() < 0.0001);
+        assert!((get_f64(&s, "center") - 0.158149).abs() < 0.0001);
+        assert_eq!(get_f64(&s, "lower"), 0.0);
+        assert!((get_f64(&s, "upper") - 0.330372).abs() < 0.0001);
+    }
+
+    fn shifted_series() -> JsValue {
+        vec_to_jsvalue(vec![
+            10.1, 9.8, 10.2, 10.0, 9.9, 10.3, 10.4, 10.6, 10.5, 10.8, 10.7, 10.9,
+        ])
+    }
+
+    fn target_options() -> JsValue {
+        let options = Object::new();
+        let _ = Reflect::set(
+            &options,
+            &JsValue::from_str("target"),
+            &JsValue::from_f64(10.0),
+        );
+        options.into()
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_cusum_chart() {
+        let result = cusum_chart(&shifted_series(), &target_options());
+
+        let upper =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("upper")).unwrap());
+        let lower =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("lower")).unwrap());
+        let signals =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("signals")).unwrap());
+
+        assert!((get_f64(&result, "limit") - 0.967118).abs() < 0.0001);
+        assert!((upper[7] - 1.009865).abs() < 0.0001);
+        assert!((upper[11] - 3.523017).abs() < 0.0001);
+        assert!((lower[1] - 0.103288).abs() < 0.0001);
+        assert_eq!(signals, vec![7.0, 8.0, 9.0, 10.0, 11.0]);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_ewma_chart() {
+        let result = ewma_chart(&shifted_series(), &target_options());
+
+        let points =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("points")).unwrap());
+        let upper =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("upper")).unwrap());
+        let signals =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("signals")).unwrap());
+
+        assert!((points[0] - 10.02).abs() < 1e-9);
+        assert!((points[11] - 10.535292).abs() < 0.0001);
+        assert!((upper[0] - 10.116054).abs() < 0.0001);
+        assert!((upper[11] - 10.192966).abs() < 0.0001);
+        assert_eq!(signals, vec![7.0, 8.0, 9.0, 10.0, 11.0]);
+    }
+}
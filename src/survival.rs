@@ -0,0 +1,461 @@
+use crate::utils::*;
+use js_sys::Object;
+use js_sys::Reflect;
+use statrs::distribution::{ContinuousCDF, Normal};
+use wasm_bindgen::prelude::*;
+
+/// Reads survival times and event indicators, treating every observation as an event if
+/// the indicators are undefined.
+///
+/// # Returns
+///
+/// * The times and event indicators, or `None` if they are empty, differ in length, or
+///   contain negative times.
+fn survival_data(times: &JsValue, events: &JsValue) -> Option<(Vec<f64>, Vec<bool>)> {
+    let times = js_array_to_vector(times);
+    let events: Vec<bool> = if events.is_undefined() || events.is_null() {
+        vec![true; times.len()]
+    } else {
+        js_array_to_vector(events)
+            .iter()
+            .map(|e| *e != 0.0)
+            .collect()
+    };
+
+    if times.is_empty() || times.len() != events.len() || times.iter().any(|t| *t < 0.0) {
+        return None;
+    }
+    Some((times, events))
+}
+
+/// Tabulates the number at risk and the number of events at each distinct event time.
+///
+/// # Returns
+///
+/// * A vector of tuples of the event time, number at risk, and number of events.
+fn risk_table(times: &[f64], events: &[bool]) -> Vec<(f64, f64, f64)> {
+    let mut event_times: Vec<f64> = times
+        .iter()
+        .zip(events)
+        .filter(|(_, e)| **e)
+        .map(|(t, _)| *t)
+        .collect();
+    event_times.sort_by(|a, b| a.total_cmp(b));
+    event_times.dedup();
+
+    event_times
+        .iter()
+        .map(|&u| {
+            let at_risk = times.iter().filter(|t| **t >= u).count() as f64;
+            let deaths = times
+                .iter()
+                .zip(events)
+                .filter(|(t, e)| **t == u && **e)
+                .count() as f64;
+            (u, at_risk, deaths)
+        })
+        .collect()
+}
+
+/// Estimates the survival function with the Kaplan-Meier product-limit estimator.
+///
+/// # Arguments
+///
+/// * `times` - A reference to a JsValue representing the JavaScript array of follow-up
+///   times.
+/// * `events` - A reference to a JsValue representing the JavaScript array of event
+///   indicators (1 for an event, 0 for censoring), or undefined if every observation is
+///   an event.
+///
+/// # Returns
+///
+/// * An object with the arrays `time` (the distinct event times), `survival`, `std_error`
+///   (Greenwood's formula), `at_risk`, and `events`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn kaplan_meier(times: &JsValue, events: &JsValue) -> JsValue {
+    let (times, events) = match survival_data(times, events) {
+        Some(data) => data,
+        None => return JsValue::NULL,
+    };
+
+    let table = risk_table(&times, &events);
+    let mut survival = Vec::with_capacity(table.len());
+    let mut std_error = Vec::with_capacity(table.len());
+    let mut s = 1.0;
+    let mut greenwood = 0.0;
+    for &(_, at_risk, deaths) in &table {
+        s *= 1.0 - deaths / at_risk;
+        if at_risk > deaths {
+            greenwood += deaths / (at_risk * (at_risk - deaths));
+        }
+        survival.push(s);
+        std_error.push(s * greenwood.sqrt());
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("time"),
+        &vec_to_jsvalue(table.iter().map(|r| r.0).collect()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("survival"),
+        &vec_to_jsvalue(survival),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &vec_to_jsvalue(std_error),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("at_risk"),
+        &vec_to_jsvalue(table.iter().map(|r| r.1).collect()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("events"),
+        &vec_to_jsvalue(table.iter().map(|r| r.2).collect()),
+    );
+    obj.into()
+}
+
+/// Estimates the cumulative hazard with the Nelson-Aalen estimator.
+///
+/// # Arguments
+///
+/// * `times` - A reference to a JsValue representing the JavaScript array of follow-up
+///   times.
+/// * `events` - A reference to a JsValue representing the JavaScript array of event
+///   indicators (1 for an event, 0 for censoring), or undefined if every observation is
+///   an event.
+/// * `alpha` - A reference to a JsValue representing the significance level for the
+///   log-transformed confidence intervals; defaults to 0.05.
+///
+/// # Returns
+///
+/// * An object with the arrays `time`, `cumulative_hazard`, `std_error`, `lower`, and
+///   `upper`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn nelson_aalen(times: &JsValue, events: &JsValue, alpha: &JsValue) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let (times, events) = match survival_data(times, events) {
+        Some(data) => data,
+        None => return JsValue::NULL,
+    };
+
+    let z = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let table = risk_table(&times, &events);
+    let mut hazard = Vec::with_capacity(table.len());
+    let mut std_error = Vec::with_capacity(table.len());
+    let mut lower = Vec::with_capacity(table.len());
+    let mut upper = Vec::with_capacity(table.len());
+    let mut h = 0.0;
+    let mut variance = 0.0;
+    for &(_, at_risk, deaths) in &table {
+        h += deaths / at_risk;
+        variance += deaths / at_risk.powi(2);
+        let se = variance.sqrt();
+        hazard.push(h);
+        std_error.push(se);
+        lower.push(h * (-z * se / h).exp());
+        upper.push(h * (z * se / h).exp());
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("time"),
+        &vec_to_jsvalue(table.iter().map(|r| r.0).collect()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("cumulative_hazard"),
+        &vec_to_jsvalue(hazard),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("std_error"),
+        &vec_to_jsvalue(std_error),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("lower"), &vec_to_jsvalue(lower));
+    let _ = Reflect::set(&obj, &JsValue::from_str("upper"), &vec_to_jsvalue(upper));
+    obj.into()
+}
+
+/// Computes the Weibull log-likelihood for right-censored data from the log scale and
+/// log shape.
+fn weibull_log_likelihood(times: &[f64], events: &[bool], log_scale: f64, log_shape: f64) -> f64 {
+    let shape = log_shape.exp();
+    let scale = log_scale.exp();
+    times
+        .iter()
+        .zip(events)
+        .map(|(t, e)| {
+            let event_term = if *e {
+                log_shape - shape * log_scale + (shape - 1.0) * t.ln()
+            } else {
+                0.0
+            };
+            event_term - (t / scale).powf(shape)
+        })
+        .sum()
+}
+
+/// Fits a parametric survival distribution to right-censored data by maximum
+/// likelihood and estimates the median survival time.
+///
+/// The exponential median interval uses the standard error `1/√d` of the log rate. The
+/// Weibull median interval uses the delta method on the log median with the observed
+/// information for the log scale and log shape.
+///
+/// # Arguments
+///
+/// * `times` - A reference to a JsValue representing the JavaScript array of positive
+///   follow-up times.
+/// * `events` - A reference to a JsValue representing the JavaScript array of event
+///   indicators (1 for an event, 0 for censoring), or undefined if every observation is
+///   an event.
+/// * `distribution` - A reference to a JsValue representing the distribution:
+///   "exponential" (the default) or "weibull".
+/// * `alpha` - A reference to a JsValue representing the significance level for the
+///   median interval; defaults to 0.05.
+///
+/// # Returns
+///
+/// * An object with the properties `distribution`, `scale`, `shape` (1 for the
+///   exponential), `rate` (for the exponential), `median`, `median_ci`,
+///   `log_likelihood`, and `aic`, or null if the inputs are invalid or there are no
+///   events.
+#[wasm_bindgen]
+pub fn survival_fit(
+    times: &JsValue,
+    events: &JsValue,
+    distribution: &JsValue,
+    alpha: &JsValue,
+) -> JsValue {
+    let alpha = alpha.as_f64().unwrap_or(0.05);
+    let distribution = distribution
+        .as_string()
+        .unwrap_or_else(|| "exponential".to_string());
+    let (times, events) = match survival_data(times, events) {
+        Some(data) => data,
+        None => return JsValue::NULL,
+    };
+
+    let deaths = events.iter().filter(|e| **e).count() as f64;
+    if deaths == 0.0 || times.iter().any(|t| *t <= 0.0) {
+        return JsValue::NULL;
+    }
+    let z = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0);
+    let ln_ln2 = std::f64::consts::LN_2.ln();
+
+    let obj = Object::new();
+    let (log_scale, log_shape, log_median_se, parameters) = match distribution.as_str() {
+        "exponential" => {
+            let rate = deaths / times.iter().sum::<f64>();
+            let _ = Reflect::set(&obj, &JsValue::from_str("rate"), &JsValue::from_f64(rate));
+            (-rate.ln(), 0.0, 1.0 / deaths.sqrt(), 1.0)
+        }
+        "weibull" => {
+            // The profile score equation for the shape is increasing, so bisect on it
+            let mean_log_event = times
+                .iter()
+                .zip(&events)
+                .filter(|(_, e)| **e)
+                .map(|(t, _)| t.ln())
+                .sum::<f64>()
+                / deaths;
+            let score = |shape: f64| {
+                let weighted = times.iter().map(|t| t.powf(shape)).sum::<f64>();
+                let weighted_log = times.iter().map(|t| t.powf(shape) * t.ln()).sum::<f64>();
+                weighted_log / weighted - 1.0 / shape - mean_log_event
+            };
+            let (mut low, mut high) = (-5.0_f64, 5.0_f64);
+            for _ in 0..100 {
+                let mid = (low + high) / 2.0;
+                if score(mid.exp()) < 0.0 {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            let log_shape = (low + high) / 2.0;
+            let shape = log_shape.exp();
+            let log_scale =
+                (times.iter().map(|t| t.powf(shape)).sum::<f64>() / deaths).ln() / shape;
+
+            // Observed information from central differences of the log-likelihood
+            let h = 1e-4;
+            let ll = |a: f64, b: f64| weibull_log_likelihood(&times, &events, a, b);
+            let center = ll(log_scale, log_shape);
+            let haa = (ll(log_scale + h, log_shape) - 2.0 * center + ll(log_scale - h, log_shape))
+                / h.powi(2);
+            let hbb = (ll(log_scale, log_shape + h) - 2.0 * center + ll(log_scale, log_shape - h))
+                / h.powi(2);
+            let hab = (ll(log_scale + h, log_shape + h)
+                - ll(log_scale + h, log_shape - h)
+                - ll(log_scale - h, log_shape + h)
+                + ll(log_scale - h, log_shape - h))
+                / (4.0 * h.powi(2));
+            let covariance = match invert_matrix(&[vec![-haa, -hab], vec![-hab, -hbb]]) {
+                Some(covariance) => covariance,
+                None => return JsValue::NULL,
+            };
+            let gradient = [1.0, -ln_ln2 / shape];
+            let variance = (0..2)
+                .map(|i| {
+                    (0..2)
+                        .map(|j| gradient[i] * covariance[i][j] * gradient[j])
+                        .sum::<f64>()
+                })
+                .sum::<f64>();
+            (log_scale, log_shape, variance.sqrt(), 2.0)
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let shape = log_shape.exp();
+    let log_median = log_scale + ln_ln2 / shape;
+    let log_likelihood = weibull_log_likelihood(&times, &events, log_scale, log_shape);
+
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("distribution"),
+        &JsValue::from_str(&distribution),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("scale"),
+        &JsValue::from_f64(log_scale.exp()),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("shape"), &JsValue::from_f64(shape));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("median"),
+        &JsValue::from_f64(log_median.exp()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("median_ci"),
+        &vec_to_jsvalue(vec![
+            (log_median - z * log_median_se).exp(),
+            (log_median + z * log_median_se).exp(),
+        ]),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("log_likelihood"),
+        &JsValue::from_f64(log_likelihood),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("aic"),
+        &JsValue::from_f64(-2.0 * log_likelihood + 2.0 * parameters),
+    );
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn remission() -> (JsValue, JsValue) {
+        (
+            vec_to_jsvalue(vec![
+                6.0, 6.0, 6.0, 6.0, 7.0, 9.0, 10.0, 10.0, 11.0, 13.0, 16.0, 17.0, 19.0, 20.0, 22.0,
+                23.0, 25.0, 32.0, 32.0, 34.0, 35.0,
+            ]),
+            vec_to_jsvalue(vec![
+                1.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0,
+                0.0, 0.0, 0.0, 0.0, 0.0,
+            ]),
+        )
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_kaplan_meier() {
+        let (times, events) = remission();
+
+        let result = kaplan_meier(&times, &events);
+
+        let survival =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("survival")).unwrap());
+        let std_error =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("std_error")).unwrap());
+
+        assert!((survival[0] - 0.8571).abs() < 0.0001);
+        assert!((survival[1] - 0.8067).abs() < 0.0001);
+        assert!((survival[6] - 0.4482).abs() < 0.0001);
+        assert!((std_error[0] - 0.0764).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_nelson_aalen() {
+        let (times, events) = remission();
+
+        let result = nelson_aalen(&times, &events, &JsValue::UNDEFINED);
+
+        let hazard = js_array_to_vector(
+            &Reflect::get(&result, &JsValue::from_str("cumulative_hazard")).unwrap(),
+        );
+        let std_error =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("std_error")).unwrap());
+        let lower =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("lower")).unwrap());
+        let upper =
+            js_array_to_vector(&Reflect::get(&result, &JsValue::from_str("upper")).unwrap());
+
+        assert!((hazard[0] - 0.14286).abs() < 0.0001);
+        assert!((hazard[6] - 0.75211).abs() < 0.0001);
+        assert!((std_error[6] - 0.27947).abs() < 0.0001);
+        assert!((lower[6] - 0.36308).abs() < 0.0001);
+        assert!((upper[6] - 1.55801).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_survival_fit() {
+        let (times, events) = remission();
+
+        let exponential = survival_fit(&times, &events, &JsValue::UNDEFINED, &JsValue::UNDEFINED);
+        let weibull = survival_fit(
+            &times,
+            &events,
+            &JsValue::from_str("weibull"),
+            &JsValue::UNDEFINED,
+        );
+
+        let rate = Reflect::get(&exponential, &JsValue::from_str("rate")).unwrap();
+        let median = Reflect::get(&exponential, &JsValue::from_str("median")).unwrap();
+        let median_ci = js_array_to_vector(
+            &Reflect::get(&exponential, &JsValue::from_str("median_ci")).unwrap(),
+        );
+        let log_likelihood =
+            Reflect::get(&exponential, &JsValue::from_str("log_likelihood")).unwrap();
+        let shape = Reflect::get(&weibull, &JsValue::from_str("shape")).unwrap();
+        let scale = Reflect::get(&weibull, &JsValue::from_str("scale")).unwrap();
+        let weibull_median = Reflect::get(&weibull, &JsValue::from_str("median")).unwrap();
+        let weibull_ci =
+            js_array_to_vector(&Reflect::get(&weibull, &JsValue::from_str("median_ci")).unwrap());
+
+        assert!((rate.as_f64().unwrap() - 0.025070).abs() < 0.00001);
+        assert!((median.as_f64().unwrap() - 27.6489).abs() < 0.001);
+        assert!((median_ci[0] - 14.3861).abs() < 0.001);
+        assert!((median_ci[1] - 53.1387).abs() < 0.001);
+        assert!((log_likelihood.as_f64().unwrap() - (-42.1749)).abs() < 0.001);
+        assert!((shape.as_f64().unwrap() - 1.35373).abs() < 0.0001);
+        assert!((scale.as_f64().unwrap() - 33.7652).abs() < 0.001);
+        assert!((weibull_median.as_f64().unwrap() - 25.7565).abs() < 0.001);
+        assert!((weibull_ci[0] - 15.7786).abs() < 0.01);
+        assert!((weibull_ci[1] - 42.0443).abs() < 0.01);
+    }
+}
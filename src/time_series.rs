@@ -0,0 +1,1879 @@
+use crate::conf_int::t_bounds;
+use crate::regression::{design_matrix, ols_fit};
+use crate::utils::*;
+use js_sys::Object;
+use js_sys::Reflect;
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor, Normal};
+use statrs::statistics::{Data, OrderStatistics};
+use wasm_bindgen::prelude::*;
+
+/// Builds the deterministic regressors for a unit-root regression.
+///
+/// # Arguments
+///
+/// * `trend` - The deterministic terms: "n" (none), "c" (constant), or "ct" (constant and
+///   linear trend).
+/// * `t` - The time index of the observation, starting at 1.
+///
+/// # Returns
+///
+/// * The deterministic terms for the observation, or `None` if the trend is unknown.
+fn deterministic_terms(trend: &str, t: f64) -> Option<Vec<f64>> {
+    match trend {
+        "n" => Some(vec![]),
+        "c" => Some(vec![1.0]),
+        "ct" => Some(vec![1.0, t]),
+        _ => None,
+    }
+}
+
+/// Computes MacKinnon's (1994) approximate p-value for a unit-root test statistic with a
+/// single integrated series.
+///
+/// # Arguments
+///
+/// * `stat` - The Dickey-Fuller t statistic.
+/// * `trend` - The deterministic terms: "n", "c", or "ct".
+///
+/// # Returns
+///
+/// * The approximate p-value.
+pub(crate) fn mackinnon_p(stat: f64, trend: &str) -> f64 {
+    let (min, star, max, small, large): (f64, f64, f64, [f64; 3], [f64; 4]) = match trend {
+        "n" => (
+            -19.04,
+            -1.04,
+            f64::INFINITY,
+            [0.6344, 1.2378, 3.2496e-2],
+            [0.4797, 9.3557e-1, -0.6999e-1, 3.3066e-2],
+        ),
+        "ct" => (
+            -16.18,
+            -2.89,
+            0.7,
+            [3.2512, 1.6047, 4.9588e-2],
+            [2.5261, 6.1654e-1, -3.7956e-1, -6.0285e-2],
+        ),
+        _ => (
+            -18.83,
+            -1.61,
+            2.74,
+            [2.1659, 1.4412, 3.8269e-2],
+            [1.7339, 9.3202e-1, -1.2745e-1, -1.0368e-2],
+        ),
+    };
+
+    if stat > max {
+        return 1.0;
+    }
+    if stat < min {
+        return 0.0;
+    }
+
+    let coefficients: &[f64] = if stat <= star { &small } else { &large };
+    let z = coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, c)| c * stat.powi(i as i32))
+        .sum::<f64>();
+    Normal::new(0.0, 1.0).unwrap().cdf(z)
+}
+
+/// Fits the augmented Dickey-Fuller regression of the differenced series on the
+/// deterministic terms, the lagged level, and `lags` lagged differences.
+///
+/// # Arguments
+///
+/// * `y` - The series.
+/// * `lags` - The number of lagged differences.
+/// * `trend` - The deterministic terms: "n", "c", or "ct".
+/// * `start` - The first differenced observation to use as a response, which must be at
+///   least `lags`.
+///
+/// # Returns
+///
+/// * A tuple of the t statistic on the lagged level, the residual sum of squares, the
+///   number of observations, and the number of regressors, or `None` if the regression
+///   cannot be fitted.
+fn adf_regression(
+    y: &[f64],
+    lags: usize,
+    trend: &str,
+    start: usize,
+) -> Option<(f64, f64, usize, usize)> {
+    let diff: Vec<f64> = y.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut design = Vec::new();
+    let mut response = Vec::new();
+    for t in start..diff.len() {
+        let mut row = deterministic_terms(trend, (t + 1) as f64)?;
+        row.push(y[t]);
+        row.extend((1..=lags).map(|i| diff[t - i]));
+        design.push(row);
+        response.push(diff[t]);
+    }
+
+    let level = deterministic_terms(trend, 1.0)?.len();
+    let regressors = design.first()?.len();
+    let fit = ols_fit(&design, &response)?;
+    Some((
+        fit.coefficients[level] / fit.std_errors[level],
+        fit.sse,
+        response.len(),
+        regressors,
+    ))
+}
+
+/// Performs an augmented Dickey-Fuller test for a unit root, whose null hypothesis is
+/// that the series is nonstationary.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `lags` - A reference to a JsValue representing the number of lagged differences, or
+///   undefined to choose the lag order by AIC up to `12 * (n / 100)^(1/4)`.
+/// * `trend` - A reference to a JsValue representing the deterministic terms: "n" (none),
+///   "c" (constant; the default), or "ct" (constant and linear trend).
+///
+/// # Returns
+///
+/// * An object with the properties `statistic`, `p` (MacKinnon's approximate p-value),
+///   `lags`, and `n_obs` (the observations used in the regression), or null if the inputs
+///   are invalid.
+#[wasm_bindgen]
+pub fn adf_test(column: &JsValue, lags: &JsValue, trend: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let trend = trend.as_string().unwrap_or_else(|| "c".to_string());
+    let n = y.len();
+
+    let terms = match deterministic_terms(&trend, 1.0) {
+        Some(terms) => terms.len(),
+        None => return JsValue::NULL,
+    };
+    if n < terms + 4 {
+        return JsValue::NULL;
+    }
+
+    let lags = match lags.as_f64() {
+        Some(lags) => lags as usize,
+        None => {
+            // Compare the information criteria over a common sample
+            let max_lag = ((12.0 * (n as f64 / 100.0).powf(0.25)) as usize)
+                .min(((n - 1) / 2).saturating_sub(terms + 1));
+            (0..=max_lag)
+                .filter_map(|lag| {
+                    adf_regression(&y, lag, &trend, max_lag).map(|(_, sse, m, k)| {
+                        let m = m as f64;
+                        (lag, m * (sse / m).ln() + 2.0 * k as f64)
+                    })
+                })
+                .fold((0, f64::INFINITY), |best, (lag, aic)| {
+                    if aic < best.1 {
+                        (lag, aic)
+                    } else {
+                        best
+                    }
+                })
+                .0
+        }
+    };
+
+    let (statistic, _, n_obs, _) = match adf_regression(&y, lags, &trend, lags) {
+        Some(result) => result,
+        None => return JsValue::NULL,
+    };
+    let p = mackinnon_p(statistic, &trend);
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("statistic"),
+        &JsValue::from_f64(statistic),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("lags"),
+        &JsValue::from_f64(lags as f64),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("n_obs"),
+        &JsValue::from_f64(n_obs as f64),
+    );
+    obj.into()
+}
+
+/// Estimates the long-run variance of residuals with the Bartlett (Newey-West) kernel.
+fn long_run_variance(residuals: &[f64], lags: usize) -> f64 {
+    let n = residuals.len();
+    let autocovariance =
+        |s: usize| (s..n).map(|t| residuals[t] * residuals[t - s]).sum::<f64>() / n as f64;
+    autocovariance(0)
+        + 2.0
+            * (1..=lags)
+                .map(|s| (1.0 - s as f64 / (lags as f64 + 1.0)) * autocovariance(s))
+                .sum::<f64>()
+}
+
+/// Performs the KPSS test, whose null hypothesis is that the series is stationary around
+/// a level or a linear trend, as a complement to the augmented Dickey-Fuller test.
+///
+/// The long-run variance uses the Bartlett kernel with `ceil(12 * (n / 100)^(1/4))` lags.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `trend` - A reference to a JsValue representing the deterministic terms: "c" (level
+///   stationarity; the default) or "ct" (trend stationarity).
+///
+/// # Returns
+///
+/// * An object with the properties `statistic`, `p`, `lags`, and `critical_values` (keyed
+///   by "10%", "5%", "2.5%", and "1%"), or null if the inputs are invalid or the series
+///   does not vary about its deterministic terms. The p-value is interpolated from the
+///   critical value table, so it is clipped to [0.01, 0.1].
+#[wasm_bindgen]
+pub fn kpss_test(column: &JsValue, trend: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let trend = trend.as_string().unwrap_or_else(|| "c".to_string());
+    let n = y.len();
+
+    let critical_values = match trend.as_str() {
+        "c" => [0.347, 0.463, 0.574, 0.739],
+        "ct" => [0.119, 0.146, 0.176, 0.216],
+        _ => return JsValue::NULL,
+    };
+    let levels = [0.1, 0.05, 0.025, 0.01];
+
+    let design: Vec<Vec<f64>> = (0..n)
+        .filter_map(|t| deterministic_terms(&trend, (t + 1) as f64))
+        .collect();
+    let residuals = match ols_fit(&design, &y) {
+        Some(fit) => fit.residuals,
+        None => return JsValue::NULL,
+    };
+
+    // Bartlett-weighted estimate of the long-run variance of the residuals
+    let lags = ((12.0 * (n as f64 / 100.0).powf(0.25)).ceil() as usize).min(n - 1);
+    let long_run_variance = long_run_variance(&residuals, lags);
+
+    let partial_sums = residuals.iter().scan(0.0, |sum, e| {
+        *sum += e;
+        Some(*sum)
+    });
+    let statistic =
+        partial_sums.map(|s| s.powi(2)).sum::<f64>() / ((n as f64).powi(2) * long_run_variance);
+    // A series with no variation about its deterministic terms, up to rounding, has no
+    // defined statistic
+    let scale = y.iter().fold(0.0_f64, |max, v| max.max(v.abs()));
+    if long_run_variance <= 0.0
+        || !statistic.is_finite()
+        || residuals.iter().all(|e| e.abs() <= 1e-10 * scale)
+    {
+        return JsValue::NULL;
+    }
+
+    // Interpolate the p-value linearly between the tabulated critical values
+    let p = if statistic <= critical_values[0] {
+        levels[0]
+    } else if statistic >= critical_values[3] {
+        levels[3]
+    } else {
+        let i = match critical_values.windows(2).position(|w| statistic < w[1]) {
+            Some(i) => i,
+            None => return JsValue::NULL,
+        };
+        levels[i]
+            + (statistic - critical_values[i]) / (critical_values[i + 1] - critical_values[i])
+                * (levels[i + 1] - levels[i])
+    };
+
+    let critical = Object::new();
+    for (label, value) in ["10%", "5%", "2.5%", "1%"].iter().zip(critical_values) {
+        let _ = Reflect::set(
+            &critical,
+            &JsValue::from_str(label),
+            &JsValue::from_f64(value),
+        );
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("statistic"),
+        &JsValue::from_f64(statistic),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("lags"),
+        &JsValue::from_f64(lags as f64),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("critical_values"), &critical);
+    obj.into()
+}
+
+/// Computes the moving averages of every full window of the values.
+fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    values
+        .windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+/// Computes the centered moving average used as the classical trend estimate, with a
+/// 2×m average for even periods and NaN where the window does not fit.
+fn centered_moving_average(y: &[f64], period: usize) -> Vec<f64> {
+    let half = period / 2;
+    (0..y.len())
+        .map(|t| {
+            if t < half || t + half >= y.len() {
+                f64::NAN
+            } else if period.is_multiple_of(2) {
+                (0.5 * y[t - half]
+                    + y[t + 1 - half..t + half].iter().sum::<f64>()
+                    + 0.5 * y[t + half])
+                    / period as f64
+            } else {
+                y[t - half..=t + half].iter().sum::<f64>() / period as f64
+            }
+        })
+        .collect()
+}
+
+/// Evaluates a local linear loess smoother with tricube weights at a position, using the
+/// `span` nearest observations.
+fn loess(positions: &[f64], values: &[f64], span: usize, at: f64) -> f64 {
+    let n = positions.len();
+    let mut distances: Vec<f64> = positions.iter().map(|x| (x - at).abs()).collect();
+    distances.sort_by(|a, b| a.total_cmp(b));
+    let bandwidth = if span <= n {
+        distances[span - 1]
+    } else {
+        distances[n - 1] * span as f64 / n as f64
+    }
+    .max(f64::EPSILON);
+
+    let weights: Vec<f64> = positions
+        .iter()
+        .map(|x| {
+            let u = (x - at).abs() / bandwidth;
+            if u < 1.0 {
+                (1.0 - u.powi(3)).powi(3)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let total = weights.iter().sum::<f64>();
+    let x_mean = weights
+        .iter()
+        .zip(positions)
+        .map(|(w, x)| w * x)
+        .sum::<f64>()
+        / total;
+    let y_mean = weights.iter().zip(values).map(|(w, y)| w * y).sum::<f64>() / total;
+    let sxx = weights
+        .iter()
+        .zip(positions)
+        .map(|(w, x)| w * (x - x_mean).powi(2))
+        .sum::<f64>();
+    let sxy = weights
+        .iter()
+        .zip(positions.iter().zip(values))
+        .map(|(w, (x, y))| w * (x - x_mean) * (y - y_mean))
+        .sum::<f64>();
+
+    if sxx > 0.0 {
+        y_mean + sxy / sxx * (at - x_mean)
+    } else {
+        y_mean
+    }
+}
+
+/// Decomposes a series with the inner loop of STL (seasonal-trend decomposition by
+/// loess), without the robustness iterations.
+///
+/// # Returns
+///
+/// * A tuple of the trend and seasonal components.
+fn stl(y: &[f64], period: usize) -> (Vec<f64>, Vec<f64>) {
+    let n = y.len();
+    let next_odd = |x: f64| {
+        let x = x.ceil() as usize;
+        if x.is_multiple_of(2) {
+            x + 1
+        } else {
+            x
+        }
+    };
+    let seasonal_span = 7;
+    let trend_span = next_odd(1.5 * period as f64 / (1.0 - 1.5 / seasonal_span as f64));
+    let low_pass_span = next_odd(period as f64);
+    let index: Vec<f64> = (0..n).map(|t| t as f64).collect();
+
+    let mut trend = vec![0.0; n];
+    let mut seasonal = vec![0.0; n];
+    for _ in 0..2 {
+        let detrended: Vec<f64> = y.iter().zip(&trend).map(|(y, t)| y - t).collect();
+
+        // Smooth each cycle-subseries, extending it by one period at each end
+        let mut cycle = vec![0.0; n + 2 * period];
+        for k in 0..period {
+            let positions: Vec<f64> = (k..n).step_by(period).map(|t| t as f64).collect();
+            let values: Vec<f64> = (k..n).step_by(period).map(|t| detrended[t]).collect();
+            if positions.is_empty() {
+                continue;
+            }
+            for j in 0..positions.len() + 2 {
+                let slot = k + j * period;
+                if slot < cycle.len() {
+                    let at = k as f64 + (j as f64 - 1.0) * period as f64;
+                    cycle[slot] = loess(&positions, &values, seasonal_span, at);
+                }
+            }
+        }
+
+        // Remove the low-frequency content of the smoothed subseries
+        let filtered = moving_average(&moving_average(&moving_average(&cycle, period), period), 3);
+        let low_pass: Vec<f64> = (0..n)
+            .map(|t| loess(&index, &filtered[..n], low_pass_span, t as f64))
+            .collect();
+        seasonal = (0..n).map(|t| cycle[t + period] - low_pass[t]).collect();
+
+        let deseasonalized: Vec<f64> = y.iter().zip(&seasonal).map(|(y, s)| y - s).collect();
+        trend = (0..n)
+            .map(|t| loess(&index, &deseasonalized, trend_span, t as f64))
+            .collect();
+    }
+
+    (trend, seasonal)
+}
+
+/// Decomposes a series into trend, seasonal, and remainder components.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `period` - A reference to a JsValue representing the number of observations per
+///   seasonal cycle.
+/// * `method` - A reference to a JsValue representing the method: "additive" (classical
+///   additive decomposition; the default), "multiplicative" (classical multiplicative
+///   decomposition), or "stl" (loess-based additive decomposition).
+///
+/// # Returns
+///
+/// * An object with the arrays `trend`, `seasonal`, and `remainder`, or null if the inputs
+///   are invalid. The classical trend is a centered moving average, so it and the
+///   remainder are NaN for the first and last half-periods.
+#[wasm_bindgen]
+pub fn decompose(column: &JsValue, period: &JsValue, method: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let period = period.as_f64().unwrap_or(0.0) as usize;
+    let method = method.as_string().unwrap_or_else(|| "additive".to_string());
+    let n = y.len();
+
+    if period < 2 || n < 2 * period {
+        return JsValue::NULL;
+    }
+
+    let (trend, seasonal, remainder) = match method.as_str() {
+        "additive" | "multiplicative" => {
+            let multiplicative = method == "multiplicative";
+            if multiplicative && y.iter().any(|v| *v <= 0.0) {
+                return JsValue::NULL;
+            }
+            let trend = centered_moving_average(&y, period);
+            let detrended: Vec<f64> = y
+                .iter()
+                .zip(&trend)
+                .map(|(y, t)| if multiplicative { y / t } else { y - t })
+                .collect();
+
+            // Average the detrended values at each position in the cycle, then center
+            let mut means: Vec<f64> = (0..period)
+                .map(|k| {
+                    let values: Vec<f64> = (k..n)
+                        .step_by(period)
+                        .map(|t| detrended[t])
+                        .filter(|v| !v.is_nan())
+                        .collect();
+                    values.iter().sum::<f64>() / values.len() as f64
+                })
+                .collect();
+            let overall = means.iter().sum::<f64>() / period as f64;
+            for mean in means.iter_mut() {
+                if multiplicative {
+                    *mean /= overall;
+                } else {
+                    *mean -= overall;
+                }
+            }
+
+            let seasonal: Vec<f64> = (0..n).map(|t| means[t % period]).collect();
+            let remainder: Vec<f64> = (0..n)
+                .map(|t| {
+                    if multiplicative {
+                        y[t] / (trend[t] * seasonal[t])
+                    } else {
+                        y[t] - trend[t] - seasonal[t]
+                    }
+                })
+                .collect();
+            (trend, seasonal, remainder)
+        }
+        "stl" => {
+            let (trend, seasonal) = stl(&y, period);
+            let remainder: Vec<f64> = (0..n).map(|t| y[t] - trend[t] - seasonal[t]).collect();
+            (trend, seasonal, remainder)
+        }
+        _ => return JsValue::NULL,
+    };
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("trend"), &vec_to_jsvalue(trend));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("seasonal"),
+        &vec_to_jsvalue(seasonal),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("remainder"),
+        &vec_to_jsvalue(remainder),
+    );
+    obj.into()
+}
+
+/// Performs the Mann-Kendall test for a monotonic trend and estimates the trend with
+/// Sen's slope, optionally using the seasonal variant of Hirsch et al. (1982).
+///
+/// The seasonal variant compares observations only within the same season, summing the
+/// statistics and variances over seasons. Sen's slope is the median of the pairwise
+/// slopes per time step, with the rank-based 95% confidence interval of Gilbert (1987).
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `period` - A reference to a JsValue representing the number of seasons per cycle for
+///   the seasonal test, or undefined for the ordinary test.
+///
+/// # Returns
+///
+/// * An object with the properties `s`, `var_s` (corrected for ties), `z`, `p`
+///   (two-sided), `tau`, `slope`, and `ci`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn mann_kendall_test(column: &JsValue, period: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let period = period.as_f64().map(|p| p as usize).unwrap_or(1);
+    let n = y.len();
+
+    if period == 0 || n < 2 * period + 1 {
+        return JsValue::NULL;
+    }
+
+    let mut s = 0.0;
+    let mut var_s = 0.0;
+    let mut pairs = 0.0;
+    let mut slopes = Vec::new();
+    for season in 0..period {
+        let indices: Vec<usize> = (season..n).step_by(period).collect();
+        let m = indices.len() as f64;
+        for (a, &i) in indices.iter().enumerate() {
+            for &j in &indices[a + 1..] {
+                let diff = y[j] - y[i];
+                if diff > 0.0 {
+                    s += 1.0;
+                } else if diff < 0.0 {
+                    s -= 1.0;
+                }
+                slopes.push(diff / (j - i) as f64);
+            }
+        }
+
+        // Correct the variance for groups of tied values
+        let mut values: Vec<f64> = indices.iter().map(|&i| y[i]).collect();
+        values.sort_by(|a, b| a.total_cmp(b));
+        let ties = values
+            .chunk_by(|a, b| a == b)
+            .map(|group| {
+                let t = group.len() as f64;
+                t * (t - 1.0) * (2.0 * t + 5.0)
+            })
+            .sum::<f64>();
+        var_s += (m * (m - 1.0) * (2.0 * m + 5.0) - ties) / 18.0;
+        pairs += m * (m - 1.0) / 2.0;
+    }
+
+    let z = if s > 0.0 {
+        (s - 1.0) / var_s.sqrt()
+    } else if s < 0.0 {
+        (s + 1.0) / var_s.sqrt()
+    } else {
+        0.0
+    };
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let p = 2.0 * (1.0 - normal.cdf(z.abs()));
+
+    slopes.sort_by(|a, b| a.total_cmp(b));
+    let count = slopes.len();
+    let slope = if count % 2 == 1 {
+        slopes[count / 2]
+    } else {
+        (slopes[count / 2 - 1] + slopes[count / 2]) / 2.0
+    };
+    let c = normal.inverse_cdf(0.975) * var_s.sqrt();
+    let rank = |r: f64| slopes[(r.round() as usize).clamp(1, count) - 1];
+    let ci = vec![
+        rank((count as f64 - c) / 2.0),
+        rank((count as f64 + c) / 2.0 + 1.0),
+    ];
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("s"), &JsValue::from_f64(s));
+    let _ = Reflect::set(&obj, &JsValue::from_str("var_s"), &JsValue::from_f64(var_s));
+    let _ = Reflect::set(&obj, &JsValue::from_str("z"), &JsValue::from_f64(z));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("tau"),
+        &JsValue::from_f64(s / pairs),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("slope"), &JsValue::from_f64(slope));
+    let _ = Reflect::set(&obj, &JsValue::from_str("ci"), &vec_to_jsvalue(ci));
+    obj.into()
+}
+
+/// Splits the segment `[start, end)` at the point with the largest standardized CUSUM
+/// statistic if its squared value exceeds the penalty, recursing into both halves.
+fn binary_segmentation(
+    prefix: &[f64],
+    start: usize,
+    end: usize,
+    min_size: usize,
+    penalty: f64,
+    sigma2: f64,
+    changepoints: &mut Vec<usize>,
+) {
+    if end - start < 2 * min_size {
+        return;
+    }
+
+    let n = (end - start) as f64;
+    let total = prefix[end] - prefix[start];
+    let (best, statistic) = (start + min_size..=end - min_size)
+        .map(|k| {
+            // The squared CUSUM statistic equals the drop in the scaled sum of squares
+            let m = (k - start) as f64;
+            let left = prefix[k] - prefix[start];
+            let statistic = (left - m / n * total).powi(2) * n / (m * (n - m)) / sigma2;
+            (k, statistic)
+        })
+        .fold((start, f64::NEG_INFINITY), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+
+    if statistic > penalty {
+        changepoints.push(best);
+        binary_segmentation(prefix, start, best, min_size, penalty, sigma2, changepoints);
+        binary_segmentation(prefix, best, end, min_size, penalty, sigma2, changepoints);
+    }
+}
+
+/// Detects shifts in the mean of a series.
+///
+/// Both methods minimize the sum of squares scaled by the noise variance plus a penalty
+/// per changepoint. The noise standard deviation is estimated robustly from the median
+/// absolute deviation of the first differences, so it is not inflated by the shifts.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `method` - A reference to a JsValue representing the method: "pelt" (the exact
+///   penalized optimum by pruned exact linear time search; the default) or "cusum"
+///   (binary segmentation with the CUSUM statistic).
+/// * `penalty` - A reference to a JsValue representing the penalty per changepoint;
+///   defaults to `ln(n)`.
+///
+/// # Returns
+///
+/// * An object with the properties `changepoints` (the index at which each new segment
+///   starts), `segment_means`, `penalty`, and `sigma`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn changepoints(column: &JsValue, method: &JsValue, penalty: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let method = method.as_string().unwrap_or_else(|| "pelt".to_string());
+    let n = y.len();
+    let min_size = 2;
+
+    if n < 2 * min_size {
+        return JsValue::NULL;
+    }
+    let penalty = penalty.as_f64().unwrap_or((n as f64).ln());
+
+    let diffs: Vec<f64> = y.windows(2).map(|w| w[1] - w[0]).collect();
+    let median = Data::new(diffs.clone()).median();
+    let mad = Data::new(
+        diffs
+            .iter()
+            .map(|d| (d - median).abs())
+            .collect::<Vec<f64>>(),
+    )
+    .median();
+    let sigma = mad / (0.6745 * 2.0_f64.sqrt());
+    if sigma <= 0.0 || penalty < 0.0 {
+        return JsValue::NULL;
+    }
+    let sigma2 = sigma.powi(2);
+
+    let mut prefix = vec![0.0; n + 1];
+    let mut prefix_sq = vec![0.0; n + 1];
+    for (i, v) in y.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + v;
+        prefix_sq[i + 1] = prefix_sq[i] + v.powi(2);
+    }
+    let cost = |a: usize, b: usize| {
+        let m = (b - a) as f64;
+        let sum = prefix[b] - prefix[a];
+        (prefix_sq[b] - prefix_sq[a] - sum.powi(2) / m) / sigma2
+    };
+
+    let mut found = match method.as_str() {
+        "pelt" => {
+            let mut best = vec![0.0; n + 1];
+            let mut last = vec![0; n + 1];
+            best[0] = -penalty;
+            let mut candidates = vec![0];
+            for t in min_size..=n {
+                let mut min = f64::INFINITY;
+                for &tau in candidates.iter().filter(|&&tau| t - tau >= min_size) {
+                    let value = best[tau] + cost(tau, t) + penalty;
+                    if value < min {
+                        min = value;
+                        last[t] = tau;
+                    }
+                }
+                best[t] = min;
+
+                // Prune candidates that can never be optimal again
+                candidates.retain(|&tau| t - tau < min_size || best[tau] + cost(tau, t) <= min);
+                candidates.push(t);
+            }
+
+            let mut found = Vec::new();
+            let mut t = n;
+            while last[t] > 0 {
+                t = last[t];
+                found.push(t);
+            }
+            found
+        }
+        "cusum" => {
+            let mut found = Vec::new();
+            binary_segmentation(&prefix, 0, n, min_size, penalty, sigma2, &mut found);
+            found
+        }
+        _ => return JsValue::NULL,
+    };
+    found.sort_unstable();
+
+    let bounds: Vec<usize> = std::iter::once(0)
+        .chain(found.iter().cloned())
+        .chain(std::iter::once(n))
+        .collect();
+    let segment_means: Vec<f64> = bounds
+        .windows(2)
+        .map(|w| (prefix[w[1]] - prefix[w[0]]) / (w[1] - w[0]) as f64)
+        .collect();
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("changepoints"),
+        &vec_to_jsvalue(found.iter().map(|&c| c as f64).collect()),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("segment_means"),
+        &vec_to_jsvalue(segment_means),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("penalty"),
+        &JsValue::from_f64(penalty),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("sigma"), &JsValue::from_f64(sigma));
+    obj.into()
+}
+
+/// Performs Engle's Lagrange multiplier test for autoregressive conditional
+/// heteroskedasticity (ARCH effects) by regressing the squared deviations of the series
+/// on their own lags.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of returns or
+///   residuals, which are centered at their mean.
+/// * `lags` - A reference to a JsValue representing the number of lags; defaults to 1.
+///
+/// # Returns
+///
+/// * An object with the properties `lm`, `p` (from the chi-square distribution with `lags`
+///   degrees of freedom), `f` and `f_p` (the F-test form of the same regression), and
+///   `lags`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn arch_test(column: &JsValue, lags: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let lags = lags.as_f64().unwrap_or(1.0) as usize;
+    let n = y.len();
+
+    if lags == 0 || n < 2 * lags + 3 {
+        return JsValue::NULL;
+    }
+
+    let mean = y.iter().sum::<f64>() / n as f64;
+    let squared: Vec<f64> = y.iter().map(|v| (v - mean).powi(2)).collect();
+    let predictors: Vec<Vec<f64>> = (1..=lags)
+        .map(|i| (lags..n).map(|t| squared[t - i]).collect())
+        .collect();
+    let response = &squared[lags..];
+    let n_obs = response.len();
+
+    let fit = match ols_fit(&design_matrix(&predictors, n_obs), response) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+    let r2 = 1.0 - fit.sse / fit.sst;
+    let lm = n_obs as f64 * r2;
+    let p = 1.0 - ChiSquared::new(lags as f64).unwrap().cdf(lm);
+    let f = (r2 / lags as f64) / ((1.0 - r2) / fit.df_resid);
+    let f_p = 1.0
+        - FisherSnedecor::new(lags as f64, fit.df_resid)
+            .unwrap()
+            .cdf(f);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("lm"), &JsValue::from_f64(lm));
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(&obj, &JsValue::from_str("f"), &JsValue::from_f64(f));
+    let _ = Reflect::set(&obj, &JsValue::from_str("f_p"), &JsValue::from_f64(f_p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("lags"),
+        &JsValue::from_f64(lags as f64),
+    );
+    obj.into()
+}
+
+/// Performs the Phillips-Perron test for a unit root, which corrects the Dickey-Fuller
+/// t statistic for serial correlation nonparametrically instead of adding lagged
+/// differences.
+///
+/// The long-run variance uses the Bartlett kernel with `ceil(12 * (n / 100)^(1/4))` lags.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `trend` - A reference to a JsValue representing the deterministic terms: "n" (none),
+///   "c" (constant; the default), or "ct" (constant and linear trend).
+///
+/// # Returns
+///
+/// * An object with the properties `statistic` (the adjusted Z-tau statistic), `p`
+///   (MacKinnon's approximate p-value), and `lags`, or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn pp_test(column: &JsValue, trend: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let trend = trend.as_string().unwrap_or_else(|| "c".to_string());
+    let n = y.len();
+
+    if n < 4 {
+        return JsValue::NULL;
+    }
+
+    // Regress the level on its lag and the deterministic terms
+    let mut design = Vec::new();
+    for t in 1..n {
+        let mut row = vec![y[t - 1]];
+        match deterministic_terms(&trend, t as f64) {
+            Some(terms) => row.extend(terms),
+            None => return JsValue::NULL,
+        }
+        design.push(row);
+    }
+    let fit = match ols_fit(&design, &y[1..]) {
+        Some(fit) => fit,
+        None => return JsValue::NULL,
+    };
+
+    let n_obs = (n - 1) as f64;
+    let lags = ((12.0 * (n as f64 / 100.0).powf(0.25)).ceil() as usize).min(n - 2);
+    let lambda2 = long_run_variance(&fit.residuals, lags);
+    let s2 = fit.sse / fit.df_resid;
+    let gamma0 = fit.sse / n_obs;
+    let se = fit.std_errors[0];
+    let t = (fit.coefficients[0] - 1.0) / se;
+
+    let statistic = (gamma0 / lambda2).sqrt() * t
+        - 0.5 * (lambda2 - gamma0) / lambda2.sqrt() * (n_obs * se / s2.sqrt());
+    let p = mackinnon_p(statistic, &trend);
+
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("statistic"),
+        &JsValue::from_f64(statistic),
+    );
+    let _ = Reflect::set(&obj, &JsValue::from_str("p"), &JsValue::from_f64(p));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("lags"),
+        &JsValue::from_f64(lags as f64),
+    );
+    obj.into()
+}
+
+/// Estimates the Hurst exponent of a series to characterize long memory, from the slope
+/// of a log-log regression of fluctuation size on window size.
+///
+/// The window sizes are up to ten geometrically spaced values, from 8 to n/2 for the
+/// rescaled range and from 4 to n/4 for detrended fluctuation analysis. Each size splits
+/// the series into non-overlapping windows.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `method` - A reference to a JsValue representing the estimator: "rs" (rescaled range;
+///   the default) or "dfa" (detrended fluctuation analysis).
+///
+/// # Returns
+///
+/// * An object with the properties `h`, `ci` (a 95% confidence interval from the log-log
+///   regression), and `sizes` (the window sizes used), or null if the inputs are invalid.
+#[wasm_bindgen]
+pub fn hurst_exponent(column: &JsValue, method: &JsValue) -> JsValue {
+    let y = js_array_to_vector(column);
+    let method = method.as_string().unwrap_or_else(|| "rs".to_string());
+    let n = y.len();
+
+    let (min_size, max_size) = match method.as_str() {
+        "rs" => (8, n / 2),
+        "dfa" => (4, n / 4),
+        _ => return JsValue::NULL,
+    };
+    if max_size <= min_size {
+        return JsValue::NULL;
+    }
+
+    let mut sizes: Vec<usize> = (0..10)
+        .map(|k| {
+            let ratio = max_size as f64 / min_size as f64;
+            (min_size as f64 * ratio.powf(k as f64 / 9.0) + 1e-9).floor() as usize
+        })
+        .collect();
+    sizes.dedup();
+    if sizes.len() < 3 {
+        return JsValue::NULL;
+    }
+
+    let mean = y.iter().sum::<f64>() / n as f64;
+    let profile: Vec<f64> = y
+        .iter()
+        .scan(0.0, |sum, v| {
+            *sum += v - mean;
+            Some(*sum)
+        })
+        .collect();
+
+    let fluctuations: Vec<f64> = sizes
+        .iter()
+        .map(|&size| {
+            let values: Vec<f64> = if method == "rs" {
+                // Range of the cumulative deviations over the standard deviation
+                y.chunks_exact(size)
+                    .filter_map(|window| {
+                        let m = window.iter().sum::<f64>() / size as f64;
+                        let deviations: Vec<f64> = window
+                            .iter()
+                            .scan(0.0, |sum, v| {
+                                *sum += v - m;
+                                Some(*sum)
+                            })
+                            .collect();
+                        let range = deviations.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+                            - deviations.iter().cloned().fold(f64::INFINITY, f64::min);
+                        let sd = (window.iter().map(|v| (v - m).powi(2)).sum::<f64>()
+                            / size as f64)
+                            .sqrt();
+                        (sd > 0.0).then(|| range / sd)
+                    })
+                    .collect()
+            } else {
+                // Mean squared residual of the profile around a linear trend
+                let t_mean = (size as f64 - 1.0) / 2.0;
+                let sxx = (0..size).map(|t| (t as f64 - t_mean).powi(2)).sum::<f64>();
+                profile
+                    .chunks_exact(size)
+                    .map(|window| {
+                        let m = window.iter().sum::<f64>() / size as f64;
+                        let slope = window
+                            .iter()
+                            .enumerate()
+                            .map(|(t, v)| (t as f64 - t_mean) * (v - m))
+                            .sum::<f64>()
+                            / sxx;
+                        window
+                            .iter()
+                            .enumerate()
+                            .map(|(t, v)| (v - m - slope * (t as f64 - t_mean)).powi(2))
+                            .sum::<f64>()
+                            / size as f64
+                    })
+                    .collect()
+            };
+            let average = values.iter().sum::<f64>() / values.len() as f64;
+            if method == "rs" {
+                average.ln()
+            } else {
+                average.sqrt().ln()
+            }
+        })
+        .collect();
+
+    let log_sizes = vec![sizes.iter().map(|&s| (s as f64).ln()).collect::<Vec<f64>>()];
+    let fit = match ols_fit(&design_matrix(&log_sizes, sizes.len()), &fluctuations) {
+        Some(fit) if fluctuations.iter().all(|f| f.is_finite()) => fit,
+        _ => return JsValue::NULL,
+    };
+    let h = fit.coefficients[1];
+    let (lower, upper) = t_bounds(h, fit.std_errors[1], fit.df_resid, 0.05);
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("h"), &JsValue::from_f64(h));
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("ci"),
+        &vec_to_jsvalue(vec![lower, upper]),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("sizes"),
+        &vec_to_jsvalue(sizes.iter().map(|&s| s as f64).collect()),
+    );
+    obj.into()
+}
+
+/// Minimizes a function with the Nelder-Mead simplex method, restarting once from the
+/// best point with a smaller simplex to avoid premature convergence.
+fn nelder_mead<F: Fn(&[f64]) -> f64>(f: F, start: &[f64]) -> Vec<f64> {
+    let dim = start.len();
+    let mut best = start.to_vec();
+    for step in [0.1, 0.01] {
+        let mut points: Vec<Vec<f64>> = std::iter::once(best.clone())
+            .chain((0..dim).map(|i| {
+                let mut point = best.clone();
+                point[i] += step;
+                point
+            }))
+            .collect();
+        let mut values: Vec<f64> = points.iter().map(|p| f(p)).collect();
+
+        for _ in 0..5000 {
+            let mut order: Vec<usize> = (0..=dim).collect();
+            order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+            points = order.iter().map(|&i| points[i].clone()).collect();
+            values = order.iter().map(|&i| values[i]).collect();
+            if (values[dim] - values[0]).abs() < 1e-12 {
+                break;
+            }
+
+            let centroid: Vec<f64> = (0..dim)
+                .map(|j| points[..dim].iter().map(|p| p[j]).sum::<f64>() / dim as f64)
+                .collect();
+            let toward = |scale: f64| -> Vec<f64> {
+                (0..dim)
+                    .map(|j| centroid[j] + scale * (points[dim][j] - centroid[j]))
+                    .collect()
+            };
+
+            let reflected = toward(-1.0);
+            let reflected_value = f(&reflected);
+            if reflected_value < values[0] {
+                let expanded = toward(-2.0);
+                let expanded_value = f(&expanded);
+                if expanded_value < reflected_value {
+                    points[dim] = expanded;
+                    values[dim] = expanded_value;
+                } else {
+                    points[dim] = reflected;
+                    values[dim] = reflected_value;
+                }
+            } else if reflected_value < values[dim - 1] {
+                points[dim] = reflected;
+                values[dim] = reflected_value;
+            } else {
+                let contracted = toward(0.5);
+                let contracted_value = f(&contracted);
+                if contracted_value < values[dim] {
+                    points[dim] = contracted;
+                    values[dim] = contracted_value;
+                } else {
+                    // Shrink the simplex toward the best point
+                    for i in 1..=dim {
+                        points[i] = (0..dim)
+                            .map(|j| points[0][j] + 0.5 * (points[i][j] - points[0][j]))
+                            .collect();
+                        values[i] = f(&points[i]);
+                    }
+                }
+            }
+        }
+
+        let index = (0..=dim)
+            .min_by(|&a, &b| values[a].total_cmp(&values[b]))
+            .unwrap();
+        best = points[index].clone();
+    }
+    best
+}
+
+/// Computes the conditional residuals of an ARMA model for a (differenced) series, with
+/// the residuals before the first `p` observations set to zero.
+fn arma_residuals(w: &[f64], mean: f64, ar: &[f64], ma: &[f64]) -> Vec<f64> {
+    let mut residuals = vec![0.0; w.len()];
+    for t in ar.len()..w.len() {
+        let ar_part = ar
+            .iter()
+            .enumerate()
+            .map(|(i, phi)| phi * (w[t - 1 - i] - mean))
+            .sum::<f64>();
+        let ma_part = ma
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| t > *j)
+            .map(|(j, theta)| theta * residuals[t - 1 - j])
+            .sum::<f64>();
+        residuals[t] = w[t] - mean - ar_part - ma_part;
+    }
+    residuals
+}
+
+/// An ARIMA(p, d, q) model fitted by conditional sum of squares.
+#[wasm_bindgen]
+pub struct ArimaModel {
+    series: Vec<f64>,
+    d: usize,
+    mean: f64,
+    ar: Vec<f64>,
+    ma: Vec<f64>,
+    sigma2: f64,
+    aic: f64,
+    residuals: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl ArimaModel {
+    /// The autoregressive coefficients.
+    #[wasm_bindgen(getter)]
+    pub fn ar(&self) -> JsValue {
+        vec_to_jsvalue(self.ar.clone())
+    }
+
+    /// The moving average coefficients.
+    #[wasm_bindgen(getter)]
+    pub fn ma(&self) -> JsValue {
+        vec_to_jsvalue(self.ma.clone())
+    }
+
+    /// The mean of the series, which is estimated only when `d` is zero.
+    #[wasm_bindgen(getter)]
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The estimated innovation variance.
+    #[wasm_bindgen(getter)]
+    pub fn sigma2(&self) -> f64 {
+        self.sigma2
+    }
+
+    /// The Akaike information criterion from the conditional log-likelihood.
+    #[wasm_bindgen(getter)]
+    pub fn aic(&self) -> f64 {
+        self.aic
+    }
+
+    /// The conditional residuals of the differenced series.
+    #[wasm_bindgen(getter)]
+    pub fn residuals(&self) -> JsValue {
+        vec_to_jsvalue(self.residuals.clone())
+    }
+
+    /// Forecasts the series with prediction intervals from the psi-weights of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - A reference to a JsValue representing the number of steps to forecast.
+    /// * `alpha` - A reference to a JsValue representing the significance level for the
+    ///   prediction intervals; defaults to 0.05.
+    ///
+    /// # Returns
+    ///
+    /// * An object with the arrays `mean`, `lower`, and `upper`, or null if `h` is invalid.
+    pub fn forecast(&self, h: &JsValue, alpha: &JsValue) -> JsValue {
+        let h = h.as_f64().unwrap_or(0.0) as usize;
+        let alpha = alpha.as_f64().unwrap_or(0.05);
+        if h == 0 {
+            return JsValue::NULL;
+        }
+
+        // Expand the AR polynomial by the differencing to forecast on the original scale
+        let mut polynomial: Vec<f64> = std::iter::once(1.0)
+            .chain(self.ar.iter().map(|phi| -phi))
+            .collect();
+        for _ in 0..self.d {
+            polynomial = (0..=polynomial.len())
+                .map(|i| {
+                    polynomial.get(i).copied().unwrap_or(0.0)
+                        - if i > 0 { polynomial[i - 1] } else { 0.0 }
+                })
+                .collect();
+        }
+        let ar: Vec<f64> = polynomial[1..].iter().map(|a| -a).collect();
+        let constant = self.mean * (1.0 - self.ar.iter().sum::<f64>());
+
+        let n = self.series.len();
+        let mut values = self.series.clone();
+        let mut shocks: Vec<f64> = vec![0.0; self.d];
+        shocks.extend(&self.residuals);
+        for t in n..n + h {
+            let value = constant
+                + ar.iter()
+                    .enumerate()
+                    .map(|(i, a)| a * values[t - 1 - i])
+                    .sum::<f64>()
+                + self
+                    .ma
+                    .iter()
+                    .enumerate()
+                    .map(|(j, theta)| theta * shocks[t - 1 - j])
+                    .sum::<f64>();
+            values.push(value);
+            shocks.push(0.0);
+        }
+
+        let mut psi = vec![1.0];
+        for j in 1..h {
+            let weight = self.ma.get(j - 1).copied().unwrap_or(0.0)
+                + (1..=j.min(ar.len()))
+                    .map(|i| ar[i - 1] * psi[j - i])
+                    .sum::<f64>();
+            psi.push(weight);
+        }
+
+        let z = Normal::new(0.0, 1.0)
+            .unwrap()
+            .inverse_cdf(1.0 - alpha / 2.0);
+        let mut lower = Vec::with_capacity(h);
+        let mut upper = Vec::with_capacity(h);
+        for k in 0..h {
+            let se = (self.sigma2 * psi[..=k].iter().map(|w| w.powi(2)).sum::<f64>()).sqrt();
+            lower.push(values[n + k] - z * se);
+            upper.push(values[n + k] + z * se);
+        }
+
+        let obj = Object::new();
+        let _ = Reflect::set(
+            &obj,
+            &JsValue::from_str("mean"),
+            &vec_to_jsvalue(values[n..].to_vec()),
+        );
+        let _ = Reflect::set(&obj, &JsValue::from_str("lower"), &vec_to_jsvalue(lower));
+        let _ = Reflect::set(&obj, &JsValue::from_str("upper"), &vec_to_jsvalue(upper));
+        obj.into()
+    }
+}
+
+/// Fits an ARIMA(p, d, q) model by conditional sum of squares, minimized with the
+/// Nelder-Mead method. A mean is estimated only when `d` is zero, and stationarity and
+/// invertibility are not enforced.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `p` - A reference to a JsValue representing the autoregressive order.
+/// * `d` - A reference to a JsValue representing the order of differencing.
+/// * `q` - A reference to a JsValue representing the moving average order.
+///
+/// # Returns
+///
+/// * The fitted model, with the properties `ar`, `ma`, `mean`, `sigma2`, `aic`, and
+///   `residuals` and a `forecast(h, alpha)` method, or undefined if the inputs are
+///   invalid or the series is too short.
+#[wasm_bindgen]
+pub fn arima_fit(column: &JsValue, p: &JsValue, d: &JsValue, q: &JsValue) -> Option<ArimaModel> {
+    let series = js_array_to_vector(column);
+    let p = p.as_f64().unwrap_or(0.0) as usize;
+    let d = d.as_f64().unwrap_or(0.0) as usize;
+    let q = q.as_f64().unwrap_or(0.0) as usize;
+
+    let mut w = series.clone();
+    for _ in 0..d {
+        w = w.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    }
+    let include_mean = d == 0;
+    let k = p + q + include_mean as usize;
+    if w.len() <= p + k + 1 {
+        return None;
+    }
+
+    let split = |params: &[f64]| {
+        let mean = if include_mean { params[0] } else { 0.0 };
+        let offset = include_mean as usize;
+        (
+            mean,
+            params[offset..offset + p].to_vec(),
+            params[offset + p..].to_vec(),
+        )
+    };
+    let css = |params: &[f64]| {
+        let (mean, ar, ma) = split(params);
+        arma_residuals(&w, mean, &ar, &ma)
+            .iter()
+            .map(|e| e.powi(2))
+            .sum::<f64>()
+    };
+
+    let start: Vec<f64> = std::iter::once(w.iter().sum::<f64>() / w.len() as f64)
+        .filter(|_| include_mean)
+        .chain(std::iter::repeat_n(0.0, p + q))
+        .collect();
+    let params = if k > 0 {
+        nelder_mead(css, &start)
+    } else {
+        start
+    };
+    let (mean, ar, ma) = split(&params);
+    let residuals = arma_residuals(&w, mean, &ar, &ma);
+
+    let n_eff = (w.len() - p) as f64;
+    let sigma2 = residuals.iter().map(|e| e.powi(2)).sum::<f64>() / n_eff;
+    let log_likelihood = -n_eff / 2.0 * ((2.0 * std::f64::consts::PI * sigma2).ln() + 1.0);
+    let aic = -2.0 * log_likelihood + 2.0 * (k + 1) as f64;
+
+    Some(ArimaModel {
+        series,
+        d,
+        mean,
+        ar,
+        ma,
+        sigma2,
+        aic,
+        residuals,
+    })
+}
+
+/// A multiset of values stored in a map keyed by the total order of their bits, so that
+/// inserting, removing, and finding the extremes all take logarithmic time.
+#[derive(Default)]
+struct SortedMultiset {
+    counts: std::collections::BTreeMap<u64, usize>,
+    len: usize,
+}
+
+impl SortedMultiset {
+    fn key(value: f64) -> u64 {
+        let bits = value.to_bits();
+        if bits >> 63 == 1 {
+            !bits
+        } else {
+            bits | (1 << 63)
+        }
+    }
+
+    fn value(key: u64) -> f64 {
+        if key >> 63 == 1 {
+            f64::from_bits(key & !(1 << 63))
+        } else {
+            f64::from_bits(!key)
+        }
+    }
+
+    fn insert(&mut self, value: f64) {
+        *self.counts.entry(Self::key(value)).or_insert(0) += 1;
+        self.len += 1;
+    }
+
+    fn remove(&mut self, value: f64) -> bool {
+        let key = Self::key(value);
+        match self.counts.get_mut(&key) {
+            Some(count) => {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(&key);
+                }
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn first(&self) -> Option<f64> {
+        self.counts.keys().next().map(|key| Self::value(*key))
+    }
+
+    fn last(&self) -> Option<f64> {
+        self.counts.keys().next_back().map(|key| Self::value(*key))
+    }
+}
+
+/// Computes a statistic over every full sliding window of a series in a single pass.
+/// Means and standard deviations are updated as values enter and leave the window,
+/// minima and maxima use monotonic queues, and medians keep the window split between two
+/// sorted halves, so each step costs at most logarithmic time in the window size.
+///
+/// # Arguments
+///
+/// * `column` - A reference to a JsValue representing the JavaScript array of the series.
+/// * `window` - A reference to a JsValue representing the window size.
+/// * `statistic` - A reference to a JsValue representing the statistic, one of "mean"
+///   (the default), "sd" (the sample standard deviation), "min", "max", or "median".
+///
+/// # Returns
+///
+/// * A JavaScript array whose element `i` summarizes the values at positions `i` through
+///   `i + window - 1`, or null if the window is not between 1 (2 for "sd") and the series
+///   length, the statistic is unknown, or any value is NaN.
+#[wasm_bindgen]
+pub fn rolling_stats(column: &JsValue, window: &JsValue, statistic: &JsValue) -> JsValue {
+    let values = js_array_to_vector(column);
+    let statistic = statistic.as_string().unwrap_or("mean".to_string());
+    let window = match window.as_f64() {
+        Some(w) if w >= 1.0 && w.fract() == 0.0 && w as usize <= values.len() => w as usize,
+        _ => return JsValue::NULL,
+    };
+    if values.iter().any(|v| v.is_nan()) || (statistic == "sd" && window < 2) {
+        return JsValue::NULL;
+    }
+    let w = window as f64;
+
+    let result: Vec<f64> = match statistic.as_str() {
+        "mean" | "sd" => {
+            // Welford-style updates of the window mean and sum of squared deviations
+            let mut mean = values[..window].iter().sum::<f64>() / w;
+            let mut m2 = values[..window]
+                .iter()
+                .map(|v| (v - mean).powi(2))
+                .sum::<f64>();
+            let summarize = |mean: f64, m2: f64| {
+                if statistic == "sd" {
+                    (m2.max(0.0) / (w - 1.0)).sqrt()
+                } else {
+                    mean
+                }
+            };
+            let mut result = vec![summarize(mean, m2)];
+            for t in window..values.len() {
+                let (incoming, outgoing) = (values[t], values[t - window]);
+                let updated = mean + (incoming - outgoing) / w;
+                m2 += (incoming - outgoing) * (incoming - updated + outgoing - mean);
+                mean = updated;
+                result.push(summarize(mean, m2));
+            }
+            result
+        }
+        "min" | "max" => {
+            // Indices whose values are monotonic from the front of the queue
+            let keeps = |front: f64, back: f64| {
+                if statistic == "min" {
+                    front < back
+                } else {
+                    front > back
+                }
+            };
+            let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+            let mut result = Vec::with_capacity(values.len() + 1 - window);
+            for t in 0..values.len() {
+                while queue.back().is_some_and(|&i| !keeps(values[i], values[t])) {
+                    queue.pop_back();
+                }
+                queue.push_back(t);
+                if queue.front().is_some_and(|&i| i + window <= t) {
+                    queue.pop_front();
+                }
+                if t + 1 >= window {
+                    result.push(values[queue[0]]);
+                }
+            }
+            result
+        }
+        "median" => {
+            // The lower half holds the extra value when the window size is odd
+            let mut lower = SortedMultiset::default();
+            let mut upper = SortedMultiset::default();
+            let rebalance = |lower: &mut SortedMultiset, upper: &mut SortedMultiset| {
+                while lower.len > upper.len + 1 {
+                    let value = lower.last().unwrap();
+                    lower.remove(value);
+                    upper.insert(value);
+                }
+                while upper.len > lower.len {
+                    let value = upper.first().unwrap();
+                    upper.remove(value);
+                    lower.insert(value);
+                }
+            };
+            let mut result = Vec::with_capacity(values.len() + 1 - window);
+            for t in 0..values.len() {
+                if lower.last().is_none_or(|max| values[t] <= max) {
+                    lower.insert(values[t]);
+                } else {
+                    upper.insert(values[t]);
+                }
+                if t >= window {
+                    let outgoing = values[t - window];
+                    if !lower.remove(outgoing) {
+                        upper.remove(outgoing);
+                    }
+                }
+                rebalance(&mut lower, &mut upper);
+                if t + 1 >= window {
+                    let middle = lower.last().unwrap();
+                    result.push(if window % 2 == 1 {
+                        middle
+                    } else {
+                        (middle + upper.first().unwrap()) / 2.0
+                    });
+                }
+            }
+            result
+        }
+        _ => return JsValue::NULL,
+    };
+    vec_to_jsvalue(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn series() -> Vec<f64> {
+        vec![
+            0.0, 0.09, 1.3, -0.15, 0.9, 0.28, -0.09, 1.85, 1.27, 0.72, 1.16, 1.82, 1.06, 1.22,
+            -0.24, -0.51, -0.74, -1.78, -2.58, -3.17, -2.14, -1.46, -1.2, -0.65, -1.73, -1.12,
+            -0.43, 0.49, -0.55, -0.73, -2.45, -1.97, -3.38, -3.45, -0.97, -2.78, -0.87, -0.19,
+            -0.43, 0.2, 0.65, 1.44, 0.63, -0.21, -0.73, -1.42, -0.9, -1.33, 0.27, -1.71,
+        ]
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_adf_test() {
+        let column = vec_to_jsvalue(series());
+
+        let result1 = adf_test(&column, &JsValue::UNDEFINED, &JsValue::UNDEFINED);
+        let result2 = adf_test(&column, &JsValue::from_f64(2.0), &JsValue::from_str("ct"));
+
+        let stat1 = Reflect::get(&result1, &JsValue::from_str("statistic")).unwrap();
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let lags1 = Reflect::get(&result1, &JsValue::from_str("lags")).unwrap();
+        let stat2 = Reflect::get(&result2, &JsValue::from_str("statistic")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+
+        assert!((stat1.as_f64().unwrap() - (-2.70792)).abs() < 0.001);
+        assert!((p1.as_f64().unwrap() - 0.07268).abs() < 0.001);
+        assert_eq!(lags1.as_f64().unwrap(), 0.0);
+        assert!((stat2.as_f64().unwrap() - (-2.30290)).abs() < 0.001);
+        assert!((p2.as_f64().unwrap() - 0.43234).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_kpss_test() {
+        let column = vec_to_jsvalue(series());
+
+        let result1 = kpss_test(&column, &JsValue::UNDEFINED);
+        let result2 = kpss_test(&column, &JsValue::from_str("ct"));
+
+        let stat1 = Reflect::get(&result1, &JsValue::from_str("statistic")).unwrap();
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let lags1 = Reflect::get(&result1, &JsValue::from_str("lags")).unwrap();
+        let stat2 = Reflect::get(&result2, &JsValue::from_str("statistic")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+
+        assert!((stat1.as_f64().unwrap() - 0.23702).abs() < 0.0001);
+        assert!((p1.as_f64().unwrap() - 0.1).abs() < 1e-10);
+        assert_eq!(lags1.as_f64().unwrap(), 11.0);
+        assert!((stat2.as_f64().unwrap() - 0.12439).abs() < 0.0001);
+        assert!((p2.as_f64().unwrap() - 0.09003).abs() < 0.0001);
+        assert!(kpss_test(&vec_to_jsvalue(vec![5.0; 20]), &JsValue::from_str("c")).is_null());
+        let line: Vec<f64> = (0..20).map(|t| 2.0 + 0.5 * t as f64).collect();
+        let linear = kpss_test(&vec_to_jsvalue(line), &JsValue::from_str("ct"));
+        assert!(linear.is_null());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_decompose() {
+        let column = vec_to_jsvalue(vec![
+            10.0, 14.0, 8.0, 25.0, 16.0, 22.0, 14.0, 35.0, 15.0, 27.0, 18.0, 40.0, 28.0, 40.0,
+            25.0, 65.0,
+        ]);
+        let seasonal_series: Vec<f64> = (0..24)
+            .map(|t| 0.5 * t as f64 + [2.0, -1.0, 0.5, -1.5][t % 4])
+            .collect();
+
+        let additive = decompose(&column, &JsValue::from_f64(4.0), &JsValue::UNDEFINED);
+        let multiplicative = decompose(
+            &column,
+            &JsValue::from_f64(4.0),
+            &JsValue::from_str("multiplicative"),
+        );
+        let stl = decompose(
+            &vec_to_jsvalue(seasonal_series),
+            &JsValue::from_f64(4.0),
+            &JsValue::from_str("stl"),
+        );
+
+        let trend =
+            js_array_to_vector(&Reflect::get(&additive, &JsValue::from_str("trend")).unwrap());
+        let seasonal =
+            js_array_to_vector(&Reflect::get(&additive, &JsValue::from_str("seasonal")).unwrap());
+        let remainder =
+            js_array_to_vector(&Reflect::get(&additive, &JsValue::from_str("remainder")).unwrap());
+        let seasonal_mult = js_array_to_vector(
+            &Reflect::get(&multiplicative, &JsValue::from_str("seasonal")).unwrap(),
+        );
+        let seasonal_stl =
+            js_array_to_vector(&Reflect::get(&stl, &JsValue::from_str("seasonal")).unwrap());
+        let remainder_stl =
+            js_array_to_vector(&Reflect::get(&stl, &JsValue::from_str("remainder")).unwrap());
+
+        assert!(trend[0].is_nan());
+        assert!((trend[2] - 15.0).abs() < 1e-10);
+        assert!((trend[5] - 20.5).abs() < 1e-10);
+        assert!((seasonal[0] - (-5.09375)).abs() < 1e-6);
+        assert!((seasonal[3] - 10.36458).abs() < 1e-4);
+        assert!((remainder[5] - (-1.03125)).abs() < 1e-6);
+        assert!((seasonal_mult[1] - 1.10033).abs() < 1e-4);
+        assert!((seasonal_stl[8] - 2.0).abs() < 0.1);
+        assert!((seasonal_stl[9] - (-1.0)).abs() < 0.1);
+        assert!(remainder_stl.iter().all(|r| r.abs() < 0.1));
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_mann_kendall_test() {
+        let column = vec_to_jsvalue(vec![
+            5.1, 4.8, 5.6, 5.9, 5.4, 6.2, 6.0, 6.6, 6.1, 6.9, 7.2, 6.8, 7.5, 7.1, 7.9, 8.0,
+        ]);
+
+        let result1 = mann_kendall_test(&column, &JsValue::UNDEFINED);
+        let result2 = mann_kendall_test(&column, &JsValue::from_f64(4.0));
+
+        let s1 = Reflect::get(&result1, &JsValue::from_str("s")).unwrap();
+        let z1 = Reflect::get(&result1, &JsValue::from_str("z")).unwrap();
+        let slope1 = Reflect::get(&result1, &JsValue::from_str("slope")).unwrap();
+        let ci1 = js_array_to_vector(&Reflect::get(&result1, &JsValue::from_str("ci")).unwrap());
+        let s2 = Reflect::get(&result2, &JsValue::from_str("s")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+        let slope2 = Reflect::get(&result2, &JsValue::from_str("slope")).unwrap();
+        let ci2 = js_array_to_vector(&Reflect::get(&result2, &JsValue::from_str("ci")).unwrap());
+
+        assert_eq!(s1.as_f64().unwrap(), 100.0);
+        assert!((z1.as_f64().unwrap() - 4.45723).abs() < 0.0001);
+        assert!((slope1.as_f64().unwrap() - 0.19167).abs() < 0.0001);
+        assert!((ci1[0] - 0.16667).abs() < 0.0001);
+        assert!((ci1[1] - 0.22857).abs() < 0.0001);
+        assert_eq!(s2.as_f64().unwrap(), 24.0);
+        assert!((p2.as_f64().unwrap() - 0.0000937).abs() < 0.000001);
+        assert!((slope2.as_f64().unwrap() - 0.175).abs() < 0.0001);
+        assert!((ci2[0] - 0.1125).abs() < 0.0001);
+        assert!((ci2[1] - 0.2625).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_changepoints() {
+        let column = vec_to_jsvalue(vec![
+            0.1, -0.2, 0.0, 0.3, -0.1, 0.2, -0.3, 0.1, 0.0, -0.1, 5.2, 4.9, 5.1, 4.8, 5.0, 5.3,
+            4.9, 5.1, 5.0, 4.7, 2.1, 1.8, 2.0, 2.2, 1.9, 2.0, 2.1, 1.9, 2.3, 1.8,
+        ]);
+
+        let pelt = changepoints(&column, &JsValue::UNDEFINED, &JsValue::UNDEFINED);
+        let cusum = changepoints(&column, &JsValue::from_str("cusum"), &JsValue::UNDEFINED);
+
+        let pelt_points =
+            js_array_to_vector(&Reflect::get(&pelt, &JsValue::from_str("changepoints")).unwrap());
+        let pelt_means =
+            js_array_to_vector(&Reflect::get(&pelt, &JsValue::from_str("segment_means")).unwrap());
+        let cusum_points =
+            js_array_to_vector(&Reflect::get(&cusum, &JsValue::from_str("changepoints")).unwrap());
+
+        assert_eq!(pelt_points, vec![10.0, 20.0]);
+        assert!(pelt_means[0].abs() < 1e-10);
+        assert!((pelt_means[1] - 5.0).abs() < 1e-10);
+        assert!((pelt_means[2] - 2.01).abs() < 1e-10);
+        assert_eq!(cusum_points, vec![10.0, 20.0]);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_arch_test() {
+        let column = vec_to_jsvalue(series());
+
+        let result1 = arch_test(&column, &JsValue::UNDEFINED);
+        let result2 = arch_test(&column, &JsValue::from_f64(3.0));
+
+        let lm1 = Reflect::get(&result1, &JsValue::from_str("lm")).unwrap();
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let f1 = Reflect::get(&result1, &JsValue::from_str("f")).unwrap();
+        let lm2 = Reflect::get(&result2, &JsValue::from_str("lm")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+        let f_p2 = Reflect::get(&result2, &JsValue::from_str("f_p")).unwrap();
+
+        assert!((lm1.as_f64().unwrap() - 4.97027).abs() < 0.0001);
+        assert!((p1.as_f64().unwrap() - 0.02579).abs() < 0.0001);
+        assert!((f1.as_f64().unwrap() - 5.30557).abs() < 0.0001);
+        assert!((lm2.as_f64().unwrap() - 5.14555).abs() < 0.0001);
+        assert!((p2.as_f64().unwrap() - 0.16144).abs() < 0.0001);
+        assert!((f_p2.as_f64().unwrap() - 0.16870).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_pp_test() {
+        let column = vec_to_jsvalue(series());
+
+        let result1 = pp_test(&column, &JsValue::UNDEFINED);
+        let result2 = pp_test(&column, &JsValue::from_str("ct"));
+
+        let stat1 = Reflect::get(&result1, &JsValue::from_str("statistic")).unwrap();
+        let p1 = Reflect::get(&result1, &JsValue::from_str("p")).unwrap();
+        let stat2 = Reflect::get(&result2, &JsValue::from_str("statistic")).unwrap();
+        let p2 = Reflect::get(&result2, &JsValue::from_str("p")).unwrap();
+
+        assert!((stat1.as_f64().unwrap() - (-2.52767)).abs() < 0.001);
+        assert!((p1.as_f64().unwrap() - 0.10889).abs() < 0.001);
+        assert!((stat2.as_f64().unwrap() - (-2.76973)).abs() < 0.001);
+        assert!((p2.as_f64().unwrap() - 0.20828).abs() < 0.001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_hurst_exponent() {
+        let column = vec_to_jsvalue(
+            (0..256)
+                .map(|t| ((t * 7919) % 101) as f64 / 101.0 - 0.5)
+                .collect(),
+        );
+
+        let rs = hurst_exponent(&column, &JsValue::UNDEFINED);
+        let dfa = hurst_exponent(&column, &JsValue::from_str("dfa"));
+
+        let h_rs = Reflect::get(&rs, &JsValue::from_str("h")).unwrap();
+        let ci_rs = js_array_to_vector(&Reflect::get(&rs, &JsValue::from_str("ci")).unwrap());
+        let sizes_rs = js_array_to_vector(&Reflect::get(&rs, &JsValue::from_str("sizes")).unwrap());
+        let h_dfa = Reflect::get(&dfa, &JsValue::from_str("h")).unwrap();
+        let ci_dfa = js_array_to_vector(&Reflect::get(&dfa, &JsValue::from_str("ci")).unwrap());
+
+        assert!((h_rs.as_f64().unwrap() - 0.46792).abs() < 0.0001);
+        assert!((ci_rs[0] - 0.36002).abs() < 0.0001);
+        assert!((ci_rs[1] - 0.57582).abs() < 0.0001);
+        assert_eq!(sizes_rs[3], 20.0);
+        assert!((h_dfa.as_f64().unwrap() - 0.31387).abs() < 0.0001);
+        assert!((ci_dfa[0] - 0.26493).abs() < 0.0001);
+        assert!((ci_dfa[1] - 0.36281).abs() < 0.0001);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_arima_fit() {
+        let column = vec_to_jsvalue(series());
+
+        let ar1 = arima_fit(
+            &column,
+            &JsValue::from_f64(1.0),
+            &JsValue::from_f64(0.0),
+            &JsValue::from_f64(0.0),
+        )
+        .unwrap();
+        let arima111 = arima_fit(
+            &column,
+            &JsValue::from_f64(1.0),
+            &JsValue::from_f64(1.0),
+            &JsValue::from_f64(1.0),
+        )
+        .unwrap();
+
+        let ar1_forecast = ar1.forecast(&JsValue::from_f64(3.0), &JsValue::UNDEFINED);
+        let ar1_mean =
+            js_array_to_vector(&Reflect::get(&ar1_forecast, &JsValue::from_str("mean")).unwrap());
+        let ar1_upper =
+            js_array_to_vector(&Reflect::get(&ar1_forecast, &JsValue::from_str("upper")).unwrap());
+        let forecast = arima111.forecast(&JsValue::from_f64(3.0), &JsValue::UNDEFINED);
+        let mean =
+            js_array_to_vector(&Reflect::get(&forecast, &JsValue::from_str("mean")).unwrap());
+        let lower =
+            js_array_to_vector(&Reflect::get(&forecast, &JsValue::from_str("lower")).unwrap());
+
+        // The conditional AR(1) fit matches least squares on the lagged series
+        assert!((js_array_to_vector(&ar1.ar())[0] - 0.72390).abs() < 0.001);
+        assert!((ar1.mean() - (-0.636598)).abs() < 0.001);
+        assert!((ar1.sigma2() - 0.88292).abs() < 0.001);
+        assert!((ar1.aic() - 138.9546).abs() < 0.01);
+        assert!((ar1_mean[0] - (-1.41363)).abs() < 0.001);
+        assert!((ar1_mean[2] - (-1.04378)).abs() < 0.001);
+        assert!((ar1_upper[1] - 1.07447).abs() < 0.001);
+        assert!((js_array_to_vector(&arima111.ar())[0] - (-0.38509)).abs() < 0.01);
+        assert!((js_array_to_vector(&arima111.ma())[0] - 0.04943).abs() < 0.01);
+        assert!((arima111.aic() - 138.8228).abs() < 0.01);
+        assert!((mean[0] - (-1.01847)).abs() < 0.01);
+        assert!((lower[2] - (-3.90488)).abs() < 0.01);
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_rolling_stats() {
+        let column = vec_to_jsvalue(vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0]);
+        let rolling = |window: f64, statistic: &str| {
+            js_array_to_vector(&rolling_stats(
+                &column,
+                &JsValue::from_f64(window),
+                &JsValue::from_str(statistic),
+            ))
+        };
+
+        let mean = rolling(3.0, "mean");
+        let sd = rolling(3.0, "sd");
+
+        assert_eq!(mean.len(), 8);
+        assert!((mean[0] - 8.0 / 3.0).abs() < 1e-10);
+        assert!((mean[7] - 14.0 / 3.0).abs() < 1e-10);
+        assert!((sd[0] - 1.527525).abs() < 0.0001);
+        assert!((sd[3] - 4.0).abs() < 1e-10);
+        assert_eq!(
+            rolling(3.0, "min"),
+            vec![1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            rolling(3.0, "max"),
+            vec![4.0, 4.0, 5.0, 9.0, 9.0, 9.0, 6.0, 6.0]
+        );
+        assert_eq!(
+            rolling(3.0, "median"),
+            vec![3.0, 1.0, 4.0, 5.0, 5.0, 6.0, 5.0, 5.0]
+        );
+        assert_eq!(
+            rolling(4.0, "median"),
+            vec![2.0, 2.5, 4.5, 3.5, 5.5, 5.5, 4.0]
+        );
+        assert!(rolling_stats(&column, &JsValue::from_f64(11.0), &JsValue::UNDEFINED).is_null());
+    }
+}
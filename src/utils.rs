@@ -1,4 +1,6 @@
-use js_sys;
+use js_sys::{Array, Object, Reflect};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use wasm_bindgen::JsValue;
 
 /// Subtracts two JavaScript arrays and returns the result as a new JavaScript array.
@@ -29,7 +31,7 @@ pub fn subtract_jsvalue_arrays(arr1: &JsValue, arr2: &JsValue) -> JsValue {
 /// * A Vec<f64> containing the converted elements of the JavaScript array.
 pub fn js_array_to_vector(js_array: &JsValue) -> Vec<f64> {
     // Convert the JsValue to a Vec<f64>
-    let array: Vec<JsValue> = js_sys::Array::from(js_array).to_vec(); // Convert to Vec<JsValue>
+    let array: Vec<JsValue> = Array::from(js_array).to_vec(); // Convert to Vec<JsValue>
 
     array
         .into_iter()
@@ -48,7 +50,7 @@ pub fn js_array_to_vector(js_array: &JsValue) -> Vec<f64> {
 /// * A Vec<JsValue> containing the converted elements of the nested JavaScript array.
 pub fn js_nested_array_to_vector(js_array: &JsValue) -> Vec<JsValue> {
     // Convert the JsValue to a Vec<JsValue>
-    js_sys::Array::from(js_array).to_vec() // Convert to Vec<JsValue>
+    Array::from(js_array).to_vec() // Convert to Vec<JsValue>
 }
 
 /// Converts a vector of f64 to a JsValue representing a JavaScript array.
@@ -62,7 +64,7 @@ pub fn js_nested_array_to_vector(js_array: &JsValue) -> Vec<JsValue> {
 /// * A JsValue representing the JavaScript array.
 pub fn vec_to_jsvalue(vec: Vec<f64>) -> JsValue {
     // Create a JavaScript array from the Vec
-    let js_array = js_sys::Array::new();
+    let js_array = Array::new();
     for item in vec {
         js_array.push(&JsValue::from(item));
     }
@@ -81,13 +83,297 @@ pub fn vec_to_jsvalue(vec: Vec<f64>) -> JsValue {
 #[allow(unused)]
 pub fn nested_vec_to_jsvalue(vec: Vec<Vec<f64>>) -> JsValue {
     // Create a JavaScript array from the Vec
-    let js_array = js_sys::Array::new();
+    let js_array = Array::new();
     for item in vec {
         js_array.push(&vec_to_jsvalue(item));
     }
     js_array.into() // Convert the js_sys::Array to JsValue
 }
 
+/// Reads a boolean flag from an optional JavaScript options object.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a JsValue representing an options object (may be undefined).
+/// * `key` - The name of the option to read.
+///
+/// # Returns
+///
+/// * `true` only if the option is present and set to `true`.
+pub fn get_bool_option(options: &JsValue, key: &str) -> bool {
+    if !options.is_object() {
+        return false;
+    }
+    Reflect::get(options, &JsValue::from_str(key))
+        .ok()
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+}
+
+/// Reads a numeric value from an optional JavaScript options object.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a JsValue representing an options object (may be undefined).
+/// * `key` - The name of the option to read.
+///
+/// # Returns
+///
+/// * The numeric value of the option, or `None` if it is missing or not a number.
+pub fn get_f64_option(options: &JsValue, key: &str) -> Option<f64> {
+    if !options.is_object() {
+        return None;
+    }
+    Reflect::get(options, &JsValue::from_str(key))
+        .ok()
+        .and_then(|value| value.as_f64())
+}
+
+/// Reads a string value from an optional JavaScript options object.
+///
+/// # Arguments
+///
+/// * `options` - A reference to a JsValue representing an options object (may be undefined).
+/// * `key` - The name of the option to read.
+///
+/// # Returns
+///
+/// * The string value of the option, or `None` if it is missing or not a string.
+pub fn get_string_option(options: &JsValue, key: &str) -> Option<String> {
+    if !options.is_object() {
+        return None;
+    }
+    Reflect::get(options, &JsValue::from_str(key))
+        .ok()
+        .and_then(|value| value.as_string())
+}
+
+/// Adds the point estimate and its confidence interval to a test result object.
+///
+/// # Arguments
+///
+/// * `obj` - The result object to modify.
+/// * `estimate` - The point estimate of the tested parameter.
+/// * `ci` - The lower and upper bounds of the confidence interval for the estimate.
+pub fn set_estimate(obj: &Object, estimate: f64, ci: (f64, f64)) {
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("estimate"),
+        &JsValue::from_f64(estimate),
+    );
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("ci"),
+        &vec_to_jsvalue(vec![ci.0, ci.1]),
+    );
+}
+
+/// Adds an effect size and the name of its measure to a test result object.
+///
+/// # Arguments
+///
+/// * `obj` - The result object to modify.
+/// * `measure` - The name of the effect size measure (e.g., "cohens_d").
+/// * `value` - The value of the effect size.
+pub fn set_effect_size(obj: &Object, measure: &str, value: f64) {
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("effect_size"),
+        &JsValue::from_f64(value),
+    );
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("effect_size_measure"),
+        &JsValue::from_str(measure),
+    );
+}
+
+/// Computes the pooled standard deviation of two samples for Cohen's d, weighting each
+/// variance by its degrees of freedom so the pooled variance has `n1 + n2 - 2` in the
+/// denominator.
+///
+/// # Arguments
+///
+/// * `(s1, n1)` - The variance and size of the first sample.
+/// * `(s2, n2)` - The variance and size of the second sample.
+///
+/// # Returns
+///
+/// * The pooled standard deviation.
+pub fn pooled_std_dev((s1, n1): (f64, f64), (s2, n2): (f64, f64)) -> f64 {
+    (((n1 - 1.0) * s1 + (n2 - 1.0) * s2) / (n1 + n2 - 2.0)).sqrt()
+}
+
+/// Adds the assumption-check warnings to a test result object.
+///
+/// # Arguments
+///
+/// * `obj` - The result object to modify.
+/// * `warnings` - The warning messages, each naming a suggested alternative.
+pub fn set_warnings(obj: &Object, warnings: Vec<String>) {
+    let array: Array = warnings.iter().map(|w| JsValue::from_str(w)).collect();
+    let _ = Reflect::set(obj, &JsValue::from_str("warnings"), &array);
+}
+
+/// Builds the error object the hypothesis tests return for an unrecognized `tails`
+/// argument.
+///
+/// # Returns
+///
+/// * An object with the property `error` set to "Invalid test type".
+pub fn invalid_test_type() -> JsValue {
+    let obj = Object::new();
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("error"),
+        &JsValue::from_str("Invalid test type"),
+    );
+    obj.into()
+}
+
+/// Inverts a square matrix using Gauss-Jordan elimination with partial pivoting.
+///
+/// # Arguments
+///
+/// * `matrix` - A square matrix represented as a slice of rows.
+///
+/// # Returns
+///
+/// * The inverse of the matrix, or `None` if the matrix is singular, judged by a pivot
+///   below `1e-12` times the largest absolute row sum so the check does not depend on the
+///   matrix's scale.
+pub fn invert_matrix(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let size = matrix.len();
+    let mut left: Vec<Vec<f64>> = matrix.to_vec();
+    let mut right: Vec<Vec<f64>> = (0..size)
+        .map(|i| (0..size).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let norm = matrix
+        .iter()
+        .map(|row| row.iter().map(|v| v.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let tolerance = 1e-12 * norm;
+
+    for col in 0..size {
+        // Pick the row with the largest pivot for numerical stability
+        let pivot =
+            (col..size).max_by(|&a, &b| left[a][col].abs().total_cmp(&left[b][col].abs()))?;
+        if left[pivot][col].abs() <= tolerance || !left[pivot][col].is_finite() {
+            return None;
+        }
+        left.swap(col, pivot);
+        right.swap(col, pivot);
+
+        let scale = left[col][col];
+        for j in 0..size {
+            left[col][j] /= scale;
+            right[col][j] /= scale;
+        }
+
+        for row in 0..size {
+            if row != col {
+                let factor = left[row][col];
+                for j in 0..size {
+                    left[row][j] -= factor * left[col][j];
+                    right[row][j] -= factor * right[col][j];
+                }
+            }
+        }
+    }
+
+    Some(right)
+}
+
+/// Creates a random number generator from a seed, or from entropy if there is none.
+///
+/// # Arguments
+///
+/// * `seed` - The seed, typically read from a JavaScript number.
+///
+/// # Returns
+///
+/// * A seeded `StdRng`.
+pub fn seeded_rng(seed: Option<f64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed as u64),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Sorts values and groups the runs of tied values.
+///
+/// # Arguments
+///
+/// * `values` - The values to group.
+///
+/// # Returns
+///
+/// * The indices of the values in sorted order, and the first and last position in that
+///   order of each run of equal values. Values are ordered with `total_cmp`, so NaN never
+///   panics and sorts after every number.
+pub fn tie_runs(values: &[f64]) -> (Vec<usize>, Vec<(usize, usize)>) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && values[order[end + 1]] == values[order[start]] {
+            end += 1;
+        }
+        runs.push((start, end));
+        start = end + 1;
+    }
+    (order, runs)
+}
+
+/// Ranks values from 1 to n, assigning tied values the average of their ranks.
+///
+/// # Arguments
+///
+/// * `values` - The values to rank.
+///
+/// # Returns
+///
+/// * The rank of each value, in the original order. Values are ordered with `total_cmp`,
+///   so NaN never panics and ranks after every number.
+pub fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let (order, runs) = tie_runs(values);
+    let mut ranks = vec![0.0; values.len()];
+    for (start, end) in runs {
+        let rank = (start + end) as f64 / 2.0 + 1.0;
+        for i in &order[start..=end] {
+            ranks[*i] = rank;
+        }
+    }
+    ranks
+}
+
+/// Computes the Pearson correlation coefficient of two equal-length slices.
+///
+/// # Arguments
+///
+/// * `x` - The first variable.
+/// * `y` - The second variable.
+///
+/// # Returns
+///
+/// * The correlation coefficient, which is NaN if either variable is constant.
+pub fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+    let sxy: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum();
+    let sxx: f64 = x.iter().map(|a| (a - mean_x).powi(2)).sum();
+    let syy: f64 = y.iter().map(|b| (b - mean_y).powi(2)).sum();
+    sxy / (sxx * syy).sqrt()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +381,7 @@ mod tests {
 
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
-    #[allow(unused)]
+    #[allow(unused, clippy::eq_op)]
     #[wasm_bindgen_test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
@@ -127,4 +413,32 @@ mod tests {
             vec![-1.0, -1.0, -1.0, -1.0, -1.0]
         );
     }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_invert_matrix() {
+        let matrix = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+
+        let inverse = invert_matrix(&matrix).unwrap();
+
+        assert!((inverse[0][0] - 0.6).abs() < 1e-9);
+        assert!((inverse[0][1] + 0.7).abs() < 1e-9);
+        assert!((inverse[1][0] + 0.2).abs() < 1e-9);
+        assert!((inverse[1][1] - 0.4).abs() < 1e-9);
+        assert!(invert_matrix(&[vec![1.0, 2.0], vec![2.0, 4.0]]).is_none());
+
+        // The singularity check is relative, so tiny but well-conditioned matrices invert
+        let small = invert_matrix(&[vec![4e-14, 7e-14], vec![2e-14, 6e-14]]).unwrap();
+        assert!((small[0][0] - 0.6e14).abs() < 1e4);
+        assert!(invert_matrix(&[vec![1e14, 2e14], vec![2e14, 4e14 + 1e-3]]).is_none());
+    }
+
+    #[allow(unused)]
+    #[wasm_bindgen_test]
+    fn test_average_ranks() {
+        let ranks = average_ranks(&[3.0, 1.0, 4.0, 1.0, 5.0]);
+
+        assert_eq!(ranks, vec![3.0, 1.5, 4.0, 1.5, 5.0]);
+        assert_eq!(average_ranks(&[2.0, f64::NAN, 1.0]), vec![2.0, 3.0, 1.0]);
+    }
 }